@@ -11,6 +11,12 @@ pub struct CliContext {
     client: Option<Arc<LinearClient>>,
 }
 
+impl Default for CliContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CliContext {
     /// Create a new CLI context
     pub fn new() -> Self {
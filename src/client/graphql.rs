@@ -53,7 +53,7 @@ impl GraphQLClient {
             .json(&request_body)
             .send()
             .await
-            .map_err(|e| LinearError::RequestError(e))?;
+            .map_err(LinearError::RequestError)?;
         
         if !response.status().is_success() {
             let status = response.status();
@@ -67,7 +67,7 @@ impl GraphQLClient {
         response
             .json::<GraphQLResponse>()
             .await
-            .map_err(|e| LinearError::RequestError(e))
+            .map_err(LinearError::RequestError)
     }
     
     /// Extract data from GraphQL response, handling errors
@@ -86,7 +86,7 @@ impl GraphQLClient {
         
         match response.data {
             Some(data) => serde_json::from_value(data)
-                .map_err(|e| LinearError::JsonError(e)),
+                .map_err(LinearError::JsonError),
             None => Err(LinearError::GraphQLError("No data in response".to_string())),
         }
     }
@@ -2,7 +2,7 @@ use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
 use serde::Deserialize;
 use serde_json::{json, Value};
 
-use crate::constants::{COMMENT_FIELDS, ISSUE_FIELDS, LINEAR_API_URL, PROJECT_FIELDS};
+use crate::constants::{COMMENT_FIELDS, CUSTOM_VIEW_FIELDS, DOCUMENT_FIELDS, ISSUE_FIELDS, LABEL_FIELDS, LINEAR_API_URL, PROJECT_FIELDS, WORKFLOW_STATE_FIELDS};
 use crate::models::*;
 use crate::error::LinearError;
 
@@ -60,6 +60,27 @@ impl LinearClient {
             .ok_or("No data returned from GraphQL query".into())
     }
 
+    /// Run a query for a connection page by page, from the cursor in `$after`, until
+    /// the connection `connection` picks out has no next page.
+    async fn execute_paginated<D: for<'de> Deserialize<'de>, T>(
+        &self,
+        query: &str,
+        mut variables: Value,
+        connection: fn(D) -> Connection<T>,
+    ) -> Result<Vec<T>, Box<dyn std::error::Error>> {
+        let mut nodes = Vec::new();
+        loop {
+            let page = connection(self.execute_query(query, Some(variables.clone())).await?);
+            nodes.extend(page.nodes);
+
+            match page.page_info.filter(|page| page.has_next_page).and_then(|page| page.end_cursor) {
+                Some(cursor) => variables["after"] = json!(cursor),
+                None => break,
+            }
+        }
+        Ok(nodes)
+    }
+
     pub async fn get_viewer(&self) -> Result<User, Box<dyn std::error::Error>> {
         let query = r#"
             query {
@@ -67,6 +88,7 @@ impl LinearClient {
                     id
                     name
                     email
+                    displayName
                 }
             }
         "#;
@@ -76,9 +98,19 @@ impl LinearClient {
     }
 
     pub async fn get_issues(&self, filter: Option<Value>, first: Option<i32>) -> Result<Vec<Issue>, Box<dyn std::error::Error>> {
+        self.get_issues_ordered(filter, first, None).await
+    }
+
+    /// Like `get_issues`, newest first by `order_by` (`createdAt` or `updatedAt`).
+    pub async fn get_issues_ordered(
+        &self,
+        filter: Option<Value>,
+        first: Option<i32>,
+        order_by: Option<&str>,
+    ) -> Result<Vec<Issue>, Box<dyn std::error::Error>> {
         let query = format!(r#"
-            query($filter: IssueFilter, $first: Int) {{
-                issues(filter: $filter, first: $first) {{
+            query($filter: IssueFilter, $first: Int, $orderBy: PaginationOrderBy) {{
+                issues(filter: $filter, first: $first, orderBy: $orderBy) {{
                     nodes {{{}}}
                 }}
             }}
@@ -86,13 +118,77 @@ impl LinearClient {
 
         let variables = json!({
             "filter": filter,
-            "first": first.unwrap_or(50)
+            "first": first.unwrap_or(50),
+            "orderBy": order_by
         });
 
         let data: graphql::IssuesData = self.execute_query(&query, Some(variables)).await?;
         Ok(data.issues.nodes)
     }
 
+    /// Every issue matching `filter`, fetched a page at a time.
+    pub async fn get_all_issues(&self, filter: Option<Value>) -> Result<Vec<Issue>, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            query($filter: IssueFilter, $first: Int, $after: String) {{
+                issues(filter: $filter, first: $first, after: $after) {{
+                    nodes {{{}}}
+                    pageInfo {{
+                        hasNextPage
+                        endCursor
+                    }}
+                }}
+            }}
+        "#, ISSUE_FIELDS);
+
+        let variables = json!({
+            "filter": filter,
+            "first": 100
+        });
+        self.execute_paginated(&query, variables, |data: graphql::IssuesData| data.issues).await
+    }
+
+    /// Full-text search over issue titles, descriptions and, with
+    /// `include_comments`, comments. Results come most relevant first.
+    pub async fn search_issues(
+        &self,
+        term: &str,
+        filter: Option<Value>,
+        first: Option<i32>,
+        include_comments: bool,
+    ) -> Result<Vec<IssueSearchResult>, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            query($term: String!, $filter: IssueFilter, $first: Int, $includeComments: Boolean) {{
+                searchIssues(term: $term, filter: $filter, first: $first, includeComments: $includeComments) {{
+                    nodes {{{}
+                        comments(first: 50) {{
+                            nodes {{
+                                id
+                                body
+                                createdAt
+                                updatedAt
+                                user {{
+                                    id
+                                    name
+                                    email
+                                }}
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+        "#, ISSUE_FIELDS);
+
+        let variables = json!({
+            "term": term,
+            "filter": filter,
+            "first": first.unwrap_or(50),
+            "includeComments": include_comments
+        });
+
+        let data: graphql::SearchIssuesData = self.execute_query(&query, Some(variables)).await?;
+        Ok(data.search_issues.nodes)
+    }
+
     pub async fn get_issue_by_identifier(&self, identifier: &str) -> Result<Issue, Box<dyn std::error::Error>> {
         let query = format!(r#"
             query($identifier: String!) {{
@@ -130,14 +226,47 @@ impl LinearClient {
         Ok(data.teams.nodes)
     }
 
+    /// The previous, current and next cycle of every team that uses cycles.
+    pub async fn get_cycles(&self) -> Result<Vec<Cycle>, Box<dyn std::error::Error>> {
+        let query = r#"
+            query {
+                cycles(first: 250, filter: { or: [
+                    { isActive: { eq: true } },
+                    { isPrevious: { eq: true } },
+                    { isNext: { eq: true } }
+                ] }) {
+                    nodes {
+                        id
+                        number
+                        name
+                        startsAt
+                        endsAt
+                        isActive
+                        isPrevious
+                        isNext
+                        team {
+                            id
+                            name
+                            key
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let data: graphql::CyclesData = self.execute_query(query, None).await?;
+        Ok(data.cycles.nodes)
+    }
+
     pub async fn get_team_members(&self) -> Result<Vec<User>, Box<dyn std::error::Error>> {
         let query = r#"
             query {
-                users(first: 100) {
+                users(first: 250) {
                     nodes {
                         id
                         name
                         email
+                        displayName
                     }
                 }
             }
@@ -150,7 +279,7 @@ impl LinearClient {
     pub async fn get_projects(&self) -> Result<Vec<Project>, Box<dyn std::error::Error>> {
         let query = format!(r#"
             query {{
-                projects {{
+                projects(first: 250) {{
                     nodes {{{}}}
                 }}
             }}
@@ -160,6 +289,7 @@ impl LinearClient {
         Ok(data.projects.nodes)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_issue(
         &self,
         title: &str,
@@ -168,6 +298,8 @@ impl LinearClient {
         priority: Option<u8>,
         assignee_id: Option<&str>,
         label_ids: Option<Vec<&str>>,
+        project_id: Option<&str>,
+        estimate: Option<f64>,
     ) -> Result<Issue, Box<dyn std::error::Error>> {
         let query = format!(r#"
             mutation($input: IssueCreateInput!) {{
@@ -195,6 +327,12 @@ impl LinearClient {
         if let Some(labels) = label_ids {
             input["labelIds"] = json!(labels);
         }
+        if let Some(project_id) = project_id {
+            input["projectId"] = json!(project_id);
+        }
+        if let Some(estimate) = estimate {
+            input["estimate"] = json!(estimate);
+        }
 
         let variables = json!({ "input": input });
 
@@ -239,6 +377,7 @@ impl LinearClient {
         Self::check_success(data.project_create.success, data.project_create.project, "Failed to create project")
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_issue(
         &self,
         issue_id: &str,
@@ -252,6 +391,7 @@ impl LinearClient {
         self.update_issue_with_project(issue_id, title, description, state_id, priority, assignee_id, label_ids, None).await
     }
     
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_issue_with_project(
         &self,
         issue_id: &str,
@@ -413,11 +553,16 @@ impl LinearClient {
         Ok(data.issue.comments.nodes)
     }
 
-    pub async fn create_comment(&self, issue_id: &str, body: &str) -> Result<Comment, Box<dyn std::error::Error>> {
+    pub async fn create_comment(
+        &self,
+        issue_id: &str,
+        body: &str,
+        parent_id: Option<&str>,
+    ) -> Result<Comment, Box<dyn std::error::Error>> {
         let query = format!(
             r#"
-            mutation($issueId: String!, $body: String!) {{
-                commentCreate(input: {{ issueId: $issueId, body: $body }}) {{
+            mutation($issueId: String!, $body: String!, $parentId: String) {{
+                commentCreate(input: {{ issueId: $issueId, body: $body, parentId: $parentId }}) {{
                     success
                     comment {{
                         {}
@@ -427,7 +572,7 @@ impl LinearClient {
             "#,
             COMMENT_FIELDS
         );
-        let variables = json!({ "issueId": issue_id, "body": body });
+        let variables = json!({ "issueId": issue_id, "body": body, "parentId": parent_id });
         let data: graphql::CommentCreateData = self.execute_query(&query, Some(variables)).await?;
         
         if data.comment_create.success {
@@ -437,6 +582,42 @@ impl LinearClient {
         }
     }
 
+    /// Look up the ID of the issue a comment belongs to
+    pub async fn get_comment_issue_id(&self, comment_id: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let query = r#"
+            query($id: String!) {
+                comment(id: $id) {
+                    issue {
+                        id
+                    }
+                }
+            }
+        "#;
+        let variables = json!({ "id": comment_id });
+
+        #[derive(Debug, Deserialize)]
+        struct CommentIssueData {
+            comment: CommentWithIssue,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct CommentWithIssue {
+            issue: Option<IssueRef>,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct IssueRef {
+            id: String,
+        }
+
+        let data: CommentIssueData = self.execute_query(query, Some(variables)).await?;
+
+        data.comment
+            .issue
+            .map(|i| i.id)
+            .ok_or_else(|| format!("Comment {} is not attached to an issue", comment_id).into())
+    }
+
     pub async fn update_comment(&self, comment_id: &str, body: &str) -> Result<Comment, Box<dyn std::error::Error>> {
         let query = format!(
             r#"
@@ -475,6 +656,47 @@ impl LinearClient {
         Ok(data.comment_delete.success)
     }
 
+    pub async fn create_reaction(&self, comment_id: &str, emoji: &str) -> Result<Reaction, Box<dyn std::error::Error>> {
+        let query = r#"
+            mutation($commentId: String!, $emoji: String!) {
+                reactionCreate(input: { commentId: $commentId, emoji: $emoji }) {
+                    success
+                    reaction {
+                        id
+                        emoji
+                        user {
+                            id
+                            name
+                            email
+                        }
+                    }
+                }
+            }
+        "#;
+        let variables = json!({ "commentId": comment_id, "emoji": emoji });
+        let data: graphql::ReactionCreateData = self.execute_query(query, Some(variables)).await?;
+
+        if data.reaction_create.success {
+            data.reaction_create.reaction.ok_or("Failed to add reaction".into())
+        } else {
+            Err("Failed to add reaction".into())
+        }
+    }
+
+    pub async fn delete_reaction(&self, reaction_id: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        let query = r#"
+            mutation($id: String!) {
+                reactionDelete(id: $id) {
+                    success
+                }
+            }
+        "#;
+        let variables = json!({ "id": reaction_id });
+        let data: graphql::ReactionDeleteData = self.execute_query(query, Some(variables)).await?;
+
+        Ok(data.reaction_delete.success)
+    }
+
     pub async fn update_issue_bulk(
         &self,
         issue_id: &str,
@@ -500,12 +722,10 @@ impl LinearClient {
             input["priority"] = json!(priority);
         }
         if let Some(add_labels) = add_label_ids {
-            input["labelIds"] = json!(add_labels);
+            input["addedLabelIds"] = json!(add_labels);
         }
         if let Some(remove_labels) = remove_label_ids {
-            // For removing labels, we need to get current labels and filter them
-            // This is a simplified version - in production, you'd want to fetch current labels first
-            input["removeLabelIds"] = json!(remove_labels);
+            input["removedLabelIds"] = json!(remove_labels);
         }
         
         let query = format!(
@@ -531,20 +751,19 @@ impl LinearClient {
         Self::check_success(data.issue_update.success, data.issue_update.issue, "Failed to update issue")
     }
 
+    /// Workflow states of every team, each tagged with the team it belongs to.
     pub async fn get_workflow_states(&self) -> Result<Vec<WorkflowState>, Box<dyn std::error::Error>> {
-        let query = r#"
-            query {
-                workflowStates(first: 50) {
-                    nodes {
-                        id
-                        name
-                        type
-                        color
-                        position
-                    }
-                }
-            }
-        "#;
+        let query = format!(r#"
+            query($first: Int, $after: String) {{
+                workflowStates(first: $first, after: $after) {{
+                    nodes {{{}}}
+                    pageInfo {{
+                        hasNextPage
+                        endCursor
+                    }}
+                }}
+            }}
+        "#, WORKFLOW_STATE_FIELDS);
         
         #[derive(Debug, Deserialize)]
         struct WorkflowStatesData {
@@ -552,8 +771,80 @@ impl LinearClient {
             workflow_states: Connection<WorkflowState>,
         }
         
-        let data: WorkflowStatesData = self.execute_query(query, None).await?;
-        Ok(data.workflow_states.nodes)
+        let variables = json!({ "first": 250 });
+        self.execute_paginated(&query, variables, |data: WorkflowStatesData| data.workflow_states).await
+    }
+
+    pub async fn create_workflow_state(
+        &self,
+        team_id: &str,
+        name: &str,
+        state_type: &str,
+        color: &str,
+        position: Option<f64>,
+        description: Option<&str>,
+    ) -> Result<WorkflowState, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            mutation($input: WorkflowStateCreateInput!) {{
+                workflowStateCreate(input: $input) {{
+                    success
+                    workflowState {{{}}}
+                }}
+            }}
+        "#, WORKFLOW_STATE_FIELDS);
+
+        let mut input = json!({
+            "teamId": team_id,
+            "name": name,
+            "type": state_type,
+            "color": color
+        });
+
+        if let Some(position) = position {
+            input["position"] = json!(position);
+        }
+        if let Some(description) = description {
+            input["description"] = json!(description);
+        }
+
+        let variables = json!({ "input": input });
+
+        let data: graphql::WorkflowStateCreateData = self.execute_query(&query, Some(variables)).await?;
+        Self::check_success(data.workflow_state_create.success, data.workflow_state_create.workflow_state, "Failed to create workflow state")
+    }
+
+    pub async fn update_workflow_state(
+        &self,
+        state_id: &str,
+        name: Option<&str>,
+        color: Option<&str>,
+        position: Option<f64>,
+    ) -> Result<WorkflowState, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            mutation($id: String!, $input: WorkflowStateUpdateInput!) {{
+                workflowStateUpdate(id: $id, input: $input) {{
+                    success
+                    workflowState {{{}}}
+                }}
+            }}
+        "#, WORKFLOW_STATE_FIELDS);
+
+        let mut input = json!({});
+
+        if let Some(name) = name {
+            input["name"] = json!(name);
+        }
+        if let Some(color) = color {
+            input["color"] = json!(color);
+        }
+        if let Some(position) = position {
+            input["position"] = json!(position);
+        }
+
+        let variables = json!({ "id": state_id, "input": input });
+
+        let data: graphql::WorkflowStateUpdateData = self.execute_query(&query, Some(variables)).await?;
+        Self::check_success(data.workflow_state_update.success, data.workflow_state_update.workflow_state, "Failed to update workflow state")
     }
 
     pub async fn get_labels(&self) -> Result<Vec<crate::models::issue::Label>, Box<dyn std::error::Error>> {
@@ -579,6 +870,325 @@ impl LinearClient {
         Ok(data.issue_labels.nodes)
     }
 
+    /// Documents, most recently updated first, optionally limited to one project.
+    pub async fn get_documents(&self, project_id: Option<&str>, limit: Option<i32>) -> Result<Vec<Document>, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            query($first: Int, $filter: DocumentFilter) {{
+                documents(first: $first, filter: $filter, orderBy: updatedAt) {{
+                    nodes {{{}}}
+                }}
+            }}
+        "#, DOCUMENT_FIELDS);
+
+        let filter = project_id.map(|id| json!({ "project": { "id": { "eq": id } } }));
+        let variables = json!({
+            "first": limit.unwrap_or(50),
+            "filter": filter,
+        });
+
+        let data: graphql::DocumentsData = self.execute_query(&query, Some(variables)).await?;
+        Ok(data.documents.nodes)
+    }
+
+    /// A single document with its content. Accepts the document id or slug id.
+    pub async fn get_document(&self, id: &str) -> Result<Document, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            query($id: String!) {{
+                document(id: $id) {{
+                    {}
+                    content
+                }}
+            }}
+        "#, DOCUMENT_FIELDS);
+
+        let variables = json!({ "id": id });
+
+        let data: graphql::DocumentData = self.execute_query(&query, Some(variables)).await?;
+        Ok(data.document)
+    }
+
+    pub async fn create_document(
+        &self,
+        title: &str,
+        content: &str,
+        project_id: &str,
+    ) -> Result<Document, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            mutation($input: DocumentCreateInput!) {{
+                documentCreate(input: $input) {{
+                    success
+                    document {{
+                        {}
+                        content
+                    }}
+                }}
+            }}
+        "#, DOCUMENT_FIELDS);
+
+        let variables = json!({
+            "input": {
+                "title": title,
+                "content": content,
+                "projectId": project_id,
+            }
+        });
+
+        let data: graphql::DocumentCreateData = self.execute_query(&query, Some(variables)).await?;
+        Self::check_success(data.document_create.success, data.document_create.document, "Failed to create document")
+    }
+
+    pub async fn update_document(
+        &self,
+        document_id: &str,
+        title: Option<&str>,
+        content: Option<&str>,
+    ) -> Result<Document, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            mutation($id: String!, $input: DocumentUpdateInput!) {{
+                documentUpdate(id: $id, input: $input) {{
+                    success
+                    document {{
+                        {}
+                        content
+                    }}
+                }}
+            }}
+        "#, DOCUMENT_FIELDS);
+
+        let mut input = json!({});
+
+        if let Some(title) = title {
+            input["title"] = json!(title);
+        }
+        if let Some(content) = content {
+            input["content"] = json!(content);
+        }
+
+        let variables = json!({
+            "id": document_id,
+            "input": input,
+        });
+
+        let data: graphql::DocumentUpdateData = self.execute_query(&query, Some(variables)).await?;
+        Self::check_success(data.document_update.success, data.document_update.document, "Failed to update document")
+    }
+
+    /// Custom views visible to the current user, shared or personal.
+    pub async fn get_custom_views(&self) -> Result<Vec<CustomView>, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            query {{
+                customViews(first: 250) {{
+                    nodes {{{}}}
+                }}
+            }}
+        "#, CUSTOM_VIEW_FIELDS);
+
+        let data: graphql::CustomViewsData = self.execute_query(&query, None).await?;
+        Ok(data.custom_views.nodes)
+    }
+
+    pub async fn create_custom_view(
+        &self,
+        name: &str,
+        filter_data: Value,
+        team_id: Option<&str>,
+        shared: bool,
+    ) -> Result<CustomView, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            mutation($input: CustomViewCreateInput!) {{
+                customViewCreate(input: $input) {{
+                    success
+                    customView {{{}}}
+                }}
+            }}
+        "#, CUSTOM_VIEW_FIELDS);
+
+        let mut input = json!({
+            "name": name,
+            "filterData": filter_data,
+            "shared": shared,
+        });
+
+        if let Some(team_id) = team_id {
+            input["teamId"] = json!(team_id);
+        }
+
+        let variables = json!({ "input": input });
+
+        let data: graphql::CustomViewCreateData = self.execute_query(&query, Some(variables)).await?;
+        Self::check_success(data.custom_view_create.success, data.custom_view_create.custom_view, "Failed to create custom view")
+    }
+
+    pub async fn update_custom_view_filter(
+        &self,
+        view_id: &str,
+        filter_data: Value,
+    ) -> Result<CustomView, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            mutation($id: String!, $input: CustomViewUpdateInput!) {{
+                customViewUpdate(id: $id, input: $input) {{
+                    success
+                    customView {{{}}}
+                }}
+            }}
+        "#, CUSTOM_VIEW_FIELDS);
+
+        let variables = json!({
+            "id": view_id,
+            "input": { "filterData": filter_data },
+        });
+
+        let data: graphql::CustomViewUpdateData = self.execute_query(&query, Some(variables)).await?;
+        Self::check_success(data.custom_view_update.success, data.custom_view_update.custom_view, "Failed to update custom view")
+    }
+
+    /// Templates defined in Linear, across the workspace and all teams.
+    pub async fn get_templates(&self) -> Result<Vec<Template>, Box<dyn std::error::Error>> {
+        let query = r#"
+            query {
+                templates {
+                    id
+                    name
+                    type
+                    description
+                    templateData
+                    team {
+                        id
+                        name
+                        key
+                    }
+                }
+            }
+        "#;
+
+        let data: graphql::TemplatesData = self.execute_query(query, None).await?;
+        Ok(data.templates)
+    }
+
+    /// Labels with their group and team information. When `team_id` is given, only
+    /// that team's labels and workspace-wide labels are returned.
+    pub async fn get_issue_labels(&self, team_id: Option<&str>) -> Result<Vec<IssueLabel>, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            query($filter: IssueLabelFilter, $first: Int, $after: String) {{
+                issueLabels(filter: $filter, first: $first, after: $after) {{
+                    nodes {{{}}}
+                    pageInfo {{
+                        hasNextPage
+                        endCursor
+                    }}
+                }}
+            }}
+        "#, LABEL_FIELDS);
+
+        let filter = team_id.map(|id| json!({
+            "or": [
+                { "team": { "id": { "eq": id } } },
+                { "team": { "null": true } }
+            ]
+        }));
+        let variables = json!({ "filter": filter, "first": 250 });
+
+        #[derive(Debug, Deserialize)]
+        struct IssueLabelsData {
+            #[serde(rename = "issueLabels")]
+            issue_labels: Connection<IssueLabel>,
+        }
+
+        self.execute_paginated(&query, variables, |data: IssueLabelsData| data.issue_labels).await
+    }
+
+    pub async fn create_label(
+        &self,
+        name: &str,
+        color: Option<&str>,
+        team_id: Option<&str>,
+        parent_id: Option<&str>,
+        is_group: bool,
+        description: Option<&str>,
+    ) -> Result<IssueLabel, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            mutation($input: IssueLabelCreateInput!) {{
+                issueLabelCreate(input: $input) {{
+                    success
+                    issueLabel {{{}}}
+                }}
+            }}
+        "#, LABEL_FIELDS);
+
+        let mut input = json!({ "name": name });
+
+        if let Some(color) = color {
+            input["color"] = json!(color);
+        }
+        if let Some(team_id) = team_id {
+            input["teamId"] = json!(team_id);
+        }
+        if let Some(parent_id) = parent_id {
+            input["parentId"] = json!(parent_id);
+        }
+        if is_group {
+            input["isGroup"] = json!(true);
+        }
+        if let Some(description) = description {
+            input["description"] = json!(description);
+        }
+
+        let variables = json!({ "input": input });
+
+        let data: graphql::IssueLabelCreateData = self.execute_query(&query, Some(variables)).await?;
+        Self::check_success(data.issue_label_create.success, data.issue_label_create.issue_label, "Failed to create label")
+    }
+
+    pub async fn update_label(
+        &self,
+        label_id: &str,
+        name: Option<&str>,
+        color: Option<&str>,
+        parent_id: Option<&str>,
+    ) -> Result<IssueLabel, Box<dyn std::error::Error>> {
+        let query = format!(r#"
+            mutation($id: String!, $input: IssueLabelUpdateInput!) {{
+                issueLabelUpdate(id: $id, input: $input) {{
+                    success
+                    issueLabel {{{}}}
+                }}
+            }}
+        "#, LABEL_FIELDS);
+
+        let mut input = json!({});
+
+        if let Some(name) = name {
+            input["name"] = json!(name);
+        }
+        if let Some(color) = color {
+            input["color"] = json!(color);
+        }
+        if let Some(parent_id) = parent_id {
+            input["parentId"] = json!(parent_id);
+        }
+
+        let variables = json!({ "id": label_id, "input": input });
+
+        let data: graphql::IssueLabelUpdateData = self.execute_query(&query, Some(variables)).await?;
+        Self::check_success(data.issue_label_update.success, data.issue_label_update.issue_label, "Failed to update label")
+    }
+
+    pub async fn archive_label(&self, label_id: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        let query = r#"
+            mutation($id: String!) {
+                issueLabelArchive(id: $id) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = json!({ "id": label_id });
+
+        let data: graphql::IssueLabelArchiveData = self.execute_query(query, Some(variables)).await?;
+
+        Ok(data.issue_label_archive.success)
+    }
+
     pub async fn move_issue(
         &self,
         issue_id: &str,
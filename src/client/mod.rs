@@ -2,4 +2,5 @@ pub mod linear_client;
 pub mod graphql;
 
 pub use linear_client::LinearClient;
+#[allow(unused_imports)]
 pub use graphql::{GraphQLClient, QueryBuilder, MutationBuilder};
\ No newline at end of file
@@ -2,6 +2,7 @@ use clap::ArgMatches;
 use colored::*;
 use crate::cli_context::CliContext;
 use crate::error::{LinearError, LinearResult, ErrorContext};
+use crate::resolver::Resolver;
 
 fn parse_issue_ids(matches: &ArgMatches) -> Vec<String> {
     let mut ids = Vec::new();
@@ -21,6 +22,41 @@ fn parse_issue_ids(matches: &ArgMatches) -> Vec<String> {
     ids
 }
 
+/// State and label ids resolved against one issue's team.
+struct IssueUpdateIds {
+    state_id: Option<String>,
+    add_label_ids: Option<Vec<String>>,
+    remove_label_ids: Option<Vec<String>>,
+}
+
+async fn resolve_for_issue(
+    resolver: &mut Resolver<'_>,
+    issue_id: &str,
+    state: Option<&String>,
+    labels: Option<&[String]>,
+    remove_labels: Option<&[String]>,
+) -> LinearResult<IssueUpdateIds> {
+    if state.is_none() && labels.is_none() && remove_labels.is_none() {
+        return Ok(IssueUpdateIds { state_id: None, add_label_ids: None, remove_label_ids: None });
+    }
+
+    let team_id = resolver.issue(issue_id).await?.team.id;
+    let state_id = match state {
+        Some(state) => Some(resolver.state(&team_id, state).await?.id),
+        None => None,
+    };
+    let add_label_ids = match labels {
+        Some(labels) => Some(resolver.label_ids(labels, Some(&team_id)).await?),
+        None => None,
+    };
+    let remove_label_ids = match remove_labels {
+        Some(labels) => Some(resolver.label_ids(labels, Some(&team_id)).await?),
+        None => None,
+    };
+
+    Ok(IssueUpdateIds { state_id, add_label_ids, remove_label_ids })
+}
+
 pub async fn handle_bulk_update(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
     handle_bulk_update_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
 }
@@ -34,8 +70,8 @@ async fn handle_bulk_update_impl(matches: &ArgMatches) -> LinearResult<()> {
         return Err(LinearError::InvalidInput("No issue IDs provided".to_string()));
     }
     
-    let state_id = matches.get_one::<String>("state");
-    let assignee_id = matches.get_one::<String>("assignee");
+    let state = matches.get_one::<String>("state");
+    let assignee = matches.get_one::<String>("assignee");
     let priority = matches.get_one::<String>("priority")
         .and_then(|p| p.parse::<u8>().ok());
     let labels = matches.get_one::<String>("labels")
@@ -43,7 +79,7 @@ async fn handle_bulk_update_impl(matches: &ArgMatches) -> LinearResult<()> {
     let remove_labels = matches.get_one::<String>("remove-labels")
         .map(|l| l.split(',').map(|s| s.trim().to_string()).collect::<Vec<_>>());
     
-    if state_id.is_none() && assignee_id.is_none() && priority.is_none() && labels.is_none() && remove_labels.is_none() {
+    if state.is_none() && assignee.is_none() && priority.is_none() && labels.is_none() && remove_labels.is_none() {
         return Err(LinearError::InvalidInput("No update parameters provided. Use --state, --assignee, --priority, --labels, or --remove-labels".to_string()));
     }
     
@@ -52,14 +88,32 @@ async fn handle_bulk_update_impl(matches: &ArgMatches) -> LinearResult<()> {
     let mut success_count = 0;
     let mut failed_ids = Vec::new();
     
+    let mut resolver = Resolver::new(&client);
+
+    // An empty assignee id unassigns the issues
+    let assignee_id = match assignee {
+        Some(assignee) => Some(resolver.assignee_id(assignee).await?.unwrap_or_default()),
+        None => None,
+    };
+
     for issue_id in &issue_ids {
+        // Issues may belong to different teams, each with its own states and labels
+        let ids = match resolve_for_issue(&mut resolver, issue_id, state, labels.as_deref(), remove_labels.as_deref()).await {
+            Ok(ids) => ids,
+            Err(e) => {
+                failed_ids.push(issue_id.clone());
+                println!("  ✗ Failed to update {}: {}", issue_id.bright_red(), e);
+                continue;
+            }
+        };
+
         match client.update_issue_bulk(
             issue_id,
-            state_id.map(|s| s.as_str()),
-            assignee_id.map(|s| s.as_str()),
+            ids.state_id.as_deref(),
+            assignee_id.as_deref(),
             priority,
-            labels.as_ref().map(|v| v.as_slice()),
-            remove_labels.as_ref().map(|v| v.as_slice()),
+            ids.add_label_ids.as_deref(),
+            ids.remove_label_ids.as_deref(),
         ).await {
             Ok(_) => {
                 success_count += 1;
@@ -94,12 +148,22 @@ async fn handle_bulk_move_impl(matches: &ArgMatches) -> LinearResult<()> {
         return Err(LinearError::InvalidInput("No issue IDs provided".to_string()));
     }
     
-    let team_id = matches.get_one::<String>("team");
-    let project_id = matches.get_one::<String>("project");
+    let team = matches.get_one::<String>("team");
+    let project = matches.get_one::<String>("project");
     
-    if team_id.is_none() && project_id.is_none() {
+    if team.is_none() && project.is_none() {
         return Err(LinearError::InvalidInput("No move parameters provided. Use --team or --project".to_string()));
     }
+
+    let mut resolver = Resolver::new(&client);
+    let team_id = match team {
+        Some(team) => Some(resolver.team(team).await?.id),
+        None => None,
+    };
+    let project_id = match project {
+        Some(project) => Some(resolver.project(project).await?.id),
+        None => None,
+    };
     
     println!("Moving {} issues...", issue_ids.len());
     
@@ -109,8 +173,8 @@ async fn handle_bulk_move_impl(matches: &ArgMatches) -> LinearResult<()> {
     for issue_id in &issue_ids {
         match client.move_issue(
             issue_id,
-            team_id.map(|s| s.as_str()),
-            project_id.map(|s| s.as_str()),
+            team_id.as_deref(),
+            project_id.as_deref(),
         ).await {
             Ok(_) => {
                 success_count += 1;
@@ -1,8 +1,13 @@
 use clap::ArgMatches;
 use crate::cli_context::CliContext;
 use crate::error::{LinearError, LinearResult, ErrorContext};
-use crate::formatting::markdown::format_markdown;
+use crate::formatting::hyperlink::hyperlink;
+use crate::formatting::markdown::{format_markdown, format_markdown_width};
+use crate::formatting::table::terminal_width;
+use crate::formatting::output::print_records;
+use crate::formatting::template::OutputTemplate;
 use crate::formatting::utils::format_relative_time;
+use crate::models::thread_comments;
 use colored::*;
 
 pub async fn handle_list_comments(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
@@ -24,26 +29,50 @@ async fn handle_list_comments_impl(matches: &ArgMatches) -> LinearResult<()> {
         .map_err(|e| LinearError::ApiError(format!("Failed to get comments: {}", e)))
         .context("Getting comments for issue")?;
     
+    let format = matches.get_one::<String>("format").map(|s| s.as_str()).unwrap_or("simple");
+    let template = OutputTemplate::from_args(matches.get_one::<String>("template"), matches.get_one::<String>("template-file"))?;
+    if let Some(template) = &template {
+        template.print(&comments);
+        return Ok(());
+    }
+    if print_records(&comments, format) {
+        return Ok(());
+    }
     if comments.is_empty() {
         println!("No comments found on issue {}.", issue_identifier);
     } else {
-        println!("Comments on {} - {}:", issue.identifier, issue.title);
+        println!("Comments on {} - {}:", hyperlink(&issue.identifier, &issue.url), issue.title);
         println!("{}", "─".repeat(80));
         
-        for comment in comments {
-            println!("\n{} {} - {}", 
-                "▸".bright_blue(),
+        for (depth, comment) in thread_comments(&comments) {
+            let indent = "    ".repeat(depth);
+            let marker = if depth > 0 { "↳" } else { "▸" };
+            println!("\n{}{} {} - {} {}", 
+                indent,
+                marker.bright_blue(),
                 comment.user.as_ref().map(|u| u.name.as_str()).unwrap_or("Unknown").bright_cyan(),
-                format_relative_time(&comment.created_at).dimmed()
+                format_relative_time(&comment.created_at).dimmed(),
+                format!("[{}]", comment.id).dimmed()
             );
             if comment.created_at != comment.updated_at {
-                println!("  {} {}", 
+                println!("{}  {} {}", 
+                    indent,
                     "Updated:".dimmed(),
                     format_relative_time(&comment.updated_at).dimmed()
                 );
             }
-            println!("\n{}", format_markdown(&comment.body));
-            println!("{}", "─".repeat(40).dimmed());
+            println!();
+            for line in format_markdown_width(&comment.body, terminal_width().saturating_sub(indent.len())).lines() {
+                println!("{}{}", indent, line);
+            }
+            let reactions = comment.reaction_summary();
+            if !reactions.is_empty() {
+                let summary: Vec<String> = reactions.iter()
+                    .map(|(emoji, count)| format!("{} {}", emoji, count))
+                    .collect();
+                println!("{}{}", indent, summary.join("  "));
+            }
+            println!("{}{}", indent, "─".repeat(40).dimmed());
         }
     }
     
@@ -67,12 +96,41 @@ async fn handle_add_comment_impl(matches: &ArgMatches) -> LinearResult<()> {
     let issue = client.get_issue_by_identifier(issue_identifier).await
         .map_err(|e| LinearError::ApiError(format!("Failed to get issue: {}", e)))
         .context("Getting issue by identifier")?;
-    let comment = client.create_comment(&issue.id, body).await
+    let comment = client.create_comment(&issue.id, body, None).await
         .map_err(|e| LinearError::ApiError(format!("Failed to create comment: {}", e)))
         .context("Creating comment")?;
     
     println!("✅ Comment added successfully!");
-    println!("Issue: {} - {}", issue.identifier, issue.title);
+    println!("Issue: {} - {}", hyperlink(&issue.identifier, &issue.url), issue.title);
+    println!("Comment by: {}", comment.user.as_ref().map(|u| u.name.as_str()).unwrap_or("Unknown"));
+    println!("\n{}", format_markdown(&comment.body));
+    
+    Ok(())
+}
+
+pub async fn handle_reply_comment(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_reply_comment_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_reply_comment_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+    
+    let parent_id = matches.get_one::<String>("id")
+        .ok_or_else(|| LinearError::InvalidInput("Comment ID is required".to_string()))?;
+    let body = matches.get_one::<String>("body")
+        .ok_or_else(|| LinearError::InvalidInput("Comment body is required".to_string()))?;
+    
+    // Replies must be attached to the same issue as their parent
+    let issue_id = client.get_comment_issue_id(parent_id).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to get parent comment: {}", e)))
+        .context("Getting parent comment")?;
+    let comment = client.create_comment(&issue_id, body, Some(parent_id)).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to create reply: {}", e)))
+        .context("Creating reply")?;
+    
+    println!("✅ Reply added successfully!");
+    println!("In reply to: {}", parent_id);
     println!("Comment by: {}", comment.user.as_ref().map(|u| u.name.as_str()).unwrap_or("Unknown"));
     println!("\n{}", format_markdown(&comment.body));
     
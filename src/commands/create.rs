@@ -1,8 +1,15 @@
+use std::collections::HashMap;
+
+use chrono::Local;
 use clap::ArgMatches;
 use colored::*;
 use crate::cli_context::CliContext;
 use crate::config::load_config;
 use crate::error::{LinearError, LinearResult, ErrorContext};
+use crate::filtering::parse_priority;
+use crate::formatting::hyperlink::hyperlink;
+use crate::resolver::Resolver;
+use crate::templates::{find_template, load_templates, placeholders};
 
 pub async fn handle_create_issue(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
     handle_create_issue_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
@@ -12,54 +19,104 @@ async fn handle_create_issue_impl(matches: &ArgMatches) -> LinearResult<()> {
     let mut context = CliContext::load().context("Failed to load CLI context")?;
     let client = context.verified_client().context("Failed to get Linear client")?;
 
-    let title = matches.get_one::<String>("title")
-        .ok_or_else(|| LinearError::InvalidInput("Title is required".to_string()))?;
-    let description = matches.get_one::<String>("description");
-    
-    // Get team ID
-    let team_id = if let Some(team_key) = matches.get_one::<String>("team") {
-        let teams = client.get_teams().await
-            .map_err(|e| LinearError::ApiError(format!("Failed to get teams: {}", e)))
-            .context("Getting teams for team lookup")?;
-        teams.iter()
-            .find(|t| t.key == *team_key)
-            .map(|t| t.id.clone())
-            .ok_or_else(|| LinearError::InvalidInput(format!("Team '{}' not found", team_key)))?
-    } else {
-        let config = load_config();
-        config.default_team_id
-            .ok_or_else(|| LinearError::InvalidInput("No team specified and no default team configured".to_string()))?
+    let template = match matches.get_one::<String>("template") {
+        Some(name) => {
+            let templates = load_templates(&client).await?;
+            Some(find_template(&templates, name)?.clone())
+        }
+        None => None,
+    };
+
+    let mut vars: HashMap<String, String> = HashMap::new();
+    vars.insert("date".to_string(), Local::now().format("%Y-%m-%d").to_string());
+    if let Some(title) = matches.get_one::<String>("title") {
+        vars.insert("title".to_string(), title.clone());
+    }
+    for var in matches.get_many::<String>("var").into_iter().flatten() {
+        let (key, value) = var.split_once('=')
+            .ok_or_else(|| LinearError::InvalidInput(format!("Invalid --var '{}'. Use KEY=VALUE", var)))?;
+        vars.insert(key.trim().to_string(), value.to_string());
+    }
+
+    let title = match &template {
+        Some(template) => template.render_title(&vars),
+        None => vars.get("title").cloned(),
+    }
+    .ok_or_else(|| LinearError::InvalidInput("Title is required".to_string()))?;
+    let description = match matches.get_one::<String>("description") {
+        Some(description) => Some(description.clone()),
+        None => template.as_ref().and_then(|t| t.render_description(&vars)),
+    };
+
+    let unfilled = placeholders(&format!("{}\n{}", title, description.as_deref().unwrap_or("")));
+    if !unfilled.is_empty() {
+        eprintln!(
+            "{} Unfilled template placeholders: {} (set them with --var KEY=VALUE)",
+            "⚠️".yellow(),
+            unfilled.join(", ")
+        );
+    }
+
+    let mut resolver = Resolver::new(&client);
+
+    // Get team ID: flag, then template, then the configured default
+    let team_id = match matches.get_one::<String>("team").or(template.as_ref().and_then(|t| t.team.as_ref())) {
+        Some(team) => resolver.team(team).await?.id,
+        None => {
+            let config = load_config();
+            config.default_team_id
+                .ok_or_else(|| LinearError::InvalidInput("No team specified and no default team configured".to_string()))?
+        }
     };
 
     let priority = matches.get_one::<String>("priority")
-        .and_then(|p| match p.as_str() {
-            "none" | "0" => Some(0),
-            "low" | "1" => Some(1),
-            "medium" | "2" => Some(2),
-            "high" | "3" => Some(3),
-            "urgent" | "4" => Some(4),
-            _ => None,
-        });
-
-    let assignee_id = matches.get_one::<String>("assignee");
-    let label_ids: Option<Vec<&str>> = matches.get_many::<String>("labels")
-        .map(|labels| labels.map(|s| s.as_str()).collect());
+        .and_then(|p| parse_priority(p))
+        .or(template.as_ref().and_then(|t| t.priority));
+
+    let assignee_id = match matches.get_one::<String>("assignee") {
+        Some(assignee) => resolver.assignee_id(assignee).await?,
+        None => None,
+    };
+
+    // Labels from the template and the command line are combined
+    let mut labels: Vec<String> = template.as_ref().map(|t| t.labels.clone()).unwrap_or_default();
+    labels.extend(matches.get_many::<String>("labels").into_iter().flatten().cloned());
+    let label_ids = if labels.is_empty() {
+        None
+    } else {
+        let mut ids = resolver.label_ids(&labels, Some(&team_id)).await?;
+        ids.sort();
+        ids.dedup();
+        Some(ids)
+    };
+
+    let project_id = match matches.get_one::<String>("project").or(template.as_ref().and_then(|t| t.project.as_ref())) {
+        Some(project) => Some(resolver.project(project).await?.id),
+        None => None,
+    };
+    let estimate = match matches.get_one::<String>("estimate") {
+        Some(estimate) => Some(estimate.parse::<f64>()
+            .map_err(|_| LinearError::InvalidInput(format!("Invalid estimate '{}'", estimate)))?),
+        None => template.as_ref().and_then(|t| t.estimate),
+    };
 
     let issue = client.create_issue(
-        title,
-        description.map(|s| s.as_str()),
+        &title,
+        description.as_deref(),
         &team_id,
         priority,
-        assignee_id.map(|s| s.as_str()),
-        label_ids,
+        assignee_id.as_deref(),
+        label_ids.as_ref().map(|ids| ids.iter().map(|s| s.as_str()).collect()),
+        project_id.as_deref(),
+        estimate,
     ).await
         .map_err(|e| LinearError::ApiError(format!("Failed to create issue: {}", e)))
         .context("Creating issue")?;
 
     println!("{} {}", "✅".green(), "Issue created successfully!".green().bold());
-    println!("{}: {}", "ID".bold(), issue.identifier.bright_blue().bold());
+    println!("{}: {}", "ID".bold(), hyperlink(&issue.identifier, &issue.url).bright_blue().bold());
     println!("{}: {}", "Title".bold(), issue.title);
-    println!("{}: {}", "URL".bold(), issue.url.bright_black());
+    println!("{}: {}", "URL".bold(), hyperlink(&issue.url, &issue.url).bright_black());
     println!("{}: {}", "Team".bold(), issue.team.name);
     println!("{}: {}", "State".bold(), issue.state.name);
 
@@ -78,15 +135,15 @@ async fn handle_create_project_impl(matches: &ArgMatches) -> LinearResult<()> {
         .ok_or_else(|| LinearError::InvalidInput("Project name is required".to_string()))?;
     let description = matches.get_one::<String>("description");
     
-    let mut team_ids: Vec<String> = matches.get_many::<String>("teams")
-        .map(|teams| teams.cloned().collect())
-        .unwrap_or_else(Vec::new);
+    let mut resolver = Resolver::new(&client);
+    let mut team_ids: Vec<String> = Vec::new();
+    for team in matches.get_many::<String>("teams").into_iter().flatten() {
+        team_ids.push(resolver.team(team).await?.id);
+    }
 
     // If no teams specified, get the first available team
     if team_ids.is_empty() {
-        let teams = client.get_teams().await
-            .map_err(|e| LinearError::ApiError(format!("Failed to get teams: {}", e)))
-            .context("Getting teams list")?;
+        let teams = resolver.teams().await?;
         if teams.is_empty() {
             return Err(LinearError::InvalidInput("No teams found. Projects require at least one team.".to_string()));
         }
@@ -104,13 +161,13 @@ async fn handle_create_project_impl(matches: &ArgMatches) -> LinearResult<()> {
         Ok(project) => {
             println!("✅ Project created successfully!");
             println!("ID: {}", project.id);
-            println!("Name: {}", project.name);
-            println!("URL: {}", project.url);
+            println!("Name: {}", hyperlink(&project.name, &project.url));
+            println!("URL: {}", hyperlink(&project.url, &project.url));
             Ok(())
         }
         Err(e) => {
             eprintln!("Failed to create project: {}", e);
-            eprintln!("\nTip: Projects require at least one team. Use --teams flag with team ID.");
+            eprintln!("\nTip: Projects require at least one team. Use --teams with a team key, e.g. --teams ENG.");
             eprintln!("Run 'linear teams' to see available teams.");
             Err(LinearError::ApiError(format!("Failed to create project: {}", e)))
         }
@@ -1,6 +1,7 @@
 use clap::ArgMatches;
 use crate::cli_context::CliContext;
 use crate::error::{LinearError, LinearResult, ErrorContext};
+use crate::resolver::Resolver;
 
 pub async fn handle_delete(matches: &ArgMatches, resource_type: &str) -> Result<(), Box<dyn std::error::Error>> {
     handle_delete_impl(matches, resource_type).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
@@ -17,7 +18,7 @@ async fn handle_delete_impl(matches: &ArgMatches, resource_type: &str) -> Linear
         "Issue" => client.archive_issue(id).await
             .map_err(|e| LinearError::ApiError(format!("Failed to archive issue: {}", e)))
             .context("Archiving issue")?,
-        "Project" => client.archive_project(id).await
+        "Project" => client.archive_project(&Resolver::new(&client).project(id).await?.id).await
             .map_err(|e| LinearError::ApiError(format!("Failed to archive project: {}", e)))
             .context("Archiving project")?,
         _ => return Err(LinearError::InvalidInput("Invalid resource type".to_string())),
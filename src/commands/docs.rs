@@ -0,0 +1,183 @@
+use clap::ArgMatches;
+use colored::*;
+use crate::cli_context::CliContext;
+use crate::editor::edit_text;
+use crate::error::{ErrorContext, LinearError, LinearResult};
+use crate::formatting::hyperlink::hyperlink;
+use crate::formatting::markdown::format_markdown;
+use crate::formatting::utils::format_relative_time;
+use crate::models::{document_id_from_url, parse_editor_text, Document};
+use crate::resolver::Resolver;
+
+fn print_document_header(document: &Document) {
+    println!("{}", hyperlink(&document.title, &document.url).bold());
+    if let Some(project) = &document.project {
+        println!("{}: {}", "Project".bold(), project.name);
+    }
+    if let Some(creator) = &document.creator {
+        println!("{}: {}", "Author".bold(), creator.name);
+    }
+    println!("{}: {}", "Updated".bold(), format_relative_time(&document.updated_at));
+    println!("{}: {}", "URL".bold(), hyperlink(&document.url, &document.url).bright_black());
+}
+
+pub async fn handle_docs(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_docs_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_docs_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let limit = matches.get_one::<String>("limit")
+        .and_then(|s| s.parse::<i32>().ok())
+        .unwrap_or(50);
+    let project = match matches.get_one::<String>("project") {
+        Some(project) => Some(Resolver::new(&client).project(project).await?),
+        None => None,
+    };
+
+    let documents = client.get_documents(project.as_ref().map(|p| p.id.as_str()), Some(limit)).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to get documents: {}", e)))
+        .context("Getting documents")?;
+
+    if documents.is_empty() {
+        println!("No documents found.");
+        return Ok(());
+    }
+
+    match &project {
+        Some(project) => println!("Found {} documents in {}:", documents.len(), project.name),
+        None => println!("Found {} documents:", documents.len()),
+    }
+    for document in &documents {
+        let project = document.project.as_ref().map(|p| p.name.as_str()).unwrap_or("-");
+        println!(
+            "  {} {} {} {}",
+            document.slug_id.bright_blue(),
+            hyperlink(&document.title, &document.url).bold(),
+            format!("[{}]", project).cyan(),
+            format_relative_time(&document.updated_at).dimmed()
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn handle_doc_show(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_doc_show_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_doc_show_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let id = matches.get_one::<String>("id")
+        .ok_or_else(|| LinearError::InvalidInput("Document ID is required".to_string()))?;
+    let id = document_id_from_url(id);
+
+    let document = client.get_document(id).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to get document: {}", e)))
+        .with_context(|| format!("Getting document {}", id))?;
+
+    print_document_header(&document);
+    println!("{}", "─".repeat(80));
+    match document.content.as_deref().filter(|c| !c.trim().is_empty()) {
+        Some(content) => println!("{}", format_markdown(content)),
+        None => println!("{}", "(empty document)".dimmed()),
+    }
+
+    Ok(())
+}
+
+pub async fn handle_doc_new(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_doc_new_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_doc_new_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let project = matches.get_one::<String>("project")
+        .ok_or_else(|| LinearError::InvalidInput("Project is required".to_string()))?;
+    let project = Resolver::new(&client).project(project).await?;
+    let title = matches.get_one::<String>("title");
+
+    let initial = format!("# {}\n\n", title.map(|t| t.as_str()).unwrap_or(""));
+    let edited = edit_text(&initial)?;
+    let (edited_title, content) = parse_editor_text(&edited);
+
+    let title = edited_title.or_else(|| title.cloned())
+        .ok_or_else(|| LinearError::InvalidInput("Document needs a title: start it with a '# Title' line".to_string()))?;
+    if content.is_empty() && edited.trim() == initial.trim() {
+        println!("Document is empty, nothing created.");
+        return Ok(());
+    }
+
+    let document = client.create_document(&title, &content, &project.id).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to create document: {}", e)))
+        .context("Creating document")?;
+
+    println!("✅ Document {} created in {}", document.title.bold(), project.name);
+    println!("ID: {}", document.slug_id);
+    println!("URL: {}", hyperlink(&document.url, &document.url));
+
+    Ok(())
+}
+
+pub async fn handle_doc_edit(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_doc_edit_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_doc_edit_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let id = matches.get_one::<String>("id")
+        .ok_or_else(|| LinearError::InvalidInput("Document ID is required".to_string()))?;
+    let id = document_id_from_url(id);
+
+    let original = client.get_document(id).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to get document: {}", e)))
+        .with_context(|| format!("Getting document {}", id))?;
+
+    let initial = original.to_editor_text();
+    let edited = edit_text(&initial)?;
+    if edited.trim() == initial.trim() {
+        println!("No changes made to {}.", original.title.bold());
+        return Ok(());
+    }
+
+    let (title, content) = parse_editor_text(&edited);
+    let title = title.unwrap_or_else(|| original.title.clone());
+
+    // Refuse to overwrite changes someone else saved while the editor was open
+    let latest = client.get_document(&original.id).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to get document: {}", e)))
+        .with_context(|| format!("Checking document {} for changes", id))?;
+    if latest.updated_at != original.updated_at {
+        let backup = std::env::temp_dir().join(format!("linear-doc-{}.md", original.slug_id));
+        std::fs::write(&backup, &edited)?;
+        return Err(LinearError::Conflict(format!(
+            "'{}' was changed in Linear while you were editing (updated {}). Your version was saved to {}",
+            latest.title,
+            format_relative_time(&latest.updated_at),
+            backup.display()
+        )));
+    }
+
+    let title_changed = title != original.title;
+    let content_changed = content != original.content.as_deref().unwrap_or("").trim();
+    let updated = client.update_document(
+        &original.id,
+        title_changed.then_some(title.as_str()),
+        content_changed.then_some(content.as_str()),
+    ).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to update document: {}", e)))
+        .with_context(|| format!("Updating document {}", original.title))?;
+
+    println!("✅ Document {} updated", updated.title.bold());
+    println!("URL: {}", hyperlink(&updated.url, &updated.url));
+
+    Ok(())
+}
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use clap::ArgMatches;
+use colored::*;
+use crate::cli_context::CliContext;
+use crate::client::LinearClient;
+use crate::config::load_config;
+use crate::error::{ErrorContext, LinearError, LinearResult};
+use crate::filtering::builder::{FilterExpression, LogicalOperator};
+use crate::filtering::dates::timezone_setting;
+use crate::filtering::{
+    bind, builtin_values, check, check_values, complete, diagnose, format_expression, format_query, format_tree, order_by, parse_filter,
+    split_for_server, uses_cycle_dates, Calendar, Diagnostic, FilterAdapter, FilterError, Severity, Vocabulary, Zone,
+};
+use crate::resolver::Resolver;
+
+/// How long cached names are used before completion asks Linear again
+const VOCABULARY_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Fetch the label, state, member, project and team names offered as filter values.
+pub async fn fetch_vocabulary(client: &LinearClient) -> LinearResult<Vocabulary> {
+    let api = |e: Box<dyn std::error::Error>| LinearError::ApiError(e.to_string());
+    let mut vocabulary = Vocabulary {
+        labels: client.get_labels().await.map_err(api)?.into_iter().map(|l| l.name).collect(),
+        states: client.get_workflow_states().await.map_err(api)?.into_iter().map(|s| s.name).collect(),
+        members: client.get_team_members().await.map_err(api)?.into_iter().map(|u| u.name).collect(),
+        projects: client.get_projects().await.map_err(api)?.into_iter().map(|p| p.name).collect(),
+        teams: client.get_teams().await.map_err(api)?.into_iter().map(|t| t.key).collect(),
+    };
+    vocabulary.normalize();
+    Ok(vocabulary)
+}
+
+/// The cached vocabulary, refreshed from Linear when it's missing, older than a day
+/// or `refresh` is set. Falls back to whatever is cached if Linear can't be reached.
+async fn cached_vocabulary(refresh: bool) -> Vocabulary {
+    let cached = Vocabulary::load().unwrap_or_default();
+    let fresh = Vocabulary::path()
+        .ok()
+        .and_then(|path| path.metadata().ok())
+        .and_then(|meta| meta.modified().ok())
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < VOCABULARY_MAX_AGE);
+    if fresh && !refresh && !cached.is_empty() {
+        return cached;
+    }
+
+    let Some(client) = CliContext::load().ok().and_then(|mut context| context.unverified_client()) else {
+        return cached;
+    };
+    match fetch_vocabulary(&client).await {
+        Ok(vocabulary) => {
+            let _ = vocabulary.save();
+            vocabulary
+        }
+        Err(_) => cached,
+    }
+}
+
+/// Print diagnostics for `query` to stderr, each with the query and a caret under
+/// the part it's about.
+pub fn print_diagnostics(query: &str, diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        let rendered = diagnostic.render(query);
+        let (header, rest) = rendered.split_once('\n').unwrap_or((&rendered, ""));
+        let header = match diagnostic.severity {
+            Severity::Error => header.red().bold(),
+            Severity::Warning => header.yellow().bold(),
+        };
+        eprintln!("{}\n{}", header, rest);
+    }
+}
+
+/// The calendar to read `query` in. When it uses `this-cycle`, `last-cycle` or
+/// `next-cycle`, the cycles are loaded: those of the team the query pins, else of
+/// `team`, else of the default team, else of the only team with cycles.
+pub async fn load_calendar(client: &LinearClient, query: &str, team: Option<&str>) -> LinearResult<Calendar> {
+    if !uses_cycle_dates(query) {
+        return Ok(Calendar::from_config());
+    }
+    let cycles = client.get_cycles().await.map_err(|e| LinearError::ApiError(e.to_string()))?;
+    let team = team.map(str::to_string).or_else(|| load_config().default_team_id);
+    Calendar::for_query(query, &cycles, team.as_deref()).map_err(LinearError::InvalidInput)
+}
+
+/// Parse `--set NAME=VALUE` arguments.
+pub fn parse_set_values(matches: &ArgMatches) -> LinearResult<HashMap<String, String>> {
+    let mut values = HashMap::new();
+    for set in matches.get_many::<String>("set").into_iter().flatten() {
+        let (name, value) = set.split_once('=')
+            .ok_or_else(|| LinearError::InvalidInput(format!("Invalid --set '{}'. Use NAME=VALUE", set)))?;
+        values.insert(name.trim().to_string(), value.to_string());
+    }
+    Ok(values)
+}
+
+/// Fill in the variables of saved search `name`: `--set` values first, then `$me`,
+/// `$team` and `$cycle` from Linear, then the defaults written in the query.
+pub async fn bind_search(client: &LinearClient, name: &str, query: &str, matches: &ArgMatches) -> LinearResult<String> {
+    let invalid = |e: crate::filtering::VariableError| LinearError::InvalidInput(format!("Saved search '{}': {}", name, e));
+    let mut values = parse_set_values(matches)?;
+    check_values(query, &values).map_err(invalid)?;
+
+    let needed: Vec<String> = crate::filtering::variables(query)
+        .map_err(invalid)?
+        .into_iter()
+        .filter(|variable| variable.is_builtin() && !values.contains_key(&variable.name))
+        .map(|variable| variable.name)
+        .collect();
+    if !needed.is_empty() {
+        let api = |e: Box<dyn std::error::Error>| LinearError::ApiError(e.to_string());
+        let wants = |name: &str| needed.iter().any(|needed| needed == name);
+        let viewer = if wants("me") {
+            Some(client.get_viewer().await.map_err(api).context("Resolving $me")?)
+        } else {
+            None
+        };
+        let team = match (wants("team") || wants("cycle"), load_config().default_team_id) {
+            (true, Some(team)) => Some(Resolver::new(client).team(&team).await.context("Resolving $team")?),
+            _ => None,
+        };
+        let cycles = if wants("cycle") {
+            client.get_cycles().await.map_err(api).context("Resolving $cycle")?
+        } else {
+            Vec::new()
+        };
+        let builtins = builtin_values(viewer.as_ref(), team.as_ref(), &cycles)
+            .map_err(LinearError::InvalidInput)
+            .context("Resolving $cycle")?;
+        for (name, value) in builtins {
+            if wants(&name) {
+                values.insert(name, value);
+            }
+        }
+    }
+
+    bind(query, &values).map_err(invalid)
+}
+
+pub async fn handle_filter_complete(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_filter_complete_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+/// Print each completion of a partial query as the whole completed query, one per
+/// line, for shell completion scripts.
+async fn handle_filter_complete_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let query = matches.get_one::<String>("query").map(String::as_str).unwrap_or("");
+    let cursor = match matches.get_one::<String>("cursor") {
+        Some(cursor) => cursor.parse::<usize>()
+            .map_err(|_| LinearError::InvalidInput(format!("Invalid cursor position: {}", cursor)))
+            .context("Parsing --cursor")?,
+        None => query.len(),
+    };
+
+    let vocabulary = cached_vocabulary(matches.get_flag("refresh")).await;
+    for completion in complete(query, cursor, &vocabulary) {
+        println!("{}", completion.apply(query, cursor).0);
+    }
+    Ok(())
+}
+
+pub async fn handle_filter_explain(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_filter_explain_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+/// Show how a query is read: its parse tree, canonical form, the `IssueFilter` sent
+/// to Linear, and the conditions that are checked locally or can't be applied at all.
+async fn handle_filter_explain_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let query = match matches.get_one::<String>("search") {
+        Some(name) => {
+            let query = load_config().saved_searches.get(name).cloned()
+                .ok_or_else(|| LinearError::InvalidInput(format!("Saved search '{}' not found", name)))?;
+            let client = CliContext::load()?.verified_client()?;
+            bind_search(&client, name, &query, matches).await?
+        }
+        None => matches.get_one::<String>("query").cloned()
+            .ok_or_else(|| LinearError::InvalidInput("A query or --search is required".to_string()))?,
+    };
+    println!("{} {}", "Query:".bold(), query);
+
+    let diagnostics = diagnose(&query, &Vocabulary::load().unwrap_or_default());
+    print_diagnostics(&query, &diagnostics);
+    if let Some(setting) = timezone_setting().filter(|setting| Zone::parse(setting).is_none()) {
+        println!("{} unknown timezone '{}'; dates are read in local time", "Note:".yellow(), setting);
+    }
+    // Without a client the cycle dates are reported as missing below
+    let mut calendar = Calendar::from_config();
+    if let Some(client) = CliContext::load().ok().and_then(|mut context| context.unverified_client()) {
+        match load_calendar(&client, &query, None).await {
+            Ok(loaded) => calendar = loaded,
+            Err(e) => println!("{} no cycle dates: {}", "Note:".yellow(), e),
+        }
+    }
+    let builder = match parse_filter(&query) {
+        Ok(builder) => builder,
+        Err(e) => {
+            // `linear issues -f` and saved searches fall back to the old syntax
+            let legacy = FilterAdapter::parse_and_build(&query, &calendar)
+                .map_err(|_| LinearError::InvalidInput(format!("Invalid filter: {}", e)))
+                .context("Parsing filter query")?;
+            println!("{} not filter syntax; read with the legacy syntax as:", "Note:".yellow());
+            println!("{}", serde_json::to_string_pretty(&legacy)?);
+            return Ok(());
+        }
+    };
+    let sort = builder.sort_keys().to_vec();
+    let expr = match builder.build() {
+        Ok(expr) => Some(expr),
+        Err(FilterError::EmptyFilter) => None,
+        Err(e) => return Err(LinearError::InvalidInput(format!("Failed to build filter: {}", e))),
+    };
+
+    println!("{} {}", "Canonical:".bold(), format_query(expr.as_ref(), &sort));
+    if !sort.is_empty() {
+        let how = match order_by(&sort) {
+            Some(field) => format!("ordered by Linear on {}, then sorted locally", field),
+            None => "sorted locally".to_string(),
+        };
+        println!("{} {}", "Sort:".bold(), how);
+    }
+
+    let Some(expr) = expr else {
+        println!("\n{}\n{{}}", "IssueFilter:".bold());
+        return Ok(());
+    };
+
+    println!("\n{}", "Parse tree:".bold());
+    for line in format_tree(&expr).lines() {
+        println!("  {}", line);
+    }
+
+    let (server, local) = split_for_server(&expr, &calendar)
+        .map_err(|e| LinearError::InvalidInput(format!("Failed to build filter: {}", e)))?;
+    println!("\n{}", "IssueFilter:".bold());
+    println!("{}", serde_json::to_string_pretty(&server.unwrap_or_else(|| serde_json::json!({})))?);
+
+    // Sort what Linear can't filter on into what the CLI checks itself and what it can't
+    let residual = match local {
+        Some(FilterExpression::Group(group)) if group.operator == LogicalOperator::And => group.conditions,
+        Some(expr) => vec![expr],
+        None => Vec::new(),
+    };
+    let (checked, dropped): (Vec<_>, Vec<_>) = residual
+        .iter()
+        .map(|expr| (format_expression(expr), check(expr, Some("viewer"), &calendar)))
+        .partition(|(_, result)| result.is_ok());
+    if !checked.is_empty() {
+        println!("\n{}", "Checked locally (Linear can't filter on these):".bold());
+        for (condition, _) in checked {
+            println!("  {}", condition);
+        }
+    }
+    if !dropped.is_empty() {
+        println!("\n{}", "Dropped (can't be applied):".red().bold());
+        for (condition, result) in dropped {
+            let reason = result.err().map(|e| e.to_string()).unwrap_or_default();
+            println!("  {}  {}", condition, reason.dimmed());
+        }
+    }
+    Ok(())
+}
@@ -5,6 +5,8 @@ use regex::Regex;
 use std::process::Command;
 use crate::cli_context::CliContext;
 use crate::error::{LinearError, LinearResult, ErrorContext};
+use crate::formatting::hyperlink::hyperlink;
+use crate::resolver::Resolver;
 
 // Common Linear issue ID patterns
 const ISSUE_PATTERN: &str = r"([A-Z]{2,}-\d+)";
@@ -35,7 +37,7 @@ async fn handle_git_commit_impl(matches: &ArgMatches) -> LinearResult<()> {
     
     // Create the commit
     let output = Command::new("git")
-        .args(&["commit", "-m", &formatted_message])
+        .args(["commit", "-m", &formatted_message])
         .output()?;
     
     if !output.status.success() {
@@ -51,17 +53,26 @@ async fn handle_git_commit_impl(matches: &ArgMatches) -> LinearResult<()> {
             let mut context = CliContext::load().context("Failed to load CLI context")?;
             let client = context.verified_client().context("Failed to get Linear client")?;
             
+            let mut resolver = Resolver::new(&client);
+            
             for issue_id in &issue_ids {
+                let state = match resolver.state_for_issue(issue_id, new_state).await {
+                    Ok((_, state)) => state,
+                    Err(e) => {
+                        eprintln!("  ✗ Failed to update {}: {}", issue_id, e);
+                        continue;
+                    }
+                };
                 match client.update_issue(
                     issue_id,
                     None,
                     None,
-                    Some(new_state),
+                    Some(&state.id),
                     None,
                     None,
                     None,
                 ).await {
-                    Ok(_) => println!("  ✓ Updated {} status to {}", issue_id, new_state),
+                    Ok(_) => println!("  ✓ Updated {} status to {}", issue_id, state.name),
                     Err(e) => eprintln!("  ✗ Failed to update {}: {}", issue_id, e),
                 }
             }
@@ -72,7 +83,7 @@ async fn handle_git_commit_impl(matches: &ArgMatches) -> LinearResult<()> {
     if push {
         println!("\nPushing to remote...");
         let push_output = Command::new("git")
-            .args(&["push"])
+            .args(["push"])
             .output()?;
         
         if push_output.status.success() {
@@ -109,13 +120,13 @@ async fn handle_git_branch_impl(matches: &ArgMatches) -> LinearResult<()> {
     
     // Create and checkout the branch
     let output = Command::new("git")
-        .args(&["checkout", "-b", &branch_name])
+        .args(["checkout", "-b", &branch_name])
         .output()?;
     
     if !output.status.success() {
         // Try just checking out if branch already exists
         let checkout_output = Command::new("git")
-            .args(&["checkout", &branch_name])
+            .args(["checkout", &branch_name])
             .output()?;
         
         if !checkout_output.status.success() {
@@ -127,7 +138,7 @@ async fn handle_git_branch_impl(matches: &ArgMatches) -> LinearResult<()> {
         println!("✅ Created and checked out new branch: {}", branch_name);
     }
     
-    println!("\nIssue: {} - {}", issue.identifier.blue(), issue.title);
+    println!("\nIssue: {} - {}", hyperlink(&issue.identifier, &issue.url).blue(), issue.title);
     println!("Branch: {}", branch_name.green());
     
     Ok(())
@@ -145,7 +156,7 @@ async fn handle_git_pr_impl(matches: &ArgMatches) -> LinearResult<()> {
     
     // Get current branch
     let branch_output = Command::new("git")
-        .args(&["branch", "--show-current"])
+        .args(["branch", "--show-current"])
         .output()?;
     
     if !branch_output.status.success() {
@@ -245,6 +256,7 @@ async fn handle_git_hook_impl(_matches: &ArgMatches) -> LinearResult<()> {
     // Update issue status based on keywords
     let mut context = CliContext::load().context("Failed to load CLI context")?;
     let client = context.verified_client().context("Failed to get Linear client")?;
+    let mut resolver = Resolver::new(&client);
     
     for issue_id in issue_ids {
         // Check for status keywords
@@ -253,25 +265,33 @@ async fn handle_git_hook_impl(_matches: &ArgMatches) -> LinearResult<()> {
         let new_state = if lower_message.contains("fixes") || 
                           lower_message.contains("closes") || 
                           lower_message.contains("resolves") {
-            Some("Done")
+            Some("completed")
         } else if lower_message.contains("wip") || 
                   lower_message.contains("in progress") {
-            Some("In Progress")
+            Some("started")
         } else {
             None
         };
         
-        if let Some(state) = new_state {
+        if let Some(state_type) = new_state {
+            // Map the state type onto the issue team's own workflow
+            let state = match resolver.state_for_issue(&issue_id, state_type).await {
+                Ok((_, state)) => state,
+                Err(e) => {
+                    eprintln!("  ✗ Failed to update {}: {}", issue_id, e);
+                    continue;
+                }
+            };
             match client.update_issue(
                 &issue_id,
                 None,
                 None,
-                Some(state),
+                Some(&state.id),
                 None,
                 None,
                 None,
             ).await {
-                Ok(_) => println!("  ✓ Updated {} to {}", issue_id, state),
+                Ok(_) => println!("  ✓ Updated {} to {}", issue_id, state.name),
                 Err(e) => eprintln!("  ✗ Failed to update {}: {}", issue_id, e),
             }
         }
@@ -286,7 +306,7 @@ pub async fn handle_install_hook(_matches: &ArgMatches) -> Result<(), Box<dyn st
 
 async fn handle_install_hook_impl(_matches: &ArgMatches) -> LinearResult<()> {
     let git_dir = Command::new("git")
-        .args(&["rev-parse", "--git-dir"])
+        .args(["rev-parse", "--git-dir"])
         .output()
         .context("Failed to execute git command")?;
 
@@ -2,8 +2,13 @@ use clap::ArgMatches;
 use serde_json::json;
 use crate::cli_context::CliContext;
 use crate::error::{LinearError, ErrorContext};
-use crate::filtering::FilterAdapter;
+use crate::commands::filter::{load_calendar, print_diagnostics};
+use crate::filtering::{check, diagnose, matches as filter_matches, order_by, parse_filter, parse_sort, sort_issues, split_for_server, Calendar, Diagnostic, FilterAdapter, FilterError, Vocabulary};
 use crate::formatting::issues::{print_issues, print_single_issue};
+use crate::formatting::output::is_structured;
+use crate::formatting::table::columns_from_arg;
+use crate::formatting::template::OutputTemplate;
+use crate::resolver::Resolver;
 
 pub async fn handle_issues(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
     // Create CLI context and get verified client
@@ -13,19 +18,58 @@ pub async fn handle_issues(matches: &ArgMatches) -> Result<(), Box<dyn std::erro
         .context("Failed to get Linear client")?;
     
     let format = matches.get_one::<String>("format").map(|s| s.as_str()).unwrap_or("simple");
+    let template = OutputTemplate::from_args(matches.get_one::<String>("template"), matches.get_one::<String>("template-file"))?;
+    let columns = columns_from_arg(matches.get_one::<String>("columns"))?;
     let group_by = matches.get_one::<String>("group-by").map(|s| s.as_str()).unwrap_or("status");
     let limit = matches.get_one::<String>("limit")
         .and_then(|s| s.parse::<i32>().ok())
         .unwrap_or(50);
 
     let mut filter = json!({});
+    // Conditions Linear can't filter on, checked once the issues arrive
+    let mut local_filter = None;
+    let mut sort_keys = Vec::new();
+    let mut calendar = Calendar::from_config();
     
     // Check if advanced filter is provided
     if let Some(filter_query) = matches.get_one::<String>("filter") {
-        // Try new filter system first, fall back to legacy if needed
-        filter = FilterAdapter::parse_and_build(filter_query)
-            .map_err(|e| LinearError::InvalidInput(format!("Failed to parse filter: {}", e)))
-            .with_context(|| format!("Filter query: {}", filter_query))?;
+        let diagnostics = diagnose(filter_query, &Vocabulary::load().unwrap_or_default());
+        print_diagnostics(filter_query, &diagnostics);
+        let invalid = diagnostics.iter().any(Diagnostic::is_error);
+        calendar = load_calendar(&client, filter_query, None).await.context("Loading cycle dates for the filter")?;
+        let parsed = parse_filter(filter_query).map(|builder| {
+            let keys = builder.sort_keys().to_vec();
+            (builder.build(), keys)
+        });
+        match parsed {
+            Ok((Ok(expr), keys)) if !invalid => {
+                sort_keys = keys;
+                let (server, local) = split_for_server(&expr, &calendar)
+                    .map_err(|e| LinearError::InvalidInput(format!("Failed to build filter: {}", e)))
+                    .with_context(|| format!("Filter query: {}", filter_query))?;
+                if let Some(server) = server {
+                    filter = server;
+                }
+                local_filter = local;
+            }
+            // A query with nothing but a sort clause
+            Ok((Err(FilterError::EmptyFilter), keys)) if !invalid && !keys.is_empty() => {
+                sort_keys = keys;
+            }
+            // Fall back to the legacy syntax
+            _ => {
+                filter = FilterAdapter::parse_and_build(filter_query, &calendar)
+                    .map_err(|e| LinearError::InvalidInput(format!("Failed to parse filter: {}", e)))
+                    .with_context(|| format!("Filter query: {}", filter_query))?;
+                if invalid {
+                    // It reads anything, so only go along with it when it found a filter
+                    if filter.as_object().is_some_and(|o| o.is_empty()) {
+                        return Err(LinearError::InvalidInput("Invalid filter query".to_string()).into());
+                    }
+                    eprintln!("Falling back to the legacy filter syntax\n");
+                }
+            }
+        }
     } else {
         // Handle legacy filters for backward compatibility
         // Handle state filters
@@ -39,19 +83,22 @@ pub async fn handle_issues(matches: &ArgMatches) -> Result<(), Box<dyn std::erro
             filter["state"] = json!({"type": {"eq": "completed"}});
         }
 
+        let mut resolver = Resolver::new(&client);
+
         // Handle assignee filters
         if matches.get_flag("mine") {
-            let viewer = client.get_viewer().await
-                .map_err(|e| LinearError::ApiError(format!("Failed to get current user: {}", e)))
+            let viewer = resolver.viewer().await
                 .context("Getting viewer information for --mine filter")?;
             filter["assignee"] = json!({"id": {"eq": viewer.id}});
         } else if let Some(assignee) = matches.get_one::<String>("assignee") {
-            filter["assignee"] = json!({"email": {"eq": assignee}});
+            let user = resolver.user(assignee).await?;
+            filter["assignee"] = json!({"id": {"eq": user.id}});
         }
 
         // Handle team filter
         if let Some(team) = matches.get_one::<String>("team") {
-            filter["team"] = json!({"key": {"eq": team}});
+            let team = resolver.team(team).await?;
+            filter["team"] = json!({"id": {"eq": team.id}});
         }
 
         // Handle search
@@ -60,21 +107,49 @@ pub async fn handle_issues(matches: &ArgMatches) -> Result<(), Box<dyn std::erro
         }
     }
 
+    // --sort wins over a sort clause in the filter
+    if let Some(spec) = matches.get_one::<String>("sort") {
+        sort_keys = parse_sort(spec)
+            .map_err(|e| LinearError::InvalidInput(e.to_string()))
+            .with_context(|| format!("Sort: {}", spec))?;
+    }
+
     let filter_param = if filter.as_object().unwrap().is_empty() {
         None
     } else {
         Some(filter)
     };
 
-    let issues = client.get_issues(filter_param, Some(limit)).await
+    let viewer_id = match &local_filter {
+        Some(_) => Some(Resolver::new(&client).viewer().await
+            .context("Getting viewer information for the filter")?.id),
+        None => None,
+    };
+    if let Some(local) = &local_filter {
+        check(local, viewer_id.as_deref(), &calendar)
+            .map_err(|e| LinearError::InvalidInput(format!("Failed to build filter: {}", e)))?;
+    }
+
+    // Fetch extra issues when some are filtered out locally
+    let fetch_limit = if local_filter.is_some() { (limit * 4).min(250) } else { limit };
+    let mut issues = client.get_issues_ordered(filter_param, Some(fetch_limit), order_by(&sort_keys)).await
         .map_err(|e| LinearError::ApiError(format!("Failed to fetch issues: {}", e)))
         .context("Fetching issues from Linear API")?;
+    if let Some(local) = &local_filter {
+        issues.retain(|issue| filter_matches(local, issue, viewer_id.as_deref(), &calendar).unwrap_or(false));
+        issues.truncate(limit.max(0) as usize);
+    }
+    sort_issues(&mut issues, &sort_keys);
     
-    if issues.is_empty() {
+    if let Some(template) = &template {
+        template.print(&issues);
+    } else if is_structured(format) {
+        print_issues(&issues, format, group_by, &columns);
+    } else if issues.is_empty() {
         println!("No issues found matching your criteria.");
     } else {
         println!("Found {} issues:", issues.len());
-        print_issues(&issues, format, group_by);
+        print_issues(&issues, format, group_by, &columns);
     }
 
     Ok(())
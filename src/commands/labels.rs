@@ -0,0 +1,288 @@
+use clap::ArgMatches;
+use colored::*;
+use serde_json::json;
+use crate::cli_context::CliContext;
+use crate::error::{LinearError, LinearResult, ErrorContext};
+use crate::formatting::hyperlink::hyperlink;
+use crate::formatting::issues::print_labels;
+use crate::formatting::utils::{color_swatch, normalize_hex_color};
+use crate::resolver::Resolver;
+
+/// Upper bound on relabel passes during a merge, so a stuck update can't loop forever.
+const MAX_MERGE_PASSES: usize = 40;
+
+fn parse_color(color: &str) -> LinearResult<String> {
+    normalize_hex_color(color)
+        .ok_or_else(|| LinearError::InvalidInput(format!("Invalid color '{}'. Use a hex color like #5e6ad2", color)))
+}
+
+pub async fn handle_labels(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_labels_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_labels_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let mut resolver = Resolver::new(&client);
+    let team = match matches.get_one::<String>("team") {
+        Some(team) => Some(resolver.team(team).await?),
+        None => None,
+    };
+
+    let labels = client.get_issue_labels(team.as_ref().map(|t| t.id.as_str())).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to get labels: {}", e)))
+        .context("Getting labels")?;
+
+    if labels.is_empty() {
+        println!("No labels found.");
+    } else {
+        match &team {
+            Some(team) => println!("Found {} labels for {}:", labels.len(), team.name),
+            None => println!("Found {} labels:", labels.len()),
+        }
+        print_labels(&labels);
+    }
+
+    Ok(())
+}
+
+pub async fn handle_label_create(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_label_create_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_label_create_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let name = matches.get_one::<String>("name")
+        .ok_or_else(|| LinearError::InvalidInput("Label name is required".to_string()))?;
+    let color = matches.get_one::<String>("color").map(|c| parse_color(c)).transpose()?;
+    let description = matches.get_one::<String>("description");
+    let is_group = matches.get_flag("group");
+
+    let mut resolver = Resolver::new(&client);
+    let team = match matches.get_one::<String>("team") {
+        Some(team) => Some(resolver.team(team).await?),
+        None => None,
+    };
+    let team_id = team.as_ref().map(|t| t.id.as_str());
+
+    let parent_id = match matches.get_one::<String>("parent") {
+        Some(parent) => {
+            let parent = resolver.label(parent, team_id).await?;
+            if !parent.is_group {
+                return Err(LinearError::InvalidInput(format!(
+                    "Label '{}' is not a group. Create it with --group to use it as a parent",
+                    parent.name
+                )));
+            }
+            Some(parent.id.clone())
+        }
+        None => None,
+    };
+
+    let label = client.create_label(
+        name,
+        color.as_deref(),
+        team_id,
+        parent_id.as_deref(),
+        is_group,
+        description.map(|d| d.as_str()),
+    ).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to create label: {}", e)))
+        .context("Creating label")?;
+
+    println!("✅ {} {} created successfully!", if label.is_group { "Label group" } else { "Label" }, label.full_name().bold());
+    println!("{} {}", color_swatch(&label.color), label.color);
+    println!("Label ID: {}", label.id);
+
+    Ok(())
+}
+
+pub async fn handle_label_rename(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_label_rename_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_label_rename_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let query = matches.get_one::<String>("label")
+        .ok_or_else(|| LinearError::InvalidInput("Label is required".to_string()))?;
+    let new_name = matches.get_one::<String>("name")
+        .ok_or_else(|| LinearError::InvalidInput("New label name is required".to_string()))?;
+
+    let label = Resolver::new(&client).label(query, None).await?;
+
+    let updated = client.update_label(&label.id, Some(new_name), None, None).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to rename label: {}", e)))
+        .with_context(|| format!("Renaming label {}", label.name))?;
+
+    println!("✅ Renamed {} to {}", label.full_name().dimmed(), updated.full_name().bold());
+
+    Ok(())
+}
+
+pub async fn handle_label_color(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_label_color_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_label_color_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let query = matches.get_one::<String>("label")
+        .ok_or_else(|| LinearError::InvalidInput("Label is required".to_string()))?;
+    let color = matches.get_one::<String>("color")
+        .ok_or_else(|| LinearError::InvalidInput("Color is required".to_string()))?;
+    let color = parse_color(color)?;
+
+    let label = Resolver::new(&client).label(query, None).await?;
+
+    let updated = client.update_label(&label.id, None, Some(&color), None).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to recolor label: {}", e)))
+        .with_context(|| format!("Recoloring label {}", label.name))?;
+
+    println!(
+        "✅ {}: {} {} → {} {}",
+        updated.full_name().bold(),
+        color_swatch(&label.color),
+        label.color.dimmed(),
+        color_swatch(&updated.color),
+        updated.color
+    );
+
+    Ok(())
+}
+
+pub async fn handle_label_archive(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_label_archive_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_label_archive_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let query = matches.get_one::<String>("label")
+        .ok_or_else(|| LinearError::InvalidInput("Label is required".to_string()))?;
+
+    let label = Resolver::new(&client).label(query, None).await?;
+
+    let success = client.archive_label(&label.id).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to archive label: {}", e)))
+        .with_context(|| format!("Archiving label {}", label.name))?;
+
+    if success {
+        println!("✅ Label {} archived successfully!", label.full_name().bold());
+    } else {
+        return Err(LinearError::ApiError("Failed to archive label".to_string()));
+    }
+
+    Ok(())
+}
+
+pub async fn handle_label_merge(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_label_merge_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_label_merge_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let old_query = matches.get_one::<String>("old")
+        .ok_or_else(|| LinearError::InvalidInput("Label to merge is required".to_string()))?;
+    let new_query = matches.get_one::<String>("new")
+        .ok_or_else(|| LinearError::InvalidInput("Target label is required".to_string()))?;
+
+    let mut resolver = Resolver::new(&client);
+    let old = resolver.label(old_query, None).await?;
+    let new = resolver.label(new_query, None).await?;
+
+    if old.id == new.id {
+        return Err(LinearError::InvalidInput("Cannot merge a label into itself".to_string()));
+    }
+    if old.is_group || new.is_group {
+        return Err(LinearError::InvalidInput("Label groups cannot be merged; merge their labels instead".to_string()));
+    }
+
+    println!("Merging {} into {}...", old.full_name().bold(), new.full_name().bold());
+
+    // Relabelled issues drop out of the filter, so keep fetching until none are left
+    // or a pass makes no progress.
+    let filter = json!({ "labels": { "some": { "id": { "eq": old.id } } } });
+    let mut success_count = 0;
+    let mut failed_ids: Vec<String> = Vec::new();
+    let mut finished = false;
+
+    for _ in 0..MAX_MERGE_PASSES {
+        let issues = client.get_issues(Some(filter.clone()), Some(100)).await
+            .map_err(|e| LinearError::ApiError(format!("Failed to fetch issues: {}", e)))
+            .with_context(|| format!("Fetching issues labelled {}", old.name))?;
+
+        let pending: Vec<_> = issues
+            .iter()
+            .filter(|issue| !failed_ids.contains(&issue.identifier))
+            .collect();
+        if pending.is_empty() {
+            finished = true;
+            break;
+        }
+
+        let relabelled_before = success_count;
+        for issue in pending {
+            let mut label_ids: Vec<&str> = issue.labels.nodes
+                .iter()
+                .map(|l| l.id.as_str())
+                .filter(|id| *id != old.id)
+                .collect();
+            if !label_ids.contains(&new.id.as_str()) {
+                label_ids.push(&new.id);
+            }
+
+            match client.update_issue(&issue.id, None, None, None, None, None, Some(label_ids)).await {
+                Ok(_) => {
+                    success_count += 1;
+                    println!("  ✓ Relabelled {}", hyperlink(&issue.identifier, &issue.url).bright_green());
+                }
+                Err(e) => {
+                    println!("  ✗ Failed to relabel {}: {}", hyperlink(&issue.identifier, &issue.url).bright_red(), e);
+                    failed_ids.push(issue.identifier.clone());
+                }
+            }
+        }
+        if success_count == relabelled_before {
+            break;
+        }
+    }
+
+    println!("\n✅ Relabelled {} issues", success_count);
+
+    if !failed_ids.is_empty() {
+        println!("❌ Failed to relabel: {}", failed_ids.join(", "));
+        return Err(LinearError::ApiError(format!(
+            "Not archiving {} because {} issues still carry it",
+            old.name,
+            failed_ids.len()
+        )));
+    }
+    if !finished {
+        return Err(LinearError::ApiError(format!(
+            "Not archiving {} because issues still carry it after {} passes",
+            old.name,
+            MAX_MERGE_PASSES
+        )));
+    }
+
+    let success = client.archive_label(&old.id).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to archive label: {}", e)))
+        .with_context(|| format!("Archiving label {}", old.name))?;
+
+    if success {
+        println!("✅ Label {} archived", old.full_name().bold());
+    } else {
+        return Err(LinearError::ApiError("Failed to archive label".to_string()));
+    }
+
+    Ok(())
+}
@@ -3,25 +3,42 @@ pub mod bulk;
 pub mod comments;
 pub mod create;
 pub mod delete;
+pub mod docs;
+pub mod filter;
 pub mod filter_help;
 pub mod git;
 pub mod issues;
+pub mod labels;
 pub mod projects;
+pub mod report;
 pub mod search;
+pub mod states;
 pub mod teams;
+pub mod templates;
+pub mod timer;
 pub mod update;
+pub mod views;
 pub mod whoami;
 
 pub use auth::handle_auth;
 pub use bulk::{handle_bulk_archive, handle_bulk_move, handle_bulk_update};
-pub use comments::{handle_add_comment, handle_delete_comment, handle_list_comments, handle_update_comment};
+pub use comments::{handle_add_comment, handle_delete_comment, handle_list_comments, handle_reply_comment, handle_update_comment};
 pub use create::{handle_create_issue, handle_create_project};
 pub use delete::handle_delete;
+pub use docs::{handle_doc_edit, handle_doc_new, handle_doc_show, handle_docs};
+pub use filter::{handle_filter_complete, handle_filter_explain};
+#[allow(unused_imports)]
 pub use filter_help::handle_filter_help;
 pub use git::{handle_git_branch, handle_git_commit, handle_git_hook, handle_git_pr, handle_install_hook};
 pub use issues::{handle_issue, handle_issues};
+pub use labels::{handle_label_archive, handle_label_color, handle_label_create, handle_label_merge, handle_label_rename, handle_labels};
 pub use projects::handle_projects;
-pub use search::{handle_delete_search, handle_list_searches, handle_run_search, handle_save_search};
+pub use report::handle_report;
+pub use search::{handle_delete_search, handle_list_searches, handle_pull_searches, handle_push_search, handle_run_search, handle_save_search, handle_text_search};
+pub use states::{handle_state_create, handle_state_move, handle_states};
 pub use teams::handle_teams;
+pub use templates::handle_templates;
+pub use timer::{handle_timer_start, handle_timer_status, handle_timer_stop, handle_timesheet};
 pub use update::{handle_update_issue, handle_update_project};
+pub use views::{handle_view_run, handle_views};
 pub use whoami::handle_whoami;
\ No newline at end of file
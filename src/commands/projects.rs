@@ -2,19 +2,30 @@ use clap::ArgMatches;
 use crate::cli_context::CliContext;
 use crate::error::{LinearError, LinearResult, ErrorContext};
 use crate::formatting::issues::print_projects;
+use crate::formatting::output::print_records;
+use crate::formatting::template::OutputTemplate;
 
-pub async fn handle_projects(_matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
-    handle_projects_impl(_matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+pub async fn handle_projects(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_projects_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
 }
 
-async fn handle_projects_impl(_matches: &ArgMatches) -> LinearResult<()> {
+async fn handle_projects_impl(matches: &ArgMatches) -> LinearResult<()> {
     let mut context = CliContext::load().context("Failed to load CLI context")?;
     let client = context.verified_client().context("Failed to get Linear client")?;
+    let format = matches.get_one::<String>("format").map(|s| s.as_str()).unwrap_or("simple");
+    let template = OutputTemplate::from_args(matches.get_one::<String>("template"), matches.get_one::<String>("template-file"))?;
 
     let projects = client.get_projects().await
         .map_err(|e| LinearError::ApiError(format!("Failed to get projects: {}", e)))
         .context("Getting projects")?;
     
+    if let Some(template) = &template {
+        template.print(&projects);
+        return Ok(());
+    }
+    if print_records(&projects, format) {
+        return Ok(());
+    }
     if projects.is_empty() {
         println!("No projects found.");
     } else {
@@ -0,0 +1,65 @@
+use chrono::Utc;
+use clap::ArgMatches;
+use serde_json::json;
+use crate::cli_context::CliContext;
+use crate::commands::filter::load_calendar;
+use crate::error::{ErrorContext, LinearError, LinearResult};
+use crate::filtering::DateSpec;
+use crate::formatting::table::terminal_width;
+use crate::report::{render_json, render_markdown, render_table, GroupBy, Report, Window};
+use crate::resolver::Resolver;
+
+pub async fn handle_report(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_report_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_report_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let since = matches.get_one::<String>("since").map(|s| s.as_str()).unwrap_or("2w");
+    let spec = DateSpec::parse(since)
+        .ok_or_else(|| LinearError::InvalidInput(format!("--since expects a date like 2w, 2026-10-01 or last-month, got '{}'", since)))?;
+    let group_by = matches.get_one::<String>("by")
+        .map(|by| GroupBy::from_name(by).ok_or_else(|| {
+            LinearError::InvalidInput(format!("Unknown --by '{}'. Use one of: {}", by, GroupBy::NAMES.join(", ")))
+        }))
+        .transpose()?;
+
+    let team = matches.get_one::<String>("team").map(|s| s.as_str());
+    let calendar = load_calendar(&client, since, team).await.context("Loading cycle dates for --since")?;
+    let window = Window::from_spec(&spec, &calendar, Utc::now())
+        .map_err(|e| LinearError::InvalidInput(format!("--since {}: {}", since, e)))?;
+
+    // Everything created or completed in the window
+    let (start, end) = (window.start.to_rfc3339(), window.end.to_rfc3339());
+    let mut filter = json!({
+        "or": [
+            { "createdAt": { "gte": start, "lt": end } },
+            { "completedAt": { "gte": start, "lt": end } }
+        ]
+    });
+    let mut team_name = None;
+    if let Some(team) = matches.get_one::<String>("team") {
+        let team = Resolver::new(&client).team(team).await?;
+        filter["team"] = json!({"id": {"eq": team.id}});
+        team_name = Some(team.key);
+    }
+
+    let issues = client.get_all_issues(Some(filter)).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to fetch issues: {}", e)))
+        .context("Loading issues for the report")?;
+    let mut report = Report::build(&issues, window, group_by);
+    report.team = team_name;
+
+    match matches.get_one::<String>("format").map(|s| s.as_str()).unwrap_or("table") {
+        "json" => println!("{}", serde_json::to_string_pretty(&render_json(&report))?),
+        "markdown" => print!("{}", render_markdown(&report)),
+        _ => {
+            for line in render_table(&report, terminal_width()) {
+                println!("{}", line);
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,9 +1,21 @@
 use clap::ArgMatches;
 use colored::*;
+use crate::cli_context::CliContext;
 use crate::client::LinearClient;
+use crate::commands::filter::{bind_search, load_calendar, print_diagnostics};
 use crate::config::{get_api_key, load_config, save_config};
-use crate::filtering::FilterAdapter;
+use crate::error::{ErrorContext, LinearError, LinearResult};
+use crate::filtering::{
+    check, check_search, diagnose, graphql_to_query, matches as filter_matches, order_by, parse_filter, parse_sort,
+    sort_issues, split_for_server, variables, Calendar, Diagnostic, FilterAdapter, FilterError, Variable,
+    Vocabulary,
+};
 use crate::formatting::issues::print_issues;
+use crate::formatting::output::is_structured;
+use crate::formatting::table::columns_from_arg;
+use crate::formatting::template::OutputTemplate;
+use crate::formatting::snippets::{print_search_results, search_results_json, search_terms};
+use crate::resolver::{match_view, Resolver};
 
 pub async fn handle_save_search(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
     let name = matches.get_one::<String>("name")
@@ -11,27 +23,48 @@ pub async fn handle_save_search(matches: &ArgMatches) -> Result<(), Box<dyn std:
     let query = matches.get_one::<String>("query")
         .ok_or("Search query is required")?;
     
-    // Validate the query using the new filter system
-    match FilterAdapter::parse_and_build(query) {
-        Ok(_) => {
-            let mut config = load_config();
-            config.saved_searches.insert(name.clone(), query.clone());
-            save_config(&config)?;
-            
-            println!("✅ Saved search '{}' successfully!", name);
-            println!("Query: {}", query);
-            println!("\nRun it with: linear search run {}", name);
-        }
-        Err(e) => {
-            eprintln!("Error: Invalid filter query - {}", e);
-            eprintln!("Use 'linear issues --help' to see filter syntax examples");
-            return Err(e.into());
-        }
+    // Refuse queries that don't parse or name unknown fields; a saved search that
+    // quietly falls back to the legacy syntax is hard to notice later. Variables are
+    // checked with their defaults or stand-in values filled in.
+    let diagnostics = check_search(query, &Vocabulary::load().unwrap_or_default());
+    print_diagnostics(query, &diagnostics);
+    if diagnostics.iter().any(Diagnostic::is_error) {
+        eprintln!("Use 'linear issues --help' to see filter syntax examples");
+        return Err("Invalid filter query".into());
     }
-    
+
+    let mut config = load_config();
+    config.saved_searches.insert(name.clone(), query.clone());
+    save_config(&config)?;
+
+    println!("✅ Saved search '{}' successfully!", name);
+    println!("Query: {}", query);
+    let variables = variables(query)?;
+    if !variables.is_empty() {
+        println!("Variables: {}", describe_variables(&variables));
+    }
+    let required: Vec<String> = variables
+        .iter()
+        .filter(|variable| !variable.is_builtin() && variable.default.is_none())
+        .map(|variable| format!(" --set {}=VALUE", variable.name))
+        .collect();
+    println!("\nRun it with: linear search run {}{}", name, required.concat());
+
     Ok(())
 }
 
+/// Each distinct variable once, with its default: `$me, ${label:-bug}`
+fn describe_variables(variables: &[Variable]) -> String {
+    let mut shown: Vec<String> = Vec::new();
+    for variable in variables {
+        let display = variable.display();
+        if !shown.contains(&display) {
+            shown.push(display);
+        }
+    }
+    shown.join(", ")
+}
+
 pub async fn handle_list_searches() -> Result<(), Box<dyn std::error::Error>> {
     let config = load_config();
     
@@ -48,6 +81,11 @@ pub async fn handle_list_searches() -> Result<(), Box<dyn std::error::Error>> {
         for (name, query) in searches {
             println!("\n{} {}", "▸".bright_blue(), name.bright_cyan().bold());
             println!("  Query: {}", query);
+            if let Ok(variables) = variables(query) {
+                if !variables.is_empty() {
+                    println!("  Variables: {}", describe_variables(&variables));
+                }
+            }
             println!("  Run: linear search run {}", name);
         }
     }
@@ -76,35 +114,48 @@ pub async fn handle_run_search(matches: &ArgMatches) -> Result<(), Box<dyn std::
         .ok_or("Search name is required")?;
     
     let config = load_config();
-    let query = config.saved_searches.get(name)
+    let saved = config.saved_searches.get(name)
         .ok_or(format!("Saved search '{}' not found", name))?;
-    
-    println!("Running saved search '{}': {}", name.bright_cyan(), query);
-    println!("{}", "─".repeat(80));
-    
+
     // Parse and execute the search
     let api_key = get_api_key()?;
-    let client = LinearClient::new(api_key);
-    
+    let client = LinearClient::new(api_key)?;
+    let query = &bind_search(&client, name, saved, matches).await?;
+
     let format = matches.get_one::<String>("format").map(|s| s.as_str()).unwrap_or("simple");
+    let template = OutputTemplate::from_args(matches.get_one::<String>("template"), matches.get_one::<String>("template-file"))?;
+    let columns = columns_from_arg(matches.get_one::<String>("columns"))?;
+    if template.is_none() && !is_structured(format) {
+        println!("Running saved search '{}': {}", name.bright_cyan(), query);
+        println!("{}", "─".repeat(80));
+    }
+
     let limit = matches.get_one::<String>("limit")
         .and_then(|s| s.parse::<i32>().ok())
         .unwrap_or(50);
     
-    match FilterAdapter::parse_and_build(query) {
-        Ok(filter) => {
+    let calendar = load_calendar(&client, query, None).await?;
+
+    match FilterAdapter::parse_and_build_sorted(query, &calendar) {
+        Ok((filter, mut sort_keys)) => {
             let filter_param = if filter.as_object().unwrap().is_empty() {
                 None
             } else {
                 Some(filter)
             };
+            if let Some(spec) = matches.get_one::<String>("sort") {
+                sort_keys = parse_sort(spec)?;
+            }
             
-            let issues = client.get_issues(filter_param, Some(limit)).await?;
+            let mut issues = client.get_issues_ordered(filter_param, Some(limit), order_by(&sort_keys)).await?;
+            sort_issues(&mut issues, &sort_keys);
             
-            if issues.is_empty() {
+            if let Some(template) = &template {
+                template.print(&issues);
+            } else if issues.is_empty() && !is_structured(format) {
                 println!("No issues found matching your saved search.");
             } else {
-                print_issues(&issues, format, "status");
+                print_issues(&issues, format, "status", &columns);
             }
         }
         Err(e) => {
@@ -114,4 +165,179 @@ pub async fn handle_run_search(matches: &ArgMatches) -> Result<(), Box<dyn std::
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Publish a saved search as a Linear custom view, updating the view of the same
+/// name if there is one.
+pub async fn handle_push_search(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let name = matches.get_one::<String>("name")
+        .ok_or("Search name is required")?;
+    let view_name = matches.get_one::<String>("view").unwrap_or(name);
+    let shared = !matches.get_flag("personal");
+
+    let config = load_config();
+    let saved = config.saved_searches.get(name)
+        .ok_or(format!("Saved search '{}' not found", name))?;
+
+    let api_key = get_api_key()?;
+    let client = LinearClient::new(api_key)?;
+
+    // A view can't hold variables, so they're filled in now
+    let query = &bind_search(&client, name, saved, matches).await?;
+    let calendar = load_calendar(&client, query, None).await?;
+    let filter = parse_filter(query)
+        .map_err(|e| format!("Saved search '{}' is not a valid filter: {}", name, e))?
+        .to_graphql(&calendar)
+        .map_err(|e| format!("Failed to compile saved search '{}': {}", name, e))?;
+
+    let views = client.get_custom_views().await?;
+    let existing = views.iter().find(|v| v.name.eq_ignore_ascii_case(view_name));
+
+    let view = match existing {
+        Some(view) => {
+            let view = client.update_custom_view_filter(&view.id, filter).await?;
+            println!("✅ Updated view '{}' from saved search '{}'", view.name.bright_cyan(), name);
+            view
+        }
+        None => {
+            let team_id = match matches.get_one::<String>("team") {
+                Some(team) => Some(Resolver::new(&client).team(team).await?.id),
+                None => None,
+            };
+            let view = client.create_custom_view(view_name, filter, team_id.as_deref(), shared).await?;
+            println!("✅ Created view '{}' from saved search '{}'", view.name.bright_cyan(), name);
+            view
+        }
+    };
+
+    println!("Query: {}", query);
+    println!("\nRun it with: linear view run \"{}\"", view.name);
+
+    Ok(())
+}
+
+/// Import custom views as saved searches. Views whose filter can't be written in
+/// the query syntax are skipped; they can still be run with `linear view run`.
+pub async fn handle_pull_searches(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let overwrite = matches.get_flag("force");
+
+    let api_key = get_api_key()?;
+    let client = LinearClient::new(api_key)?;
+
+    let views = client.get_custom_views().await?;
+    let selected = match matches.get_one::<String>("view") {
+        Some(query) => vec![match_view(&views, query)?],
+        None => views.iter().collect(),
+    };
+
+    let mut config = load_config();
+    let mut imported = 0;
+
+    for view in selected {
+        let query = match graphql_to_query(&view.filter_data) {
+            Ok(query) => query,
+            Err(e) => {
+                println!("  {} {} ({})", "✗".bright_red(), view.name, e);
+                continue;
+            }
+        };
+
+        match config.saved_searches.get(&view.name) {
+            Some(current) if *current == query => {
+                println!("  {} {} (up to date)", "·".dimmed(), view.name);
+                continue;
+            }
+            Some(_) if !overwrite => {
+                println!("  {} {} (a different saved search has this name; use --force to replace it)", "✗".bright_red(), view.name);
+                continue;
+            }
+            _ => {}
+        }
+
+        println!("  {} {}: {}", "✓".bright_green(), view.name.bright_cyan(), query);
+        config.saved_searches.insert(view.name.clone(), query);
+        imported += 1;
+    }
+
+    if imported > 0 {
+        save_config(&config)?;
+    }
+    println!("\n✅ Imported {} views as saved searches", imported);
+
+    Ok(())
+}
+
+pub async fn handle_text_search(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_text_search_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+/// Full-text search with Linear's issue search, narrowed by an optional filter
+/// query. Conditions Linear can't filter on are checked on the results.
+async fn handle_text_search_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let terms = matches.get_one::<String>("terms")
+        .ok_or_else(|| LinearError::InvalidInput("Search terms are required".to_string()))?;
+    let format = matches.get_one::<String>("format").map(|s| s.as_str()).unwrap_or("simple");
+    let limit = matches.get_one::<String>("limit")
+        .and_then(|s| s.parse::<i32>().ok())
+        .unwrap_or(25);
+
+    let client = CliContext::load()?.verified_client()?;
+
+    let mut server_filter = None;
+    let mut local_filter = None;
+    let mut sort_keys = Vec::new();
+    let mut calendar = Calendar::from_config();
+    if let Some(query) = matches.get_one::<String>("filter") {
+        let diagnostics = diagnose(query, &Vocabulary::load().unwrap_or_default());
+        print_diagnostics(query, &diagnostics);
+        if diagnostics.iter().any(Diagnostic::is_error) {
+            return Err(LinearError::InvalidInput("Invalid filter query".to_string()));
+        }
+        calendar = load_calendar(&client, query, None).await.context("Loading cycle dates for the filter")?;
+        let builder = parse_filter(query).map_err(|e| LinearError::InvalidInput(format!("Invalid filter: {}", e)))?;
+        sort_keys = builder.sort_keys().to_vec();
+        match builder.build() {
+            Ok(expr) => {
+                (server_filter, local_filter) = split_for_server(&expr, &calendar)
+                    .map_err(|e| LinearError::InvalidInput(format!("Failed to build filter: {}", e)))
+                    .with_context(|| format!("Filter query: {}", query))?;
+            }
+            Err(FilterError::EmptyFilter) => {}
+            Err(e) => return Err(LinearError::InvalidInput(format!("Failed to build filter: {}", e))),
+        }
+    }
+
+    let viewer_id = match &local_filter {
+        Some(_) => Some(Resolver::new(&client).viewer().await
+            .context("Getting viewer information for the filter")?.id),
+        None => None,
+    };
+    if let Some(local) = &local_filter {
+        check(local, viewer_id.as_deref(), &calendar)
+            .map_err(|e| LinearError::InvalidInput(format!("Failed to build filter: {}", e)))?;
+    }
+
+    // Fetch extra results when some are filtered out locally
+    let fetch_limit = if local_filter.is_some() { (limit * 4).min(250) } else { limit };
+    let mut results = client.search_issues(terms, server_filter, Some(fetch_limit), !matches.get_flag("no-comments")).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to search issues: {}", e)))
+        .context("Searching issues")?;
+    if let Some(local) = &local_filter {
+        results.retain(|result| filter_matches(local, &result.issue, viewer_id.as_deref(), &calendar).unwrap_or(false));
+    }
+    results.truncate(limit.max(0) as usize);
+    // Relevance order unless the filter asks for another
+    if !sort_keys.is_empty() {
+        let mut issues: Vec<_> = results.iter().map(|result| result.issue.clone()).collect();
+        sort_issues(&mut issues, &sort_keys);
+        results.sort_by_key(|result| issues.iter().position(|issue| issue.id == result.issue.id));
+    }
+
+    let terms = search_terms(terms);
+    match format {
+        "json" => println!("{}", serde_json::to_string_pretty(&search_results_json(&results, &terms))?),
+        _ if results.is_empty() => println!("No issues found matching your search."),
+        _ => print_search_results(&results, &terms),
+    }
+    Ok(())
+}
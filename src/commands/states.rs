@@ -0,0 +1,215 @@
+use clap::ArgMatches;
+use colored::*;
+use crate::cli_context::CliContext;
+use crate::error::{LinearError, LinearResult, ErrorContext};
+use crate::formatting::issues::print_workflow_states;
+use crate::formatting::utils::normalize_hex_color;
+use crate::models::WorkflowState;
+use crate::resolver::{resolve_state, sort_states, state_type_alias, team_states, Resolver};
+
+/// Default colors Linear uses for new states of each type.
+fn default_state_color(state_type: &str) -> &'static str {
+    match state_type {
+        "triage" => "#fc7840",
+        "backlog" => "#bec2c8",
+        "unstarted" => "#e2e2e2",
+        "started" => "#f2c94c",
+        "completed" => "#5e6ad2",
+        _ => "#95a2b3",
+    }
+}
+
+/// Position that places `target` directly before or after `anchor`, given a team's
+/// states in board order. Only states of the anchor's type are considered, since
+/// Linear orders states within their type.
+pub fn reorder_position(ordered: &[&WorkflowState], target_id: &str, anchor_id: &str, place_before: bool) -> Option<f64> {
+    let anchor = ordered.iter().find(|s| s.id == anchor_id)?;
+    let column: Vec<f64> = ordered
+        .iter()
+        .filter(|s| s.id != target_id && s.state_type == anchor.state_type)
+        .map(|s| s.position.unwrap_or(0.0))
+        .collect();
+    let anchor_pos = anchor.position.unwrap_or(0.0);
+    let index = column.iter().position(|p| *p == anchor_pos)?;
+
+    let neighbor = if place_before {
+        index.checked_sub(1).and_then(|i| column.get(i))
+    } else {
+        column.get(index + 1)
+    };
+
+    Some(match neighbor {
+        Some(neighbor) => (anchor_pos + neighbor) / 2.0,
+        None if place_before => anchor_pos - 1.0,
+        None => anchor_pos + 1.0,
+    })
+}
+
+pub async fn handle_states(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_states_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_states_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let mut resolver = Resolver::new(&client);
+
+    if let Some(team) = matches.get_one::<String>("team") {
+        let team = resolver.team(team).await?;
+        let states = resolver.states().await?;
+        let ordered = team_states(states, &team.id);
+        if ordered.is_empty() {
+            println!("No workflow states found for {}.", team.name);
+        } else {
+            println!("{} ({}):", team.name.bold(), team.key.cyan());
+            print_workflow_states(&ordered);
+        }
+        return Ok(());
+    }
+
+    let states = resolver.states().await?;
+    if states.is_empty() {
+        println!("No workflow states found.");
+        return Ok(());
+    }
+
+    let mut teams: Vec<&crate::models::Team> = Vec::new();
+    for state in states {
+        if let Some(team) = &state.team {
+            if !teams.iter().any(|t| t.id == team.id) {
+                teams.push(team);
+            }
+        }
+    }
+    teams.sort_by(|a, b| a.key.cmp(&b.key));
+
+    for team in teams {
+        println!("\n{} ({}):", team.name.bold(), team.key.cyan());
+        print_workflow_states(&team_states(states, &team.id));
+    }
+
+    Ok(())
+}
+
+pub async fn handle_state_create(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_state_create_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_state_create_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let name = matches.get_one::<String>("name")
+        .ok_or_else(|| LinearError::InvalidInput("State name is required".to_string()))?;
+    let team = matches.get_one::<String>("team")
+        .ok_or_else(|| LinearError::InvalidInput("Team is required".to_string()))?;
+    let state_type = matches.get_one::<String>("type")
+        .ok_or_else(|| LinearError::InvalidInput("State type is required".to_string()))?;
+    let state_type = state_type_alias(state_type)
+        .ok_or_else(|| LinearError::InvalidInput(format!(
+            "Invalid state type '{}'. Use triage, backlog, unstarted, started, completed or canceled",
+            state_type
+        )))?;
+    let color = match matches.get_one::<String>("color") {
+        Some(color) => normalize_hex_color(color)
+            .ok_or_else(|| LinearError::InvalidInput(format!("Invalid color '{}'. Use a hex color like #5e6ad2", color)))?,
+        None => default_state_color(state_type).to_string(),
+    };
+    let description = matches.get_one::<String>("description");
+
+    let mut resolver = Resolver::new(&client);
+    let team = resolver.team(team).await?;
+    let states = resolver.states().await?;
+
+    if team_states(states, &team.id).iter().any(|s| s.name.eq_ignore_ascii_case(name)) {
+        return Err(LinearError::InvalidInput(format!("{} already has a state named '{}'", team.key, name)));
+    }
+
+    // Append to the end of its type's column
+    let position = team_states(states, &team.id)
+        .iter()
+        .filter(|s| s.state_type == state_type)
+        .filter_map(|s| s.position)
+        .fold(None, |max: Option<f64>, p| Some(max.map_or(p, |m| m.max(p))))
+        .map(|max| max + 1.0);
+
+    let state = client.create_workflow_state(
+        &team.id,
+        name,
+        state_type,
+        &color,
+        position,
+        description.map(|d| d.as_str()),
+    ).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to create state: {}", e)))
+        .context("Creating workflow state")?;
+
+    println!("✅ State {} created in {}", state.name.bold(), team.key.cyan());
+    println!("Type: {}", state.state_type);
+    println!("State ID: {}", state.id);
+
+    Ok(())
+}
+
+pub async fn handle_state_move(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_state_move_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_state_move_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let query = matches.get_one::<String>("state")
+        .ok_or_else(|| LinearError::InvalidInput("State is required".to_string()))?;
+    let team = matches.get_one::<String>("team")
+        .ok_or_else(|| LinearError::InvalidInput("Team is required".to_string()))?;
+    let (anchor_query, place_before) = match (matches.get_one::<String>("before"), matches.get_one::<String>("after")) {
+        (Some(before), _) => (before, true),
+        (None, Some(after)) => (after, false),
+        (None, None) => return Err(LinearError::InvalidInput("Use --before or --after to say where the state goes".to_string())),
+    };
+
+    let mut resolver = Resolver::new(&client);
+    let team = resolver.team(team).await?;
+    let states = resolver.states().await?;
+
+    let state = resolve_state(states, &team.id, query)?;
+    let anchor = resolve_state(states, &team.id, anchor_query)?;
+
+    if state.id == anchor.id {
+        return Err(LinearError::InvalidInput("Cannot move a state relative to itself".to_string()));
+    }
+    if state.state_type != anchor.state_type {
+        return Err(LinearError::InvalidInput(format!(
+            "'{}' is a {} state and '{}' is a {} state; states can only be reordered within their type",
+            state.name, state.state_type, anchor.name, anchor.state_type
+        )));
+    }
+
+    let ordered = team_states(states, &team.id);
+    let position = reorder_position(&ordered, &state.id, &anchor.id, place_before)
+        .ok_or_else(|| LinearError::StateError(format!("Could not place '{}' next to '{}'", state.name, anchor.name)))?;
+
+    let updated = client.update_workflow_state(&state.id, None, None, Some(position)).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to move state: {}", e)))
+        .with_context(|| format!("Moving state {}", state.name))?;
+
+    println!(
+        "✅ Moved {} {} {}",
+        updated.name.bold(),
+        if place_before { "before" } else { "after" },
+        anchor.name.bold()
+    );
+
+    // Show the new order, patching in the updated position
+    let mut refreshed: Vec<WorkflowState> = team_states(states, &team.id).into_iter().cloned().collect();
+    if let Some(moved) = refreshed.iter_mut().find(|s| s.id == updated.id) {
+        moved.position = updated.position.or(Some(position));
+    }
+    let mut ordered: Vec<&WorkflowState> = refreshed.iter().collect();
+    sort_states(&mut ordered);
+    print_workflow_states(&ordered);
+
+    Ok(())
+}
@@ -2,19 +2,30 @@ use clap::ArgMatches;
 use crate::cli_context::CliContext;
 use crate::error::{LinearError, LinearResult, ErrorContext};
 use crate::formatting::issues::print_teams;
+use crate::formatting::output::print_records;
+use crate::formatting::template::OutputTemplate;
 
-pub async fn handle_teams(_matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
-    handle_teams_impl(_matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+pub async fn handle_teams(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_teams_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
 }
 
-async fn handle_teams_impl(_matches: &ArgMatches) -> LinearResult<()> {
+async fn handle_teams_impl(matches: &ArgMatches) -> LinearResult<()> {
     let mut context = CliContext::load().context("Failed to load CLI context")?;
     let client = context.verified_client().context("Failed to get Linear client")?;
+    let format = matches.get_one::<String>("format").map(|s| s.as_str()).unwrap_or("simple");
+    let template = OutputTemplate::from_args(matches.get_one::<String>("template"), matches.get_one::<String>("template-file"))?;
 
     let teams = client.get_teams().await
         .map_err(|e| LinearError::ApiError(format!("Failed to get teams: {}", e)))
         .context("Getting teams")?;
     
+    if let Some(template) = &template {
+        template.print(&teams);
+        return Ok(());
+    }
+    if print_records(&teams, format) {
+        return Ok(());
+    }
     if teams.is_empty() {
         println!("No teams found.");
     } else {
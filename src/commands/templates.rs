@@ -0,0 +1,48 @@
+use clap::ArgMatches;
+use colored::*;
+use crate::cli_context::CliContext;
+use crate::error::{ErrorContext, LinearResult};
+use crate::templates::{load_templates, placeholders, templates_dir, TemplateSource};
+
+pub async fn handle_templates(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_templates_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_templates_impl(_matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let templates = load_templates(&client).await?;
+
+    if templates.is_empty() {
+        println!("No issue templates found.");
+    } else {
+        println!("Found {} issue templates:", templates.len());
+        for template in &templates {
+            let source = match &template.source {
+                TemplateSource::Local(path) => path.display().to_string(),
+                TemplateSource::Linear { .. } => "Linear".to_string(),
+            };
+            println!("  {} {}", template.name.bold(), format!("({})", source).dimmed());
+
+            let text = format!(
+                "{}\n{}",
+                template.title.as_deref().unwrap_or(""),
+                template.description.as_deref().unwrap_or("")
+            );
+            let vars: Vec<String> = placeholders(&text)
+                .into_iter()
+                .filter(|name| name != "title" && name != "date")
+                .collect();
+            if !vars.is_empty() {
+                println!("    {} {}", "vars:".dimmed(), vars.join(", "));
+            }
+        }
+    }
+
+    if let Some(dir) = templates_dir() {
+        println!("\nLocal templates are read from {}", dir.display().to_string().cyan());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone, Utc};
+use clap::ArgMatches;
+use colored::*;
+use crate::cli_context::CliContext;
+use crate::error::{ErrorContext, LinearError, LinearResult};
+use crate::formatting::hyperlink::hyperlink;
+use crate::formatting::output::to_csv;
+use crate::resolver::Resolver;
+use crate::timer::{format_duration, summarize, week_bounds, Journal, Session, TimesheetRow};
+
+fn print_stopped(session: &Session) {
+    println!(
+        "{} Stopped {} after {}",
+        "■".red(),
+        session.identifier.bright_blue(),
+        format_duration(session.seconds(Utc::now())).bold()
+    );
+}
+
+/// The comment posted on `--comment`: the time logged plus an optional note.
+fn summary_comment(session: &Session, message: Option<&str>) -> String {
+    let started = session.started_at.with_timezone(&Local);
+    let ended = session.ended_at.unwrap_or_else(Utc::now).with_timezone(&Local);
+    let mut body = format!(
+        "⏱ Logged {} ({} {}–{})",
+        format_duration(session.seconds(Utc::now())),
+        started.format("%Y-%m-%d"),
+        started.format("%H:%M"),
+        ended.format("%H:%M")
+    );
+    if let Some(message) = message.filter(|m| !m.trim().is_empty()) {
+        body.push_str("\n\n");
+        body.push_str(message.trim());
+    }
+    body
+}
+
+pub async fn handle_timer_start(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_timer_start_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_timer_start_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let identifier = matches.get_one::<String>("issue")
+        .ok_or_else(|| LinearError::InvalidInput("Issue identifier is required".to_string()))?;
+    let issue = Resolver::new(&client).issue(identifier).await?;
+
+    let mut journal = Journal::load().context("Loading timer journal")?;
+    if journal.running().is_some_and(|s| s.issue_id == issue.id) {
+        println!("Timer already running for {}", hyperlink(&issue.identifier, &issue.url).bright_blue());
+        return Ok(());
+    }
+    let stopped = journal.start(&issue, Utc::now());
+    journal.save().context("Saving timer journal")?;
+
+    if let Some(stopped) = &stopped {
+        print_stopped(stopped);
+    }
+    println!("{} Started timer for {} - {}", "▶".green(), hyperlink(&issue.identifier, &issue.url).bright_blue(), issue.title);
+    Ok(())
+}
+
+pub async fn handle_timer_stop(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_timer_stop_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_timer_stop_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut journal = Journal::load().context("Loading timer journal")?;
+    let Some(session) = journal.stop(Utc::now()) else {
+        println!("No timer running.");
+        return Ok(());
+    };
+    journal.save().context("Saving timer journal")?;
+    print_stopped(&session);
+
+    if matches.get_flag("comment") {
+        let mut context = CliContext::load().context("Failed to load CLI context")?;
+        let client = context.verified_client().context("Failed to get Linear client")?;
+        let body = summary_comment(&session, matches.get_one::<String>("message").map(|s| s.as_str()));
+        client.create_comment(&session.issue_id, &body, None).await
+            .map_err(|e| LinearError::ApiError(format!("Failed to create comment: {}", e)))
+            .with_context(|| format!("Commenting on {}", session.identifier))?;
+        println!("Posted time summary on {}", session.identifier.bright_blue());
+    }
+    Ok(())
+}
+
+pub async fn handle_timer_status() -> Result<(), Box<dyn std::error::Error>> {
+    handle_timer_status_impl().map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+fn handle_timer_status_impl() -> LinearResult<()> {
+    let journal = Journal::load().context("Loading timer journal")?;
+    match journal.running() {
+        Some(session) => {
+            println!(
+                "{} {} - {} {}",
+                "▶".green(),
+                session.identifier.bright_blue(),
+                session.title,
+                format_duration(session.seconds(Utc::now())).bold()
+            );
+            println!(
+                "  {} {}",
+                "Started".dimmed(),
+                session.started_at.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string().dimmed()
+            );
+        }
+        None => println!("No timer running."),
+    }
+    Ok(())
+}
+
+fn parse_day(value: &str, flag: &str) -> LinearResult<DateTime<Utc>> {
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| LinearError::InvalidInput(format!("--{} expects a date like 2024-01-31, got '{}'", flag, value)))?;
+    Local.from_local_datetime(&date.and_time(NaiveTime::MIN))
+        .earliest()
+        .map(|at| at.with_timezone(&Utc))
+        .ok_or_else(|| LinearError::InvalidInput(format!("--{} is not a valid local date: {}", flag, value)))
+}
+
+fn print_table(heading: &str, rows: &[TimesheetRow], show_label: bool) {
+    println!("\n{}", heading.bold());
+    let width = rows.iter().map(|r| r.key.chars().count()).max().unwrap_or(0);
+    for row in rows {
+        let hours = format!("{:>6.2}h", row.seconds as f64 / 3600.0);
+        if show_label {
+            println!("  {:<width$}  {}  {}", row.key.bright_blue(), hours.bold(), row.label, width = width);
+        } else {
+            println!("  {:<width$}  {}", row.key, hours.bold(), width = width);
+        }
+    }
+}
+
+pub async fn handle_timesheet(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_timesheet_impl(matches).map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+fn handle_timesheet_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let now = Utc::now();
+    let (from, to) = match (matches.get_one::<String>("since"), matches.get_one::<String>("until")) {
+        (None, None) => {
+            let weeks_ago = matches.get_one::<String>("weeks-ago")
+                .map(|s| s.parse::<i64>().map_err(|_| LinearError::InvalidInput(format!("--weeks-ago expects a number, got '{}'", s))))
+                .transpose()?
+                .unwrap_or(0);
+            week_bounds(now.with_timezone(&Local), weeks_ago)
+        }
+        (since, until) => {
+            let from = since.map(|s| parse_day(s, "since")).transpose()?.unwrap_or(DateTime::<Utc>::MIN_UTC);
+            // --until is inclusive, so the range ends at the start of the next day
+            let to = until.map(|s| parse_day(s, "until")).transpose()?
+                .map(|at| at + chrono::Duration::days(1))
+                .unwrap_or(now);
+            (from, to)
+        }
+    };
+
+    let journal = Journal::load().context("Loading timer journal")?;
+    let (issues, projects) = summarize(&journal.sessions, from, to, now);
+    let total: i64 = issues.iter().map(|r| r.seconds).sum();
+
+    let format = matches.get_one::<String>("format").map(|s| s.as_str()).unwrap_or("table");
+    if format == "csv" {
+        let header = ["kind", "key", "name", "hours"].map(String::from).to_vec();
+        let rows = [("issue", &issues), ("project", &projects)].into_iter().flat_map(|(kind, rows)| {
+            rows.iter().map(move |row| vec![kind.to_string(), row.key.clone(), row.label.clone(), format!("{:.2}", row.seconds as f64 / 3600.0)])
+        });
+        print!("{}", to_csv(std::iter::once(header).chain(rows)));
+        return Ok(());
+    }
+
+    let last_day = (to - chrono::Duration::seconds(1)).with_timezone(&Local);
+    let first_day = if from == DateTime::<Utc>::MIN_UTC {
+        "the beginning".to_string()
+    } else {
+        from.with_timezone(&Local).format("%Y-%m-%d").to_string()
+    };
+    println!("Timesheet {} to {}", first_day, last_day.format("%Y-%m-%d"));
+    if issues.is_empty() {
+        println!("No time tracked.");
+        return Ok(());
+    }
+    print_table("By issue", &issues, true);
+    print_table("By project", &projects, false);
+    println!("\n{} {}", "Total".bold(), format_duration(total).bold());
+    Ok(())
+}
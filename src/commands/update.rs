@@ -2,6 +2,9 @@ use clap::ArgMatches;
 use colored::*;
 use crate::cli_context::CliContext;
 use crate::error::{LinearError, LinearResult, ErrorContext};
+use crate::filtering::parse_priority;
+use crate::formatting::hyperlink::hyperlink;
+use crate::resolver::Resolver;
 
 pub async fn handle_update_issue(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
     handle_update_issue_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
@@ -16,42 +19,57 @@ async fn handle_update_issue_impl(matches: &ArgMatches) -> LinearResult<()> {
     
     let title = matches.get_one::<String>("title");
     let description = matches.get_one::<String>("description");
-    let state_id = matches.get_one::<String>("state");
+    let state = matches.get_one::<String>("state");
     let priority = matches.get_one::<String>("priority")
-        .and_then(|p| match p.as_str() {
-            "none" | "0" => Some(0),
-            "low" | "1" => Some(1),
-            "medium" | "2" => Some(2),
-            "high" | "3" => Some(3),
-            "urgent" | "4" => Some(4),
-            _ => None,
-        });
-    let assignee_id = matches.get_one::<String>("assignee");
-    let label_ids: Option<Vec<&str>> = matches.get_many::<String>("labels")
-        .map(|labels| labels.map(|s| s.as_str()).collect());
+        .and_then(|p| parse_priority(p));
+    let assignee = matches.get_one::<String>("assignee");
+    let labels: Option<Vec<&String>> = matches.get_many::<String>("labels")
+        .map(|labels| labels.collect());
 
     // Check if at least one field is being updated
-    if title.is_none() && description.is_none() && state_id.is_none() && 
-       priority.is_none() && assignee_id.is_none() && label_ids.is_none() {
+    if title.is_none() && description.is_none() && state.is_none() && 
+       priority.is_none() && assignee.is_none() && labels.is_none() {
         return Err(LinearError::InvalidInput("No fields to update. Provide at least one field to update.".to_string()));
     }
 
+    let mut resolver = Resolver::new(&client);
+
+    // States and team labels are per team, so resolve them against the issue's own team
+    let team_id = if state.is_some() || labels.is_some() {
+        Some(resolver.issue(issue_id).await?.team.id)
+    } else {
+        None
+    };
+    let state_id = match (state, &team_id) {
+        (Some(state), Some(team_id)) => Some(resolver.state(team_id, state).await?.id),
+        _ => None,
+    };
+    // An empty assignee id unassigns the issue
+    let assignee_id = match assignee {
+        Some(assignee) => Some(resolver.assignee_id(assignee).await?.unwrap_or_default()),
+        None => None,
+    };
+    let label_ids = match &labels {
+        Some(labels) => Some(resolver.label_ids(labels, team_id.as_deref()).await?),
+        None => None,
+    };
+
     let issue = client.update_issue(
         issue_id,
         title.map(|s| s.as_str()),
         description.map(|s| s.as_str()),
-        state_id.map(|s| s.as_str()),
+        state_id.as_deref(),
         priority,
-        assignee_id.map(|s| s.as_str()),
-        label_ids,
+        assignee_id.as_deref(),
+        label_ids.as_ref().map(|ids| ids.iter().map(|s| s.as_str()).collect()),
     ).await
         .map_err(|e| LinearError::ApiError(format!("Failed to update issue: {}", e)))
         .context("Updating issue")?;
 
     println!("{} {}", "✅".green(), "Issue updated successfully!".green().bold());
-    println!("{}: {}", "ID".bold(), issue.identifier.bright_blue().bold());
+    println!("{}: {}", "ID".bold(), hyperlink(&issue.identifier, &issue.url).bright_blue().bold());
     println!("{}: {}", "Title".bold(), issue.title);
-    println!("{}: {}", "URL".bold(), issue.url.bright_black());
+    println!("{}: {}", "URL".bold(), hyperlink(&issue.url, &issue.url).bright_black());
     println!("{}: {}", "State".bold(), issue.state.name);
 
     Ok(())
@@ -65,8 +83,8 @@ async fn handle_update_project_impl(matches: &ArgMatches) -> LinearResult<()> {
     let mut context = CliContext::load().context("Failed to load CLI context")?;
     let client = context.verified_client().context("Failed to get Linear client")?;
 
-    let project_id = matches.get_one::<String>("id")
-        .ok_or_else(|| LinearError::InvalidInput("Project ID is required".to_string()))?;
+    let project = matches.get_one::<String>("id")
+        .ok_or_else(|| LinearError::InvalidInput("Project is required".to_string()))?;
     
     let name = matches.get_one::<String>("name");
     let description = matches.get_one::<String>("description");
@@ -77,8 +95,10 @@ async fn handle_update_project_impl(matches: &ArgMatches) -> LinearResult<()> {
         return Err(LinearError::InvalidInput("No fields to update. Provide at least one field to update.".to_string()));
     }
 
+    let project_id = Resolver::new(&client).project(project).await?.id;
+
     let project = client.update_project(
-        project_id,
+        &project_id,
         name.map(|s| s.as_str()),
         description.map(|s| s.as_str()),
         state.map(|s| s.as_str()),
@@ -88,8 +108,8 @@ async fn handle_update_project_impl(matches: &ArgMatches) -> LinearResult<()> {
 
     println!("✅ Project updated successfully!");
     println!("ID: {}", project.id);
-    println!("Name: {}", project.name);
-    println!("URL: {}", project.url);
+    println!("Name: {}", hyperlink(&project.name, &project.url));
+    println!("URL: {}", hyperlink(&project.url, &project.url));
     println!("State: {}", project.state);
 
     Ok(())
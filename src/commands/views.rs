@@ -0,0 +1,105 @@
+use clap::ArgMatches;
+use colored::*;
+use crate::cli_context::CliContext;
+use crate::error::{ErrorContext, LinearError, LinearResult};
+use crate::filtering::graphql_to_query;
+use crate::formatting::issues::print_issues;
+use crate::formatting::output::is_structured;
+use crate::formatting::table::columns_from_arg;
+use crate::formatting::template::OutputTemplate;
+use crate::resolver::match_view;
+
+pub async fn handle_views(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_views_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_views_impl(_matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let mut views = client.get_custom_views().await
+        .map_err(|e| LinearError::ApiError(format!("Failed to get custom views: {}", e)))
+        .context("Getting custom views")?;
+
+    if views.is_empty() {
+        println!("No custom views found.");
+        return Ok(());
+    }
+
+    views.sort_by_key(|v| v.name.to_lowercase());
+
+    println!("Custom views:");
+    println!("{}", "─".repeat(80));
+    for view in &views {
+        let scope = match &view.team {
+            Some(team) => team.key.clone(),
+            None => "workspace".to_string(),
+        };
+        let visibility = if view.shared { "shared" } else { "personal" };
+        println!(
+            "\n{} {} {}",
+            "▸".bright_blue(),
+            view.name.bright_cyan().bold(),
+            format!("({}, {})", scope, visibility).dimmed()
+        );
+        if let Some(description) = view.description.as_deref().filter(|d| !d.is_empty()) {
+            println!("  {}", description);
+        }
+        match graphql_to_query(&view.filter_data) {
+            Ok(query) => println!("  Query: {}", query),
+            Err(_) => println!("  Query: {}", "(uses filters the query syntax can't express)".dimmed()),
+        }
+    }
+    println!("\nRun one with: linear view run <name>");
+
+    Ok(())
+}
+
+pub async fn handle_view_run(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    handle_view_run_impl(matches).await.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+async fn handle_view_run_impl(matches: &ArgMatches) -> LinearResult<()> {
+    let mut context = CliContext::load().context("Failed to load CLI context")?;
+    let client = context.verified_client().context("Failed to get Linear client")?;
+
+    let name = matches.get_one::<String>("name")
+        .ok_or_else(|| LinearError::InvalidInput("View name is required".to_string()))?;
+    let format = matches.get_one::<String>("format").map(|s| s.as_str()).unwrap_or("simple");
+    let template = OutputTemplate::from_args(matches.get_one::<String>("template"), matches.get_one::<String>("template-file"))?;
+    let columns = columns_from_arg(matches.get_one::<String>("columns"))?;
+    let limit = matches.get_one::<String>("limit")
+        .and_then(|s| s.parse::<i32>().ok())
+        .unwrap_or(50);
+
+    let views = client.get_custom_views().await
+        .map_err(|e| LinearError::ApiError(format!("Failed to get custom views: {}", e)))
+        .context("Getting custom views")?;
+    let view = match_view(&views, name)?;
+
+    if template.is_none() && !is_structured(format) {
+        println!("Running view '{}'", view.name.bright_cyan());
+        println!("{}", "─".repeat(80));
+    }
+
+    // The view's filter is already an IssueFilter, so it runs as is on the server
+    let filter = match &view.filter_data {
+        serde_json::Value::Object(map) if map.is_empty() => None,
+        serde_json::Value::Null => None,
+        filter => Some(filter.clone()),
+    };
+
+    let issues = client.get_issues(filter, Some(limit)).await
+        .map_err(|e| LinearError::ApiError(format!("Failed to get issues: {}", e)))
+        .with_context(|| format!("Running view {}", view.name))?;
+
+    if let Some(template) = &template {
+        template.print(&issues);
+    } else if issues.is_empty() && !is_structured(format) {
+        println!("No issues found in this view.");
+    } else {
+        print_issues(&issues, format, "status", &columns);
+    }
+
+    Ok(())
+}
@@ -5,22 +5,27 @@ use serde::{Deserialize, Serialize};
 
 use crate::constants::CONFIG_FILE;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Config {
     pub api_key: Option<String>,
     pub default_team_id: Option<String>,
     #[serde(default)]
     pub saved_searches: HashMap<String, String>,
-}
-
-impl Default for Config {
-    fn default() -> Self {
-        Config {
-            api_key: None,
-            default_team_id: None,
-            saved_searches: HashMap::new(),
-        }
-    }
+    /// Timezone filter dates are read in: `local` (the default), `UTC` or an offset
+    /// like `+02:00`. `LINEAR_TIMEZONE` overrides it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Named `--template` output formats, e.g. `"bar": "{{identifier}} {{title}}"`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub output_templates: HashMap<String, String>,
+    /// Color theme: a preset (`dark`, `light`, `high-contrast`, `monochrome`) or the
+    /// name of a file in the themes directory. `LINEAR_THEME` overrides it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    /// Whether to write OSC 8 hyperlinks: `auto` (the default), `always` or `never`.
+    /// `LINEAR_HYPERLINKS` overrides it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hyperlinks: Option<String>,
 }
 
 pub fn load_config() -> Config {
@@ -11,6 +11,10 @@ pub const ISSUE_FIELDS: &str = r#"
     priority
     createdAt
     updatedAt
+    startedAt
+    completedAt
+    dueDate
+    estimate
     state {
         id
         name
@@ -36,6 +40,7 @@ pub const ISSUE_FIELDS: &str = r#"
     project {
         id
         name
+        url
     }
 "#;
 
@@ -44,6 +49,7 @@ pub const PROJECT_FIELDS: &str = r#"
     name
     description
     url
+    slugId
     createdAt
     state
     progress
@@ -59,4 +65,92 @@ pub const COMMENT_FIELDS: &str = r#"
         name
         email
     }
-"#;
\ No newline at end of file
+    parent {
+        id
+    }
+    reactions {
+        id
+        emoji
+        user {
+            id
+            name
+            email
+        }
+    }
+"#;
+pub const LABEL_FIELDS: &str = r#"
+    id
+    name
+    color
+    description
+    isGroup
+    parent {
+        id
+        name
+    }
+    team {
+        id
+        name
+        key
+    }
+"#;
+
+pub const WORKFLOW_STATE_FIELDS: &str = r#"
+    id
+    name
+    type
+    color
+    position
+    team {
+        id
+        name
+        key
+    }
+"#;
+
+pub const CUSTOM_VIEW_FIELDS: &str = r#"
+    id
+    name
+    description
+    filterData
+    shared
+    updatedAt
+    team {
+        id
+        name
+        key
+    }
+"#;
+
+// Documents are listed without their content; it is fetched per document
+pub const DOCUMENT_FIELDS: &str = r#"
+    id
+    title
+    url
+    slugId
+    createdAt
+    updatedAt
+    creator {
+        id
+        name
+        email
+    }
+    project {
+        id
+        name
+        url
+    }
+"#;
+
+// Local issue templates live in <config dir>/linear-cli/templates unless overridden
+pub const TEMPLATES_DIR: &str = "linear-cli/templates";
+pub const TEMPLATES_DIR_ENV: &str = "LINEAR_TEMPLATES_DIR";
+
+// Theme files, <name>.toml or <name>.json, under the config directory
+pub const THEMES_DIR: &str = "linear-cli/themes";
+
+// Timer sessions, one JSON object per line, under the config directory
+pub const TIMER_JOURNAL: &str = "linear-cli/timer.jsonl";
+
+// Label, state, member, project and team names for filter completion
+pub const COMPLETION_CACHE: &str = "linear-cli/completion.json";
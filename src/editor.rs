@@ -0,0 +1,43 @@
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::process::Command;
+
+use crate::error::{LinearError, LinearResult};
+
+/// The editor command from `$VISUAL` or `$EDITOR`, falling back to `vi`. The value
+/// may carry arguments, as in `code --wait`.
+pub fn editor_command() -> Vec<String> {
+    let editor = env::var("VISUAL")
+        .or_else(|_| env::var("EDITOR"))
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    editor.split_whitespace().map(str::to_string).collect()
+}
+
+/// Open `initial` in the user's editor as a markdown file and return what was saved.
+pub fn edit_text(initial: &str) -> LinearResult<String> {
+    let mut file = tempfile::Builder::new()
+        .prefix("linear-")
+        .suffix(".md")
+        .tempfile()?;
+    file.write_all(initial.as_bytes())?;
+    file.flush()?;
+
+    let command = editor_command();
+    let (program, args) = command.split_first()
+        .ok_or_else(|| LinearError::ConfigError("No editor configured".to_string()))?;
+
+    let status = Command::new(program)
+        .args(args)
+        .arg(file.path())
+        .status()
+        .map_err(|e| LinearError::ConfigError(format!("Failed to start editor '{}': {}", program, e)))?;
+
+    if !status.success() {
+        return Err(LinearError::InvalidInput(format!("Editor '{}' exited with {}", program, status)));
+    }
+
+    Ok(fs::read_to_string(file.path())?)
+}
@@ -37,6 +37,16 @@ pub enum LinearError {
     #[error("State error: {0}")]
     StateError(String),
     
+    #[error("Conflict: {0}")]
+    Conflict(String),
+    
+    #[error("Ambiguous {kind} '{query}', it matches: {}. Use a more specific value or an ID", .candidates.join(", "))]
+    Ambiguous {
+        kind: String,
+        query: String,
+        candidates: Vec<String>,
+    },
+    
     #[error("Unknown error: {0}")]
     Unknown(String),
 }
@@ -1,8 +1,10 @@
 #![allow(dead_code)]
 
-use serde_json::Value;
+use serde_json::{json, Value};
 
-use super::builder::FilterBuilder;
+use super::builder::{FilterBuilder, FilterError};
+use super::dates::Calendar;
+use super::sort::SortKey;
 use super::parser::parse_filter;
 use super::query::{FilterQuery, FilterOperator as LegacyOperator, parse_filter_query as legacy_parse, build_graphql_filter as legacy_build};
 
@@ -12,18 +14,28 @@ pub struct FilterAdapter;
 impl FilterAdapter {
     /// Parse a filter query string and return GraphQL filter JSON
     /// This provides a drop-in replacement for the existing parse + build workflow
-    pub fn parse_and_build(query: &str) -> Result<Value, String> {
+    pub fn parse_and_build(query: &str, calendar: &Calendar) -> Result<Value, String> {
+        Self::parse_and_build_sorted(query, calendar).map(|(filter, _)| filter)
+    }
+
+    /// Like `parse_and_build`, also returning the keys of any `sort:` clause. A query
+    /// that only sorts gives an empty filter.
+    pub fn parse_and_build_sorted(query: &str, calendar: &Calendar) -> Result<(Value, Vec<SortKey>), String> {
         // First, try the new parser
         match parse_filter(query) {
             Ok(builder) => {
-                builder.to_graphql()
-                    .map_err(|e| format!("Filter build error: {}", e))
+                let sort = builder.sort_keys().to_vec();
+                match builder.to_graphql(calendar) {
+                    Ok(filter) => Ok((filter, sort)),
+                    Err(FilterError::EmptyFilter) if !sort.is_empty() => Ok((json!({}), sort)),
+                    Err(e) => Err(format!("Filter build error: {}", e)),
+                }
             }
             Err(_e) => {
                 // Fall back to legacy parser for backward compatibility
                 
                 let filters = legacy_parse(query)?;
-                Ok(legacy_build(filters))
+                Ok((legacy_build(filters), Vec::new()))
             }
         }
     }
@@ -119,6 +131,27 @@ pub fn print_filter_examples() {
     println!("  assignee:null                      # Unassigned issues");
     println!("  project!=null                      # Issues with a project");
     println!();
+    println!("More fields:");
+    println!("  cycle:current                      # In the active cycle (also next, previous, 42 or a name)");
+    println!("  estimate>=3                        # Estimated at 3 points or more");
+    println!("  due<2w AND due!=null               # Due within two weeks");
+    println!("  creator:me                         # Created by you (also an email or a name)");
+    println!("  subscriber:ada@example.com         # Ada is subscribed");
+    println!("  parent:ENG-12                      # Sub-issues of ENG-12");
+    println!("  has-children                       # Issues with sub-issues (no-children for none)");
+    println!("  state-type:started                 # triage, backlog, unstarted, started, completed, canceled");
+    println!("  completed>7d                       # Completed in the last 7 days");
+    println!("  started:null                       # Not started yet");
+    println!("  sla:breached                       # SLA breached (high-risk, medium-risk, low-risk)");
+    println!("  relation:blocked                   # Blocked by another issue (blocking, duplicate, related)");
+    println!("  team:ENG                           # Team by key or name");
+    println!("  id in:ENG-1,ENG-2                  # Specific issues");
+    println!();
+    println!("Sorting:");
+    println!("  state:started sort:priority,-updated  # Most urgent first, then recently updated");
+    println!("  sort:-created                      # Newest first (also --sort -created)");
+    println!("  Fields: priority, created, updated, title, id, status, assignee, project, team");
+    println!();
     println!("Negation:");
     println!("  NOT status:completed               # Not completed");
     println!("  NOT (priority<2 OR assignee:null) # Assigned important issues");
@@ -130,15 +163,17 @@ mod tests {
     
     #[test]
     fn test_adapter_basic() {
-        let result = FilterAdapter::parse_and_build("status:completed").unwrap();
+        let result = FilterAdapter::parse_and_build("status:completed", &Calendar::default()).unwrap();
         assert!(result.get("state").is_some());
     }
     
     #[test]
     fn test_adapter_complex() {
-        let result = FilterAdapter::parse_and_build("status!=completed AND priority>2 AND created>7d").unwrap();
-        assert!(result.get("state").is_some());
-        assert!(result.get("priority").is_some());
-        assert!(result.get("createdAt").is_some());
+        let result = FilterAdapter::parse_and_build("status!=completed AND priority>2 AND created>7d", &Calendar::default()).unwrap();
+        let and = result["and"].as_array().unwrap();
+        assert_eq!(and.len(), 3);
+        assert!(and[0].get("state").is_some());
+        assert!(and[1].get("priority").is_some());
+        assert!(and[2].get("createdAt").is_some());
     }
 }
\ No newline at end of file
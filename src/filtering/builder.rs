@@ -3,6 +3,9 @@
 use chrono::{Duration, Utc};
 use serde_json::{json, Value};
 
+use super::dates::{comparisons, format_timestamp, Calendar, DateSpec};
+use super::sort::SortKey;
+
 /// Represents a single filter condition
 #[derive(Debug, Clone)]
 pub struct FilterCondition {
@@ -25,7 +28,18 @@ pub enum FilterField {
     CreatedAt,
     UpdatedAt,
     DueDate,
+    CompletedAt,
+    StartedAt,
     Identifier,
+    Cycle,
+    Estimate,
+    Creator,
+    Parent,
+    Children,
+    Subscriber,
+    StateType,
+    Sla,
+    Relation,
     Custom(String),
 }
 
@@ -44,7 +58,48 @@ impl FilterField {
             Self::CreatedAt => "createdAt",
             Self::UpdatedAt => "updatedAt",
             Self::DueDate => "dueDate",
+            Self::CompletedAt => "completedAt",
+            Self::StartedAt => "startedAt",
             Self::Identifier => "identifier",
+            Self::Cycle => "cycle",
+            Self::Estimate => "estimate",
+            Self::Creator => "creator",
+            Self::Parent => "parent",
+            Self::Children => "children",
+            Self::Subscriber => "subscribers",
+            Self::StateType => "state-type",
+            Self::Sla => "slaStatus",
+            Self::Relation => "relation",
+            Self::Custom(name) => name,
+        }
+    }
+
+    /// The field's name in filter queries
+    pub fn query_name(&self) -> &str {
+        match self {
+            Self::Title => "title",
+            Self::Description => "description",
+            Self::Status => "status",
+            Self::Priority => "priority",
+            Self::Assignee => "assignee",
+            Self::Label => "label",
+            Self::Project => "project",
+            Self::Team => "team",
+            Self::CreatedAt => "created",
+            Self::UpdatedAt => "updated",
+            Self::DueDate => "due",
+            Self::CompletedAt => "completed",
+            Self::StartedAt => "started",
+            Self::Identifier => "id",
+            Self::Cycle => "cycle",
+            Self::Estimate => "estimate",
+            Self::Creator => "creator",
+            Self::Parent => "parent",
+            Self::Children => "children",
+            Self::Subscriber => "subscriber",
+            Self::StateType => "state-type",
+            Self::Sla => "sla",
+            Self::Relation => "relation",
             Self::Custom(name) => name,
         }
     }
@@ -83,6 +138,31 @@ pub enum FilterOperator {
     HasNone,
 }
 
+impl FilterOperator {
+    /// The operator as written in filter queries
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Equals => ":",
+            Self::NotEquals => "!=",
+            Self::GreaterThan => ">",
+            Self::GreaterThanOrEquals => ">=",
+            Self::LessThan => "<",
+            Self::LessThanOrEquals => "<=",
+            Self::Contains => "~",
+            Self::NotContains => "!~",
+            Self::StartsWith => "^=",
+            Self::EndsWith => "$=",
+            Self::In => " in:",
+            Self::NotIn => " !in:",
+            Self::IsNull => ":",
+            Self::IsNotNull => "!=",
+            Self::HasAny => " has:",
+            Self::HasAll => " has-all:",
+            Self::HasNone => " has-none:",
+        }
+    }
+}
+
 /// Filter value types
 #[derive(Debug, Clone)]
 pub enum FilterValue {
@@ -123,6 +203,7 @@ pub struct FilterBuilder {
     root: Option<FilterExpression>,
     current_group: Vec<FilterExpression>,
     current_operator: LogicalOperator,
+    sort: Vec<SortKey>,
 }
 
 impl FilterBuilder {
@@ -132,6 +213,7 @@ impl FilterBuilder {
             root: None,
             current_group: Vec::new(),
             current_operator: LogicalOperator::And,
+            sort: Vec::new(),
         }
     }
 
@@ -141,9 +223,21 @@ impl FilterBuilder {
             root: Some(root),
             current_group: Vec::new(),
             current_operator: LogicalOperator::And,
+            sort: Vec::new(),
         }
     }
     
+    /// Set the order for results, from a `sort:` clause
+    pub fn with_sort(mut self, sort: Vec<SortKey>) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Sort keys from the query's `sort:` clauses, if any
+    pub fn sort_keys(&self) -> &[SortKey] {
+        &self.sort
+    }
+
     /// Add a condition to the current group
     fn add_condition(&mut self, condition: FilterCondition) -> &mut Self {
         self.current_group.push(FilterExpression::Condition(condition));
@@ -275,10 +369,11 @@ impl FilterBuilder {
         }
     }
     
-    /// Convert to GraphQL filter format
-    pub fn to_graphql(self) -> Result<Value, FilterError> {
+    /// Convert to GraphQL filter format, reading date values in `calendar`
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_graphql(self, calendar: &Calendar) -> Result<Value, FilterError> {
         let expr = self.build()?;
-        Ok(expression_to_graphql(&expr))
+        expression_to_graphql(&expr, calendar)
     }
 }
 
@@ -390,6 +485,7 @@ impl<'a> FieldBuilder<'a> {
     }
     
     // Existence operators
+    #[allow(clippy::wrong_self_convention)]
     pub fn is_null(self) -> &'a mut FilterBuilder {
         self.builder.add_condition(FilterCondition {
             field: self.field,
@@ -398,6 +494,7 @@ impl<'a> FieldBuilder<'a> {
         })
     }
     
+    #[allow(clippy::wrong_self_convention)]
     pub fn is_not_null(self) -> &'a mut FilterBuilder {
         self.builder.add_condition(FilterCondition {
             field: self.field,
@@ -429,158 +526,469 @@ impl<'a> FieldBuilder<'a> {
 }
 
 /// Convert expression to GraphQL filter
-fn expression_to_graphql(expr: &FilterExpression) -> Value {
+pub(crate) fn expression_to_graphql(expr: &FilterExpression, calendar: &Calendar) -> Result<Value, FilterError> {
     match expr {
-        FilterExpression::Condition(condition) => condition_to_graphql(condition),
-        FilterExpression::Group(group) => group_to_graphql(group),
+        FilterExpression::Condition(condition) => condition_to_graphql(condition, calendar),
+        FilterExpression::Group(group) => group_to_graphql(group, calendar),
     }
 }
 
-/// Convert a filter group to GraphQL
-fn group_to_graphql(group: &FilterGroup) -> Value {
+/// Convert a filter group to GraphQL. AND and OR always become `and`/`or` arrays:
+/// merging sibling keys into one object would let `label:bug AND label:urgent` or two
+/// bounds on `created` overwrite each other.
+fn group_to_graphql(group: &FilterGroup, calendar: &Calendar) -> Result<Value, FilterError> {
     match &group.operator {
-        LogicalOperator::And => {
-            let mut combined = json!({});
-            for expr in &group.conditions {
-                let value = expression_to_graphql(expr);
-                if let Some(obj) = value.as_object() {
-                    for (k, v) in obj {
-                        combined[k] = v.clone();
-                    }
-                }
+        LogicalOperator::And => combine("and", &group.conditions, calendar),
+        LogicalOperator::Or => combine("or", &group.conditions, calendar),
+        LogicalOperator::Not => expression_to_graphql(&negate_group(group)?, calendar),
+    }
+}
+
+/// Compile `conditions` into a `{ key: [...] }` array, unwrapping a single condition
+/// and flattening nested arrays of the same kind.
+fn combine(key: &str, conditions: &[FilterExpression], calendar: &Calendar) -> Result<Value, FilterError> {
+    let mut items = Vec::new();
+    for expr in conditions {
+        let value = expression_to_graphql(expr, calendar)?;
+        match value.as_object() {
+            Some(obj) if obj.len() == 1 && obj.get(key).is_some_and(Value::is_array) => {
+                items.extend(obj[key].as_array().cloned().unwrap_or_default());
             }
-            combined
+            _ => items.push(value),
         }
-        LogicalOperator::Or => {
-            json!({
-                "or": group.conditions.iter()
-                    .map(expression_to_graphql)
-                    .collect::<Vec<_>>()
-            })
-        }
-        LogicalOperator::Not => {
-            json!({
-                "not": group.conditions.iter()
-                    .map(expression_to_graphql)
-                    .collect::<Vec<_>>()
-            })
+    }
+    match items.len() {
+        0 => Err(FilterError::EmptyFilter),
+        1 => Ok(items.remove(0)),
+        _ => Ok(json!({ key: items })),
+    }
+}
+
+/// The conditions of a NOT group are ANDed together before being negated.
+pub(crate) fn negate_group(group: &FilterGroup) -> Result<FilterExpression, FilterError> {
+    match group.conditions.as_slice() {
+        [] => Err(FilterError::EmptyFilter),
+        [single] => negate(single),
+        conditions => negate(&FilterExpression::Group(Box::new(FilterGroup {
+            operator: LogicalOperator::And,
+            conditions: conditions.to_vec(),
+        }))),
+    }
+}
+
+/// Push a NOT down to the conditions, since Linear's `IssueFilter` has no `not`.
+/// Conditions take the opposite comparator, and groups follow De Morgan's laws:
+/// `NOT (a AND b)` is `NOT a OR NOT b`.
+pub(crate) fn negate(expr: &FilterExpression) -> Result<FilterExpression, FilterError> {
+    match expr {
+        FilterExpression::Condition(condition) => {
+            let operator = negate_operator(&condition.operator).ok_or_else(|| {
+                FilterError::Unsupported(format!("NOT {}", describe(condition)))
+            })?;
+            Ok(FilterExpression::Condition(FilterCondition {
+                field: condition.field.clone(),
+                operator,
+                value: condition.value.clone(),
+            }))
         }
+        FilterExpression::Group(group) => match group.operator {
+            LogicalOperator::And | LogicalOperator::Or => {
+                let operator = if group.operator == LogicalOperator::And {
+                    LogicalOperator::Or
+                } else {
+                    LogicalOperator::And
+                };
+                let conditions = group.conditions.iter().map(negate).collect::<Result<Vec<_>, _>>()?;
+                Ok(FilterExpression::Group(Box::new(FilterGroup { operator, conditions })))
+            }
+            // NOT NOT x is x
+            LogicalOperator::Not => Ok(FilterExpression::Group(Box::new(FilterGroup {
+                operator: LogicalOperator::And,
+                conditions: group.conditions.clone(),
+            }))),
+        },
     }
 }
 
+fn negate_operator(operator: &FilterOperator) -> Option<FilterOperator> {
+    Some(match operator {
+        FilterOperator::Equals => FilterOperator::NotEquals,
+        FilterOperator::NotEquals => FilterOperator::Equals,
+        FilterOperator::GreaterThan => FilterOperator::LessThanOrEquals,
+        FilterOperator::GreaterThanOrEquals => FilterOperator::LessThan,
+        FilterOperator::LessThan => FilterOperator::GreaterThanOrEquals,
+        FilterOperator::LessThanOrEquals => FilterOperator::GreaterThan,
+        FilterOperator::Contains => FilterOperator::NotContains,
+        FilterOperator::NotContains => FilterOperator::Contains,
+        FilterOperator::In => FilterOperator::NotIn,
+        FilterOperator::NotIn => FilterOperator::In,
+        FilterOperator::IsNull => FilterOperator::IsNotNull,
+        FilterOperator::IsNotNull => FilterOperator::IsNull,
+        FilterOperator::HasAny => FilterOperator::HasNone,
+        FilterOperator::HasNone => FilterOperator::HasAny,
+        FilterOperator::StartsWith | FilterOperator::EndsWith | FilterOperator::HasAll => return None,
+    })
+}
+
+/// A condition in query syntax, for error messages.
+fn describe(condition: &FilterCondition) -> String {
+    let value = match &condition.value {
+        FilterValue::String(s) | FilterValue::Date(s) => s.clone(),
+        FilterValue::Number(n) => n.to_string(),
+        FilterValue::Boolean(b) => b.to_string(),
+        FilterValue::RelativeDate(d) => format!("{}d", d.num_days()),
+        FilterValue::StringList(list) => list.join(","),
+        FilterValue::NumberList(list) => list.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(","),
+        FilterValue::Null => "null".to_string(),
+    };
+    format!("{}{}{}", condition.field.field_name(), condition.operator.symbol(), value)
+}
+
 /// Convert a single condition to GraphQL
-fn condition_to_graphql(condition: &FilterCondition) -> Value {
+fn condition_to_graphql(condition: &FilterCondition, calendar: &Calendar) -> Result<Value, FilterError> {
     let field_name = condition.field.field_name();
-    
-    match (&condition.field, &condition.operator, &condition.value) {
-        // Title operations
-        (FilterField::Title, FilterOperator::Contains, FilterValue::String(s)) => {
-            json!({ field_name: { "containsIgnoreCase": s } })
+
+    let filter = match (&condition.field, &condition.operator, &condition.value) {
+        // Title and description operations
+        (FilterField::Title | FilterField::Description, op, FilterValue::String(s)) => {
+            let comparator = match op {
+                FilterOperator::Equals => "eq",
+                FilterOperator::NotEquals => "neq",
+                FilterOperator::Contains => "containsIgnoreCase",
+                FilterOperator::NotContains => "notContainsIgnoreCase",
+                FilterOperator::StartsWith => "startsWithIgnoreCase",
+                FilterOperator::EndsWith => "endsWith",
+                _ => return Err(unsupported(condition)),
+            };
+            json!({ field_name: { comparator: s } })
         }
-        (FilterField::Title, FilterOperator::NotContains, FilterValue::String(s)) => {
-            json!({ field_name: { "not": { "containsIgnoreCase": s } } })
+
+        (FilterField::Description, FilterOperator::IsNull, _) => json!({ field_name: { "null": true } }),
+        (FilterField::Description, FilterOperator::IsNotNull, _) => json!({ field_name: { "null": false } }),
+
+        // Status and project operations, by name
+        (FilterField::Status | FilterField::Project, op, FilterValue::String(s)) => match op {
+            FilterOperator::Equals => json!({ field_name: { "name": { "eq": s } } }),
+            FilterOperator::NotEquals => json!({ field_name: { "name": { "neq": s } } }),
+            FilterOperator::Contains => json!({ field_name: { "name": { "containsIgnoreCase": s } } }),
+            FilterOperator::NotContains => json!({ field_name: { "name": { "notContainsIgnoreCase": s } } }),
+            _ => return Err(unsupported(condition)),
+        },
+        (FilterField::Status | FilterField::Project, FilterOperator::In, FilterValue::StringList(list)) => {
+            json!({ field_name: { "name": { "in": list } } })
         }
-        (FilterField::Title, FilterOperator::StartsWith, FilterValue::String(s)) => {
-            json!({ field_name: { "startsWithIgnoreCase": s } })
+        (FilterField::Status | FilterField::Project, FilterOperator::NotIn, FilterValue::StringList(list)) => {
+            json!({ field_name: { "name": { "nin": list } } })
         }
-        
-        // Status operations
-        (FilterField::Status, FilterOperator::Equals, FilterValue::String(s)) => {
-            json!({ field_name: { "name": { "eq": s } } })
+        (FilterField::Project | FilterField::Assignee | FilterField::Cycle | FilterField::Parent, FilterOperator::IsNull, _) => {
+            json!({ field_name: { "null": true } })
         }
-        (FilterField::Status, FilterOperator::NotEquals, FilterValue::String(s)) => {
-            json!({ field_name: { "name": { "neq": s } } })
+        (FilterField::Project | FilterField::Assignee | FilterField::Cycle | FilterField::Parent, FilterOperator::IsNotNull, _) => {
+            json!({ field_name: { "null": false } })
         }
-        (FilterField::Status, FilterOperator::In, FilterValue::StringList(list)) => {
-            json!({ field_name: { "name": { "in": list } } })
+
+        // Team operations, by key or name
+        (FilterField::Team, op @ (FilterOperator::Equals | FilterOperator::NotEquals), FilterValue::String(s)) => {
+            let team = json!({ "or": [
+                { "key": { "eqIgnoreCase": s } },
+                { "name": { "eqIgnoreCase": s } }
+            ] });
+            if *op == FilterOperator::Equals {
+                json!({ field_name: team })
+            } else {
+                json!({ field_name: { "and": [
+                    { "key": { "neqIgnoreCase": s } },
+                    { "name": { "neqIgnoreCase": s } }
+                ] } })
+            }
         }
-        
-        // Priority operations
-        (FilterField::Priority, FilterOperator::Equals, FilterValue::Number(n)) => {
-            json!({ field_name: { "eq": n } })
+        (FilterField::Team, op @ (FilterOperator::In | FilterOperator::NotIn), FilterValue::StringList(list)) => {
+            let comparator = if *op == FilterOperator::In { "in" } else { "nin" };
+            json!({ field_name: { "key": { comparator: list } } })
         }
-        (FilterField::Priority, FilterOperator::NotEquals, FilterValue::Number(n)) => {
-            json!({ field_name: { "neq": n } })
+
+        // Identifier operations: `ENG-12` is team key ENG and number 12
+        (FilterField::Identifier, FilterOperator::Equals, FilterValue::String(s)) => identifier_filter(s, true)
+            .ok_or_else(|| FilterError::InvalidValue { field: "identifier".to_string() })?,
+        (FilterField::Identifier, FilterOperator::NotEquals, FilterValue::String(s)) => identifier_filter(s, false)
+            .ok_or_else(|| FilterError::InvalidValue { field: "identifier".to_string() })?,
+        (FilterField::Identifier, op @ (FilterOperator::In | FilterOperator::NotIn), FilterValue::StringList(list)) => {
+            let equals = *op == FilterOperator::In;
+            let filters = list
+                .iter()
+                .map(|id| identifier_filter(id, equals))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| FilterError::InvalidValue { field: "identifier".to_string() })?;
+            json!({ if equals { "or" } else { "and" }: filters })
         }
-        (FilterField::Priority, FilterOperator::GreaterThan, FilterValue::Number(n)) => {
-            json!({ field_name: { "gt": n } })
+
+        // Parent operations, by identifier
+        (FilterField::Parent, FilterOperator::Equals, FilterValue::String(s)) => {
+            json!({ field_name: identifier_filter(s, true)
+                .ok_or_else(|| FilterError::InvalidValue { field: "parent".to_string() })? })
         }
-        (FilterField::Priority, FilterOperator::GreaterThanOrEquals, FilterValue::Number(n)) => {
-            json!({ field_name: { "gte": n } })
+
+        // Children: whether the issue has sub-issues
+        (FilterField::Children, FilterOperator::IsNotNull, _) => {
+            json!({ field_name: { "some": { "id": { "null": false } } } })
         }
-        (FilterField::Priority, FilterOperator::LessThan, FilterValue::Number(n)) => {
-            json!({ field_name: { "lt": n } })
+        (FilterField::Children, FilterOperator::IsNull, _) => {
+            json!({ field_name: { "every": { "id": { "null": true } } } })
         }
-        (FilterField::Priority, FilterOperator::LessThanOrEquals, FilterValue::Number(n)) => {
-            json!({ field_name: { "lte": n } })
+
+        // Cycle operations: `current`, `next`, `previous`, a number or a name
+        (FilterField::Cycle, op @ (FilterOperator::Equals | FilterOperator::NotEquals), FilterValue::String(s)) => {
+            let equals = *op == FilterOperator::Equals;
+            let (key, comparator) = match s.to_lowercase().as_str() {
+                "current" | "active" => ("isActive", json!({ "eq": equals })),
+                "next" => ("isNext", json!({ "eq": equals })),
+                "previous" | "last" => ("isPrevious", json!({ "eq": equals })),
+                _ => match s.parse::<u32>() {
+                    Ok(number) => ("number", json!({ if equals { "eq" } else { "neq" }: number })),
+                    Err(_) => ("name", json!({ if equals { "eqIgnoreCase" } else { "neqIgnoreCase" }: s })),
+                },
+            };
+            json!({ field_name: { key: comparator } })
         }
-        
-        // Assignee operations
-        (FilterField::Assignee, FilterOperator::Equals, FilterValue::String(s)) => {
-            json!({ field_name: { "email": { "eq": s } } })
+
+        // Priority and estimate operations
+        (FilterField::Priority | FilterField::Estimate, op, FilterValue::Number(n)) => {
+            json!({ field_name: { comparison(op).ok_or_else(|| unsupported(condition))?: n } })
         }
-        (FilterField::Assignee, FilterOperator::IsNull, _) => {
+        (FilterField::Estimate | FilterField::DueDate | FilterField::CompletedAt | FilterField::StartedAt, FilterOperator::IsNull, _) => {
             json!({ field_name: { "null": true } })
         }
-        (FilterField::Assignee, FilterOperator::IsNotNull, _) => {
+        (FilterField::Estimate | FilterField::DueDate | FilterField::CompletedAt | FilterField::StartedAt, FilterOperator::IsNotNull, _) => {
             json!({ field_name: { "null": false } })
         }
-        
-        // Label operations
-        (FilterField::Label, FilterOperator::HasAny, FilterValue::StringList(list)) => {
-            json!({ field_name: { "some": { "name": { "in": list } } } })
+
+        // Workflow state type: triage, backlog, unstarted, started, completed, canceled
+        (FilterField::StateType, op, value) => {
+            let types = match value {
+                FilterValue::String(s) => vec![state_type(s).ok_or_else(|| FilterError::InvalidValue { field: "state-type".to_string() })?],
+                FilterValue::StringList(list) => list
+                    .iter()
+                    .map(|s| state_type(s))
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| FilterError::InvalidValue { field: "state-type".to_string() })?,
+                _ => return Err(unsupported(condition)),
+            };
+            let comparator = match (op, types.as_slice()) {
+                (FilterOperator::Equals, [single]) => json!({ "eq": single }),
+                (FilterOperator::NotEquals, [single]) => json!({ "neq": single }),
+                (FilterOperator::In, _) => json!({ "in": types }),
+                (FilterOperator::NotIn, _) => json!({ "nin": types }),
+                _ => return Err(unsupported(condition)),
+            };
+            json!({ "state": { "type": comparator } })
         }
-        (FilterField::Label, FilterOperator::HasAll, FilterValue::StringList(list)) => {
-            json!({ field_name: { "every": { "name": { "in": list } } } })
+
+        // SLA status: breached, high-risk, medium-risk, low-risk, completed, failed
+        (FilterField::Sla, op @ (FilterOperator::Equals | FilterOperator::NotEquals), FilterValue::String(s)) => {
+            let status = sla_status(s).ok_or_else(|| FilterError::InvalidValue { field: "sla".to_string() })?;
+            json!({ field_name: { if *op == FilterOperator::Equals { "eq" } else { "neq" }: status } })
         }
-        (FilterField::Label, FilterOperator::HasNone, FilterValue::StringList(list)) => {
-            json!({ field_name: { "none": { "name": { "in": list } } } })
+        (FilterField::Sla, FilterOperator::IsNull, _) => json!({ field_name: { "null": true } }),
+        (FilterField::Sla, FilterOperator::IsNotNull, _) => json!({ field_name: { "null": false } }),
+
+        // Relations: relation:blocked, relation:blocking, relation:duplicate, relation:related
+        (FilterField::Relation, op @ (FilterOperator::Equals | FilterOperator::NotEquals), FilterValue::String(s)) => {
+            let key = match s.to_lowercase().as_str() {
+                "blocked" | "blocked-by" => "hasBlockedByRelations",
+                "blocking" | "blocks" => "hasBlockingRelations",
+                "duplicate" | "duplicates" => "hasDuplicateRelations",
+                "related" => "hasRelatedRelations",
+                _ => return Err(FilterError::InvalidValue { field: "relation".to_string() }),
+            };
+            json!({ key: { "eq": *op == FilterOperator::Equals } })
         }
-        (FilterField::Label, FilterOperator::IsNull, _) => {
-            json!({ field_name: { "every": { "id": { "null": true } } } })
+
+        // Assignee and creator operations
+        (FilterField::Assignee | FilterField::Creator, FilterOperator::Equals, FilterValue::String(s)) => {
+            json!({ field_name: user_filter(s, true) })
         }
-        (FilterField::Label, FilterOperator::IsNotNull, _) => {
-            json!({ field_name: { "some": { "id": { "null": false } } } })
+        (FilterField::Assignee | FilterField::Creator, FilterOperator::NotEquals, FilterValue::String(s)) => {
+            json!({ field_name: user_filter(s, false) })
+        }
+        (FilterField::Creator, FilterOperator::IsNull, _) => json!({ field_name: { "null": true } }),
+        (FilterField::Creator, FilterOperator::IsNotNull, _) => json!({ field_name: { "null": false } }),
+
+        // Subscribers: some subscriber matches, or none does
+        (FilterField::Subscriber, FilterOperator::Equals, FilterValue::String(s)) => {
+            json!({ field_name: { "some": user_filter(s, true) } })
+        }
+        (FilterField::Subscriber, FilterOperator::NotEquals, FilterValue::String(s)) => {
+            json!({ field_name: { "every": user_filter(s, false) } })
         }
+
+        // Label operations. `every` holds for issues without labels, so it expresses
+        // "no label matches".
         (FilterField::Label, FilterOperator::Equals, FilterValue::String(s)) => {
             json!({ field_name: { "some": { "name": { "eq": s } } } })
         }
-        
-        // Project operations
-        (FilterField::Project, FilterOperator::Equals, FilterValue::String(s)) => {
-            json!({ field_name: { "name": { "eq": s } } })
+        (FilterField::Label, FilterOperator::NotEquals, FilterValue::String(s)) => {
+            json!({ field_name: { "every": { "name": { "neq": s } } } })
         }
-        (FilterField::Project, FilterOperator::IsNull, _) => {
-            json!({ field_name: { "null": true } })
+        (FilterField::Label, FilterOperator::Contains, FilterValue::String(s)) => {
+            json!({ field_name: { "some": { "name": { "containsIgnoreCase": s } } } })
         }
-        (FilterField::Project, FilterOperator::IsNotNull, _) => {
-            json!({ field_name: { "null": false } })
+        (FilterField::Label, FilterOperator::NotContains, FilterValue::String(s)) => {
+            json!({ field_name: { "every": { "name": { "notContainsIgnoreCase": s } } } })
         }
-        
-        // Date operations
-        (FilterField::CreatedAt | FilterField::UpdatedAt | FilterField::DueDate, op, FilterValue::Date(date)) => {
-            match op {
-                FilterOperator::GreaterThan => json!({ field_name: { "gt": date } }),
-                FilterOperator::GreaterThanOrEquals => json!({ field_name: { "gte": date } }),
-                FilterOperator::LessThan => json!({ field_name: { "lt": date } }),
-                FilterOperator::LessThanOrEquals => json!({ field_name: { "lte": date } }),
-                _ => json!({}),
+        (FilterField::Label, FilterOperator::HasAny | FilterOperator::In, FilterValue::StringList(list)) => {
+            json!({ field_name: { "some": { "name": { "in": list } } } })
+        }
+        (FilterField::Label, FilterOperator::HasNone | FilterOperator::NotIn, FilterValue::StringList(list)) => {
+            json!({ field_name: { "every": { "name": { "nin": list } } } })
+        }
+        (FilterField::Label, FilterOperator::HasAll, FilterValue::StringList(list)) => {
+            let all: Vec<Value> = list
+                .iter()
+                .map(|name| json!({ field_name: { "some": { "name": { "eq": name } } } }))
+                .collect();
+            match all.len() {
+                0 => return Err(unsupported(condition)),
+                1 => all.into_iter().next().unwrap_or_default(),
+                _ => json!({ "and": all }),
             }
         }
-        
-        // Default string operations
-        (_, FilterOperator::Equals, FilterValue::String(s)) => {
-            json!({ field_name: { "eq": s } })
+        (FilterField::Label, FilterOperator::IsNull, _) => {
+            json!({ field_name: { "every": { "id": { "null": true } } } })
         }
-        (_, FilterOperator::NotEquals, FilterValue::String(s)) => {
-            json!({ field_name: { "neq": s } })
+        (FilterField::Label, FilterOperator::IsNotNull, _) => {
+            json!({ field_name: { "some": { "id": { "null": false } } } })
         }
-        (_, FilterOperator::Contains, FilterValue::String(s)) => {
-            json!({ field_name: { "containsIgnoreCase": s } })
+
+        // Date operations
+        (
+            FilterField::CreatedAt
+            | FilterField::UpdatedAt
+            | FilterField::DueDate
+            | FilterField::CompletedAt
+            | FilterField::StartedAt,
+            _,
+            FilterValue::Date(date),
+        ) => date_filter(condition, date, calendar)?,
+
+        _ => return Err(unsupported(condition)),
+    };
+    Ok(filter)
+}
+
+/// A filter on a date field. Offsets like `7d` are sent as ISO 8601 durations, which
+/// Linear reads against its own clock (so they stay relative in saved views); anything
+/// else is resolved in the calendar's timezone. `dueDate` compares plain dates.
+fn date_filter(condition: &FilterCondition, text: &str, calendar: &Calendar) -> Result<Value, FilterError> {
+    let field_name = condition.field.field_name();
+    let spec = DateSpec::parse(text).ok_or_else(|| FilterError::InvalidDate(format!("'{}' is not a date", text)))?;
+    let timeless = condition.field == FilterField::DueDate;
+
+    // One day on a plain-date field is that date
+    if let (true, DateSpec::Day(date)) = (timeless, &spec) {
+        let comparator = comparison(&condition.operator).ok_or_else(|| unsupported(condition))?;
+        return Ok(json!({ field_name: { comparator: date.format("%Y-%m-%d").to_string() } }));
+    }
+
+    let period = spec.resolve(calendar, Utc::now()).map_err(FilterError::InvalidDate)?;
+    let bounds = comparisons(&condition.operator, &period).ok_or_else(|| unsupported(condition))?;
+    let value = |at| match &spec {
+        DateSpec::Offset(offset) => offset.iso(),
+        _ if timeless => calendar.zone.date(at).format("%Y-%m-%d").to_string(),
+        _ => format_timestamp(at),
+    };
+    let comparators: Vec<(&str, String)> = bounds.into_iter().map(|(comparator, at)| (comparator, value(at))).collect();
+    Ok(match (&condition.operator, comparators.as_slice()) {
+        // Outside the period: before it starts or after it ends
+        (FilterOperator::NotEquals, [_, _]) => json!({ "or": comparators
+            .iter()
+            .map(|(comparator, value)| json!({ field_name: { *comparator: value } }))
+            .collect::<Vec<_>>() }),
+        _ => {
+            let comparator: serde_json::Map<String, Value> =
+                comparators.into_iter().map(|(comparator, value)| (comparator.to_string(), json!(value))).collect();
+            json!({ field_name: comparator })
         }
-        
-        _ => json!({}),
+    })
+}
+
+/// A user filter for `me`, an email address, or a name or display name.
+fn user_filter(value: &str, equals: bool) -> Value {
+    if value.eq_ignore_ascii_case("me") {
+        json!({ "isMe": { "eq": equals } })
+    } else if value.contains('@') {
+        json!({ "email": { if equals { "eq" } else { "neq" }: value } })
+    } else if equals {
+        json!({ "or": [
+            { "name": { "eqIgnoreCase": value } },
+            { "displayName": { "eqIgnoreCase": value } }
+        ] })
+    } else {
+        json!({ "and": [
+            { "name": { "neqIgnoreCase": value } },
+            { "displayName": { "neqIgnoreCase": value } }
+        ] })
+    }
+}
+
+/// An issue filter for an identifier such as `ENG-12`.
+fn identifier_filter(identifier: &str, equals: bool) -> Option<Value> {
+    let (key, number) = identifier.rsplit_once('-')?;
+    let number = number.parse::<u32>().ok()?;
+    if key.is_empty() {
+        return None;
     }
+    Some(if equals {
+        json!({ "and": [
+            { "team": { "key": { "eqIgnoreCase": key } } },
+            { "number": { "eq": number } }
+        ] })
+    } else {
+        json!({ "or": [
+            { "team": { "key": { "neqIgnoreCase": key } } },
+            { "number": { "neq": number } }
+        ] })
+    })
+}
+
+pub(crate) fn state_type(value: &str) -> Option<&'static str> {
+    Some(match value.to_lowercase().as_str() {
+        "triage" => "triage",
+        "backlog" => "backlog",
+        "unstarted" | "todo" => "unstarted",
+        "started" | "in-progress" => "started",
+        "completed" | "done" => "completed",
+        "canceled" | "cancelled" => "canceled",
+        _ => return None,
+    })
+}
+
+fn sla_status(value: &str) -> Option<&'static str> {
+    Some(match value.to_lowercase().replace(['-', '_'], "").as_str() {
+        "breached" => "Breached",
+        "highrisk" | "high" => "HighRisk",
+        "mediumrisk" | "medium" => "MediumRisk",
+        "lowrisk" | "low" => "LowRisk",
+        "completed" => "Completed",
+        "failed" => "Failed",
+        _ => return None,
+    })
+}
+
+/// Comparator for ordered values such as priorities and dates.
+fn comparison(operator: &FilterOperator) -> Option<&'static str> {
+    Some(match operator {
+        FilterOperator::Equals => "eq",
+        FilterOperator::NotEquals => "neq",
+        FilterOperator::GreaterThan => "gt",
+        FilterOperator::GreaterThanOrEquals => "gte",
+        FilterOperator::LessThan => "lt",
+        FilterOperator::LessThanOrEquals => "lte",
+        _ => return None,
+    })
+}
+
+fn unsupported(condition: &FilterCondition) -> FilterError {
+    FilterError::Unsupported(describe(condition))
 }
 
 /// Filter errors
@@ -594,6 +1002,12 @@ pub enum FilterError {
     
     #[error("Invalid value for field {field}")]
     InvalidValue { field: String },
+
+    #[error("Unsupported filter: {0}")]
+    Unsupported(String),
+
+    #[error("Invalid date {0}")]
+    InvalidDate(String),
 }
 
 // Implement conversions for FilterValue
@@ -682,9 +1096,11 @@ mod tests {
         builder.title().contains("bug")
             .and()
             .priority().greater_than(2);
-        let graphql = builder.to_graphql().unwrap();
+        let graphql = builder.to_graphql(&Calendar::default()).unwrap();
         
-        assert!(graphql.get("title").is_some());
-        assert!(graphql.get("priority").is_some());
+        let and = graphql["and"].as_array().unwrap();
+        assert_eq!(and.len(), 2);
+        assert!(and[0].get("title").is_some());
+        assert!(and[1].get("priority").is_some());
     }
 }
@@ -0,0 +1,352 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::builder::FilterField;
+use super::dates::ANCHORS;
+use super::parser::{field_from_name, is_flag_field};
+use super::printer::quote_value;
+use super::sort::SortField;
+use crate::constants::COMPLETION_CACHE;
+use crate::error::{LinearError, LinearResult};
+
+/// Fields offered at the start of a condition, most used first
+pub(crate) const FIELDS: &[&str] = &[
+    "status", "assignee", "label", "priority", "project", "team", "title", "description",
+    "state-type", "estimate", "creator", "subscriber", "cycle", "parent", "relation", "sla",
+    "id", "created", "updated", "started", "completed", "due",
+];
+
+pub(crate) const FLAG_FIELDS: &[&str] = &["has-assignee", "no-assignee", "no-label", "has-children", "no-children"];
+
+pub(crate) const SORT_FIELDS: &[SortField] = &[
+    SortField::Priority,
+    SortField::Created,
+    SortField::Updated,
+    SortField::Title,
+    SortField::Identifier,
+    SortField::Status,
+    SortField::Assignee,
+    SortField::Project,
+    SortField::Team,
+];
+
+/// Two-character operators, checked before their one-character prefixes
+const LONG_OPERATORS: &[&str] = &["!=", "!~", ">=", "<=", "~=", "^=", "$="];
+
+/// What a completion inserts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompletionKind {
+    Field,
+    Operator,
+    Value,
+    Keyword,
+}
+
+/// One candidate. Accepting it replaces the query from `start` up to the cursor
+/// with `text`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Completion {
+    pub kind: CompletionKind,
+    pub text: String,
+    pub start: usize,
+}
+
+impl Completion {
+    /// The query with this completion applied, and the cursor just after it.
+    pub fn apply(&self, query: &str, cursor: usize) -> (String, usize) {
+        let cursor = floor_char_boundary(query, cursor);
+        let start = floor_char_boundary(query, self.start.min(cursor));
+        let mut completed = String::with_capacity(query.len() + self.text.len());
+        completed.push_str(&query[..start]);
+        completed.push_str(&self.text);
+        let new_cursor = completed.len();
+        completed.push_str(&query[cursor..]);
+        (completed, new_cursor)
+    }
+}
+
+/// Names the completer offers as values, cached on disk so shell completion doesn't
+/// have to ask Linear on every keystroke.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Vocabulary {
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub states: Vec<String>,
+    #[serde(default)]
+    pub members: Vec<String>,
+    #[serde(default)]
+    pub projects: Vec<String>,
+    #[serde(default)]
+    pub teams: Vec<String>,
+}
+
+impl Vocabulary {
+    pub fn path() -> LinearResult<PathBuf> {
+        dirs::config_dir()
+            .map(|dir| dir.join(COMPLETION_CACHE))
+            .ok_or_else(|| LinearError::ConfigError("Could not find config directory".to_string()))
+    }
+
+    pub fn load() -> LinearResult<Self> {
+        Self::load_from(&Self::path()?)
+    }
+
+    pub fn save(&self) -> LinearResult<()> {
+        self.save_to(&Self::path()?)
+    }
+
+    pub fn load_from(path: &Path) -> LinearResult<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn save_to(&self, path: &Path) -> LinearResult<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+            && self.states.is_empty()
+            && self.members.is_empty()
+            && self.projects.is_empty()
+            && self.teams.is_empty()
+    }
+
+    /// Sort every list case-insensitively and drop duplicates, such as the same
+    /// state name in several teams.
+    pub fn normalize(&mut self) {
+        for list in [
+            &mut self.labels,
+            &mut self.states,
+            &mut self.members,
+            &mut self.projects,
+            &mut self.teams,
+        ] {
+            list.sort_by_key(|name| name.to_lowercase());
+            list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        }
+    }
+}
+
+/// Complete the filter query at `cursor` (a byte offset): fields where a condition
+/// starts, operators after a field name, values after an operator and `AND`/`OR`
+/// after a finished condition. Prefix matches come before substring matches.
+pub fn complete(query: &str, cursor: usize, vocabulary: &Vocabulary) -> Vec<Completion> {
+    let prefix = &query[..floor_char_boundary(query, cursor)];
+    let start = word_start(prefix);
+    let word = &prefix[start..];
+
+    // `status in:a,b` and `label has:a` put the operator after the field, as a word of its own
+    for keyword in ["in:", "has:"] {
+        if word.get(..keyword.len()).is_some_and(|w| w.eq_ignore_ascii_case(keyword)) {
+            let field = last_word(&prefix[..start]);
+            return complete_value(field, &word[keyword.len()..], start + keyword.len(), vocabulary);
+        }
+    }
+
+    if let Some((field, value_start)) = split_operator(word) {
+        return complete_value(field, &word[value_start..], start + value_start, vocabulary);
+    }
+    if word.starts_with('"') {
+        return Vec::new();
+    }
+
+    if ends_condition(&prefix[..start]) {
+        let candidates = ["AND ", "OR ", "sort:"].map(String::from);
+        return matching(word, &candidates, CompletionKind::Keyword, start);
+    }
+
+    let mut completions = Vec::new();
+    let field = field_from_name(word);
+    if !word.is_empty() && !matches!(field, FilterField::Custom(_)) && !is_flag_field(word) {
+        let operators: Vec<String> = operators_for(&field).iter().map(|op| format!("{}{}", word, op)).collect();
+        completions.extend(operators.into_iter().map(|text| Completion { kind: CompletionKind::Operator, text, start }));
+    }
+    let fields: Vec<String> = FIELDS.iter().map(|f| format!("{}:", f))
+        .chain(FLAG_FIELDS.iter().map(|f| f.to_string()))
+        .chain(["sort:".to_string()])
+        .collect();
+    let fields = matching(word, &fields, CompletionKind::Field, start)
+        .into_iter()
+        .filter(|field| !completions.iter().any(|c: &Completion| c.text == field.text))
+        .collect::<Vec<_>>();
+    completions.extend(fields);
+    completions.extend(matching(word, &["NOT ".to_string()], CompletionKind::Keyword, start));
+    completions
+}
+
+/// Values for `field`. `value` is what has been typed after the operator, starting
+/// at byte `offset` of the query.
+fn complete_value(field: &str, value: &str, offset: usize, vocabulary: &Vocabulary) -> Vec<Completion> {
+    // Lists like `in:todo,doing` complete their last item
+    let item_start = last_unquoted(value, ',').map(|i| i + 1).unwrap_or(0);
+    let mut start = offset + item_start;
+    let mut item = &value[item_start..];
+
+    if field.eq_ignore_ascii_case("sort") {
+        if let Some(rest) = item.strip_prefix(['-', '+']) {
+            start += 1;
+            item = rest;
+        }
+        let names: Vec<String> = SORT_FIELDS.iter().map(|f| f.name().to_string()).collect();
+        return matching(item, &names, CompletionKind::Value, start);
+    }
+
+    let fixed = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    let with_me = |extra: &[&str]| {
+        let mut values = fixed(extra);
+        values.extend(vocabulary.members.iter().cloned());
+        values
+    };
+    let candidates = match field_from_name(field) {
+        FilterField::Status => vocabulary.states.clone(),
+        FilterField::StateType => fixed(&["triage", "backlog", "unstarted", "started", "completed", "canceled"]),
+        FilterField::Priority => fixed(&["urgent", "high", "medium", "low", "none"]),
+        FilterField::Assignee => with_me(&["me", "null"]),
+        FilterField::Creator | FilterField::Subscriber => with_me(&["me"]),
+        FilterField::Label => vocabulary.labels.clone(),
+        FilterField::Project => vocabulary.projects.clone(),
+        FilterField::Team => vocabulary.teams.clone(),
+        FilterField::Cycle => fixed(&["current", "next", "previous"]),
+        FilterField::Sla => fixed(&["breached", "high-risk", "medium-risk", "low-risk"]),
+        FilterField::Relation => fixed(&["blocked", "blocking", "duplicate", "related"]),
+        FilterField::CreatedAt
+        | FilterField::UpdatedAt
+        | FilterField::DueDate
+        | FilterField::CompletedAt
+        | FilterField::StartedAt => {
+            let mut values = fixed(ANCHORS);
+            values.extend(fixed(&["1day", "1week", "2weeks", "1month"]));
+            values
+        }
+        _ => Vec::new(),
+    };
+    let typed = item.strip_prefix('"').unwrap_or(item);
+    matching(typed, &candidates, CompletionKind::Value, start)
+        .into_iter()
+        // `null` is the keyword here, not a name
+        .map(|completion| match completion.text.as_str() {
+            "null" => completion,
+            text => Completion { text: quote_value(text), ..completion },
+        })
+        .collect()
+}
+
+fn operators_for(field: &FilterField) -> &'static [&'static str] {
+    match field {
+        FilterField::Title | FilterField::Description => &[":", "~", "!~", "^", "$", "!="],
+        FilterField::Priority
+        | FilterField::Estimate
+        | FilterField::CreatedAt
+        | FilterField::UpdatedAt
+        | FilterField::DueDate
+        | FilterField::CompletedAt
+        | FilterField::StartedAt => &[":", ">", ">=", "<", "<=", "!="],
+        FilterField::Label => &[":", "!=", " in:", " has:"],
+        FilterField::Status
+        | FilterField::StateType
+        | FilterField::Assignee
+        | FilterField::Creator
+        | FilterField::Subscriber
+        | FilterField::Project
+        | FilterField::Team
+        | FilterField::Cycle => &[":", "!=", " in:"],
+        _ => &[":", "!="],
+    }
+}
+
+/// Candidates that start with `typed`, then those that merely contain it, ignoring
+/// case. A candidate identical to what's typed is left out.
+fn matching(typed: &str, candidates: &[String], kind: CompletionKind, start: usize) -> Vec<Completion> {
+    let typed_lower = typed.to_lowercase();
+    let candidate = |text: &String| Completion { kind, text: text.clone(), start };
+    let open = candidates.iter().filter(|c| c.trim_end() != typed);
+    let prefixed = open.clone().filter(|c| c.to_lowercase().starts_with(&typed_lower));
+    let contained = open.filter(|c| {
+        let lower = c.to_lowercase();
+        !lower.starts_with(&typed_lower) && lower.contains(&typed_lower)
+    });
+    prefixed.chain(contained).map(candidate).collect()
+}
+
+/// Split `field<op>value` after the operator. Returns the field and where the value starts.
+fn split_operator(word: &str) -> Option<(&str, usize)> {
+    let (index, _) = word.char_indices().find(|(_, c)| ":=!<>~^$\"".contains(*c))?;
+    if index == 0 || word[index..].starts_with('"') {
+        return None;
+    }
+    let rest = &word[index..];
+    let length = LONG_OPERATORS.iter().find(|op| rest.starts_with(*op)).map_or(1, |op| op.len());
+    Some((&word[..index], index + length))
+}
+
+/// Start of the word the cursor is in: after the last space or parenthesis that
+/// isn't inside quotes.
+fn word_start(prefix: &str) -> usize {
+    let mut in_quotes = false;
+    let mut escaped = false;
+    let mut start = 0;
+    for (index, c) in prefix.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            c if !in_quotes && (c.is_whitespace() || c == '(' || c == ')') => start = index + c.len_utf8(),
+            _ => {}
+        }
+    }
+    start
+}
+
+fn last_word(text: &str) -> &str {
+    let text = text.trim_end();
+    &text[word_start(text)..]
+}
+
+fn last_unquoted(text: &str, needle: char) -> Option<usize> {
+    let mut in_quotes = false;
+    let mut found = None;
+    for (index, c) in text.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c == needle && !in_quotes => found = Some(index),
+            _ => {}
+        }
+    }
+    found
+}
+
+/// Whether `before` ends with a whole condition, so a connective comes next.
+fn ends_condition(before: &str) -> bool {
+    let trimmed = before.trim_end();
+    if trimmed.ends_with(')') {
+        return true;
+    }
+    if trimmed.is_empty() || trimmed.len() == before.len() {
+        return false;
+    }
+    let word = last_word(trimmed);
+    if ["and", "or", "not"].iter().any(|k| word.eq_ignore_ascii_case(k)) {
+        return false;
+    }
+    is_flag_field(word)
+        || split_operator(word).is_some_and(|(_, value_start)| value_start < word.len())
+        || ["in:", "has:"].iter().any(|k| word.len() > k.len() && word.get(..k.len()).is_some_and(|w| w.eq_ignore_ascii_case(k)))
+}
+
+fn floor_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
@@ -0,0 +1,502 @@
+use std::env;
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Local, Months, NaiveDate, NaiveTime, SecondsFormat, TimeZone, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::builder::{FilterCondition, FilterExpression, FilterField, FilterOperator, FilterValue, LogicalOperator};
+use super::parser::parse_filter;
+use crate::config::load_config;
+use crate::models::Cycle;
+
+/// Calendar anchors accepted as date values
+pub const ANCHORS: &[&str] = &[
+    "today", "yesterday", "tomorrow",
+    "this-week", "last-week", "next-week",
+    "this-month", "last-month", "next-month",
+    "this-quarter", "last-quarter", "next-quarter",
+    "this-year", "last-year", "next-year",
+    "this-cycle", "last-cycle", "next-cycle",
+];
+
+lazy_static! {
+    static ref ZONE_OFFSET: Regex = Regex::new(r"^(?:utc|gmt)?([+-])(\d{1,2})(?::?(\d{2}))?$").unwrap();
+    static ref SHORT_OFFSET: Regex = Regex::new(r"^(\d+)\s*(h|hours?|d|days?|w|weeks?|m|months?|y|years?)$").unwrap();
+    static ref ISO_OFFSET: Regex = Regex::new(r"^(-?)P(?:(\d+)([DWMY])|T(\d+)H)$").unwrap();
+}
+
+/// The timezone calendar dates are read in.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Zone {
+    #[default]
+    Local,
+    Utc,
+    Fixed(FixedOffset),
+}
+
+impl Zone {
+    /// `local`, `UTC` or an offset such as `+02:00`, `-0530` or `UTC+9`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().to_lowercase();
+        match text.as_str() {
+            "local" => return Some(Zone::Local),
+            "utc" | "gmt" | "z" => return Some(Zone::Utc),
+            _ => {}
+        }
+        let captures = ZONE_OFFSET.captures(&text)?;
+        let hours: i32 = captures[2].parse().ok()?;
+        let minutes: i32 = captures.get(3).map_or(Some(0), |m| m.as_str().parse().ok())?;
+        let sign = if &captures[1] == "-" { -1 } else { 1 };
+        FixedOffset::east_opt(sign * (hours * 60 + minutes) * 60).map(Zone::Fixed)
+    }
+
+    /// The calendar day `at` falls on.
+    pub fn date(&self, at: DateTime<Utc>) -> NaiveDate {
+        match self {
+            Zone::Local => at.with_timezone(&Local).date_naive(),
+            Zone::Utc => at.date_naive(),
+            Zone::Fixed(offset) => at.with_timezone(offset).date_naive(),
+        }
+    }
+
+    /// The moment `date` starts. Where a DST change skips midnight, the day starts an
+    /// hour later.
+    pub fn start_of(&self, date: NaiveDate) -> DateTime<Utc> {
+        let midnight = date.and_time(NaiveTime::MIN);
+        match self {
+            Zone::Utc => midnight.and_utc(),
+            Zone::Fixed(offset) => midnight.and_utc() - Duration::seconds(offset.local_minus_utc() as i64),
+            Zone::Local => Local
+                .from_local_datetime(&midnight)
+                .earliest()
+                .or_else(|| Local.from_local_datetime(&(midnight + Duration::hours(1))).earliest())
+                .map(|at| at.with_timezone(&Utc))
+                .unwrap_or_else(|| midnight.and_utc()),
+        }
+    }
+}
+
+/// A span of time from `start` up to but not including `end`; either end may be
+/// open. An instant, such as a timestamp or `7d`, has `start == end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Period {
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+}
+
+impl Period {
+    pub fn instant(at: DateTime<Utc>) -> Self {
+        Self { start: Some(at), end: Some(at) }
+    }
+
+    pub fn between(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self { start: Some(start), end: Some(end) }
+    }
+
+    pub fn is_instant(&self) -> bool {
+        self.start.is_some() && self.start == self.end
+    }
+}
+
+/// The previous, current and next cycle of one team.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CycleDates {
+    pub previous: Option<Period>,
+    pub current: Option<Period>,
+    pub next: Option<Period>,
+}
+
+impl CycleDates {
+    /// The cycles of `team`, by ID, key or name. Without a team, those of the only team
+    /// with a current cycle; when several teams have one, it's an error rather than a
+    /// guess.
+    pub fn from_cycles(cycles: &[Cycle], team: Option<&str>) -> Result<Self, String> {
+        let team = cycle_team(cycles, team)?;
+        let Some(team) = team else {
+            return Ok(Self::default());
+        };
+
+        let period = |wanted: fn(&Cycle) -> bool| {
+            cycles.iter().filter(|c| c.team.id == team && wanted(c)).find_map(|c| {
+                let start = DateTime::parse_from_rfc3339(&c.starts_at).ok()?.with_timezone(&Utc);
+                let end = DateTime::parse_from_rfc3339(&c.ends_at).ok()?.with_timezone(&Utc);
+                Some(Period::between(start, end))
+            })
+        };
+        Ok(Self {
+            previous: period(|c| c.is_previous),
+            current: period(|c| c.is_active),
+            next: period(|c| c.is_next),
+        })
+    }
+}
+
+/// The ID of the team whose cycles `team` names, by ID, key or name. Without a team,
+/// the only team with a current cycle, and an error when several have one.
+pub fn cycle_team<'a>(cycles: &'a [Cycle], team: Option<&str>) -> Result<Option<&'a str>, String> {
+    match team {
+        Some(wanted) => Ok(cycles
+            .iter()
+            .map(|c| &c.team)
+            .find(|t| t.id == wanted || t.key.eq_ignore_ascii_case(wanted) || t.name.eq_ignore_ascii_case(wanted))
+            .map(|t| t.id.as_str())),
+        None => {
+            let mut teams: Vec<_> = cycles.iter().filter(|c| c.is_active).map(|c| &c.team).collect();
+            teams.sort_by(|a, b| a.key.cmp(&b.key));
+            teams.dedup_by(|a, b| a.id == b.id);
+            match teams.as_slice() {
+                [] => Ok(None),
+                [team] => Ok(Some(team.id.as_str())),
+                teams => {
+                    let keys: Vec<_> = teams.iter().map(|t| t.key.as_str()).collect();
+                    Err(format!("teams {} all have cycles; name one with team:KEY or --team", keys.join(", ")))
+                }
+            }
+        }
+    }
+}
+
+/// How date values are read: the timezone days start in, and the cycle dates behind
+/// `this-cycle`, `last-cycle` and `next-cycle`.
+#[derive(Debug, Clone, Default)]
+pub struct Calendar {
+    pub zone: Zone,
+    pub cycles: CycleDates,
+}
+
+impl Calendar {
+    /// The calendar in the timezone from `timezone_setting`, or local time when it's
+    /// unset or not understood. Cycle dates are loaded separately.
+    pub fn from_config() -> Self {
+        let zone = timezone_setting().and_then(|setting| Zone::parse(&setting)).unwrap_or_default();
+        Self { zone, cycles: CycleDates::default() }
+    }
+
+    /// The calendar to read `query` in. Cycle anchors refer to the cycles of the team
+    /// the query pins with `team:`, else of `team`, else of the only team with cycles.
+    pub fn for_query(query: &str, cycles: &[Cycle], team: Option<&str>) -> Result<Self, String> {
+        let mut calendar = Self::from_config();
+        if uses_cycle_dates(query) {
+            let pinned = pinned_team(query);
+            calendar.cycles = CycleDates::from_cycles(cycles, pinned.as_deref().or(team))?;
+        }
+        Ok(calendar)
+    }
+}
+
+/// The configured timezone: `LINEAR_TIMEZONE`, else the config file's `timezone`.
+pub fn timezone_setting() -> Option<String> {
+    env::var("LINEAR_TIMEZONE").ok().or_else(|| load_config().timezone)
+}
+
+/// Whether `query` refers to a cycle-relative date, and so needs cycle dates loaded.
+pub fn uses_cycle_dates(query: &str) -> bool {
+    let query = query.to_lowercase();
+    ["this-cycle", "last-cycle", "next-cycle"].iter().any(|anchor| query.contains(anchor))
+}
+
+/// The team `query` is limited to by a `team:KEY` condition, on its own or in the
+/// top-level AND.
+pub fn pinned_team(query: &str) -> Option<String> {
+    let expr = parse_filter(query).ok()?.build().ok()?;
+    let conditions = match &expr {
+        FilterExpression::Group(group) if group.operator == LogicalOperator::And => group.conditions.as_slice(),
+        _ => std::slice::from_ref(&expr),
+    };
+    conditions.iter().find_map(|expr| match expr {
+        FilterExpression::Condition(FilterCondition {
+            field: FilterField::Team,
+            operator: FilterOperator::Equals,
+            value: FilterValue::String(team),
+        }) => Some(team.clone()),
+        _ => None,
+    })
+}
+
+/// The unit of a calendar anchor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unit {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+    Cycle,
+}
+
+/// An amount of time before (or after) now, as in `7d` or `-P2W`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Offset {
+    pub amount: i64,
+    pub unit: OffsetUnit,
+    /// Counted forward from now rather than back
+    pub ahead: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OffsetUnit {
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl Offset {
+    /// The moment this offset from `now` falls on, None when that's out of range.
+    pub fn from(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let months = |n: i64| Some(Months::new(u32::try_from(n).ok()?));
+        match (self.unit, self.ahead) {
+            (OffsetUnit::Months, true) => now.checked_add_months(months(self.amount)?),
+            (OffsetUnit::Months, false) => now.checked_sub_months(months(self.amount)?),
+            (OffsetUnit::Years, true) => now.checked_add_months(months(self.amount.checked_mul(12)?)?),
+            (OffsetUnit::Years, false) => now.checked_sub_months(months(self.amount.checked_mul(12)?)?),
+            (unit, ahead) => {
+                let duration = match unit {
+                    OffsetUnit::Hours => Duration::try_hours(self.amount),
+                    OffsetUnit::Weeks => Duration::try_weeks(self.amount),
+                    _ => Duration::try_days(self.amount),
+                }?;
+                if ahead {
+                    now.checked_add_signed(duration)
+                } else {
+                    now.checked_sub_signed(duration)
+                }
+            }
+        }
+    }
+
+    /// The ISO 8601 duration Linear reads relative to its own clock, e.g. `-P7D`.
+    pub fn iso(&self) -> String {
+        let sign = if self.ahead { "" } else { "-" };
+        match self.unit {
+            OffsetUnit::Hours => format!("{}PT{}H", sign, self.amount),
+            OffsetUnit::Days => format!("{}P{}D", sign, self.amount),
+            OffsetUnit::Weeks => format!("{}P{}W", sign, self.amount),
+            OffsetUnit::Months => format!("{}P{}M", sign, self.amount),
+            OffsetUnit::Years => format!("{}P{}Y", sign, self.amount),
+        }
+    }
+}
+
+/// A date value as written in a query.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DateSpec {
+    /// `7d`, `2weeks` or `-P2W`: that long ago
+    Offset(Offset),
+    /// An RFC 3339 timestamp
+    Instant(DateTime<Utc>),
+    /// `2026-10-01`: the whole day
+    Day(NaiveDate),
+    /// `today`, `last-month`, `next-cycle`: the unit containing now, shifted by some
+    Anchor(Unit, i32),
+    /// `a..b`, from the start of `a` to the end of `b`; either side may be left out
+    Range(Option<Box<DateSpec>>, Option<Box<DateSpec>>),
+}
+
+impl DateSpec {
+    /// Parse a date value, or `None` if it isn't one.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if let Some((from, to)) = text.split_once("..") {
+            let side = |side: &str| match side.trim() {
+                "" => Some(None),
+                side => Self::parse_single(side).map(|spec| Some(Box::new(spec))),
+            };
+            let (from, to) = (side(from)?, side(to)?);
+            if from.is_none() && to.is_none() {
+                return None;
+            }
+            return Some(DateSpec::Range(from, to));
+        }
+        Self::parse_single(text)
+    }
+
+    fn parse_single(text: &str) -> Option<Self> {
+        if let Some(offset) = parse_offset(text) {
+            return Some(DateSpec::Offset(offset));
+        }
+        if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+            return Some(DateSpec::Day(date));
+        }
+        if let Ok(at) = DateTime::parse_from_rfc3339(text) {
+            return Some(DateSpec::Instant(at.with_timezone(&Utc)));
+        }
+        parse_anchor(text).map(|(unit, shift)| DateSpec::Anchor(unit, shift))
+    }
+
+    /// The period this value covers, as of `now`.
+    pub fn resolve(&self, calendar: &Calendar, now: DateTime<Utc>) -> Result<Period, String> {
+        let zone = calendar.zone;
+        let days = |start: NaiveDate, end: NaiveDate| Period::between(zone.start_of(start), zone.start_of(end));
+        match self {
+            DateSpec::Offset(offset) => offset
+                .from(now)
+                .map(Period::instant)
+                .ok_or_else(|| format!("{}: date out of range", offset.iso())),
+            DateSpec::Instant(at) => Ok(Period::instant(*at)),
+            DateSpec::Day(date) => Ok(days(*date, next_day(*date))),
+            DateSpec::Anchor(Unit::Cycle, shift) => {
+                let (cycle, name) = match shift {
+                    -1 => (calendar.cycles.previous, "last-cycle"),
+                    1 => (calendar.cycles.next, "next-cycle"),
+                    _ => (calendar.cycles.current, "this-cycle"),
+                };
+                cycle.ok_or_else(|| format!("{}: no cycle dates (does the team use cycles?)", name))
+            }
+            DateSpec::Anchor(unit, shift) => {
+                let (start, end) = anchor_days(*unit, *shift, zone.date(now))
+                    .ok_or_else(|| "date out of range".to_string())?;
+                Ok(days(start, end))
+            }
+            DateSpec::Range(from, to) => {
+                let start = match from {
+                    Some(from) => from.resolve(calendar, now)?.start,
+                    None => None,
+                };
+                let end = match to {
+                    Some(to) => to.resolve(calendar, now)?.end,
+                    None => None,
+                };
+                if let (Some(start), Some(end)) = (start, end) {
+                    if end < start {
+                        return Err("the range ends before it starts".to_string());
+                    }
+                }
+                Ok(Period { start, end })
+            }
+        }
+    }
+}
+
+/// The comparisons `op` with `period` stands for, all of which must hold (any one,
+/// for `!=`). On its own an instant means "since", so `created:7d` is the last week.
+/// A period compares by its bounds: `>` is after it ends, `<=` before it ends.
+/// `None` when `op` can't apply, such as `>` on a range with no end.
+pub fn comparisons(op: &FilterOperator, period: &Period) -> Option<Vec<(&'static str, DateTime<Utc>)>> {
+    use FilterOperator as Op;
+
+    if period.is_instant() {
+        let at = period.start?;
+        let comparator = match op {
+            Op::Equals | Op::GreaterThanOrEquals => "gte",
+            Op::NotEquals | Op::LessThan => "lt",
+            Op::GreaterThan => "gt",
+            Op::LessThanOrEquals => "lte",
+            _ => return None,
+        };
+        return Some(vec![(comparator, at)]);
+    }
+
+    let bound = |comparator, at: Option<DateTime<Utc>>| at.map(|at| (comparator, at));
+    let all: Vec<_> = match op {
+        Op::Equals => [bound("gte", period.start), bound("lt", period.end)].into_iter().flatten().collect(),
+        Op::NotEquals => [bound("lt", period.start), bound("gte", period.end)].into_iter().flatten().collect(),
+        Op::GreaterThan => vec![bound("gte", period.end)?],
+        Op::GreaterThanOrEquals => vec![bound("gte", period.start)?],
+        Op::LessThan => vec![bound("lt", period.start)?],
+        Op::LessThanOrEquals => vec![bound("lt", period.end)?],
+        _ => return None,
+    };
+    (!all.is_empty()).then_some(all)
+}
+
+/// Whether `at` passes one comparison from `comparisons`.
+pub fn compare(at: DateTime<Utc>, comparator: &str, bound: DateTime<Utc>) -> bool {
+    match comparator {
+        "gt" => at > bound,
+        "gte" => at >= bound,
+        "lt" => at < bound,
+        "lte" => at <= bound,
+        _ => false,
+    }
+}
+
+/// A timestamp as sent to Linear.
+pub fn format_timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// `7d`, `36h`, `2weeks`, `1month`, `1y`, or an ISO 8601 duration like `-P2W` or `P1D`.
+fn parse_offset(text: &str) -> Option<Offset> {
+    let unit = |name: &str| match name.chars().next()? {
+        'h' | 'H' => Some(OffsetUnit::Hours),
+        'd' | 'D' => Some(OffsetUnit::Days),
+        'w' | 'W' => Some(OffsetUnit::Weeks),
+        'm' | 'M' => Some(OffsetUnit::Months),
+        'y' | 'Y' => Some(OffsetUnit::Years),
+        _ => None,
+    };
+    if let Some(captures) = SHORT_OFFSET.captures(&text.to_lowercase()) {
+        return Some(Offset { amount: captures[1].parse().ok()?, unit: unit(&captures[2])?, ahead: false });
+    }
+    let captures = ISO_OFFSET.captures(text)?;
+    let ahead = captures[1].is_empty();
+    match (captures.get(2), captures.get(3), captures.get(4)) {
+        (Some(amount), Some(name), _) => Some(Offset { amount: amount.as_str().parse().ok()?, unit: unit(name.as_str())?, ahead }),
+        (_, _, Some(hours)) => Some(Offset { amount: hours.as_str().parse().ok()?, unit: OffsetUnit::Hours, ahead }),
+        _ => None,
+    }
+}
+
+fn parse_anchor(text: &str) -> Option<(Unit, i32)> {
+    let text = text.to_lowercase();
+    match text.as_str() {
+        "today" => return Some((Unit::Day, 0)),
+        "yesterday" => return Some((Unit::Day, -1)),
+        "tomorrow" => return Some((Unit::Day, 1)),
+        _ => {}
+    }
+    let (shift, unit) = text.split_once('-')?;
+    let shift = match shift {
+        "this" | "current" => 0,
+        "last" | "previous" => -1,
+        "next" => 1,
+        _ => return None,
+    };
+    let unit = match unit {
+        "day" => Unit::Day,
+        "week" => Unit::Week,
+        "month" => Unit::Month,
+        "quarter" => Unit::Quarter,
+        "year" => Unit::Year,
+        "cycle" => Unit::Cycle,
+        _ => return None,
+    };
+    Some((unit, shift))
+}
+
+fn next_day(date: NaiveDate) -> NaiveDate {
+    date.succ_opt().unwrap_or(date)
+}
+
+/// The first day of the `unit` containing `today` moved `shift` units, and the first
+/// day after it. Weeks start on Monday.
+fn anchor_days(unit: Unit, shift: i32, today: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+    let add_months = |date: NaiveDate, months: i32| {
+        if months >= 0 {
+            date.checked_add_months(Months::new(months as u32))
+        } else {
+            date.checked_sub_months(Months::new(months.unsigned_abs()))
+        }
+    };
+    let months = |first: NaiveDate, length: i32| {
+        let start = add_months(first, shift * length)?;
+        Some((start, add_months(start, length)?))
+    };
+    match unit {
+        Unit::Day => {
+            let start = today.checked_add_signed(Duration::days(shift as i64))?;
+            Some((start, next_day(start)))
+        }
+        Unit::Week => {
+            let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+            let start = monday.checked_add_signed(Duration::weeks(shift as i64))?;
+            Some((start, start + Duration::weeks(1)))
+        }
+        Unit::Month => months(today.with_day(1)?, 1),
+        Unit::Quarter => months(NaiveDate::from_ymd_opt(today.year(), (today.month0() / 3) * 3 + 1, 1)?, 3),
+        Unit::Year => months(NaiveDate::from_ymd_opt(today.year(), 1, 1)?, 12),
+        Unit::Cycle => None,
+    }
+}
@@ -0,0 +1,266 @@
+use std::fmt;
+use std::ops::Range;
+
+use super::builder::FilterField;
+use super::complete::{Vocabulary, FIELDS, FLAG_FIELDS, SORT_FIELDS};
+use super::dates::ANCHORS;
+use super::parser::{field_from_name, parse_filter_spanned, tokens_with_spans, ParseError, Token, PRIORITIES};
+
+const OPERATORS: &[&str] = &["=", "!=", ">", ">=", "<", "<=", "~", "!~", "^", "$", "in", "has"];
+
+const STATE_TYPES: &[&str] = &["triage", "backlog", "unstarted", "started", "completed", "canceled"];
+
+/// Values that stand for something other than a name
+const SPECIAL_VALUES: &[&str] = &["me", "null", "empty"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    /// The query can't be used as written
+    Error,
+    /// The query works but probably doesn't do what was meant
+    Warning,
+}
+
+/// A problem with a filter query, pointing at the part of the query it's about.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    /// Characters of the query the problem is about
+    pub span: Range<usize>,
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    fn error(message: impl Into<String>, span: Range<usize>) -> Self {
+        Self { severity: Severity::Error, message: message.into(), span, help: None }
+    }
+
+    fn warning(message: impl Into<String>, span: Range<usize>) -> Self {
+        Self { severity: Severity::Warning, message: message.into(), span, help: None }
+    }
+
+    fn with_help(mut self, help: Option<String>) -> Self {
+        self.help = help;
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// The marker line that goes under the query: spaces up to the span, then a
+    /// caret under each of its characters.
+    pub fn underline(&self) -> String {
+        let width = self.span.end.saturating_sub(self.span.start).max(1);
+        format!("{}{}", " ".repeat(self.span.start), "^".repeat(width))
+    }
+
+    /// The diagnostic as shown on a terminal:
+    ///
+    /// ```text
+    /// error: Unknown field 'asignee'
+    ///   asignee:me
+    ///   ^^^^^^^
+    ///   help: did you mean `assignee`?
+    /// ```
+    pub fn render(&self, query: &str) -> String {
+        let mut text = format!("{}\n  {}\n  {}", self, query, self.underline());
+        if let Some(help) = &self.help {
+            text.push_str(&format!("\n  help: {}", help));
+        }
+        text
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{}: {}", severity, self.message)
+    }
+}
+
+/// Check a filter query: the parse error if it doesn't parse, otherwise unknown
+/// field names (an error, since Linear can't filter on them) and status, label and
+/// project names that aren't in `vocabulary` (a warning). Suggestions come from the
+/// known fields, operators, priorities, sort fields and `vocabulary`.
+pub fn diagnose(query: &str, vocabulary: &Vocabulary) -> Vec<Diagnostic> {
+    if let Err((error, span)) = parse_filter_spanned(query) {
+        let help = parse_error_help(&error, query, &span);
+        // The caret shows where, so leave positions out of the message
+        let message = match &error {
+            ParseError::UnexpectedToken { .. } => {
+                format!("Unexpected '{}'", query.chars().skip(span.start).take(span.len()).collect::<String>())
+            }
+            ParseError::UnexpectedCharacter { char, .. } => format!("Unexpected character '{}'", char),
+            ParseError::UnterminatedString { .. } => "Unterminated quoted string".to_string(),
+            error => error.to_string(),
+        };
+        return vec![Diagnostic::error(message, span).with_help(help)];
+    }
+    let Ok(tokens) = tokens_with_spans(query) else {
+        return Vec::new();
+    };
+
+    let mut diagnostics = Vec::new();
+    let mut index = 0;
+    while index < tokens.len() {
+        let (Token::Field(name), span) = &tokens[index] else {
+            index += 1;
+            continue;
+        };
+        index += 1;
+        if index < tokens.len() && tokens[index].0 == Token::Colon {
+            index += 1;
+        }
+        let operator = match tokens.get(index) {
+            Some((Token::Operator(op), _)) => {
+                index += 1;
+                Some(op.as_str())
+            }
+            _ => None,
+        };
+        let list = matches!(operator, Some("in") | Some("has") | Some("!in"));
+        let mut values = Vec::new();
+        while let Some((Token::Value(value), span)) = tokens.get(index) {
+            // An unquoted list arrives as one value, `bug,ui`
+            let unquoted = span.len() == value.chars().count();
+            if list && unquoted && value.contains(',') {
+                let mut start = span.start;
+                for item in value.split(',') {
+                    let len = item.chars().count();
+                    if len > 0 {
+                        values.push((item, start..start + len));
+                    }
+                    start += len + 1;
+                }
+            } else {
+                values.push((value.as_str(), span.clone()));
+            }
+            index += 1;
+            if tokens.get(index).map(|(token, _)| token) != Some(&Token::Comma) {
+                break;
+            }
+            index += 1;
+        }
+
+        if name.eq_ignore_ascii_case("sort") {
+            continue;
+        }
+        let field = field_from_name(name);
+        if let FilterField::Custom(_) = field {
+            let candidates = FIELDS.iter().chain(FLAG_FIELDS).copied();
+            diagnostics.push(
+                Diagnostic::error(format!("Unknown field '{}'", name), span.clone())
+                    .with_help(closest(name, candidates).map(did_you_mean)),
+            );
+            continue;
+        }
+        // Only exact matches need the name to be right
+        if !matches!(operator, None | Some("=") | Some("!=") | Some("!") | Some("in") | Some("has")) {
+            continue;
+        }
+        for (value, span) in values {
+            if let Some(diagnostic) = check_name(&field, value, span, vocabulary) {
+                diagnostics.push(diagnostic);
+            }
+        }
+    }
+    diagnostics
+}
+
+/// A warning when `value` isn't a known name for `field`.
+fn check_name(field: &FilterField, value: &str, span: Range<usize>, vocabulary: &Vocabulary) -> Option<Diagnostic> {
+    let (names, kind) = match field {
+        FilterField::Status => (&vocabulary.states, "status"),
+        FilterField::Label => (&vocabulary.labels, "label"),
+        FilterField::Project => (&vocabulary.projects, "project"),
+        _ => return None,
+    };
+    // Nothing to compare against until names have been fetched
+    if names.is_empty() || names.iter().any(|name| name == value) {
+        return None;
+    }
+    if SPECIAL_VALUES.iter().any(|special| value.eq_ignore_ascii_case(special)) {
+        return None;
+    }
+
+    let help = if *field == FilterField::Status && STATE_TYPES.contains(&value.to_lowercase().as_str()) {
+        Some(format!("did you mean `state-type:{}`?", value.to_lowercase()))
+    } else {
+        closest(value, names.iter().map(String::as_str)).map(did_you_mean)
+    };
+    let message = match names.iter().find(|name| name.eq_ignore_ascii_case(value)) {
+        Some(_) => format!("No {} named '{}' (names are case-sensitive)", kind, value),
+        None => format!("No {} named '{}'", kind, value),
+    };
+    Some(Diagnostic::warning(message, span).with_help(help))
+}
+
+/// A suggestion for a parse error, where there's a likely fix.
+fn parse_error_help(error: &ParseError, query: &str, span: &Range<usize>) -> Option<String> {
+    match error {
+        ParseError::InvalidPriorityValue(value) => closest(value, PRIORITIES.iter().copied())
+            .map(did_you_mean)
+            .or_else(|| Some(format!("use one of {} or 0-4", PRIORITIES.join(", ")))),
+        ParseError::InvalidSortField(field) => {
+            let names = SORT_FIELDS.iter().map(|field| field.name());
+            let descending = query.chars().nth(span.start) == Some('-');
+            closest(field.trim_start_matches('-'), names).map(|name| {
+                did_you_mean(&format!("{}{}", if descending { "-" } else { "" }, name))
+            })
+        }
+        ParseError::InvalidDateValue(value) => closest(value, ANCHORS.iter().copied()).map(did_you_mean),
+        ParseError::UnknownOperator(operator) => closest(operator, OPERATORS.iter().copied()).map(did_you_mean),
+        // Two conditions side by side
+        ParseError::UnexpectedToken { .. } => tokens_with_spans(query)
+            .ok()?
+            .iter()
+            .any(|(token, token_span)| matches!(token, Token::Field(_)) && token_span == span)
+            .then(|| "join conditions with AND or OR".to_string()),
+        ParseError::UnterminatedString { .. } => Some("close the quoted value with \"".to_string()),
+        ParseError::MissingClosingParen => Some("add the missing )".to_string()),
+        _ => None,
+    }
+}
+
+fn did_you_mean(name: &str) -> String {
+    format!("did you mean `{}`?", name)
+}
+
+/// The candidate closest to `word`, if one is close enough to be a likely typo:
+/// within a third of its length in edits, ignoring case.
+pub fn closest<'a>(word: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let word = word.to_lowercase();
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(&word, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, candidate)| *distance <= (candidate.chars().count() / 3).max(1))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance, counting a swap of neighbouring characters as one edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    rows[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1).min(rows[i][j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
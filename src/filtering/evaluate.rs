@@ -0,0 +1,367 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Value;
+
+use super::builder::{
+    expression_to_graphql, negate_group, state_type, FilterCondition, FilterError, FilterExpression,
+    FilterField, FilterGroup, FilterOperator, FilterValue, LogicalOperator,
+};
+use super::dates::{compare as compare_date, comparisons, Calendar, DateSpec};
+use crate::models::{Issue, User};
+
+/// Check `expr` against an issue already in memory, with the same semantics as the
+/// `IssueFilter` that `FilterBuilder::to_graphql` sends to Linear: string `eq` is case
+/// sensitive, and comparisons on a missing assignee, project or date never match.
+/// `viewer_id` resolves `me`, and date values are read in `calendar`.
+///
+/// Every condition is checked, even when the result is already known, so a field
+/// that isn't loaded with issues (cycle, relation, ...) is always reported.
+pub fn matches(expr: &FilterExpression, issue: &Issue, viewer_id: Option<&str>, calendar: &Calendar) -> Result<bool, FilterError> {
+    match expr {
+        FilterExpression::Condition(condition) => condition_matches(condition, issue, viewer_id, calendar),
+        FilterExpression::Group(group) => group_matches(group, issue, viewer_id, calendar),
+    }
+}
+
+/// Report conditions `matches` can't evaluate, without needing an issue to hand.
+pub fn check(expr: &FilterExpression, viewer_id: Option<&str>, calendar: &Calendar) -> Result<(), FilterError> {
+    matches(expr, &placeholder_issue(), viewer_id, calendar).map(|_| ())
+}
+
+/// Split `expr` into the `IssueFilter` Linear can apply and the conditions left to
+/// check with `matches` once the issues arrive. Only the conditions of a top-level
+/// AND are split; anything else is sent whole or kept whole.
+pub fn split_for_server(expr: &FilterExpression, calendar: &Calendar) -> Result<(Option<Value>, Option<FilterExpression>), FilterError> {
+    let conditions = match expr {
+        FilterExpression::Group(group) if group.operator == LogicalOperator::And => group.conditions.as_slice(),
+        _ => std::slice::from_ref(expr),
+    };
+
+    let mut server = Vec::new();
+    let mut local = Vec::new();
+    for condition in conditions {
+        match expression_to_graphql(condition, calendar) {
+            Ok(filter) => server.push(filter),
+            Err(FilterError::Unsupported(_)) => local.push(condition.clone()),
+            Err(e) => return Err(e),
+        }
+    }
+
+    let server = match server.len() {
+        0 => None,
+        1 => server.pop(),
+        _ => Some(serde_json::json!({ "and": server })),
+    };
+    let local = match local.len() {
+        0 => None,
+        1 => local.pop(),
+        _ => Some(FilterExpression::Group(Box::new(FilterGroup {
+            operator: LogicalOperator::And,
+            conditions: local,
+        }))),
+    };
+    Ok((server, local))
+}
+
+fn group_matches(group: &FilterGroup, issue: &Issue, viewer_id: Option<&str>, calendar: &Calendar) -> Result<bool, FilterError> {
+    let all = |conditions: &[FilterExpression]| -> Result<Vec<bool>, FilterError> {
+        conditions.iter().map(|expr| matches(expr, issue, viewer_id, calendar)).collect()
+    };
+    match group.operator {
+        LogicalOperator::And => Ok(all(&group.conditions)?.into_iter().all(|m| m)),
+        LogicalOperator::Or => Ok(all(&group.conditions)?.into_iter().any(|m| m)),
+        // Negate the way the compiler does, so `NOT assignee:ada` skips unassigned
+        // issues here too. Conditions Linear can't negate are simply inverted.
+        LogicalOperator::Not => match negate_group(group) {
+            Ok(negated) => matches(&negated, issue, viewer_id, calendar),
+            Err(FilterError::Unsupported(_)) => Ok(!all(&group.conditions)?.into_iter().all(|m| m)),
+            Err(e) => Err(e),
+        },
+    }
+}
+
+fn condition_matches(condition: &FilterCondition, issue: &Issue, viewer_id: Option<&str>, calendar: &Calendar) -> Result<bool, FilterError> {
+    use FilterField as Field;
+    use FilterOperator as Op;
+    use FilterValue as Val;
+
+    let unsupported = || FilterError::Unsupported(format!(
+        "{} can't be checked against loaded issues",
+        condition.field.field_name()
+    ));
+    let invalid = |field: &str| FilterError::InvalidValue { field: field.to_string() };
+
+    let matched = match (&condition.field, &condition.operator, &condition.value) {
+        (Field::Title, op, Val::String(s)) => text(Some(&issue.title), op, s).ok_or_else(unsupported)?,
+        (Field::Description, Op::IsNull, _) => issue.description.is_none(),
+        (Field::Description, Op::IsNotNull, _) => issue.description.is_some(),
+        (Field::Description, op, Val::String(s)) => text(issue.description.as_deref(), op, s).ok_or_else(unsupported)?,
+
+        (Field::Status, op, Val::String(s)) => text(Some(&issue.state.name), op, s).ok_or_else(unsupported)?,
+        (Field::Status, op, Val::StringList(list)) => in_list(Some(&issue.state.name), op, list).ok_or_else(unsupported)?,
+        (Field::StateType, op, value) => {
+            let types = match value {
+                Val::String(s) => vec![state_type(s).ok_or_else(|| invalid("state-type"))?],
+                Val::StringList(list) => list
+                    .iter()
+                    .map(|s| state_type(s))
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| invalid("state-type"))?,
+                _ => return Err(unsupported()),
+            };
+            let found = types.contains(&issue.state.state_type.as_str());
+            match op {
+                Op::Equals | Op::In => found,
+                Op::NotEquals | Op::NotIn => !found,
+                _ => return Err(unsupported()),
+            }
+        }
+
+        (Field::Project, Op::IsNull, _) => issue.project.is_none(),
+        (Field::Project, Op::IsNotNull, _) => issue.project.is_some(),
+        (Field::Project, op, Val::String(s)) => {
+            text(issue.project.as_ref().map(|p| p.name.as_str()), op, s).ok_or_else(unsupported)?
+        }
+        (Field::Project, op, Val::StringList(list)) => {
+            in_list(issue.project.as_ref().map(|p| p.name.as_str()), op, list).ok_or_else(unsupported)?
+        }
+
+        (Field::Assignee, Op::IsNull, _) => issue.assignee.is_none(),
+        (Field::Assignee, Op::IsNotNull, _) => issue.assignee.is_some(),
+        (Field::Assignee, op @ (Op::Equals | Op::NotEquals), Val::String(s)) => {
+            let me = if s.eq_ignore_ascii_case("me") {
+                Some(viewer_id.ok_or_else(|| FilterError::Unsupported("assignee:me without a signed-in user".to_string()))?)
+            } else {
+                None
+            };
+            match &issue.assignee {
+                Some(user) => user_matches(user, s, me, *op == Op::Equals),
+                None => false,
+            }
+        }
+
+        (Field::Team, op @ (Op::Equals | Op::NotEquals), Val::String(s)) => {
+            let same = issue.team.key.eq_ignore_ascii_case(s) || issue.team.name.eq_ignore_ascii_case(s);
+            same == (*op == Op::Equals)
+        }
+        (Field::Team, op, Val::StringList(list)) => in_list(Some(&issue.team.key), op, list).ok_or_else(unsupported)?,
+        // Not available on the server; only used when post-filtering
+        (Field::Team, op @ (Op::Contains | Op::NotContains), Val::String(s)) => {
+            let needle = s.to_lowercase();
+            let found = issue.team.key.to_lowercase().contains(&needle) || issue.team.name.to_lowercase().contains(&needle);
+            found == (*op == Op::Contains)
+        }
+
+        (Field::Identifier, op @ (Op::Equals | Op::NotEquals), Val::String(s)) => {
+            let wanted = parse_identifier(s).ok_or_else(|| invalid("identifier"))?;
+            (parse_identifier(&issue.identifier) == Some(wanted)) == (*op == Op::Equals)
+        }
+        (Field::Identifier, op @ (Op::In | Op::NotIn), Val::StringList(list)) => {
+            let wanted = list
+                .iter()
+                .map(|s| parse_identifier(s))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| invalid("identifier"))?;
+            let found = parse_identifier(&issue.identifier).is_some_and(|id| wanted.contains(&id));
+            found == (*op == Op::In)
+        }
+        // Not available on the server; only used when post-filtering
+        (Field::Identifier, op, Val::String(s)) => text(Some(&issue.identifier.to_lowercase()), op, &s.to_lowercase())
+            .ok_or_else(unsupported)?,
+
+        (Field::Label, op, value) => labels_match(issue, op, value).ok_or_else(unsupported)?,
+
+        (Field::Priority, op, Val::Number(n)) => {
+            let ordering = issue.priority.and_then(|p| (p as f64).partial_cmp(n));
+            compare(op, ordering).ok_or_else(unsupported)?
+        }
+        (Field::Estimate, op, Val::Number(n)) => {
+            let ordering = issue.estimate.and_then(|e| e.partial_cmp(n));
+            compare(op, ordering).ok_or_else(unsupported)?
+        }
+        (Field::Estimate, Op::IsNull, _) => issue.estimate.is_none(),
+        (Field::Estimate, Op::IsNotNull, _) => issue.estimate.is_some(),
+
+        (Field::CreatedAt | Field::UpdatedAt | Field::StartedAt | Field::CompletedAt | Field::DueDate, op, Val::Date(date)) => {
+            let period = DateSpec::parse(date)
+                .ok_or_else(|| invalid(condition.field.field_name()))?
+                .resolve(calendar, Utc::now())
+                .map_err(FilterError::InvalidDate)?;
+            let bounds = comparisons(op, &period).ok_or_else(unsupported)?;
+            let at = match condition.field {
+                Field::CreatedAt => parse_date(&issue.created_at),
+                Field::UpdatedAt => parse_date(&issue.updated_at),
+                Field::StartedAt => issue.started_at.as_deref().and_then(parse_date),
+                Field::CompletedAt => issue.completed_at.as_deref().and_then(parse_date),
+                // A due date is the whole day, so it starts when the day does
+                _ => issue
+                    .due_date
+                    .as_deref()
+                    .and_then(|due| NaiveDate::parse_from_str(due, "%Y-%m-%d").ok())
+                    .map(|due| calendar.zone.start_of(due)),
+            };
+            let pass = |(comparator, bound): &(&str, DateTime<Utc>)| at.is_some_and(|at| compare_date(at, comparator, *bound));
+            if *op == Op::NotEquals {
+                at.is_some() && bounds.iter().any(pass)
+            } else {
+                bounds.iter().all(pass)
+            }
+        }
+
+        _ => return Err(unsupported()),
+    };
+    Ok(matched)
+}
+
+/// String comparators as compiled for Linear. `None` means the operator doesn't apply;
+/// a missing value never matches.
+fn text(value: Option<&str>, op: &FilterOperator, wanted: &str) -> Option<bool> {
+    let supported = matches!(
+        op,
+        FilterOperator::Equals
+            | FilterOperator::NotEquals
+            | FilterOperator::Contains
+            | FilterOperator::NotContains
+            | FilterOperator::StartsWith
+            | FilterOperator::EndsWith
+    );
+    if !supported {
+        return None;
+    }
+    let Some(value) = value else { return Some(false) };
+    let contains = || value.to_lowercase().contains(&wanted.to_lowercase());
+    Some(match op {
+        FilterOperator::Equals => value == wanted,
+        FilterOperator::NotEquals => value != wanted,
+        FilterOperator::Contains => contains(),
+        FilterOperator::NotContains => !contains(),
+        FilterOperator::StartsWith => value.to_lowercase().starts_with(&wanted.to_lowercase()),
+        _ => value.ends_with(wanted),
+    })
+}
+
+fn in_list(value: Option<&str>, op: &FilterOperator, list: &[String]) -> Option<bool> {
+    let found = value.is_some_and(|v| list.iter().any(|item| item == v));
+    match op {
+        FilterOperator::In => Some(found),
+        FilterOperator::NotIn => Some(value.is_some() && !found),
+        _ => None,
+    }
+}
+
+fn compare(op: &FilterOperator, ordering: Option<std::cmp::Ordering>) -> Option<bool> {
+    use std::cmp::Ordering::*;
+    let supported = matches!(
+        op,
+        FilterOperator::Equals
+            | FilterOperator::NotEquals
+            | FilterOperator::GreaterThan
+            | FilterOperator::GreaterThanOrEquals
+            | FilterOperator::LessThan
+            | FilterOperator::LessThanOrEquals
+    );
+    if !supported {
+        return None;
+    }
+    let Some(ordering) = ordering else { return Some(false) };
+    Some(match op {
+        FilterOperator::Equals => ordering == Equal,
+        FilterOperator::NotEquals => ordering != Equal,
+        FilterOperator::GreaterThan => ordering == Greater,
+        FilterOperator::GreaterThanOrEquals => ordering != Less,
+        FilterOperator::LessThan => ordering == Less,
+        _ => ordering != Greater,
+    })
+}
+
+/// `me`, an email address, or a name or display name, as `user_filter` compiles them.
+fn user_matches(user: &User, wanted: &str, me: Option<&str>, equals: bool) -> bool {
+    if let Some(me) = me {
+        (user.id == me) == equals
+    } else if wanted.contains('@') {
+        (user.email == wanted) == equals
+    } else {
+        let name = user.name.eq_ignore_ascii_case(wanted);
+        let display = user.display_name.as_deref().is_some_and(|d| d.eq_ignore_ascii_case(wanted));
+        (name || display) == equals
+    }
+}
+
+fn labels_match(issue: &Issue, op: &FilterOperator, value: &FilterValue) -> Option<bool> {
+    let names: Vec<&str> = issue.labels.nodes.iter().map(|l| l.name.as_str()).collect();
+    Some(match (op, value) {
+        (FilterOperator::Equals, FilterValue::String(s)) => names.iter().any(|n| n == s),
+        (FilterOperator::NotEquals, FilterValue::String(s)) => names.iter().all(|n| n != s),
+        (FilterOperator::Contains, FilterValue::String(s)) => {
+            names.iter().any(|n| n.to_lowercase().contains(&s.to_lowercase()))
+        }
+        (FilterOperator::NotContains, FilterValue::String(s)) => {
+            names.iter().all(|n| !n.to_lowercase().contains(&s.to_lowercase()))
+        }
+        (FilterOperator::HasAny | FilterOperator::In, FilterValue::StringList(list)) => {
+            names.iter().any(|n| list.iter().any(|l| l == n))
+        }
+        (FilterOperator::HasNone | FilterOperator::NotIn, FilterValue::StringList(list)) => {
+            names.iter().all(|n| list.iter().all(|l| l != n))
+        }
+        (FilterOperator::HasAll, FilterValue::StringList(list)) if !list.is_empty() => {
+            list.iter().all(|l| names.contains(&l.as_str()))
+        }
+        (FilterOperator::IsNull, _) => names.is_empty(),
+        (FilterOperator::IsNotNull, _) => !names.is_empty(),
+        _ => return None,
+    })
+}
+
+/// `ENG-12` as (`eng`, 12), compared the way `identifier_filter` compiles it.
+fn parse_identifier(identifier: &str) -> Option<(String, u32)> {
+    let (key, number) = identifier.rsplit_once('-')?;
+    if key.is_empty() {
+        return None;
+    }
+    Some((key.to_lowercase(), number.parse().ok()?))
+}
+
+/// RFC 3339 timestamps, or plain dates at midnight UTC.
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|at| at.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|at| at.and_utc())
+        })
+}
+
+fn placeholder_issue() -> Issue {
+    use crate::models::issue::{LabelConnection, WorkflowState};
+    use crate::models::Team;
+
+    Issue {
+        id: String::new(),
+        identifier: String::new(),
+        title: String::new(),
+        description: None,
+        url: String::new(),
+        priority: None,
+        created_at: String::new(),
+        updated_at: String::new(),
+        started_at: None,
+        completed_at: None,
+        due_date: None,
+        estimate: None,
+        state: WorkflowState {
+            id: String::new(),
+            name: String::new(),
+            state_type: String::new(),
+            color: None,
+            position: None,
+            team: None,
+        },
+        assignee: None,
+        team: Team { id: String::new(), name: String::new(), key: String::new() },
+        labels: LabelConnection { nodes: Vec::new() },
+        project: None,
+    }
+}
@@ -2,11 +2,39 @@ pub mod query;
 pub mod builder;
 pub mod parser;
 pub mod adapter;
+pub mod reverse;
+pub mod evaluate;
+pub mod sort;
+pub mod complete;
+pub mod printer;
+pub mod diagnostic;
+pub mod dates;
+pub mod variables;
 
 // Legacy exports for backward compatibility
+#[allow(unused_imports)]
 pub use query::{parse_filter_query, build_graphql_filter};
 
 // New exports
+#[allow(unused_imports)]
 pub use builder::{FilterBuilder, FilterField, FilterOperator, FilterValue, FilterError};
+#[allow(unused_imports)]
 pub use parser::{parse_filter, ParseError};
-pub use adapter::{FilterAdapter, print_filter_examples};
\ No newline at end of file
+pub use adapter::{FilterAdapter, print_filter_examples};
+pub use reverse::graphql_to_query;
+#[allow(unused_imports)]
+pub use evaluate::{check, matches, split_for_server};
+#[allow(unused_imports)]
+pub use sort::{format_sort, order_by, parse_sort, sort_issues, SortField, SortKey};
+#[allow(unused_imports)]
+pub use complete::{complete, Completion, CompletionKind, Vocabulary};
+#[allow(unused_imports)]
+pub use parser::{parse_priority, priority_label, PRIORITIES};
+#[allow(unused_imports)]
+pub use printer::{format_condition, format_expression, format_query, format_tree};
+#[allow(unused_imports)]
+pub use diagnostic::{diagnose, Diagnostic, Severity};
+#[allow(unused_imports)]
+pub use dates::{cycle_team, uses_cycle_dates, Calendar, CycleDates, DateSpec, Period, Zone};
+#[allow(unused_imports)]
+pub use variables::{bind, builtin_values, check_search, check_values, variables, Variable, VariableError, BUILTINS};
@@ -1,14 +1,40 @@
-use chrono::{Duration, Utc};
-use regex::Regex;
+use std::ops::Range;
+
+use super::dates::DateSpec;
+use super::sort::{parse_sort, SortKey};
 
 use super::builder::{
     FilterBuilder, FilterCondition, FilterExpression, FilterField, FilterGroup, FilterOperator, FilterValue,
     LogicalOperator,
 };
 
+/// Priority names, indexed by their value from 0 (none) to 4 (urgent)
+pub const PRIORITIES: [&str; 5] = ["none", "low", "medium", "high", "urgent"];
+
+/// The priority a name (`high`, or `no` and `med` for short) or number 0-4 stands for.
+pub fn parse_priority(text: &str) -> Option<u8> {
+    if let Ok(n) = text.parse::<u8>() {
+        return (usize::from(n) < PRIORITIES.len()).then_some(n);
+    }
+    let name = match text.to_lowercase().as_str() {
+        "no" => "none".to_string(),
+        "med" => "medium".to_string(),
+        name => name.to_string(),
+    };
+    PRIORITIES.iter().position(|priority| *priority == name).map(|n| n as u8)
+}
+
+/// The display name of a priority, e.g. `Urgent`; unknown numbers show as `P7`.
+pub fn priority_label(priority: u8) -> String {
+    match PRIORITIES.get(usize::from(priority)) {
+        Some(name) => name[..1].to_uppercase() + &name[1..],
+        None => format!("P{}", priority),
+    }
+}
+
 /// Token types for the filter parser
 #[derive(Debug, Clone, PartialEq)]
-enum Token {
+pub(crate) enum Token {
     Field(String),
     Operator(String),
     Value(String),
@@ -26,6 +52,9 @@ struct Tokenizer {
     chars: Vec<char>,
     position: usize,
     last_operator: Option<String>,
+    /// Where each token starts and ends, in characters
+    spans: Vec<Range<usize>>,
+    token_start: usize,
 }
 
 impl Tokenizer {
@@ -34,6 +63,8 @@ impl Tokenizer {
             chars: input.chars().collect(),
             position: 0,
             last_operator: None,
+            spans: Vec::new(),
+            token_start: 0,
         }
     }
 
@@ -46,6 +77,7 @@ impl Tokenizer {
             if self.position >= self.chars.len() {
                 break;
             }
+            self.token_start = self.position;
 
             let token = if let Some(token) = self.try_parse_operator() {
                 Some(token)
@@ -64,6 +96,7 @@ impl Tokenizer {
 
             if let Some(token) = token {
                 tokens.push(token);
+                self.spans.push(self.token_start..self.position);
             } else {
                 return Err(ParseError::UnexpectedCharacter {
                     position: self.position,
@@ -95,12 +128,10 @@ impl Tokenizer {
 
     #[allow(dead_code)]
     fn peek_literal(&self, literal: &str) -> bool {
-        let mut index = 0;
-        for expected in literal.chars() {
+        for (index, expected) in literal.chars().enumerate() {
             if self.chars.get(self.position + index).copied() != Some(expected) {
                 return false;
             }
-            index += 1;
         }
 
         true
@@ -121,15 +152,12 @@ impl Tokenizer {
     }
 
     fn starts_with_case_insensitive(&self, expected: &str) -> bool {
-        let mut index = 0;
-        for expected_char in expected.chars() {
-            if self.chars.get(self.position + index).copied().unwrap_or('\0').to_ascii_lowercase()
-                != expected_char.to_ascii_lowercase()
+        for (index, expected_char) in expected.chars().enumerate() {
+            if !self.chars.get(self.position + index).copied().unwrap_or('\0')
+                .eq_ignore_ascii_case(&expected_char)
             {
                 return false;
             }
-
-            index += 1;
         }
 
         true
@@ -311,6 +339,11 @@ impl Tokenizer {
                 break;
             }
 
+            // `in:bug,"In Review"`: leave the comma before a quoted item to the comma token
+            if c == ',' && next == Some('"') {
+                break;
+            }
+
             if c == '!'
                 || c == '='
                 || c == '>'
@@ -337,7 +370,7 @@ impl Tokenizer {
         let value: String = self.chars[start..position].iter().collect();
         self.position = position;
 
-        if position + 1 <= self.chars.len() {
+        if position < self.chars.len() {
             if let Some(lookahead) = self.chars.get(position) {
                 is_field = match lookahead {
                     ':' => true,
@@ -349,13 +382,23 @@ impl Tokenizer {
                     '^' => true,
                     '$' => true,
                     _ => {
-                        self.starts_with_keyword_from("in:", position)
-                            || self.starts_with_keyword_from("has:", position)
+                        // `status in:a,b` — the keyword operator may follow after whitespace
+                        let mut next = position;
+                        while self.chars.get(next).is_some_and(|c| c.is_whitespace()) {
+                            next += 1;
+                        }
+                        self.starts_with_keyword_from("in:", next)
+                            || self.starts_with_keyword_from("has:", next)
                     }
                 };
             }
         }
 
+        // Flag-style fields such as `no-assignee` stand alone without an operator
+        if !is_field && is_flag_field(&value) {
+            is_field = true;
+        }
+
         if is_field {
             Some(Token::Field(value))
         } else {
@@ -377,21 +420,58 @@ impl Tokenizer {
     }
 
     fn starts_with_keyword_from(&self, keyword: &str, position: usize) -> bool {
-        let mut index = 0;
-        for expected in keyword.chars() {
+        for (index, expected) in keyword.chars().enumerate() {
             if self.chars.get(position + index).copied() != Some(expected) {
                 return false;
             }
-            index += 1;
         }
 
         true
     }
 }
 
+/// Fields that carry an implicit operator and take no value
+pub(crate) fn is_flag_field(value: &str) -> bool {
+    matches!(
+        value.to_lowercase().as_str(),
+        "has-assignee" | "no-assignee" | "no-label" | "has-children" | "no-children"
+    )
+}
+
+/// Map a field name or alias onto its field. Unknown names become custom fields.
+pub(crate) fn field_from_name(field_str: &str) -> FilterField {
+    match field_str.to_lowercase().as_str() {
+        "title" => FilterField::Title,
+        "description" | "desc" => FilterField::Description,
+        "status" | "state" => FilterField::Status,
+        "priority" | "p" => FilterField::Priority,
+        "assignee" | "assigned" | "has-assignee" | "no-assignee" => FilterField::Assignee,
+        "label" | "labels" | "tag" | "tags" | "has-label" | "no-label" => FilterField::Label,
+        "project" => FilterField::Project,
+        "team" => FilterField::Team,
+        "created" | "createdat" | "created_at" => FilterField::CreatedAt,
+        "updated" | "updatedat" | "updated_at" => FilterField::UpdatedAt,
+        "due" | "duedate" | "due_date" => FilterField::DueDate,
+        "completed" | "completedat" | "completed_at" => FilterField::CompletedAt,
+        "started" | "startedat" | "started_at" => FilterField::StartedAt,
+        "id" | "identifier" => FilterField::Identifier,
+        "cycle" => FilterField::Cycle,
+        "estimate" | "points" => FilterField::Estimate,
+        "creator" | "author" | "created-by" => FilterField::Creator,
+        "parent" => FilterField::Parent,
+        "has-children" | "no-children" | "children" => FilterField::Children,
+        "subscriber" | "subscribers" => FilterField::Subscriber,
+        "state-type" | "statetype" | "type" => FilterField::StateType,
+        "sla" => FilterField::Sla,
+        "relation" | "relations" => FilterField::Relation,
+        _ => FilterField::Custom(field_str.to_string()),
+    }
+}
+
 /// Parser for filter queries
 pub struct FilterParser {
     tokens: Vec<Token>,
+    spans: Vec<Range<usize>>,
     position: usize,
 }
 
@@ -402,16 +482,38 @@ impl FilterParser {
 
         Ok(Self {
             tokens,
+            spans: tokenizer.spans,
             position: 0,
         })
     }
 
+    /// The span, in characters, that `error` from `parse` points at. `end` is the
+    /// length of the query, where errors about missing input point.
+    fn error_span(&self, error: &ParseError, end: usize) -> Range<usize> {
+        let at = |index: usize| self.spans.get(index).cloned().unwrap_or(end..end + 1);
+        match error {
+            ParseError::UnexpectedToken { position, .. } => at(*position),
+            // Raised once the offending token has been consumed
+            ParseError::UnknownOperator(_)
+            | ParseError::InvalidPriorityValue(_)
+            | ParseError::InvalidEstimateValue(_)
+            | ParseError::InvalidDateValue(_)
+            | ParseError::InvalidOperatorValueCombination => at(self.position.saturating_sub(1)),
+            _ => at(self.position),
+        }
+    }
+
     /// Parse the filter query and return a `FilterBuilder`
     pub fn parse(&mut self) -> Result<FilterBuilder, ParseError> {
         if self.tokens.is_empty() {
             return Err(ParseError::UnexpectedEndOfInput);
         }
 
+        let sort = self.take_sort_clauses()?;
+        if self.tokens.is_empty() {
+            return Ok(FilterBuilder::new().with_sort(sort));
+        }
+
         let expression = self.parse_or_expression()?;
 
         if self.position < self.tokens.len() {
@@ -421,7 +523,50 @@ impl FilterParser {
             });
         }
 
-        Ok(FilterBuilder::from_expression(expression))
+        Ok(FilterBuilder::from_expression(expression).with_sort(sort))
+    }
+
+    /// Remove `sort:priority,-updated` clauses, with an AND joining them to the rest
+    /// of the query, and return their keys in order.
+    fn take_sort_clauses(&mut self) -> Result<Vec<SortKey>, ParseError> {
+        let mut keys = Vec::new();
+        let mut index = 0;
+        while index < self.tokens.len() {
+            let is_sort = matches!(&self.tokens[index], Token::Field(f) if f.eq_ignore_ascii_case("sort"))
+                && self.tokens.get(index + 1) == Some(&Token::Colon);
+            if !is_sort {
+                index += 1;
+                continue;
+            }
+
+            let mut end = index + 2;
+            loop {
+                // Leave `position` on the bad token so errors can point at it
+                self.position = end;
+                match self.tokens.get(end) {
+                    Some(Token::Value(value)) => keys.extend(parse_sort(value)?),
+                    _ => return Err(ParseError::ExpectedValue),
+                }
+                end += 1;
+                if self.tokens.get(end) != Some(&Token::Comma) {
+                    break;
+                }
+                end += 1;
+            }
+            self.position = 0;
+
+            let (start, end) = if index > 0 && self.tokens[index - 1] == Token::And {
+                (index - 1, end)
+            } else if index == 0 && self.tokens.get(end) == Some(&Token::And) {
+                (index, end + 1)
+            } else {
+                (index, end)
+            };
+            self.tokens.drain(start..end);
+            self.spans.drain(start..end);
+            index = start;
+        }
+        Ok(keys)
     }
 
     fn parse_or_expression(&mut self) -> Result<FilterExpression, ParseError> {
@@ -447,8 +592,12 @@ impl FilterParser {
     }
 
     fn parse_unary_expression(&mut self) -> Result<FilterExpression, ParseError> {
-        let negated = self.consume_token(&Token::Not);
-        let mut expr = if self.consume_token(&Token::LeftParen) {
+        if self.consume_token(&Token::Not) {
+            let expr = self.parse_unary_expression()?;
+            return Ok(self.negate_expression(expr));
+        }
+
+        let expr = if self.consume_token(&Token::LeftParen) {
             let inner = self.parse_or_expression()?;
 
             if !self.consume_token(&Token::RightParen) {
@@ -460,10 +609,6 @@ impl FilterParser {
             self.parse_condition()?
         };
 
-        if negated {
-            expr = self.negate_expression(expr);
-        }
-
         Ok(expr)
     }
 
@@ -543,26 +688,14 @@ impl FilterParser {
             "no-label" => Some(FilterOperator::IsNull),
             "has-assignee" => Some(FilterOperator::IsNotNull),
             "no-assignee" => Some(FilterOperator::IsNull),
+            "has-children" => Some(FilterOperator::IsNotNull),
+            "no-children" => Some(FilterOperator::IsNull),
             _ => None,
         }
     }
 
     fn parse_field_name(&self, field_str: &str) -> Result<FilterField, ParseError> {
-        Ok(match field_str.to_lowercase().as_str() {
-            "title" => FilterField::Title,
-            "description" | "desc" => FilterField::Description,
-            "status" | "state" => FilterField::Status,
-            "priority" | "p" => FilterField::Priority,
-            "assignee" | "assigned" | "has-assignee" | "no-assignee" => FilterField::Assignee,
-            "label" | "labels" | "tag" | "tags" | "has-label" | "no-label" => FilterField::Label,
-            "project" => FilterField::Project,
-            "team" => FilterField::Team,
-            "created" | "createdat" | "created_at" => FilterField::CreatedAt,
-            "updated" | "updatedat" | "updated_at" => FilterField::UpdatedAt,
-            "due" | "duedate" | "due_date" => FilterField::DueDate,
-            "id" | "identifier" => FilterField::Identifier,
-            _ => FilterField::Custom(field_str.to_string()),
-        })
+        Ok(field_from_name(field_str))
     }
 
     fn parse_operator(&self, op_str: &str) -> Result<FilterOperator, ParseError> {
@@ -602,21 +735,25 @@ impl FilterParser {
                 if let Ok(n) = value_str.parse::<u8>() {
                     Ok(FilterValue::Number(n as f64))
                 } else {
-                    match value_str.to_lowercase().as_str() {
-                        "none" | "no" => Ok(FilterValue::Number(0.0)),
-                        "low" => Ok(FilterValue::Number(1.0)),
-                        "medium" | "med" => Ok(FilterValue::Number(2.0)),
-                        "high" => Ok(FilterValue::Number(3.0)),
-                        "urgent" => Ok(FilterValue::Number(4.0)),
-                        _ => Err(ParseError::InvalidPriorityValue(value_str)),
+                    match parse_priority(&value_str) {
+                        Some(n) => Ok(FilterValue::Number(n as f64)),
+                        None => Err(ParseError::InvalidPriorityValue(value_str)),
                     }
                 }
             }
-            FilterField::CreatedAt | FilterField::UpdatedAt | FilterField::DueDate => {
-                if let Some(date) = parse_relative_date(&value_str) {
-                    Ok(FilterValue::Date(date))
-                } else {
-                    Ok(FilterValue::Date(value_str))
+            FilterField::Estimate => value_str
+                .parse::<f64>()
+                .map(FilterValue::Number)
+                .map_err(|_| ParseError::InvalidEstimateValue(value_str)),
+            FilterField::CreatedAt
+            | FilterField::UpdatedAt
+            | FilterField::DueDate
+            | FilterField::CompletedAt
+            | FilterField::StartedAt => {
+                // Kept as written; it's resolved against the calendar when compiled
+                match DateSpec::parse(&value_str) {
+                    Some(_) => Ok(FilterValue::Date(value_str)),
+                    None => Err(ParseError::InvalidDateValue(value_str)),
                 }
             }
         _ => {
@@ -668,14 +805,14 @@ impl FilterParser {
 
         match left {
             FilterExpression::Group(group) if group.operator == operator => {
-                conditions.extend(group.conditions.into_iter());
+                conditions.extend(group.conditions);
             }
             other => conditions.push(other),
         }
 
         match right {
             FilterExpression::Group(group) if group.operator == operator => {
-                conditions.extend(group.conditions.into_iter());
+                conditions.extend(group.conditions);
             }
             other => conditions.push(other),
         }
@@ -686,37 +823,13 @@ impl FilterParser {
         }))
     }
 
+    /// Keep NOT in the tree; `FilterBuilder::to_graphql` pushes it down to the
+    /// conditions, and reports the ones that can't be negated.
     fn negate_expression(&self, expr: FilterExpression) -> FilterExpression {
-        match expr {
-            FilterExpression::Condition(condition) => {
-                let operator = self.negate_operator(condition.operator);
-                FilterExpression::Condition(FilterCondition {
-                    field: condition.field,
-                    operator,
-                    value: condition.value,
-                })
-            }
-            FilterExpression::Group(group) => {
-                FilterExpression::Group(Box::new(FilterGroup {
-                    operator: LogicalOperator::Not,
-                    conditions: vec![FilterExpression::Group(group)],
-                }))
-            }
-        }
-    }
-
-    fn negate_operator(&self, operator: FilterOperator) -> FilterOperator {
-        match operator {
-            FilterOperator::Equals => FilterOperator::NotEquals,
-            FilterOperator::NotEquals => FilterOperator::Equals,
-            FilterOperator::Contains => FilterOperator::NotContains,
-            FilterOperator::NotContains => FilterOperator::Contains,
-            FilterOperator::IsNull => FilterOperator::IsNotNull,
-            FilterOperator::IsNotNull => FilterOperator::IsNull,
-            FilterOperator::In => FilterOperator::NotIn,
-            FilterOperator::NotIn => FilterOperator::In,
-            _ => operator,
-        }
+        FilterExpression::Group(Box::new(FilterGroup {
+            operator: LogicalOperator::Not,
+            conditions: vec![expr],
+        }))
     }
 
     fn is_null_value(&self, value: &str) -> bool {
@@ -724,28 +837,6 @@ impl FilterParser {
     }
 }
 
-/// Parse a relative date string (e.g., "7d", "2w", "1m")
-fn parse_relative_date(input: &str) -> Option<String> {
-    let re = Regex::new(r"^(\d+)([hdwmHDWM])$").unwrap();
-    if let Some(captures) = re.captures(input) {
-        let amount = captures[1].parse::<i64>().ok()?;
-        let unit = captures[2].to_lowercase();
-
-        let duration = match unit.as_str() {
-            "h" => Duration::hours(amount),
-            "d" => Duration::days(amount),
-            "w" => Duration::weeks(amount),
-            "m" => Duration::days(amount * 30),
-            _ => return None,
-        };
-
-        let date = Utc::now() - duration;
-        return Some(date.to_rfc3339());
-    }
-
-    None
-}
-
 /// Parse errors
 #[derive(Debug, thiserror::Error)]
 pub enum ParseError {
@@ -773,6 +864,15 @@ pub enum ParseError {
     #[error("Invalid priority value: {0}")]
     InvalidPriorityValue(String),
 
+    #[error("Invalid estimate value: {0}")]
+    InvalidEstimateValue(String),
+
+    #[error("Invalid date: {0} (use e.g. 2026-10-01, 7d, today, this-week or 2026-10-01..2026-10-31)")]
+    InvalidDateValue(String),
+
+    #[error("Unknown sort field: {0} (use priority, created, updated, title, id, status, assignee, project or team)")]
+    InvalidSortField(String),
+
     #[error("Missing closing parenthesis")]
     MissingClosingParen,
 
@@ -786,44 +886,74 @@ pub fn parse_filter(query: &str) -> Result<FilterBuilder, ParseError> {
     parser.parse()
 }
 
+/// Like `parse_filter`, also returning the span of the query (in characters) that a
+/// failure points at.
+pub(crate) fn parse_filter_spanned(query: &str) -> Result<FilterBuilder, (ParseError, Range<usize>)> {
+    let end = query.chars().count();
+    let mut tokenizer = Tokenizer::new(query);
+    let tokens = match tokenizer.tokenize() {
+        Ok(tokens) => tokens,
+        Err(error) => {
+            let span = match &error {
+                ParseError::UnexpectedCharacter { position, .. } => *position..position + 1,
+                _ => tokenizer.token_start..end,
+            };
+            return Err((error, span));
+        }
+    };
+    let mut parser = FilterParser { tokens, spans: tokenizer.spans, position: 0 };
+    parser.parse().map_err(|error| {
+        let span = parser.error_span(&error, end);
+        (error, span)
+    })
+}
+
+/// The query's tokens with their spans, in characters.
+pub(crate) fn tokens_with_spans(query: &str) -> Result<Vec<(Token, Range<usize>)>, ParseError> {
+    let mut tokenizer = Tokenizer::new(query);
+    let tokens = tokenizer.tokenize()?;
+    Ok(tokens.into_iter().zip(tokenizer.spans).collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::filtering::dates::Calendar;
 
     #[test]
     fn test_simple_filter() {
         let builder = parse_filter("status:completed").unwrap();
-        assert!(matches!(builder.to_graphql(), Ok(_)));
+        assert!(builder.to_graphql(&Calendar::default()).is_ok());
     }
 
     #[test]
     fn test_compound_filter() {
         let builder = parse_filter("status!=completed AND priority>2").unwrap();
-        assert!(matches!(builder.to_graphql(), Ok(_)));
+        assert!(builder.to_graphql(&Calendar::default()).is_ok());
     }
 
     #[test]
     fn test_relative_dates() {
         let builder = parse_filter("created>7d AND updated<2w").unwrap();
-        assert!(matches!(builder.to_graphql(), Ok(_)));
+        assert!(builder.to_graphql(&Calendar::default()).is_ok());
     }
 
     #[test]
     fn test_quoted_values() {
         let builder = parse_filter(r#"title~"bug fix" AND assignee="john@example.com""#).unwrap();
-        assert!(matches!(builder.to_graphql(), Ok(_)));
+        assert!(builder.to_graphql(&Calendar::default()).is_ok());
     }
 
     #[test]
     fn test_list_values() {
         let builder = parse_filter("status in:backlog,unstarted,started").unwrap();
-        assert!(matches!(builder.to_graphql(), Ok(_)));
+        assert!(builder.to_graphql(&Calendar::default()).is_ok());
     }
 
     #[test]
     fn test_legacy_has_label_filter() {
         let builder = parse_filter("has-label:urgent").unwrap();
-        let graphql = builder.to_graphql().unwrap();
+        let graphql = builder.to_graphql(&Calendar::default()).unwrap();
 
         assert!(graphql.get("labels").is_some());
         let labels = graphql.get("labels").unwrap();
@@ -833,7 +963,7 @@ mod tests {
     #[test]
     fn test_legacy_no_assignee_filter() {
         let builder = parse_filter("no-assignee").unwrap();
-        let graphql = builder.to_graphql().unwrap();
+        let graphql = builder.to_graphql(&Calendar::default()).unwrap();
 
         let assignee = graphql.get("assignee").unwrap();
         assert_eq!(assignee["null"], true);
@@ -842,7 +972,7 @@ mod tests {
     #[test]
     fn test_legacy_no_label_filter() {
         let builder = parse_filter("no-label").unwrap();
-        let graphql = builder.to_graphql().unwrap();
+        let graphql = builder.to_graphql(&Calendar::default()).unwrap();
 
         let labels = graphql.get("labels").unwrap();
         assert!(labels.get("every").is_some());
@@ -851,19 +981,19 @@ mod tests {
     #[test]
     fn test_negation() {
         let builder = parse_filter("NOT status:completed").unwrap();
-        assert!(matches!(builder.to_graphql(), Ok(_)));
+        assert!(builder.to_graphql(&Calendar::default()).is_ok());
     }
 
     #[test]
     fn test_parentheses() {
         let builder = parse_filter("(priority>2 OR label:urgent) AND NOT status:completed").unwrap();
-        assert!(matches!(builder.to_graphql(), Ok(_)));
+        assert!(builder.to_graphql(&Calendar::default()).is_ok());
     }
 
     #[test]
     fn test_and_or_precedence() {
         let builder = parse_filter("status:done OR priority>2 AND assignee:john").unwrap();
-        let graphql = builder.to_graphql().unwrap();
+        let graphql = builder.to_graphql(&Calendar::default()).unwrap();
         let graphql_string = graphql.to_string();
         assert!(graphql_string.contains("\"or\""));
     }
@@ -0,0 +1,160 @@
+use super::builder::{FilterCondition, FilterExpression, FilterField, FilterOperator, FilterValue, LogicalOperator};
+use super::parser::PRIORITIES;
+use super::sort::{format_sort, SortKey};
+
+/// Print a parsed query back in canonical form: query field names, `AND`/`OR`/`NOT`
+/// in capitals, priorities by name, parentheses only around an OR inside an AND (and
+/// an AND inside an OR, for readability), and `sort:` last. Parsing the result gives
+/// the same filter.
+pub fn format_query(expr: Option<&FilterExpression>, sort: &[SortKey]) -> String {
+    let mut parts = Vec::new();
+    if let Some(expr) = expr {
+        parts.push(format_expression(expr));
+    }
+    if !sort.is_empty() {
+        parts.push(format!("sort:{}", format_sort(sort)));
+    }
+    parts.join(" ")
+}
+
+/// Print one expression in canonical form.
+pub fn format_expression(expr: &FilterExpression) -> String {
+    match expr {
+        FilterExpression::Condition(condition) => format_condition(condition),
+        FilterExpression::Group(group) => {
+            // A group of one is just its member, except for NOT
+            if group.operator != LogicalOperator::Not && group.conditions.len() == 1 {
+                return format_expression(&group.conditions[0]);
+            }
+            match group.operator {
+                LogicalOperator::And => join(&group.conditions, " AND ", LogicalOperator::Or),
+                LogicalOperator::Or => join(&group.conditions, " OR ", LogicalOperator::And),
+                LogicalOperator::Not => match group.conditions.as_slice() {
+                    [inner] => match unwrap_single(inner) {
+                        FilterExpression::Group(g) if g.operator != LogicalOperator::Not => {
+                            format!("NOT ({})", format_expression(inner))
+                        }
+                        inner => format!("NOT {}", format_expression(inner)),
+                    },
+                    conditions => format!("NOT ({})", join(conditions, " AND ", LogicalOperator::Or)),
+                },
+            }
+        }
+    }
+}
+
+/// The expression a chain of one-member AND/OR groups stands for.
+fn unwrap_single(expr: &FilterExpression) -> &FilterExpression {
+    match expr {
+        FilterExpression::Group(group) if group.operator != LogicalOperator::Not && group.conditions.len() == 1 => {
+            unwrap_single(&group.conditions[0])
+        }
+        _ => expr,
+    }
+}
+
+/// Members joined by `separator`, parenthesizing groups of the `wrap` kind.
+fn join(conditions: &[FilterExpression], separator: &str, wrap: LogicalOperator) -> String {
+    conditions
+        .iter()
+        .map(|expr| match unwrap_single(expr) {
+            FilterExpression::Group(group) if group.operator == wrap && group.conditions.len() > 1 => {
+                format!("({})", format_expression(expr))
+            }
+            _ => format_expression(expr),
+        })
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Print one condition, e.g. `status:"In Progress"` or `label in:bug,ui`.
+pub fn format_condition(condition: &FilterCondition) -> String {
+    let field = condition.field.query_name();
+    let value = || format_value(&condition.field, &condition.value);
+
+    match (&condition.field, &condition.operator) {
+        (FilterField::Assignee, FilterOperator::IsNull) => "no-assignee".to_string(),
+        (FilterField::Assignee, FilterOperator::IsNotNull) => "has-assignee".to_string(),
+        (FilterField::Label, FilterOperator::IsNull) => "no-label".to_string(),
+        (FilterField::Children, FilterOperator::IsNull) => "no-children".to_string(),
+        (FilterField::Children, FilterOperator::IsNotNull) => "has-children".to_string(),
+        (_, FilterOperator::IsNull) => format!("{}:null", field),
+        (_, FilterOperator::IsNotNull) => format!("{}!=null", field),
+        // The parser has no syntax for these, so spell them out
+        (_, FilterOperator::NotIn) => format!("NOT {} in:{}", field, value()),
+        (_, FilterOperator::HasNone) => format!("NOT {} has:{}", field, value()),
+        (_, FilterOperator::HasAll) => {
+            let all: Vec<String> = list_items(&condition.field, &condition.value)
+                .into_iter()
+                .map(|item| format!("{}:{}", field, item))
+                .collect();
+            if all.len() == 1 { all[0].clone() } else { format!("({})", all.join(" AND ")) }
+        }
+        (_, operator) => format!("{}{}{}", field, operator.symbol(), value()),
+    }
+}
+
+fn format_value(field: &FilterField, value: &FilterValue) -> String {
+    list_items(field, value).join(",")
+}
+
+/// The value as query text, one entry per list item.
+fn list_items(field: &FilterField, value: &FilterValue) -> Vec<String> {
+    match value {
+        FilterValue::String(s) | FilterValue::Date(s) => vec![quote_value(s)],
+        FilterValue::Number(n) => vec![format_number(field, *n)],
+        FilterValue::Boolean(b) => vec![b.to_string()],
+        FilterValue::RelativeDate(duration) => vec![if duration.num_hours() % 24 == 0 {
+            format!("{}d", duration.num_days())
+        } else {
+            format!("{}h", duration.num_hours())
+        }],
+        FilterValue::StringList(list) => list.iter().map(|s| quote_value(s)).collect(),
+        FilterValue::NumberList(list) => list.iter().map(|n| format_number(field, *n)).collect(),
+        FilterValue::Null => vec!["null".to_string()],
+    }
+}
+
+fn format_number(field: &FilterField, n: f64) -> String {
+    if *field == FilterField::Priority && n.fract() == 0.0 && (0.0..=4.0).contains(&n) {
+        return PRIORITIES[n as usize].to_string();
+    }
+    n.to_string()
+}
+
+/// Quote a value unless the tokenizer would read it back unchanged as a single value.
+pub(crate) fn quote_value(value: &str) -> String {
+    let plain = value.chars().all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '@' | '+' | '/'));
+    let keyword = ["and", "or", "not", "in", "null", "empty"].iter().any(|k| value.eq_ignore_ascii_case(k));
+    if plain && !keyword && !value.is_empty() {
+        value.to_string()
+    } else {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// Draw the expression as an indented tree, one node per line.
+pub fn format_tree(expr: &FilterExpression) -> String {
+    let mut lines = Vec::new();
+    tree_lines(expr, "", "", &mut lines);
+    lines.join("\n")
+}
+
+fn tree_lines(expr: &FilterExpression, first: &str, rest: &str, lines: &mut Vec<String>) {
+    match expr {
+        FilterExpression::Condition(condition) => lines.push(format!("{}{}", first, format_condition(condition))),
+        FilterExpression::Group(group) => {
+            let name = match group.operator {
+                LogicalOperator::And => "AND",
+                LogicalOperator::Or => "OR",
+                LogicalOperator::Not => "NOT",
+            };
+            lines.push(format!("{}{}", first, name));
+            for (index, child) in group.conditions.iter().enumerate() {
+                let last = index + 1 == group.conditions.len();
+                let (branch, indent) = if last { ("└─ ", "   ") } else { ("├─ ", "│  ") };
+                tree_lines(child, &format!("{}{}", rest, branch), &format!("{}{}", rest, indent), lines);
+            }
+        }
+    }
+}
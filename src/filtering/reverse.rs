@@ -0,0 +1,211 @@
+use serde_json::{Map, Value};
+
+use super::printer::quote_value;
+
+/// How tightly an expression binds, so `graphql_to_query` only adds the parentheses
+/// the parser needs: OR binds loosest, then AND, then NOT and single conditions.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+enum Precedence {
+    Or,
+    And,
+    Atom,
+}
+
+/// Turn a GraphQL `IssueFilter` back into filter query syntax, the inverse of
+/// `FilterBuilder::to_graphql`. Used to import Linear custom views as saved searches.
+/// Fails with a description of the first part of the filter that the query syntax
+/// cannot express (filters by id, cycle, estimate and so on).
+pub fn graphql_to_query(filter: &Value) -> Result<String, String> {
+    expression(filter).map(|(query, _)| query)
+}
+
+fn expression(filter: &Value) -> Result<(String, Precedence), String> {
+    let object = filter.as_object().ok_or_else(|| format!("expected an object, got {}", filter))?;
+    let mut parts = Vec::new();
+
+    for (key, value) in object {
+        match key.as_str() {
+            "and" => parts.push(join(items(value, "and")?, " AND ", Precedence::And)?),
+            "or" => parts.push(join(items(value, "or")?, " OR ", Precedence::Or)?),
+            "not" => {
+                let (inner, _) = join(items(value, "not")?, " AND ", Precedence::And)?;
+                parts.push((format!("NOT ({})", inner), Precedence::Atom));
+            }
+            field => {
+                for condition in field_conditions(field, value)? {
+                    parts.push((condition, Precedence::Atom));
+                }
+            }
+        }
+    }
+
+    match parts.len() {
+        0 => Err("empty filter".to_string()),
+        1 => Ok(parts.remove(0)),
+        _ => Ok((wrap_all(parts, Precedence::And).join(" AND "), Precedence::And)),
+    }
+}
+
+fn items<'a>(value: &'a Value, key: &str) -> Result<Vec<&'a Value>, String> {
+    match value {
+        Value::Array(items) => Ok(items.iter().collect()),
+        Value::Object(_) => Ok(vec![value]),
+        other => Err(format!("'{}' expects a list of filters, got {}", key, other)),
+    }
+}
+
+fn join(items: Vec<&Value>, separator: &str, precedence: Precedence) -> Result<(String, Precedence), String> {
+    let mut parts = items.into_iter().map(expression).collect::<Result<Vec<_>, _>>()?;
+    match parts.len() {
+        0 => Err("empty filter group".to_string()),
+        1 => Ok(parts.remove(0)),
+        _ => Ok((wrap_all(parts, precedence).join(separator), precedence)),
+    }
+}
+
+/// Parenthesize the parts that bind looser than the operator joining them.
+fn wrap_all(parts: Vec<(String, Precedence)>, precedence: Precedence) -> Vec<String> {
+    parts
+        .into_iter()
+        .map(|(query, p)| if p < precedence { format!("({})", query) } else { query })
+        .collect()
+}
+
+fn field_conditions(field: &str, value: &Value) -> Result<Vec<String>, String> {
+    let comparator = value.as_object().ok_or_else(|| format!("unsupported filter on '{}'", field))?;
+    let unsupported = || format!("unsupported filter on '{}': {}", field, value);
+
+    let conditions = match field {
+        "title" | "description" => string_conditions(field, comparator).ok_or_else(unsupported)?,
+        "priority" => comparator
+            .iter()
+            .map(|(op, v)| Some(format!("priority{}{}", comparison(op)?, v.as_f64()?)))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(unsupported)?,
+        "createdAt" | "updatedAt" | "dueDate" => {
+            let name = match field {
+                "createdAt" => "created",
+                "updatedAt" => "updated",
+                _ => "due",
+            };
+            comparator
+                .iter()
+                .map(|(op, v)| Some(format!("{}{}{}", name, comparison(op)?, quote_value(v.as_str()?))))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(unsupported)?
+        }
+        "state" => named_conditions("status", comparator).ok_or_else(unsupported)?,
+        "project" => named_conditions("project", comparator).ok_or_else(unsupported)?,
+        "assignee" => match (comparator.get("null"), comparator.get("email").and_then(|e| e.get("eq"))) {
+            (Some(Value::Bool(true)), None) => vec!["assignee:null".to_string()],
+            (Some(Value::Bool(false)), None) => vec!["assignee!=null".to_string()],
+            (None, Some(Value::String(email))) if comparator.len() == 1 => vec![format!("assignee:{}", quote_value(email))],
+            (None, None) => match comparator.get("email").and_then(|e| e.get("neq")) {
+                Some(Value::String(email)) if comparator.len() == 1 => vec![format!("assignee!={}", quote_value(email))],
+                _ => return Err(unsupported()),
+            },
+            _ => return Err(unsupported()),
+        },
+        "labels" => label_conditions(comparator).ok_or_else(unsupported)?,
+        _ => return Err(format!("filters on '{}' are not supported", field)),
+    };
+    Ok(conditions)
+}
+
+fn comparison(op: &str) -> Option<&'static str> {
+    Some(match op {
+        "eq" => ":",
+        "neq" => "!=",
+        "gt" => ">",
+        "gte" => ">=",
+        "lt" => "<",
+        "lte" => "<=",
+        _ => return None,
+    })
+}
+
+fn string_conditions(field: &str, comparator: &Map<String, Value>) -> Option<Vec<String>> {
+    comparator
+        .iter()
+        .map(|(op, v)| {
+            if op == "not" {
+                let inner = v.get("containsIgnoreCase").or_else(|| v.get("contains"))?;
+                return Some(format!("NOT {}~{}", field, quote_value(inner.as_str()?)));
+            }
+            if op == "notContains" || op == "notContainsIgnoreCase" {
+                return Some(format!("NOT {}~{}", field, quote_value(v.as_str()?)));
+            }
+            let operator = match op.as_str() {
+                "eq" => ":",
+                "neq" => "!=",
+                "contains" | "containsIgnoreCase" => "~",
+                "startsWith" | "startsWithIgnoreCase" => "^=",
+                "endsWith" | "endsWithIgnoreCase" => "$=",
+                _ => return None,
+            };
+            Some(format!("{}{}{}", field, operator, quote_value(v.as_str()?)))
+        })
+        .collect()
+}
+
+/// Conditions on `{ name: { eq | neq | in | nin | containsIgnoreCase } }` and
+/// `{ null: bool }` comparators.
+fn named_conditions(field: &str, comparator: &Map<String, Value>) -> Option<Vec<String>> {
+    comparator
+        .iter()
+        .map(|(key, v)| match (key.as_str(), v) {
+            ("null", Value::Bool(true)) => Some(format!("{}:null", field)),
+            ("null", Value::Bool(false)) => Some(format!("{}!=null", field)),
+            ("name", Value::Object(name)) if name.len() == 1 => {
+                let (op, value) = name.iter().next()?;
+                match op.as_str() {
+                    "eq" => Some(format!("{}:{}", field, quote_value(value.as_str()?))),
+                    "neq" => Some(format!("{}!={}", field, quote_value(value.as_str()?))),
+                    "in" => Some(format!("{} in:{}", field, string_list(value)?)),
+                    "nin" => Some(format!("NOT {} in:{}", field, string_list(value)?)),
+                    "containsIgnoreCase" => Some(format!("{}~{}", field, quote_value(value.as_str()?))),
+                    "notContainsIgnoreCase" => Some(format!("NOT {}~{}", field, quote_value(value.as_str()?))),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn label_conditions(comparator: &Map<String, Value>) -> Option<Vec<String>> {
+    comparator
+        .iter()
+        .map(|(key, v)| {
+            let (op, value) = v.get("name").and_then(Value::as_object).and_then(|n| n.iter().next())
+                .or_else(|| v.get("id").and_then(Value::as_object).and_then(|n| n.iter().next()))?;
+            let by_id = v.get("id").is_some();
+            match (key.as_str(), by_id, op.as_str(), value) {
+                ("some", false, "eq", Value::String(name)) => Some(format!("label:{}", quote_value(name))),
+                ("some", false, "in", list) => Some(format!("has-label:{}", string_list(list)?)),
+                ("some", false, "containsIgnoreCase", Value::String(name)) => Some(format!("label~{}", quote_value(name))),
+                ("every", false, "neq", Value::String(name)) => Some(format!("label!={}", quote_value(name))),
+                ("every", false, "nin", list) => Some(format!("NOT has-label:{}", string_list(list)?)),
+                ("every", false, "notContainsIgnoreCase", Value::String(name)) => {
+                    Some(format!("NOT label~{}", quote_value(name)))
+                }
+                ("some", true, "null", Value::Bool(false)) => Some("label!=null".to_string()),
+                ("every", true, "null", Value::Bool(true)) => Some("label:null".to_string()),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// A non-empty array of strings as a comma-separated query list.
+fn string_list(list: &Value) -> Option<String> {
+    let values = list
+        .as_array()?
+        .iter()
+        .map(|v| v.as_str().map(quote_value))
+        .collect::<Option<Vec<_>>>()?;
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.join(","))
+}
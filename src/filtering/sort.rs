@@ -0,0 +1,166 @@
+use std::cmp::Ordering;
+
+use super::parser::ParseError;
+use crate::models::Issue;
+
+/// Issue fields that can be sorted on
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortField {
+    Priority,
+    Created,
+    Updated,
+    Title,
+    Identifier,
+    Status,
+    Assignee,
+    Project,
+    Team,
+}
+
+impl SortField {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Priority => "priority",
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Title => "title",
+            Self::Identifier => "id",
+            Self::Status => "status",
+            Self::Assignee => "assignee",
+            Self::Project => "project",
+            Self::Team => "team",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name.to_lowercase().as_str() {
+            "priority" | "p" => Self::Priority,
+            "created" | "createdat" | "created_at" => Self::Created,
+            "updated" | "updatedat" | "updated_at" => Self::Updated,
+            "title" => Self::Title,
+            "id" | "identifier" => Self::Identifier,
+            "status" | "state" => Self::Status,
+            "assignee" => Self::Assignee,
+            "project" => Self::Project,
+            "team" => Self::Team,
+            _ => return None,
+        })
+    }
+}
+
+/// One sort key. Each field has a natural order: most urgent priority first, oldest
+/// date first, workflow order for status, A to Z otherwise. `descending` reverses it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SortKey {
+    pub field: SortField,
+    pub descending: bool,
+}
+
+impl std::fmt::Display for SortKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", if self.descending { "-" } else { "" }, self.field.name())
+    }
+}
+
+/// Parse a comma-separated sort spec such as `priority,-updated`.
+pub fn parse_sort(spec: &str) -> Result<Vec<SortKey>, ParseError> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let (descending, name) = match part.strip_prefix('-') {
+                Some(name) => (true, name),
+                None => (false, part.strip_prefix('+').unwrap_or(part)),
+            };
+            SortField::parse(name)
+                .map(|field| SortKey { field, descending })
+                .ok_or_else(|| ParseError::InvalidSortField(name.to_string()))
+        })
+        .collect()
+}
+
+/// Format sort keys back into a spec, e.g. `priority,-updated`.
+pub fn format_sort(keys: &[SortKey]) -> String {
+    keys.iter().map(SortKey::to_string).collect::<Vec<_>>().join(",")
+}
+
+/// The API's `orderBy` for these keys, when Linear can apply the first of them.
+/// Linear only orders by `createdAt` or `updatedAt`, newest first.
+pub fn order_by(keys: &[SortKey]) -> Option<&'static str> {
+    match keys.first() {
+        Some(SortKey { field: SortField::Created, descending: true }) => Some("createdAt"),
+        Some(SortKey { field: SortField::Updated, descending: true }) => Some("updatedAt"),
+        _ => None,
+    }
+}
+
+/// Stable sort, so issues that tie on every key keep the order Linear returned.
+pub fn sort_issues(issues: &mut [Issue], keys: &[SortKey]) {
+    if keys.is_empty() {
+        return;
+    }
+    issues.sort_by(|a, b| {
+        keys.iter()
+            .map(|key| {
+                let ordering = compare(a, b, key.field);
+                if key.descending { ordering.reverse() } else { ordering }
+            })
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    });
+}
+
+fn compare(a: &Issue, b: &Issue, field: SortField) -> Ordering {
+    match field {
+        // 4 is urgent and 0 is no priority, which sorts last
+        SortField::Priority => {
+            let rank = |issue: &Issue| match issue.priority {
+                Some(p) if p > 0 => 5 - p,
+                _ => 5,
+            };
+            rank(a).cmp(&rank(b))
+        }
+        SortField::Created => a.created_at.cmp(&b.created_at),
+        SortField::Updated => a.updated_at.cmp(&b.updated_at),
+        SortField::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+        SortField::Identifier => identifier_key(&a.identifier).cmp(&identifier_key(&b.identifier)),
+        SortField::Status => status_key(a)
+            .partial_cmp(&status_key(b))
+            .unwrap_or(Ordering::Equal),
+        SortField::Assignee => missing_last(
+            a.assignee.as_ref().map(|u| u.name.to_lowercase()),
+            b.assignee.as_ref().map(|u| u.name.to_lowercase()),
+        ),
+        SortField::Project => missing_last(
+            a.project.as_ref().map(|p| p.name.to_lowercase()),
+            b.project.as_ref().map(|p| p.name.to_lowercase()),
+        ),
+        SortField::Team => a.team.key.cmp(&b.team.key),
+    }
+}
+
+fn missing_last(a: Option<String>, b: Option<String>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// `ENG-12` sorts by team key, then numerically, so ENG-9 comes before ENG-10.
+fn identifier_key(identifier: &str) -> (String, u64) {
+    match identifier.rsplit_once('-') {
+        Some((key, number)) => (key.to_string(), number.parse().unwrap_or(0)),
+        None => (identifier.to_string(), 0),
+    }
+}
+
+/// Workflow order: state type, then the state's position, then name.
+fn status_key(issue: &Issue) -> (usize, f64, String) {
+    let rank = ["triage", "backlog", "unstarted", "started", "completed", "canceled"]
+        .iter()
+        .position(|t| *t == issue.state.state_type)
+        .unwrap_or(usize::MAX);
+    (rank, issue.state.position.unwrap_or(0.0), issue.state.name.clone())
+}
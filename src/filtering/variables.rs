@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use super::complete::Vocabulary;
+use super::dates::cycle_team;
+use super::diagnostic::{diagnose, Diagnostic, Severity};
+use super::parser::parse_filter_spanned;
+use super::printer::quote_value;
+use crate::models::{Cycle, Team, User};
+
+/// Variables filled in from the workspace: the viewer, the default team and its
+/// current cycle
+pub const BUILTINS: &[&str] = &["me", "team", "cycle"];
+
+/// Values tried for a variable without a default when checking a query, until one
+/// fits the field it's used with
+const STAND_INS: &[&str] = &["x", "1", "today"];
+
+/// A placeholder in a saved search: `$name`, `${name}` or `${name:-default}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variable {
+    pub name: String,
+    pub default: Option<String>,
+    /// Characters of the query the placeholder takes up
+    pub span: Range<usize>,
+    /// Whether the placeholder is inside a quoted value
+    pub quoted: bool,
+}
+
+impl Variable {
+    pub fn is_builtin(&self) -> bool {
+        BUILTINS.contains(&self.name.as_str())
+    }
+
+    /// The placeholder as written to show it: `$me`, `${label}`, `${label:-bug}`
+    pub fn display(&self) -> String {
+        match &self.default {
+            Some(default) => format!("${{{}:-{}}}", self.name, default),
+            None if self.is_builtin() => format!("${}", self.name),
+            None => format!("${{{}}}", self.name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum VariableError {
+    #[error("Unterminated variable at position {0}")]
+    Unterminated(usize),
+
+    #[error("Invalid variable name '{0}'")]
+    InvalidName(String),
+
+    #[error("No value for {} (set it with --set NAME=VALUE)", .0.iter().map(|name| format!("${}", name)).collect::<Vec<_>>().join(", "))]
+    Missing(Vec<String>),
+
+    #[error("No variable named '{0}'")]
+    Unknown(String),
+}
+
+/// The placeholders in `query`, in order. A `$` only starts a bare `$name` at the
+/// start of a value, so the ends-with operator in `title$fix` is left alone.
+pub fn variables(query: &str) -> Result<Vec<Variable>, VariableError> {
+    scan(query).map_err(|(error, _)| error)
+}
+
+fn scan(query: &str) -> Result<Vec<Variable>, (VariableError, Range<usize>)> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut variables = Vec::new();
+    let mut quoted = false;
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if c == '\\' && quoted {
+            index += 2;
+            continue;
+        }
+        if c == '"' {
+            quoted = !quoted;
+        }
+        if c != '$' {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        if chars.get(index + 1) == Some(&'{') {
+            let Some(close) = chars[index..].iter().position(|&c| c == '}').map(|offset| index + offset) else {
+                return Err((VariableError::Unterminated(start), start..chars.len()));
+            };
+            let inner: String = chars[index + 2..close].iter().collect();
+            let (name, default) = match inner.split_once(":-") {
+                Some((name, default)) => (name.trim(), Some(default.to_string())),
+                None => (inner.trim(), None),
+            };
+            if !valid_name(name) {
+                return Err((VariableError::InvalidName(name.to_string()), start..close + 1));
+            }
+            variables.push(Variable { name: name.to_string(), default, span: start..close + 1, quoted });
+            index = close + 1;
+            continue;
+        }
+
+        let starts_value = index == 0 || matches!(chars[index - 1], ':' | '(' | ',' | '=' | '<' | '>' | '~' | '!' | '"')
+            || chars[index - 1].is_whitespace();
+        let starts_name = chars.get(index + 1).is_some_and(|c| c.is_ascii_alphabetic() || *c == '_');
+        if !(starts_value && starts_name) {
+            index += 1;
+            continue;
+        }
+        let mut end = index + 1;
+        while chars.get(end).is_some_and(|c| c.is_ascii_alphanumeric() || *c == '_') {
+            end += 1;
+        }
+        let name: String = chars[index + 1..end].iter().collect();
+        variables.push(Variable { name, default: None, span: start..end, quoted });
+        index = end;
+    }
+    Ok(variables)
+}
+
+fn valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Fill in the placeholders in `query` from `values`, falling back to each one's
+/// default. Values are quoted where the query syntax needs it; a comma-separated
+/// value stays a list.
+pub fn bind(query: &str, values: &HashMap<String, String>) -> Result<String, VariableError> {
+    let variables = variables(query)?;
+    let mut missing: Vec<String> = Vec::new();
+    let mut replacements = Vec::new();
+    for variable in &variables {
+        match values.get(&variable.name).or(variable.default.as_ref()) {
+            Some(value) => replacements.push(substitution(value, variable.quoted)),
+            None => {
+                if !missing.contains(&variable.name) {
+                    missing.push(variable.name.clone());
+                }
+            }
+        }
+    }
+    if !missing.is_empty() {
+        return Err(VariableError::Missing(missing));
+    }
+    Ok(substitute(query, &variables, &replacements).0)
+}
+
+/// Check that every name in `values` is used in `query`, so a typo in `--set`
+/// isn't silently ignored.
+pub fn check_values(query: &str, values: &HashMap<String, String>) -> Result<(), VariableError> {
+    let variables = variables(query)?;
+    let mut names: Vec<&String> = values.keys().collect();
+    names.sort();
+    match names.into_iter().find(|name| !variables.iter().any(|variable| &variable.name == *name)) {
+        Some(name) => Err(VariableError::Unknown(name.clone())),
+        None => Ok(()),
+    }
+}
+
+/// The built-in variables that can be filled from what's known: `$me` is the
+/// viewer's email, `$team` the team's key and `$cycle` the number of its current
+/// cycle. Without a team, `$cycle` is that of the only team with a current cycle,
+/// as for `cycle:current`, and it's an error when several teams have one.
+pub fn builtin_values(viewer: Option<&User>, team: Option<&Team>, cycles: &[Cycle]) -> Result<HashMap<String, String>, String> {
+    let mut values = HashMap::new();
+    if let Some(viewer) = viewer {
+        values.insert("me".to_string(), viewer.email.clone());
+    }
+    if let Some(team) = team {
+        values.insert("team".to_string(), team.key.clone());
+    }
+    // The same team `cycle:current` would use
+    let cycle_team = cycle_team(cycles, team.map(|team| team.id.as_str()))?;
+    let cycle = cycles.iter().find(|cycle| cycle.is_active && Some(cycle.team.id.as_str()) == cycle_team);
+    if let Some(cycle) = cycle {
+        values.insert("cycle".to_string(), cycle.number.to_string());
+    }
+    Ok(values)
+}
+
+/// Check a saved search before it's saved: its placeholders, then the query with
+/// them filled in by their defaults or by stand-ins. Diagnostics point into `query`
+/// as written; warnings about stand-in names are left out.
+pub fn check_search(query: &str, vocabulary: &Vocabulary) -> Vec<Diagnostic> {
+    let variables = match scan(query) {
+        Ok(variables) => variables,
+        Err((error, span)) => {
+            let help = match error {
+                VariableError::Unterminated(_) => Some("close the variable with }".to_string()),
+                _ => Some("variable names are letters, digits, _ and -".to_string()),
+            };
+            return vec![Diagnostic { severity: Severity::Error, message: error.to_string(), span, help }];
+        }
+    };
+    if variables.is_empty() {
+        return diagnose(query, vocabulary);
+    }
+
+    let mut values: Vec<String> = variables
+        .iter()
+        .map(|variable| match (&variable.default, variable.name.as_str()) {
+            (Some(default), _) => substitution(default, variable.quoted),
+            (None, "me") => "me".to_string(),
+            (None, _) => STAND_INS[0].to_string(),
+        })
+        .collect();
+    // Pick the first stand-in the field accepts, e.g. a number for `priority`
+    for (index, variable) in variables.iter().enumerate() {
+        if variable.default.is_some() || variable.name == "me" {
+            continue;
+        }
+        for stand_in in STAND_INS {
+            values[index] = stand_in.to_string();
+            let (substituted, ranges) = substitute(query, &variables, &values);
+            match parse_filter_spanned(&substituted) {
+                Err((_, span)) if overlaps(&span, &ranges[index]) => continue,
+                _ => break,
+            }
+        }
+    }
+
+    let (substituted, ranges) = substitute(query, &variables, &values);
+    diagnose(&substituted, vocabulary)
+        .into_iter()
+        .filter_map(|mut diagnostic| {
+            let inside = ranges.iter().position(|range| range.start <= diagnostic.span.start && diagnostic.span.end <= range.end);
+            match inside {
+                Some(index) if variables[index].default.is_none() && diagnostic.severity == Severity::Warning => None,
+                Some(index) => {
+                    diagnostic.span = variables[index].span.clone();
+                    Some(diagnostic)
+                }
+                None => {
+                    let shift = |position: usize| {
+                        let mut mapped = position as isize;
+                        for (variable, range) in variables.iter().zip(&ranges).filter(|(_, range)| range.end <= position) {
+                            mapped += variable.span.len() as isize - range.len() as isize;
+                        }
+                        mapped.max(0) as usize
+                    };
+                    diagnostic.span = shift(diagnostic.span.start)..shift(diagnostic.span.end);
+                    Some(diagnostic)
+                }
+            }
+        })
+        .collect()
+}
+
+fn overlaps(a: &Range<usize>, b: &Range<usize>) -> bool {
+    a.start < b.end.max(b.start + 1) && b.start < a.end.max(a.start + 1)
+}
+
+/// A value as it's written in place of a placeholder.
+fn substitution(value: &str, quoted: bool) -> String {
+    if quoted {
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    } else {
+        value.split(',').map(|item| quote_value(item.trim())).collect::<Vec<_>>().join(",")
+    }
+}
+
+/// `query` with each variable's span replaced by the matching value, and the
+/// character ranges the values ended up at.
+fn substitute(query: &str, variables: &[Variable], values: &[String]) -> (String, Vec<Range<usize>>) {
+    let mut result = String::new();
+    let mut ranges = Vec::new();
+    let mut length = 0;
+    let mut variables = variables.iter().zip(values).peekable();
+    for (index, c) in query.chars().enumerate() {
+        if let Some((variable, value)) = variables.peek() {
+            if index == variable.span.start {
+                let start = length;
+                result.push_str(value);
+                length += value.chars().count();
+                ranges.push(start..length);
+            }
+            if variable.span.contains(&index) {
+                if index + 1 == variable.span.end {
+                    variables.next();
+                }
+                continue;
+            }
+        }
+        result.push(c);
+        length += 1;
+    }
+    (result, ranges)
+}
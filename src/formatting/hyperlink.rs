@@ -0,0 +1,90 @@
+use lazy_static::lazy_static;
+use std::env;
+use std::io::IsTerminal;
+
+use crate::config::load_config;
+
+/// Whether identifiers, names and markdown links are written as OSC 8 hyperlinks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HyperlinkMode {
+    /// When writing to a terminal known to support them
+    Auto,
+    Always,
+    Never,
+}
+
+impl HyperlinkMode {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "auto" => Some(Self::Auto),
+            "always" | "on" | "true" | "1" => Some(Self::Always),
+            "never" | "off" | "false" | "0" => Some(Self::Never),
+            _ => None,
+        }
+    }
+
+    /// The configured mode: `LINEAR_HYPERLINKS`, then `hyperlinks` in the config file
+    pub fn from_config() -> Self {
+        env::var("LINEAR_HYPERLINKS")
+            .ok()
+            .or_else(|| load_config().hyperlinks)
+            .and_then(|name| Self::from_name(&name))
+            .unwrap_or(Self::Auto)
+    }
+}
+
+/// Whether the terminal described by the environment `var` reads understands OSC 8.
+/// Terminals that don't would print the escape codes, so unknown ones get plain text.
+pub fn terminal_supports_hyperlinks(var: impl Fn(&str) -> Option<String>) -> bool {
+    let term = var("TERM").unwrap_or_default();
+    if term == "dumb" {
+        return false;
+    }
+    if let Some(program) = var("TERM_PROGRAM") {
+        match program.as_str() {
+            "iTerm.app" | "WezTerm" | "vscode" | "Hyper" | "ghostty" | "Tabby" | "rio" => return true,
+            "Apple_Terminal" => return false,
+            _ => {}
+        }
+    }
+    // GNOME Terminal, Tilix and other VTE terminals since 0.50
+    if var("VTE_VERSION").and_then(|v| v.parse::<u32>().ok()).is_some_and(|v| v >= 5000) {
+        return true;
+    }
+    ["WT_SESSION", "KONSOLE_VERSION", "KITTY_WINDOW_ID", "DOMTERM"].iter().any(|name| var(name).is_some())
+        || ["kitty", "alacritty", "foot", "wezterm", "ghostty"].iter().any(|name| term.contains(name))
+}
+
+lazy_static! {
+    static ref ENABLED: bool = match HyperlinkMode::from_config() {
+        HyperlinkMode::Always => true,
+        HyperlinkMode::Never => false,
+        HyperlinkMode::Auto => std::io::stdout().is_terminal() && terminal_supports_hyperlinks(|name| env::var(name).ok()),
+    };
+}
+
+/// Whether output gets hyperlinks; never when it goes to a pipe or file, unless
+/// the mode is `always`
+pub fn hyperlinks_enabled() -> bool {
+    *ENABLED
+}
+
+/// `text` as an OSC 8 hyperlink to `url`
+pub fn osc8(text: &str, url: &str) -> String {
+    format!("\u{1b}]8;;{}\u{1b}\\{}\u{1b}]8;;\u{1b}\\", url, text)
+}
+
+/// `text` linking to `url` when hyperlinks are on, else just `text`
+pub fn hyperlink(text: &str, url: &str) -> String {
+    if hyperlinks_enabled() && !url.is_empty() {
+        osc8(text, url)
+    } else {
+        text.to_string()
+    }
+}
+
+/// A padded table cell linking to `url`, with the padding left outside the link
+pub fn hyperlink_cell(cell: &str, url: &str) -> String {
+    let text = cell.trim_end();
+    format!("{}{}", hyperlink(text, url), &cell[text.len()..])
+}
@@ -1,7 +1,10 @@
 use colored::*;
-use crate::models::{Issue, Team, Project, WorkflowState};
+use crate::models::{Issue, IssueLabel, Team, Project, WorkflowState};
 use super::utils::*;
 use super::markdown::*;
+use super::output::print_records;
+use super::hyperlink::hyperlink;
+use super::table::{pad_to_width, print_issue_table, truncate_to_width, ColumnSpec};
 
 pub fn format_state_color(state: &WorkflowState) -> ColoredString {
     match state.state_type.as_str() {
@@ -58,7 +61,7 @@ fn print_issue_line(issue: &Issue) {
     println!(
         "{} {} - {}{} ({}){}{}",
         format_priority_indicator(issue.priority),
-        issue.identifier.blue(),
+        hyperlink(&issue.identifier, &issue.url).blue(),
         issue.title,
         labels,
         if assignee == "Unassigned" {
@@ -75,53 +78,18 @@ fn print_issue_line(issue: &Issue) {
     );
 }
 
-pub fn print_issues(issues: &[Issue], format: &str, group_by: &str) {
+/// Print `issues` in `format`; `columns` are the columns of the `table` format.
+pub fn print_issues(issues: &[Issue], format: &str, group_by: &str, columns: &[ColumnSpec]) {
+    if print_records(issues, format) {
+        return;
+    }
     if issues.is_empty() {
         println!("{}", "No issues found.".dimmed());
         return;
     }
 
     match format {
-        "json" => {
-            let json = serde_json::to_string_pretty(&issues).unwrap();
-            println!("{}", json);
-        }
-        "table" => {
-            // Print header
-            println!("{}", "─".repeat(120).dimmed());
-            println!(
-                "{:<20} {:<40} {:<12} {:<8} {:<20}",
-                "ID".bold(),
-                "Title".bold(),
-                "State".bold(),
-                "Team".bold(),
-                "Assignee".bold()
-            );
-            println!("{}", "─".repeat(120).dimmed());
-
-            // Print rows
-            for issue in issues {
-                let assignee = issue
-                    .assignee
-                    .as_ref()
-                    .map(|a| extract_first_name(&a.name))
-                    .unwrap_or("Unassigned");
-
-                println!(
-                    "{:<20} {:<40} {:<12} {:<8} {:<20}",
-                    issue.identifier.blue(),
-                    truncate(&issue.title, 40),
-                    format_state_color(&issue.state),
-                    issue.team.key.cyan(),
-                    if assignee == "Unassigned" {
-                        assignee.dimmed()
-                    } else {
-                        assignee.green()
-                    }
-                );
-            }
-            println!("{}", "─".repeat(120).dimmed());
-        }
+        "table" => print_issue_table(issues, columns),
         _ => {
             // Group issues based on group_by parameter
             let mut grouped: std::collections::HashMap<String, Vec<&Issue>> = std::collections::HashMap::new();
@@ -156,8 +124,12 @@ pub fn print_issues(issues: &[Issue], format: &str, group_by: &str) {
                     for project_name in project_names {
                         if let Some(group_issues) = grouped.get(&project_name) {
                             // Print project header
+                            let project_url = group_issues
+                                .iter()
+                                .find_map(|issue| issue.project.as_ref().and_then(|p| p.url.as_deref()))
+                                .unwrap_or_default();
                             println!("\n📁 {} ({})", 
-                                project_name.bold().cyan(),
+                                hyperlink(&project_name, project_url).bold().cyan(),
                                 group_issues.len()
                             );
                             println!("{}", "─".repeat(50).dimmed());
@@ -214,7 +186,7 @@ pub fn print_issues(issues: &[Issue], format: &str, group_by: &str) {
 
 pub fn print_single_issue(issue: &Issue) {
     println!("\n{}", "═".repeat(80).blue());
-    println!("{} {}", issue.identifier.blue().bold(), issue.title.bold());
+    println!("{} {}", hyperlink(&issue.identifier, &issue.url).blue().bold(), issue.title.bold());
     println!("{}", "─".repeat(80).dimmed());
     
     // Metadata row
@@ -241,17 +213,16 @@ pub fn print_single_issue(issue: &Issue) {
     if !issue.labels.nodes.is_empty() {
         let labels: Vec<String> = issue.labels.nodes
             .iter()
-            .map(|l| format!("{}", l.name.on_truecolor(
-                u8::from_str_radix(&l.color[1..3], 16).unwrap_or(128),
-                u8::from_str_radix(&l.color[3..5], 16).unwrap_or(128),
-                u8::from_str_radix(&l.color[5..7], 16).unwrap_or(128)
-            ).black()))
+            .map(|l| {
+                let (r, g, b) = parse_hex_color(&l.color).unwrap_or((128, 128, 128));
+                format!("{}", l.name.on_truecolor(r, g, b).black())
+            })
             .collect();
         println!("{}: {}", "Labels".dimmed(), labels.join(" "));
     }
     
     // URL
-    println!("{}: {}", "URL".dimmed(), issue.url.blue().underline());
+    println!("{}: {}", "URL".dimmed(), hyperlink(&issue.url, &issue.url).blue().underline());
     
     // Description
     if let Some(desc) = &issue.description {
@@ -272,6 +243,82 @@ pub fn print_teams(teams: &[Team]) {
     }
 }
 
+pub fn print_labels(labels: &[IssueLabel]) {
+    if labels.is_empty() {
+        println!("{}", "No labels found.".dimmed());
+        return;
+    }
+
+    // Top-level labels first, each group followed by its children. Children whose
+    // group is not in the list are shown at the top level with their full name.
+    let known = |id: &str| labels.iter().any(|l| l.id == id);
+    let mut roots: Vec<&IssueLabel> = labels
+        .iter()
+        .filter(|l| l.parent.as_ref().is_none_or(|p| !known(&p.id)))
+        .collect();
+    roots.sort_by_key(|l| l.full_name().to_lowercase());
+
+    println!("{}", "─".repeat(80).dimmed());
+    println!(
+        "  {:<32} {:<9} {:<8} {}",
+        "Name".bold(),
+        "Color".bold(),
+        "Team".bold(),
+        "ID".bold()
+    );
+    println!("{}", "─".repeat(80).dimmed());
+
+    for root in roots {
+        print_label_row(root, &root.full_name(), 0);
+
+        let mut children: Vec<&IssueLabel> = labels
+            .iter()
+            .filter(|l| l.parent.as_ref().is_some_and(|p| p.id == root.id))
+            .collect();
+        children.sort_by_key(|l| l.name.to_lowercase());
+        for child in children {
+            print_label_row(child, &child.name, 1);
+        }
+    }
+    println!("{}", "─".repeat(80).dimmed());
+}
+
+fn print_label_row(label: &IssueLabel, name: &str, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let (marker, name) = if label.is_group {
+        ("▾".normal(), format!("{}{} (group)", indent, name))
+    } else {
+        (color_swatch(&label.color), format!("{}{}", indent, name))
+    };
+    let team = label.team.as_ref().map(|t| t.key.as_str()).unwrap_or("-");
+
+    println!(
+        "{} {} {:<9} {:<8} {}",
+        marker,
+        pad_to_width(&truncate_to_width(&name, 32), 32),
+        label.color.dimmed(),
+        team.cyan(),
+        label.id.dimmed()
+    );
+}
+
+pub fn print_workflow_states(states: &[&WorkflowState]) {
+    for state in states {
+        let icon = get_state_icon(&state.state_type);
+        let icon = match state.color.as_deref().and_then(parse_hex_color) {
+            Some((r, g, b)) => icon.truecolor(r, g, b),
+            None => icon.normal(),
+        };
+        println!(
+            "  {} {:<24} {:<10} {}",
+            icon,
+            state.name,
+            state.state_type.dimmed(),
+            state.id.dimmed()
+        );
+    }
+}
+
 pub fn print_projects(projects: &[Project]) {
     if projects.is_empty() {
         println!("{}", "No projects found.".dimmed());
@@ -282,7 +329,7 @@ pub fn print_projects(projects: &[Project]) {
     println!("{}", "═".repeat(80).blue());
 
     for project in projects {
-        println!("\n{} {}", "▸".cyan(), project.name.bold());
+        println!("\n{} {}", "▸".cyan(), hyperlink(&project.name, &project.url).bold());
         
         if let Some(desc) = &project.description {
             if !desc.trim().is_empty() {
@@ -306,7 +353,7 @@ pub fn print_projects(projects: &[Project]) {
             "Created".dimmed(),
             format_relative_time(&project.created_at).dimmed(),
             "URL".dimmed(),
-            project.url.blue().underline()
+            hyperlink(&project.url, &project.url).blue().underline()
         );
     }
     
@@ -0,0 +1,43 @@
+use colored::*;
+
+use super::layout::{StyledLine, StyledText, Tone};
+use crate::formatting::hyperlink::osc8;
+use crate::formatting::theme::{theme_color, SemanticColor};
+
+/// Rendered markdown as text with ANSI colors, one line per line.
+pub fn to_ansi(lines: &[StyledLine]) -> String {
+    lines
+        .iter()
+        .map(|line| line.iter().map(paint).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn paint(text: &StyledText) -> String {
+    let style = text.style;
+    let mut colored = match style.tone.color() {
+        Some(semantic) => text.text.color(theme_color(semantic)),
+        None => text.text.normal(),
+    };
+    match style.tone {
+        Tone::Code => colored = colored.on_color(theme_color(SemanticColor::Surface)),
+        Tone::Comment => colored = colored.italic(),
+        _ => {}
+    }
+    if style.bold {
+        colored = colored.bold();
+    }
+    if style.italic {
+        colored = colored.italic();
+    }
+    if style.strike {
+        colored = colored.strikethrough();
+    }
+    if style.underline {
+        colored = colored.underline();
+    }
+    match &text.link {
+        Some(url) => osc8(&colored.to_string(), url),
+        None => colored.to_string(),
+    }
+}
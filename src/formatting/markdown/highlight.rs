@@ -0,0 +1,171 @@
+use super::layout::{StyledLine, StyledText, TextStyle, Tone};
+
+/// What the highlighter needs to know about a language
+struct Syntax {
+    keywords: &'static [&'static str],
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    /// Whether keywords match in any case, as in SQL
+    any_case: bool,
+    /// Whether `'` also starts lifetimes, so only a short `'x'` is a string, as in Rust
+    lifetimes: bool,
+}
+
+const C_LIKE: &[&str] = &[
+    "abstract", "break", "case", "catch", "char", "class", "const", "continue", "default", "do", "double", "else",
+    "enum", "extends", "false", "final", "float", "for", "fun", "func", "if", "implements", "import", "int",
+    "interface", "let", "long", "namespace", "new", "null", "nil", "override", "package", "private", "protected",
+    "public", "return", "self", "static", "struct", "super", "switch", "this", "throw", "throws", "true", "try",
+    "typedef", "using", "val", "var", "void", "while",
+];
+
+const RUST: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn",
+    "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self",
+    "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while", "Some", "None", "Ok",
+    "Err",
+];
+
+const JAVASCRIPT: &[&str] = &[
+    "async", "await", "break", "case", "catch", "class", "const", "continue", "default", "delete", "do", "else",
+    "export", "extends", "false", "finally", "for", "from", "function", "if", "import", "in", "instanceof",
+    "interface", "let", "new", "null", "of", "return", "static", "super", "switch", "this", "throw", "true", "try",
+    "type", "typeof", "undefined", "var", "void", "while", "yield",
+];
+
+const PYTHON: &[&str] = &[
+    "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif", "else", "except",
+    "False", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda", "None", "nonlocal", "not",
+    "or", "pass", "raise", "return", "self", "True", "try", "while", "with", "yield",
+];
+
+const GO: &[&str] = &[
+    "break", "case", "chan", "const", "continue", "default", "defer", "else", "false", "for", "func", "go", "goto",
+    "if", "import", "interface", "map", "nil", "package", "range", "return", "select", "struct", "switch", "true",
+    "type", "var",
+];
+
+const SHELL: &[&str] = &[
+    "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function", "if", "in", "local", "return",
+    "then", "until", "while",
+];
+
+const SQL: &[&str] = &[
+    "and", "as", "asc", "by", "create", "delete", "desc", "distinct", "drop", "false", "from", "group", "having",
+    "in", "index", "insert", "into", "is", "join", "left", "limit", "not", "null", "on", "or", "order", "right",
+    "select", "set", "table", "true", "union", "update", "values", "where", "with",
+];
+
+const GRAPHQL: &[&str] = &["enum", "false", "fragment", "input", "mutation", "null", "on", "query", "subscription", "true", "type"];
+
+const LITERALS: &[&str] = &["false", "null", "true"];
+
+fn syntax(language: &str) -> Option<Syntax> {
+    let plain = |keywords, line_comments, block_comment| Syntax {
+        keywords,
+        line_comments,
+        block_comment,
+        quotes: &['"', '\''],
+        any_case: false,
+        lifetimes: false,
+    };
+    Some(match language.to_lowercase().as_str() {
+        "rust" | "rs" => Syntax { lifetimes: true, ..plain(RUST, &["//"], Some(("/*", "*/"))) },
+        "javascript" | "js" | "jsx" | "mjs" | "typescript" | "ts" | "tsx" => Syntax {
+            quotes: &['"', '\'', '`'],
+            ..plain(JAVASCRIPT, &["//"], Some(("/*", "*/")))
+        },
+        "python" | "py" => plain(PYTHON, &["#"], None),
+        "go" | "golang" => Syntax { quotes: &['"', '\'', '`'], ..plain(GO, &["//"], Some(("/*", "*/"))) },
+        "c" | "h" | "cpp" | "c++" | "java" | "kotlin" | "kt" | "swift" | "cs" | "csharp" | "scala" => {
+            plain(C_LIKE, &["//"], Some(("/*", "*/")))
+        }
+        "sh" | "bash" | "zsh" | "shell" | "console" => plain(SHELL, &["#"], None),
+        "sql" => Syntax { any_case: true, ..plain(SQL, &["--"], Some(("/*", "*/"))) },
+        "graphql" | "gql" => plain(GRAPHQL, &["#"], None),
+        "json" | "jsonc" => plain(LITERALS, &["//"], None),
+        "yaml" | "yml" | "toml" => plain(LITERALS, &["#"], None),
+        _ => return None,
+    })
+}
+
+/// `code` in a fenced block's `language`, split into lines with its keywords,
+/// strings, numbers and comments marked. Unknown languages come back unmarked.
+pub fn highlight(code: &str, language: &str) -> Vec<StyledLine> {
+    let piece = |text: String, tone| StyledText { text, style: TextStyle { tone, ..TextStyle::default() }, link: None };
+    let Some(syntax) = syntax(language) else {
+        return code.lines().map(|line| vec![piece(line.to_string(), Tone::CodeBlock)]).collect();
+    };
+
+    let mut in_comment = false;
+    code.lines()
+        .map(|line| {
+            let mut pieces: StyledLine = Vec::new();
+            let mut push = |text: &str, tone| match pieces.last_mut() {
+                Some(last) if last.style.tone == tone => last.text.push_str(text),
+                _ => pieces.push(piece(text.to_string(), tone)),
+            };
+            let mut rest = line;
+            while !rest.is_empty() {
+                if in_comment {
+                    let (_, close) = syntax.block_comment.unwrap_or(("", ""));
+                    let end = rest.find(close).map_or(rest.len(), |end| {
+                        in_comment = false;
+                        end + close.len()
+                    });
+                    push(&rest[..end], Tone::Comment);
+                    rest = &rest[end..];
+                } else if syntax.line_comments.iter().any(|marker| rest.starts_with(marker)) {
+                    push(rest, Tone::Comment);
+                    rest = "";
+                } else if syntax.block_comment.is_some_and(|(open, _)| rest.starts_with(open)) {
+                    let (open, _) = syntax.block_comment.unwrap_or(("", ""));
+                    push(open, Tone::Comment);
+                    rest = &rest[open.len()..];
+                    in_comment = true;
+                } else if let Some(quote) = rest.chars().next().filter(|c| syntax.quotes.contains(c)).filter(|quote| {
+                    !(syntax.lifetimes && *quote == '\'' && string_end(rest, '\'') > 4)
+                }) {
+                    let end = string_end(rest, quote);
+                    push(&rest[..end], Tone::Str);
+                    rest = &rest[end..];
+                } else if rest.starts_with(|c: char| c.is_ascii_digit()) {
+                    let end = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '_')).unwrap_or(rest.len());
+                    push(&rest[..end], Tone::Number);
+                    rest = &rest[end..];
+                } else if rest.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+                    let end = rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+                    let word = &rest[..end];
+                    let keyword = syntax
+                        .keywords
+                        .iter()
+                        .any(|k| if syntax.any_case { k.eq_ignore_ascii_case(word) } else { *k == word });
+                    push(word, if keyword { Tone::Keyword } else { Tone::CodeBlock });
+                    rest = &rest[end..];
+                } else {
+                    let end = rest.chars().next().map_or(0, char::len_utf8);
+                    push(&rest[..end], Tone::CodeBlock);
+                    rest = &rest[end..];
+                }
+            }
+            pieces
+        })
+        .collect()
+}
+
+/// Where the string starting with `quote` at the start of `text` ends: after its
+/// closing quote, or at the end of the line.
+fn string_end(text: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (i, c) in text.char_indices().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == quote {
+            return i + c.len_utf8();
+        }
+    }
+    text.len()
+}
@@ -0,0 +1,557 @@
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, LinkType, Options, Parser, Tag};
+
+use super::highlight::highlight;
+use crate::formatting::table::{display_width, fit_widths, WidthRule};
+use crate::formatting::theme::SemanticColor;
+
+/// What a piece of rendered markdown is, which the theme gives a color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tone {
+    #[default]
+    Plain,
+    Heading(u8),
+    Link,
+    /// A link's URL, shown after its text
+    Url,
+    /// An inline code span
+    Code,
+    /// Code in a code block that isn't highlighted
+    CodeBlock,
+    Keyword,
+    Str,
+    Number,
+    Comment,
+    /// A list bullet or number
+    Marker,
+    Quote,
+    /// Borders, rules and the text of checked tasks
+    Muted,
+    Checked,
+}
+
+impl Tone {
+    /// The theme color this is drawn in; plain text keeps the terminal's color.
+    /// Inline code also gets the `surface` background.
+    pub fn color(self) -> Option<SemanticColor> {
+        Some(match self {
+            Tone::Plain => return None,
+            Tone::Heading(1) => SemanticColor::Header,
+            Tone::Heading(2) => SemanticColor::Accent,
+            Tone::Heading(_) => SemanticColor::Primary,
+            Tone::Link => SemanticColor::Link,
+            Tone::Url | Tone::Muted | Tone::Comment => SemanticColor::Muted,
+            Tone::Quote | Tone::CodeBlock => SemanticColor::Secondary,
+            Tone::Code => SemanticColor::Primary,
+            Tone::Keyword => SemanticColor::Highlight,
+            Tone::Str | Tone::Checked => SemanticColor::Success,
+            Tone::Number => SemanticColor::Warning,
+            Tone::Marker => SemanticColor::Key,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextStyle {
+    pub tone: Tone,
+    pub bold: bool,
+    pub italic: bool,
+    pub strike: bool,
+    pub underline: bool,
+}
+
+impl TextStyle {
+    fn tone(tone: Tone) -> Self {
+        Self { tone, ..Self::default() }
+    }
+}
+
+/// A run of text in one style
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyledText {
+    pub text: String,
+    pub style: TextStyle,
+    /// The URL the text links to, when links are written as terminal hyperlinks
+    pub link: Option<String>,
+}
+
+pub type StyledLine = Vec<StyledText>;
+
+const BULLETS: &[&str] = &["•", "◦", "▪"];
+
+/// Widest a horizontal rule gets
+const RULE_WIDTH: usize = 40;
+
+/// CommonMark plus GitHub's tables, strikethrough and task lists
+const OPTIONS: Options = Options::ENABLE_TABLES.union(Options::ENABLE_STRIKETHROUGH).union(Options::ENABLE_TASKLISTS);
+
+/// Lay out `markdown` as lines at most `width` cells wide, wrapping text at spaces.
+/// With `hyperlinks`, link text carries its URL instead of being followed by it.
+pub fn render(markdown: &str, width: usize, hyperlinks: bool) -> Vec<StyledLine> {
+    let mut layout = Layout { events: Parser::new_ext(markdown, OPTIONS).collect(), next: 0, hyperlinks };
+    layout.blocks(width.max(10), 0, false)
+}
+
+/// Walks the parser's events, laying out each block as its events are read.
+/// Every container reads its own start and end events.
+struct Layout<'a> {
+    events: Vec<Event<'a>>,
+    next: usize,
+    hyperlinks: bool,
+}
+
+impl<'a> Layout<'a> {
+    fn peek(&self) -> Option<&Event<'a>> {
+        self.events.get(self.next)
+    }
+
+    fn take(&mut self) -> Option<Event<'a>> {
+        let event = self.events.get(self.next).cloned();
+        self.next += 1;
+        event
+    }
+
+    /// Blocks up to the end of their container, with a blank line between them
+    /// unless `tight`.
+    fn blocks(&mut self, width: usize, depth: usize, tight: bool) -> Vec<StyledLine> {
+        let mut lines = Vec::new();
+        let mut first = true;
+        while !matches!(self.peek(), None | Some(Event::End(_))) {
+            let block = self.block(width, depth);
+            if !first && !tight {
+                lines.push(Vec::new());
+            }
+            first = false;
+            lines.extend(block);
+        }
+        lines
+    }
+
+    fn block(&mut self, width: usize, depth: usize) -> Vec<StyledLine> {
+        if !matches!(self.peek(), Some(Event::Start(tag)) if !is_inline(tag)) && !matches!(self.peek(), Some(Event::Rule)) {
+            // Text straight inside a tight list item
+            return wrap(&self.inlines(TextStyle::default()), width);
+        }
+        let lines = match self.take() {
+            Some(Event::Rule) => return vec![vec![piece(&"─".repeat(width.min(RULE_WIDTH)), TextStyle::tone(Tone::Muted))]],
+            Some(Event::Start(Tag::Paragraph)) => wrap(&self.inlines(TextStyle::default()), width),
+            Some(Event::Start(Tag::Heading { level, .. })) => self.heading(level as u8, width),
+            Some(Event::Start(Tag::CodeBlock(kind))) => {
+                let info = match kind {
+                    CodeBlockKind::Fenced(info) => info.split_whitespace().next().unwrap_or_default().to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                let text = self.text();
+                render_code(&info, text.strip_suffix('\n').unwrap_or(&text), width)
+            }
+            Some(Event::Start(Tag::HtmlBlock)) => wrap(&[piece(self.text().trim_end(), TextStyle::default())], width),
+            Some(Event::Start(Tag::BlockQuote(_))) => {
+                let bar = piece("│ ", TextStyle::tone(Tone::Quote));
+                self.blocks(width.saturating_sub(2).max(1), depth, false)
+                    .into_iter()
+                    .map(|line| {
+                        let text = line.into_iter().map(|mut text| {
+                            if text.style.tone == Tone::Plain {
+                                text.style.tone = Tone::Quote;
+                            }
+                            text
+                        });
+                        std::iter::once(bar.clone()).chain(text).collect()
+                    })
+                    .collect()
+            }
+            Some(Event::Start(Tag::List(start))) => self.list(start, width, depth),
+            Some(Event::Start(Tag::Table(align))) => self.table(&align, width),
+            // Anything else these options don't produce is laid out as its content
+            _ => self.blocks(width, depth, false),
+        };
+        self.take();
+        lines
+    }
+
+    fn heading(&mut self, level: u8, width: usize) -> Vec<StyledLine> {
+        let style = TextStyle { bold: true, ..TextStyle::tone(Tone::Heading(level)) };
+        let mut lines = wrap(&self.inlines(style), width);
+        let underline = match level {
+            1 => "═",
+            2 => "─",
+            _ => return lines,
+        };
+        let length = lines.iter().map(|line| line_width(line)).max().unwrap_or(0);
+        lines.push(vec![piece(&underline.repeat(length), TextStyle::tone(Tone::Muted))]);
+        lines
+    }
+
+    /// The raw text of a code or HTML block
+    fn text(&mut self) -> String {
+        let mut text = String::new();
+        while let Some(Event::Text(part) | Event::Html(part)) = self.peek() {
+            text.push_str(part);
+            self.next += 1;
+        }
+        text
+    }
+
+    fn list(&mut self, start: Option<u64>, width: usize, depth: usize) -> Vec<StyledLine> {
+        let (count, loose) = self.list_shape();
+        let last_number = start.map(|start| start + count.saturating_sub(1) as u64);
+        let number_width = last_number.map_or(0, |last| last.to_string().len());
+        let mut lines = Vec::new();
+
+        let mut n = 0;
+        while let Some(Event::Start(Tag::Item)) = self.peek() {
+            self.next += 1;
+            if n > 0 && loose {
+                lines.push(Vec::new());
+            }
+            // A loose item's task marker comes inside its first paragraph
+            let task = match (self.peek(), self.events.get(self.next + 1)) {
+                (Some(Event::TaskListMarker(checked)), _) | (Some(Event::Start(Tag::Paragraph)), Some(Event::TaskListMarker(checked))) => {
+                    Some(*checked)
+                }
+                _ => None,
+            };
+            let mut marker: StyledLine = match start {
+                Some(start) => vec![piece(&format!("{:>w$}.", start + n as u64, w = number_width), TextStyle::tone(Tone::Marker))],
+                None if task.is_none() => vec![piece(BULLETS[depth % BULLETS.len()], TextStyle::tone(Tone::Marker))],
+                None => Vec::new(),
+            };
+            match task {
+                Some(true) => marker.push(piece("☑", TextStyle::tone(Tone::Checked))),
+                Some(false) => marker.push(piece("☐", TextStyle::tone(Tone::Marker))),
+                None => {}
+            }
+            let marker_width = marker.iter().map(|text| display_width(&text.text)).sum::<usize>() + marker.len();
+            let mut marker = join(marker, " ");
+            marker.push(piece(" ", TextStyle::default()));
+
+            let mut content = self.blocks(width.saturating_sub(marker_width).max(1), depth + 1, !loose);
+            self.take();
+            if task == Some(true) {
+                for text in content.iter_mut().flatten().filter(|text| text.style.tone == Tone::Plain) {
+                    text.style.tone = Tone::Muted;
+                }
+            }
+            if content.is_empty() {
+                content.push(Vec::new());
+            }
+            for (index, line) in content.into_iter().enumerate() {
+                let prefix = if index == 0 { marker.clone() } else { vec![piece(&" ".repeat(marker_width), TextStyle::default())] };
+                lines.push(prefix.into_iter().chain(line).collect());
+            }
+            n += 1;
+        }
+        lines
+    }
+
+    /// How many items the list starting at the next event has, and whether it's
+    /// loose: the parser only wraps a loose list's text in paragraphs.
+    fn list_shape(&self) -> (usize, bool) {
+        let (mut count, mut loose, mut depth) = (0, false, 0);
+        for event in &self.events[self.next..] {
+            match event {
+                Event::Start(tag) => {
+                    match (depth, tag) {
+                        (0, Tag::Item) => count += 1,
+                        (1, Tag::Paragraph) => loose = true,
+                        _ => {}
+                    }
+                    depth += 1;
+                }
+                Event::End(_) if depth == 0 => break,
+                Event::End(_) => depth -= 1,
+                _ => {}
+            }
+        }
+        (count, loose)
+    }
+
+    fn table(&mut self, alignment: &[Alignment], width: usize) -> Vec<StyledLine> {
+        const SEPARATOR: &str = " │ ";
+        let muted = TextStyle::tone(Tone::Muted);
+        let columns = alignment.len();
+        let mut header = Vec::new();
+        let mut rows = Vec::new();
+        while let Some(Event::Start(tag)) = self.peek() {
+            let head = matches!(tag, Tag::TableHead);
+            self.next += 1;
+            let style = TextStyle { bold: head, ..TextStyle::default() };
+            let mut cells = Vec::new();
+            while let Some(Event::Start(Tag::TableCell)) = self.peek() {
+                self.next += 1;
+                cells.push(self.inner(style));
+            }
+            self.take();
+            cells.resize(columns, Vec::new());
+            if head {
+                header = cells;
+            } else {
+                rows.push(cells);
+            }
+        }
+        header.resize(columns, Vec::new());
+
+        let wanted: Vec<usize> = (0..columns)
+            .map(|column| {
+                std::iter::once(&header)
+                    .chain(&rows)
+                    .map(|row| line_width(&row[column]))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let rules = vec![WidthRule::between(3, usize::MAX).flex(); wanted.len()];
+        let widths: Vec<usize> = fit_widths(&rules, &wanted, width, display_width(SEPARATOR))
+            .into_iter()
+            .map(|width| width.unwrap_or(0))
+            .collect();
+
+        let render_row = |row: &[Vec<StyledText>]| -> Vec<StyledLine> {
+            let cells: Vec<Vec<StyledLine>> = row.iter().zip(&widths).map(|(cell, width)| wrap(cell, *width)).collect();
+            let height = cells.iter().map(Vec::len).max().unwrap_or(1).max(1);
+            (0..height)
+                .map(|n| {
+                    let parts = cells.iter().enumerate().map(|(column, lines)| {
+                        let line = lines.get(n).cloned().unwrap_or_default();
+                        align(line, widths[column], alignment[column])
+                    });
+                    let mut line = Vec::new();
+                    for (column, part) in parts.enumerate() {
+                        if column > 0 {
+                            line.push(piece(SEPARATOR, muted));
+                        }
+                        line.extend(part);
+                    }
+                    trim_end(line)
+                })
+                .collect()
+        };
+
+        let mut lines = render_row(&header);
+        let rule = widths.iter().map(|width| "─".repeat(*width)).collect::<Vec<_>>().join("─┼─");
+        lines.push(vec![piece(&rule, muted)]);
+        for row in &rows {
+            lines.extend(render_row(row));
+        }
+        lines
+    }
+
+    /// Inline events up to the end of their container, as styled text on one
+    /// line; hard breaks are kept as `\n`.
+    fn inlines(&mut self, style: TextStyle) -> Vec<StyledText> {
+        let mut pieces = Vec::new();
+        loop {
+            match self.peek() {
+                None | Some(Event::End(_) | Event::Rule) => break,
+                Some(Event::Start(tag)) if !is_inline(tag) => break,
+                _ => {}
+            }
+            match self.take().expect("peeked") {
+                Event::Text(text) | Event::InlineHtml(text) | Event::Html(text) => pieces.push(piece(&text, style)),
+                Event::Code(code) => pieces.push(piece(&code, TextStyle { tone: Tone::Code, ..style })),
+                Event::SoftBreak => pieces.push(piece(" ", style)),
+                Event::HardBreak => pieces.push(piece("\n", style)),
+                Event::Start(Tag::Emphasis) => pieces.extend(self.inner(TextStyle { italic: true, ..style })),
+                Event::Start(Tag::Strong) => pieces.extend(self.inner(TextStyle { bold: true, ..style })),
+                Event::Start(Tag::Strikethrough) => pieces.extend(self.inner(TextStyle { strike: true, ..style })),
+                Event::Start(Tag::Link { link_type, dest_url, .. }) => {
+                    let url = match link_type {
+                        LinkType::Email => format!("mailto:{}", dest_url),
+                        _ => dest_url.to_string(),
+                    };
+                    let text = self.inner(TextStyle { tone: Tone::Link, underline: true, ..style });
+                    if self.hyperlinks {
+                        pieces.extend(text.into_iter().map(|text| StyledText { link: Some(url.clone()), ..text }));
+                        continue;
+                    }
+                    let plain: String = text.iter().map(|text| text.text.as_str()).collect();
+                    pieces.extend(text);
+                    if plain != url && url.strip_prefix("mailto:") != Some(plain.as_str()) {
+                        pieces.push(piece(&format!(" ({})", url), TextStyle::tone(Tone::Url)));
+                    }
+                }
+                Event::Start(Tag::Image { dest_url, .. }) => {
+                    let alt: String = self.inner(style).iter().map(|text| text.text.as_str()).collect();
+                    let alt = if alt.is_empty() { "image" } else { &alt };
+                    let text = piece(alt, TextStyle { tone: Tone::Link, underline: true, ..style });
+                    if self.hyperlinks {
+                        pieces.push(StyledText { link: Some(dest_url.to_string()), ..text });
+                    } else {
+                        pieces.push(text);
+                        pieces.push(piece(&format!(" ({})", dest_url), TextStyle::tone(Tone::Url)));
+                    }
+                }
+                // Task markers are read by the list item
+                _ => {}
+            }
+        }
+        pieces
+    }
+
+    /// The content of an inline container, reading its end
+    fn inner(&mut self, style: TextStyle) -> Vec<StyledText> {
+        let pieces = self.inlines(style);
+        self.take();
+        pieces
+    }
+}
+
+/// Whether `tag` is part of a line of text rather than a block
+fn is_inline(tag: &Tag) -> bool {
+    matches!(tag, Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Link { .. } | Tag::Image { .. })
+}
+
+/// `line` padded to `width` cells as `alignment` says.
+fn align(line: StyledLine, width: usize, alignment: Alignment) -> StyledLine {
+    let space = width.saturating_sub(line_width(&line));
+    let (before, after) = match alignment {
+        Alignment::Right => (space, 0),
+        Alignment::Center => (space / 2, space - space / 2),
+        Alignment::Left | Alignment::None => (0, space),
+    };
+    let mut padded = vec![piece(&" ".repeat(before), TextStyle::default())];
+    padded.extend(line);
+    padded.push(piece(&" ".repeat(after), TextStyle::default()));
+    padded.retain(|text| !text.text.is_empty());
+    padded
+}
+
+fn render_code(info: &str, text: &str, width: usize) -> Vec<StyledLine> {
+    let muted = TextStyle::tone(Tone::Muted);
+    let code = highlight(text, info);
+    let longest = code.iter().map(|line| line_width(line)).max().unwrap_or(0);
+    let label = if info.is_empty() { String::new() } else { format!(" {} ", info) };
+    let box_width = (longest + 2).max(display_width(&label) + 4).max(20).min(width);
+
+    let mut lines = vec![vec![piece(
+        &format!("┌─{}{}", label, "─".repeat(box_width.saturating_sub(2 + display_width(&label)))),
+        muted,
+    )]];
+    for line in &code {
+        for part in split_at_width(line, width.saturating_sub(2).max(1)) {
+            lines.push(std::iter::once(piece("│ ", muted)).chain(part).collect());
+        }
+    }
+    lines.push(vec![piece(&format!("└{}", "─".repeat(box_width.saturating_sub(1))), muted)]);
+    lines
+}
+
+/// Word-wrap `pieces` into lines of at most `width` cells. Words longer than a
+/// line are split.
+fn wrap(pieces: &[StyledText], width: usize) -> Vec<StyledLine> {
+    // Words, each made of pieces, and whether a space or a line break came before
+    // A space between two words of one link stays part of the link
+    type Space = Option<(TextStyle, Option<String>)>;
+    let mut words: Vec<(Space, bool, StyledLine)> = Vec::new();
+    let mut space: Space = None;
+    let mut newline = false;
+    let mut current: StyledLine = Vec::new();
+    let mut finish = |current: &mut StyledLine, space: &mut Space, newline: &mut bool| {
+        if !current.is_empty() {
+            words.push((space.take(), std::mem::take(newline), std::mem::take(current)));
+        }
+    };
+    for text in pieces {
+        for (n, part) in text.text.split('\n').enumerate() {
+            if n > 0 {
+                finish(&mut current, &mut space, &mut newline);
+                space = None;
+                newline = true;
+            }
+            for (m, word) in part.split(' ').enumerate() {
+                if m > 0 {
+                    finish(&mut current, &mut space, &mut newline);
+                    space.get_or_insert_with(|| (text.style, text.link.clone()));
+                }
+                if !word.is_empty() {
+                    push(&mut current, word, text.style, text.link.as_deref());
+                }
+            }
+        }
+    }
+    finish(&mut current, &mut space, &mut newline);
+
+    let mut lines: Vec<StyledLine> = vec![Vec::new()];
+    for (space, newline, word) in words {
+        let line = lines.last_mut().expect("there's always a line");
+        let used = line_width(line);
+        let word_width = line_width(&word);
+        if newline || (used > 0 && used + 1 + word_width > width) {
+            lines.push(Vec::new());
+        } else if used > 0 {
+            let (style, link) = space.unwrap_or_default();
+            push(line, " ", style, link.as_deref());
+        }
+        let parts = split_at_width(&word, width);
+        let count = parts.len();
+        for (n, part) in parts.into_iter().enumerate() {
+            let line = lines.last_mut().expect("there's always a line");
+            for text in part {
+                push(line, &text.text, text.style, text.link.as_deref());
+            }
+            if n + 1 < count {
+                lines.push(Vec::new());
+            }
+        }
+    }
+    if lines.len() == 1 && lines[0].is_empty() {
+        return Vec::new();
+    }
+    lines
+}
+
+/// `line` cut into lines of at most `width` cells, breaking anywhere.
+fn split_at_width(line: &StyledLine, width: usize) -> Vec<StyledLine> {
+    let mut lines: Vec<StyledLine> = vec![Vec::new()];
+    let mut used = 0;
+    for text in line {
+        for c in text.text.chars() {
+            let c_width = display_width(c.encode_utf8(&mut [0; 4]));
+            if used + c_width > width && used > 0 {
+                lines.push(Vec::new());
+                used = 0;
+            }
+            push(lines.last_mut().expect("there's always a line"), &c.to_string(), text.style, text.link.as_deref());
+            used += c_width;
+        }
+    }
+    lines
+}
+
+fn push(line: &mut StyledLine, text: &str, style: TextStyle, link: Option<&str>) {
+    match line.last_mut() {
+        Some(last) if last.style == style && last.link.as_deref() == link => last.text.push_str(text),
+        _ => line.push(StyledText { link: link.map(str::to_string), ..piece(text, style) }),
+    }
+}
+
+/// `parts` in one line with `separator` between them
+fn join(parts: StyledLine, separator: &str) -> StyledLine {
+    let mut line = Vec::new();
+    for (n, part) in parts.into_iter().enumerate() {
+        if n > 0 {
+            push(&mut line, separator, TextStyle::default(), None);
+        }
+        line.push(part);
+    }
+    line
+}
+
+fn trim_end(mut line: StyledLine) -> StyledLine {
+    while let Some(last) = line.last_mut() {
+        let trimmed = last.text.trim_end().len();
+        last.text.truncate(trimmed);
+        if !last.text.is_empty() {
+            break;
+        }
+        line.pop();
+    }
+    line
+}
+
+fn line_width(line: &[StyledText]) -> usize {
+    line.iter().map(|text| display_width(&text.text)).sum()
+}
+
+fn piece(text: &str, style: TextStyle) -> StyledText {
+    StyledText { text: text.to_string(), style, link: None }
+}
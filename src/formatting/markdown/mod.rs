@@ -0,0 +1,34 @@
+//! Markdown rendering shared by the CLI and the interactive mode. pulldown-cmark's
+//! events are laid out into wrapped, styled lines, which are then written out
+//! either with ANSI colors or as ratatui lines.
+
+mod ansi;
+pub mod highlight;
+pub mod layout;
+mod tui;
+
+use ratatui::text::Line;
+
+use super::hyperlink::hyperlinks_enabled;
+use super::table::terminal_width;
+use layout::render;
+
+/// `text` rendered for the terminal, wrapped to its width.
+pub fn format_markdown(text: &str) -> String {
+    format_markdown_width(text, terminal_width())
+}
+
+/// `text` rendered with ANSI colors, wrapped to `width` cells. Links become
+/// terminal hyperlinks when those are on, and otherwise show their URL.
+pub fn format_markdown_width(text: &str, width: usize) -> String {
+    ansi::to_ansi(&render(text, width, hyperlinks_enabled()))
+}
+
+pub fn print_formatted_markdown(text: &str) {
+    println!("{}", format_markdown(text));
+}
+
+/// `text` rendered as ratatui lines wrapped to `width` cells.
+pub fn markdown_lines(text: &str, width: usize) -> Vec<Line<'static>> {
+    tui::to_lines(&render(text, width, false))
+}
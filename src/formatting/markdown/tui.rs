@@ -0,0 +1,36 @@
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use super::layout::{StyledLine, TextStyle, Tone};
+use crate::formatting::theme::{tui_color, SemanticColor};
+
+/// Rendered markdown as ratatui lines for the interactive mode.
+pub fn to_lines(lines: &[StyledLine]) -> Vec<Line<'static>> {
+    lines
+        .iter()
+        .map(|line| Line::from(line.iter().map(|text| Span::styled(text.text.clone(), style(text.style))).collect::<Vec<_>>()))
+        .collect()
+}
+
+fn style(style: TextStyle) -> Style {
+    let mut result = match style.tone.color() {
+        Some(semantic) => Style::default().fg(tui_color(semantic)),
+        None => Style::default(),
+    };
+    match style.tone {
+        Tone::Code => result = result.bg(tui_color(SemanticColor::Surface)),
+        Tone::Comment => result = result.add_modifier(Modifier::ITALIC),
+        _ => {}
+    }
+    for (on, modifier) in [
+        (style.bold, Modifier::BOLD),
+        (style.italic, Modifier::ITALIC),
+        (style.strike, Modifier::CROSSED_OUT),
+        (style.underline, Modifier::UNDERLINED),
+    ] {
+        if on {
+            result = result.add_modifier(modifier);
+        }
+    }
+    result
+}
@@ -3,3 +3,8 @@ pub mod markdown;
 pub mod utils;
 pub mod theme;
 
+pub mod snippets;
+pub mod output;
+pub mod template;
+pub mod table;
+pub mod hyperlink;
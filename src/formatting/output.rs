@@ -0,0 +1,137 @@
+use serde::Serialize;
+
+use crate::models::{Comment, Issue, Project, Team};
+
+/// The values `--format` takes on commands that list things
+pub const FORMATS: &[&str] = &["simple", "table", "json", "csv", "tsv", "markdown", "yaml", "ndjson"];
+
+/// Whether `format` is meant for other programs rather than people, so nothing
+/// but the records themselves should go to stdout.
+pub fn is_structured(format: &str) -> bool {
+    !matches!(format, "simple" | "table")
+}
+
+/// Something listed by a command, with the columns used for csv, tsv and
+/// markdown. The other structured formats use its serialized form.
+pub trait Record: Serialize {
+    const COLUMNS: &'static [&'static str];
+
+    fn row(&self) -> Vec<String>;
+}
+
+impl Record for Issue {
+    const COLUMNS: &'static [&'static str] =
+        &["id", "title", "state", "priority", "assignee", "team", "project", "labels", "due", "created", "updated", "url"];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.identifier.clone(),
+            self.title.clone(),
+            self.state.name.clone(),
+            self.priority.map(|p| p.to_string()).unwrap_or_default(),
+            self.assignee.as_ref().map(|a| a.name.clone()).unwrap_or_default(),
+            self.team.key.clone(),
+            self.project.as_ref().map(|p| p.name.clone()).unwrap_or_default(),
+            self.labels.nodes.iter().map(|l| l.name.as_str()).collect::<Vec<_>>().join(", "),
+            self.due_date.clone().unwrap_or_default(),
+            self.created_at.clone(),
+            self.updated_at.clone(),
+            self.url.clone(),
+        ]
+    }
+}
+
+impl Record for Team {
+    const COLUMNS: &'static [&'static str] = &["key", "name", "id"];
+
+    fn row(&self) -> Vec<String> {
+        vec![self.key.clone(), self.name.clone(), self.id.clone()]
+    }
+}
+
+impl Record for Project {
+    const COLUMNS: &'static [&'static str] = &["name", "state", "slug", "created", "url", "id"];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.state.clone(),
+            self.slug_id.clone().unwrap_or_default(),
+            self.created_at.clone(),
+            self.url.clone(),
+            self.id.clone(),
+        ]
+    }
+}
+
+impl Record for Comment {
+    const COLUMNS: &'static [&'static str] = &["id", "author", "created", "updated", "parent", "body"];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.user.as_ref().map(|u| u.name.clone()).unwrap_or_default(),
+            self.created_at.clone(),
+            self.updated_at.clone(),
+            self.parent_id().unwrap_or_default().to_string(),
+            self.body.clone(),
+        ]
+    }
+}
+
+/// Print `records` in a structured `format`. Returns false, printing nothing, for
+/// `simple` and `table`, which each command prints its own way.
+pub fn print_records<T: Record>(records: &[T], format: &str) -> bool {
+    match render_records(records, format) {
+        Some(text) => {
+            print!("{}", text);
+            true
+        }
+        None => false,
+    }
+}
+
+/// `records` in a structured `format`, or None for a format meant for people.
+pub fn render_records<T: Record>(records: &[T], format: &str) -> Option<String> {
+    let rows = || {
+        let header: Vec<String> = T::COLUMNS.iter().map(|c| c.to_string()).collect();
+        std::iter::once(header).chain(records.iter().map(Record::row))
+    };
+    let lines = |line: &dyn Fn(&[String]) -> String| rows().map(|row| line(&row) + "\n").collect::<String>();
+    Some(match format {
+        "json" => serde_json::to_string_pretty(records).unwrap_or_default() + "\n",
+        "ndjson" => records.iter().map(|r| serde_json::to_string(r).unwrap_or_default() + "\n").collect(),
+        "yaml" => serde_norway::to_string(records).unwrap_or_default(),
+        "csv" => to_csv(rows()),
+        "tsv" => lines(&|row| row.iter().map(|f| tsv_field(f)).collect::<Vec<_>>().join("\t")),
+        "markdown" => {
+            let table = lines(&|row| format!("| {} |", row.iter().map(|f| markdown_cell(f)).collect::<Vec<_>>().join(" | ")));
+            let rule = format!("|{}|\n", vec!["---"; T::COLUMNS.len()].join("|"));
+            let (header, rows) = table.split_once('\n').unwrap_or((&table, ""));
+            format!("{}\n{}{}", header, rule, rows)
+        }
+        _ => return None,
+    })
+}
+
+/// Rows as CSV, with fields quoted by the csv writer where they need it (RFC 4180).
+pub fn to_csv(rows: impl IntoIterator<Item = Vec<String>>) -> String {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for row in rows {
+        if writer.write_record(&row).is_err() {
+            break;
+        }
+    }
+    writer.into_inner().ok().and_then(|bytes| String::from_utf8(bytes).ok()).unwrap_or_default()
+}
+
+/// A TSV field. TSV can't quote, so tabs, line breaks and backslashes are escaped
+/// as `\t`, `\n`, `\r` and `\\`.
+pub fn tsv_field(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+/// A markdown table cell: pipes escaped, line breaks as `<br>`.
+pub fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace("\r\n", "<br>").replace('\n', "<br>")
+}
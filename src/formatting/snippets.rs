@@ -0,0 +1,201 @@
+use std::ops::Range;
+
+use colored::*;
+use serde_json::{json, Value};
+
+use crate::models::IssueSearchResult;
+use super::utils::extract_first_name;
+
+/// Characters of text shown around a match
+pub const SNIPPET_WIDTH: usize = 100;
+
+/// Snippets shown under each search result
+const MAX_SNIPPETS: usize = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnippetSource {
+    Description,
+    /// A comment, by the named author
+    Comment(String),
+}
+
+/// A piece of an issue's text around the terms it matched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snippet {
+    pub source: SnippetSource,
+    pub text: String,
+    /// Byte ranges of `text` that matched a term
+    pub matches: Vec<Range<usize>>,
+}
+
+/// The words of a search, lowercased, with quoted phrases kept together.
+pub fn search_terms(query: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    for (index, part) in query.split('"').enumerate() {
+        if index % 2 == 1 {
+            if !part.trim().is_empty() {
+                terms.push(part.trim().to_lowercase());
+            }
+        } else {
+            terms.extend(part.split_whitespace().map(str::to_lowercase));
+        }
+    }
+    let mut unique = Vec::new();
+    for term in terms {
+        if !unique.contains(&term) {
+            unique.push(term);
+        }
+    }
+    unique
+}
+
+/// Where `terms` occur in `text`, ignoring case, as sorted byte ranges with
+/// overlapping matches merged.
+pub fn find_matches(text: &str, terms: &[String]) -> Vec<Range<usize>> {
+    let mut matches: Vec<Range<usize>> = Vec::new();
+    for (start, _) in text.char_indices() {
+        for term in terms.iter().filter(|term| !term.is_empty()) {
+            if let Some(end) = match_at(text, start, term) {
+                matches.push(start..end);
+            }
+        }
+    }
+    matches.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<usize>> = Vec::new();
+    for range in matches {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// The end of `term` if `text` has it at byte `start`, ignoring case.
+fn match_at(text: &str, start: usize, term: &str) -> Option<usize> {
+    let mut chars = text[start..].char_indices();
+    for wanted in term.chars() {
+        let (_, c) = chars.next()?;
+        if !c.to_lowercase().eq(wanted.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(text.len(), |(offset, _)| start + offset))
+}
+
+/// About `width` characters of `text` on one line, around its first match and cut
+/// at word boundaries, with the matches in it. None when nothing matches.
+pub fn snippet(text: &str, terms: &[String], width: usize) -> Option<(String, Vec<Range<usize>>)> {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let matches = find_matches(&flat, terms);
+    let first = matches.first()?.clone();
+
+    let chars: Vec<(usize, char)> = flat.char_indices().collect();
+    let total = chars.len();
+    let match_start = chars.iter().position(|(offset, _)| *offset == first.start)?;
+    let match_end = chars.iter().position(|(offset, _)| *offset >= first.end).unwrap_or(total);
+
+    // A third of the room before the match, unless the text ends first
+    let mut start = match_start.saturating_sub(width / 3).min(total.saturating_sub(width));
+    let mut end = (start + width).min(total).max(match_end);
+    if start > 0 {
+        while start < match_start && chars[start - 1].1 != ' ' {
+            start += 1;
+        }
+    }
+    if end < total {
+        while end > match_end && chars[end].1 != ' ' {
+            end -= 1;
+        }
+    }
+
+    let from = chars[start].0;
+    let to = chars.get(end).map_or(flat.len(), |(offset, _)| *offset);
+    let prefix = if start > 0 { "…" } else { "" };
+    let suffix = if end < total { "…" } else { "" };
+    let text = format!("{}{}{}", prefix, flat[from..to].trim_end(), suffix);
+    let shift = |offset: usize| offset - from + prefix.len();
+    let matches = matches
+        .into_iter()
+        .filter(|range| range.start >= from && range.end <= to)
+        .map(|range| shift(range.start)..shift(range.end))
+        .collect();
+    Some((text, matches))
+}
+
+/// The snippets shown for a search result: where the description matched, then
+/// the comments that matched.
+pub fn issue_snippets(result: &IssueSearchResult, terms: &[String]) -> Vec<Snippet> {
+    let description = result
+        .issue
+        .description
+        .as_deref()
+        .and_then(|description| snippet(description, terms, SNIPPET_WIDTH))
+        .map(|(text, matches)| Snippet { source: SnippetSource::Description, text, matches });
+    let comments = result.comments.nodes.iter().filter_map(|comment| {
+        let (text, matches) = snippet(&comment.body, terms, SNIPPET_WIDTH)?;
+        let author = comment.user.as_ref().map(|user| extract_first_name(&user.name)).unwrap_or("Someone");
+        Some(Snippet { source: SnippetSource::Comment(author.to_string()), text, matches })
+    });
+    description.into_iter().chain(comments).take(MAX_SNIPPETS).collect()
+}
+
+/// `text` with the `matches` byte ranges highlighted.
+pub fn highlight(text: &str, matches: &[Range<usize>]) -> String {
+    let mut result = String::new();
+    let mut last = 0;
+    for range in matches {
+        result.push_str(&text[last..range.start]);
+        result.push_str(&text[range.clone()].yellow().bold().to_string());
+        last = range.end;
+    }
+    result.push_str(&text[last..]);
+    result
+}
+
+/// Print full-text search results in the order Linear ranked them, each with the
+/// matched parts of its title, description and comments highlighted.
+pub fn print_search_results(results: &[IssueSearchResult], terms: &[String]) {
+    for (rank, result) in results.iter().enumerate() {
+        let issue = &result.issue;
+        let assignee = issue.assignee.as_ref().map(|a| extract_first_name(&a.name)).unwrap_or("Unassigned");
+        println!(
+            "{:>3}. {} {} {}",
+            rank + 1,
+            issue.identifier.bright_blue(),
+            highlight(&issue.title, &find_matches(&issue.title, terms)),
+            format!("[{}] {}", issue.state.name, assignee).dimmed()
+        );
+        for snippet in issue_snippets(result, terms) {
+            let source = match &snippet.source {
+                SnippetSource::Description => "description:".to_string(),
+                SnippetSource::Comment(author) => format!("{} commented:", author),
+            };
+            println!("     {} {}", source.dimmed(), highlight(&snippet.text, &snippet.matches));
+        }
+    }
+}
+
+/// Search results as JSON: each issue with a `snippets` list.
+pub fn search_results_json(results: &[IssueSearchResult], terms: &[String]) -> Value {
+    let results: Vec<Value> = results
+        .iter()
+        .map(|result| {
+            let mut value = serde_json::to_value(&result.issue).unwrap_or_else(|_| json!({}));
+            let snippets: Vec<Value> = issue_snippets(result, terms)
+                .into_iter()
+                .map(|snippet| {
+                    let (source, author) = match snippet.source {
+                        SnippetSource::Description => ("description", None),
+                        SnippetSource::Comment(author) => ("comment", Some(author)),
+                    };
+                    json!({ "source": source, "author": author, "text": snippet.text })
+                })
+                .collect();
+            value["snippets"] = json!(snippets);
+            value
+        })
+        .collect();
+    json!(results)
+}
@@ -0,0 +1,389 @@
+use std::io::IsTerminal;
+
+use colored::*;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::error::{LinearError, LinearResult};
+use crate::filtering::priority_label;
+use crate::models::Issue;
+use super::hyperlink::hyperlink_cell;
+use super::issues::format_state_color;
+use super::utils::{extract_first_name, format_relative_time};
+
+/// Width used when stdout isn't a terminal and `COLUMNS` isn't set
+pub const DEFAULT_WIDTH: usize = 120;
+
+/// Space between table columns
+const GAP: usize = 2;
+
+/// The width tables are laid out in: `COLUMNS` if set, else the terminal's width,
+/// else [`DEFAULT_WIDTH`] when output goes to a pipe or file.
+pub fn terminal_width() -> usize {
+    if let Some(columns) = std::env::var("COLUMNS").ok().and_then(|c| c.trim().parse::<usize>().ok()) {
+        return columns;
+    }
+    if std::io::stdout().is_terminal() {
+        if let Ok((columns, _)) = crossterm::terminal::size() {
+            return columns as usize;
+        }
+    }
+    DEFAULT_WIDTH
+}
+
+/// How many terminal cells `text` takes up; wide characters such as CJK and most
+/// emoji take two.
+pub fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// `text` cut to at most `width` cells, ending in `…` when anything was cut.
+pub fn truncate_to_width(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut kept = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > width - 1 {
+            break;
+        }
+        kept.push(c);
+        used += w;
+    }
+    kept.push('…');
+    kept
+}
+
+/// `text` cut or padded with spaces to exactly `width` cells.
+pub fn pad_to_width(text: &str, width: usize) -> String {
+    let text = truncate_to_width(text, width);
+    let padding = width.saturating_sub(display_width(&text));
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+/// How a table column may be sized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WidthRule {
+    /// The narrowest it gets before optional columns are hidden to make room
+    pub min: usize,
+    /// The widest it gets, however long its values are
+    pub max: usize,
+    /// Whether it gives up width before the other columns when the table is too wide
+    pub flex: bool,
+    /// When it's hidden if the table doesn't fit: 0 never, otherwise higher first
+    pub drop: u8,
+}
+
+impl WidthRule {
+    pub const fn fixed(width: usize) -> Self {
+        Self { min: width, max: width, flex: false, drop: 0 }
+    }
+
+    pub const fn between(min: usize, max: usize) -> Self {
+        Self { min, max, flex: false, drop: 0 }
+    }
+
+    pub const fn flex(self) -> Self {
+        Self { flex: true, ..self }
+    }
+
+    pub const fn drop(self, order: u8) -> Self {
+        Self { drop: order, ..self }
+    }
+}
+
+/// Lay out columns in `available` cells with `gap` cells between them. Each column
+/// starts at its `wanted` width within its rule's bounds; if the minimums don't fit,
+/// optional columns are hidden (None), then columns shrink, flexible ones first and
+/// the widest of them first, down to their minimums and, as a last resort, below them.
+pub fn fit_widths(rules: &[WidthRule], wanted: &[usize], available: usize, gap: usize) -> Vec<Option<usize>> {
+    let total = |widths: &[Option<usize>], width: &dyn Fn(usize) -> usize| {
+        let shown: Vec<usize> = (0..widths.len()).filter(|&i| widths[i].is_some()).collect();
+        shown.iter().map(|&i| width(i)).sum::<usize>() + gap * shown.len().saturating_sub(1)
+    };
+    let mut widths: Vec<Option<usize>> = rules
+        .iter()
+        .zip(wanted)
+        .map(|(rule, wanted)| Some((*wanted).clamp(rule.min, rule.max.max(rule.min))))
+        .collect();
+
+    while total(&widths, &|i| rules[i].min) > available {
+        let hide = (0..rules.len()).filter(|&i| widths[i].is_some() && rules[i].drop > 0).max_by_key(|&i| (rules[i].drop, i));
+        match hide {
+            Some(i) => widths[i] = None,
+            None => break,
+        }
+    }
+
+    let mut over = total(&widths, &|i| widths[i].unwrap_or(0)).saturating_sub(available);
+    for (flex, to_min) in [(true, true), (false, true), (true, false), (false, false)] {
+        // A cell at a time from the widest column, so wide columns shrink first
+        while over > 0 {
+            let floor = |i: usize| if to_min { rules[i].min } else { 1 };
+            let widest = (0..widths.len())
+                .filter(|&i| rules[i].flex == flex && widths[i].is_some_and(|w| w > floor(i)))
+                .max_by_key(|&i| (widths[i], std::cmp::Reverse(i)));
+            let Some(i) = widest else { break };
+            widths[i] = widths[i].map(|w| w - 1);
+            over -= 1;
+        }
+    }
+    widths
+}
+
+/// A column `--format table` can show for issues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueColumn {
+    Identifier,
+    Title,
+    State,
+    Priority,
+    Estimate,
+    Due,
+    Labels,
+    Team,
+    Assignee,
+    Project,
+    Created,
+    Updated,
+    Url,
+}
+
+/// The columns shown when `--columns` isn't given
+pub const DEFAULT_COLUMNS: &[IssueColumn] = &[
+    IssueColumn::Identifier,
+    IssueColumn::Title,
+    IssueColumn::State,
+    IssueColumn::Team,
+    IssueColumn::Assignee,
+    IssueColumn::Labels,
+];
+
+impl IssueColumn {
+    const ALL: &'static [(&'static str, IssueColumn)] = &[
+        ("identifier", IssueColumn::Identifier),
+        ("title", IssueColumn::Title),
+        ("state", IssueColumn::State),
+        ("priority", IssueColumn::Priority),
+        ("estimate", IssueColumn::Estimate),
+        ("due", IssueColumn::Due),
+        ("labels", IssueColumn::Labels),
+        ("team", IssueColumn::Team),
+        ("assignee", IssueColumn::Assignee),
+        ("project", IssueColumn::Project),
+        ("created", IssueColumn::Created),
+        ("updated", IssueColumn::Updated),
+        ("url", IssueColumn::Url),
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        let name = match name.as_str() {
+            "id" => "identifier",
+            "status" => "state",
+            "label" => "labels",
+            other => other,
+        };
+        Self::ALL.iter().find(|(n, _)| *n == name).map(|(_, column)| *column)
+    }
+
+    pub fn header(self) -> &'static str {
+        match self {
+            IssueColumn::Identifier => "ID",
+            IssueColumn::Title => "Title",
+            IssueColumn::State => "State",
+            IssueColumn::Priority => "Priority",
+            IssueColumn::Estimate => "Est",
+            IssueColumn::Due => "Due",
+            IssueColumn::Labels => "Labels",
+            IssueColumn::Team => "Team",
+            IssueColumn::Assignee => "Assignee",
+            IssueColumn::Project => "Project",
+            IssueColumn::Created => "Created",
+            IssueColumn::Updated => "Updated",
+            IssueColumn::Url => "URL",
+        }
+    }
+
+    /// The column's width rule; the title takes what's left and the least
+    /// essential columns are hidden first.
+    pub fn rule(self) -> WidthRule {
+        match self {
+            IssueColumn::Identifier => WidthRule::between(6, 12),
+            IssueColumn::Title => WidthRule::between(20, 80).flex(),
+            IssueColumn::State => WidthRule::between(6, 16),
+            IssueColumn::Priority => WidthRule::between(6, 8).drop(3),
+            IssueColumn::Estimate => WidthRule::between(3, 6).drop(6),
+            IssueColumn::Due => WidthRule::fixed(10).drop(4),
+            IssueColumn::Labels => WidthRule::between(8, 30).flex().drop(7),
+            IssueColumn::Team => WidthRule::between(4, 8).drop(2),
+            IssueColumn::Assignee => WidthRule::between(8, 16).drop(1),
+            IssueColumn::Project => WidthRule::between(8, 24).flex().drop(5),
+            IssueColumn::Created | IssueColumn::Updated => WidthRule::between(7, 9).drop(8),
+            IssueColumn::Url => WidthRule::between(10, 60).flex().drop(9),
+        }
+    }
+
+    /// The column's plain text for `issue`
+    pub fn text(self, issue: &Issue) -> String {
+        match self {
+            IssueColumn::Identifier => issue.identifier.clone(),
+            IssueColumn::Title => issue.title.clone(),
+            IssueColumn::State => issue.state.name.clone(),
+            IssueColumn::Priority => priority_label(issue.priority.unwrap_or(0)),
+            IssueColumn::Estimate => issue.estimate.map(|e| e.to_string()).unwrap_or_default(),
+            IssueColumn::Due => issue.due_date.clone().unwrap_or_default(),
+            IssueColumn::Labels => issue.labels.nodes.iter().map(|l| l.name.as_str()).collect::<Vec<_>>().join(", "),
+            IssueColumn::Team => issue.team.key.clone(),
+            IssueColumn::Assignee => issue
+                .assignee
+                .as_ref()
+                .map(|a| extract_first_name(&a.name))
+                .unwrap_or("Unassigned")
+                .to_string(),
+            IssueColumn::Project => issue.project.as_ref().map(|p| p.name.clone()).unwrap_or_default(),
+            IssueColumn::Created => format_relative_time(&issue.created_at),
+            IssueColumn::Updated => format_relative_time(&issue.updated_at),
+            IssueColumn::Url => issue.url.clone(),
+        }
+    }
+
+    /// `cell`, already cut to width, colored for `issue`
+    fn paint(self, issue: &Issue, cell: String) -> String {
+        match self {
+            IssueColumn::Identifier => hyperlink_cell(&cell, &issue.url).blue().to_string(),
+            IssueColumn::State => {
+                let mut state = issue.state.clone();
+                state.name = cell;
+                format_state_color(&state).to_string()
+            }
+            IssueColumn::Priority => match issue.priority {
+                Some(4) => cell.red().bold(),
+                Some(3) => cell.red(),
+                Some(2) => cell.yellow(),
+                Some(1) => cell.normal(),
+                _ => cell.dimmed(),
+            }
+            .to_string(),
+            IssueColumn::Labels => cell.cyan().to_string(),
+            IssueColumn::Team => cell.cyan().to_string(),
+            IssueColumn::Assignee if issue.assignee.is_none() => cell.dimmed().to_string(),
+            IssueColumn::Assignee => cell.green().to_string(),
+            IssueColumn::Project => match issue.project.as_ref().and_then(|p| p.url.as_deref()) {
+                Some(url) => hyperlink_cell(&cell, url),
+                None => cell,
+            },
+            IssueColumn::Url => hyperlink_cell(&cell, &issue.url).dimmed().to_string(),
+            IssueColumn::Created | IssueColumn::Updated => cell.dimmed().to_string(),
+            _ => cell,
+        }
+    }
+}
+
+/// A column of `--columns`, with the widest it may get if one was given (`title:40`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColumnSpec {
+    pub column: IssueColumn,
+    pub max: Option<usize>,
+}
+
+impl ColumnSpec {
+    fn rule(&self) -> WidthRule {
+        let rule = self.column.rule();
+        match self.max {
+            Some(max) => WidthRule { min: rule.min.min(max), max, ..rule },
+            None => rule,
+        }
+    }
+}
+
+/// Parse a `--columns` list such as `identifier,title:40,priority,due`.
+pub fn parse_columns(list: &str) -> Result<Vec<ColumnSpec>, String> {
+    let columns = list
+        .split(',')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| {
+            let (name, max) = match part.split_once(':') {
+                Some((name, max)) => {
+                    let max = max
+                        .trim()
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&max| max > 0)
+                        .ok_or_else(|| format!("Invalid width '{}' for column '{}'", max.trim(), name.trim()))?;
+                    (name, Some(max))
+                }
+                None => (part, None),
+            };
+            let column = IssueColumn::from_name(name).ok_or_else(|| {
+                let names: Vec<&str> = IssueColumn::ALL.iter().map(|(n, _)| *n).collect();
+                format!("Unknown column '{}' (columns: {})", name.trim(), names.join(", "))
+            })?;
+            Ok(ColumnSpec { column, max })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    if columns.is_empty() {
+        return Err("No columns given".to_string());
+    }
+    Ok(columns)
+}
+
+/// The columns for `--columns`, or the default ones when it isn't given.
+pub fn columns_from_arg(list: Option<&String>) -> LinearResult<Vec<ColumnSpec>> {
+    match list {
+        Some(list) => parse_columns(list).map_err(LinearError::InvalidInput),
+        None => Ok(DEFAULT_COLUMNS.iter().map(|&column| ColumnSpec { column, max: None }).collect()),
+    }
+}
+
+/// Lines of an issue table laid out in `width` cells: a rule, the header, a rule,
+/// one line per issue and a closing rule.
+pub fn render_issue_table(issues: &[Issue], columns: &[ColumnSpec], width: usize) -> Vec<String> {
+    let cells: Vec<Vec<String>> = issues
+        .iter()
+        .map(|issue| columns.iter().map(|spec| spec.column.text(issue)).collect())
+        .collect();
+    let rules: Vec<WidthRule> = columns.iter().map(ColumnSpec::rule).collect();
+    let wanted: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, spec)| cells.iter().map(|row| display_width(&row[i])).chain([display_width(spec.column.header())]).max().unwrap_or(0))
+        .collect();
+    let widths = fit_widths(&rules, &wanted, width, GAP);
+    let shown: Vec<(usize, usize)> = widths.iter().enumerate().filter_map(|(i, w)| w.map(|w| (i, w))).collect();
+    let table_width = shown.iter().map(|(_, w)| w).sum::<usize>() + GAP * shown.len().saturating_sub(1);
+
+    // Pad every column but the last, so lines don't end in spaces
+    let line = |cell: &dyn Fn(usize, String) -> String, texts: &dyn Fn(usize) -> String| {
+        shown
+            .iter()
+            .enumerate()
+            .map(|(n, &(i, w))| {
+                let text = if n + 1 == shown.len() { truncate_to_width(&texts(i), w) } else { pad_to_width(&texts(i), w) };
+                cell(i, text)
+            })
+            .collect::<Vec<_>>()
+            .join(&" ".repeat(GAP))
+    };
+    let rule = "─".repeat(table_width).dimmed().to_string();
+
+    let mut lines = vec![rule.clone()];
+    lines.push(line(&|_, text| text.bold().to_string(), &|i| columns[i].column.header().to_string()));
+    lines.push(rule.clone());
+    for (issue, row) in issues.iter().zip(&cells) {
+        lines.push(line(&|i, text| columns[i].column.paint(issue, text), &|i| row[i].clone()));
+    }
+    lines.push(rule);
+    lines
+}
+
+/// Print `issues` as a table fitted to the terminal.
+pub fn print_issue_table(issues: &[Issue], columns: &[ColumnSpec]) {
+    for line in render_issue_table(issues, columns, terminal_width()) {
+        println!("{}", line);
+    }
+}
@@ -0,0 +1,242 @@
+use std::fs;
+
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::config::load_config;
+use crate::error::{LinearError, LinearResult};
+use super::theme::helpers::{priority_color, status_color};
+use super::theme::{theme_color, SemanticColor};
+use super::utils::format_relative_time;
+
+const FILTERS: &[&str] = &["truncate", "upper", "lower", "relative", "default", "pad", "join", "color"];
+
+/// Widest a `truncate` or `pad` filter can make a field
+const MAX_FILTER_WIDTH: usize = 1000;
+
+/// A one-line output format such as `{{identifier}} [{{state.name}}] {{title|truncate:50}}`,
+/// rendered once per listed record.
+///
+/// `{{path}}` looks up a field of the record as serialized to JSON, with dots for
+/// nested fields. A path through a list, or through a connection's `nodes`, gives
+/// the list of values: `{{labels.name}}` is every label's name. Filters follow the
+/// path after `|`: `truncate:N`, `upper`, `lower`, `relative` (`3d ago`),
+/// `default:TEXT`, `pad:N`, `join:SEP` and `color:NAME`, where NAME is a theme color
+/// (`link`, `muted`, `status-started`, ...) or `status` / `priority` to color by the
+/// record's own state or priority.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputTemplate {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Text(String),
+    Field { path: Vec<String>, filters: Vec<Filter> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Filter {
+    Truncate(usize),
+    Upper,
+    Lower,
+    Relative,
+    Default(String),
+    Pad(usize),
+    Join(String),
+    Color(ColorChoice),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ColorChoice {
+    Semantic(SemanticColor),
+    /// By the record's `state.type`
+    Status,
+    /// By the record's `priority`
+    Priority,
+}
+
+impl OutputTemplate {
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut rest = template;
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                segments.push(Segment::Text(rest[..start].to_string()));
+            }
+            let end = rest[start..].find("}}").ok_or_else(|| format!("Unclosed {{{{ in template: {}", &rest[start..]))?;
+            segments.push(parse_field(&rest[start + 2..start + end])?);
+            rest = &rest[start + end + 2..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Text(rest.to_string()));
+        }
+        Ok(Self { segments })
+    }
+
+    /// The template for `--template` / `--template-file`: a file's contents, a named
+    /// template from the config's `output_templates`, or the template text itself.
+    pub fn from_args(template: Option<&String>, file: Option<&String>) -> LinearResult<Option<Self>> {
+        let text = match (template, file) {
+            (_, Some(path)) => fs::read_to_string(path)
+                .map_err(|e| LinearError::InvalidInput(format!("Failed to read template file {}: {}", path, e)))?
+                .trim_end_matches(['\n', '\r'])
+                .to_string(),
+            (Some(template), None) => load_config().output_templates.get(template).cloned().unwrap_or_else(|| template.clone()),
+            (None, None) => return Ok(None),
+        };
+        Self::parse(&text).map(Some).map_err(LinearError::InvalidInput)
+    }
+
+    /// The template filled in from `record`.
+    pub fn render<T: Serialize>(&self, record: &T) -> String {
+        let record = serde_json::to_value(record).unwrap_or(Value::Null);
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => text.clone(),
+                Segment::Field { path, filters } => {
+                    let value = lookup(&record, path);
+                    filters.iter().fold(value, |value, filter| apply(filter, value, &record)).as_text()
+                }
+            })
+            .collect()
+    }
+
+    /// Print one line per record.
+    pub fn print<T: Serialize>(&self, records: &[T]) {
+        for record in records {
+            println!("{}", self.render(record));
+        }
+    }
+}
+
+fn parse_field(inner: &str) -> Result<Segment, String> {
+    let mut parts = inner.split('|');
+    let path = parts.next().unwrap_or("").trim();
+    if path.is_empty() {
+        return Err(format!("Empty field in template: {{{{{}}}}}", inner));
+    }
+    let filters = parts.map(parse_filter).collect::<Result<Vec<_>, _>>()?;
+    Ok(Segment::Field { path: path.split('.').map(str::to_string).collect(), filters })
+}
+
+fn parse_filter(text: &str) -> Result<Filter, String> {
+    let (name, arg) = match text.split_once(':') {
+        Some((name, arg)) => (name.trim(), Some(arg)),
+        None => (text.trim(), None),
+    };
+    let number = |arg: Option<&str>| {
+        let width = arg.and_then(|a| a.trim().parse::<usize>().ok())
+            .ok_or_else(|| format!("Filter '{}' needs a number, e.g. {}:20", name, name))?;
+        if width > MAX_FILTER_WIDTH {
+            return Err(format!("Filter '{}' takes a width of at most {}", name, MAX_FILTER_WIDTH));
+        }
+        Ok(width)
+    };
+    Ok(match name {
+        "truncate" => Filter::Truncate(number(arg)?),
+        "pad" => Filter::Pad(number(arg)?),
+        "upper" => Filter::Upper,
+        "lower" => Filter::Lower,
+        "relative" | "ago" => Filter::Relative,
+        "default" => Filter::Default(arg.unwrap_or("").to_string()),
+        "join" => Filter::Join(arg.unwrap_or(", ").to_string()),
+        "color" => {
+            let arg = arg.unwrap_or("").trim();
+            Filter::Color(match arg {
+                "status" => ColorChoice::Status,
+                "priority" => ColorChoice::Priority,
+                _ => ColorChoice::Semantic(SemanticColor::from_name(arg).ok_or_else(|| {
+                    format!("Unknown color '{}' (use status, priority or one of {})", arg, SemanticColor::NAMES.join(", "))
+                })?),
+            })
+        }
+        _ => return Err(format!("Unknown template filter '{}' (filters: {})", name, FILTERS.join(", "))),
+    })
+}
+
+/// The value at `path`, mapping over lists and stepping into connections' `nodes`.
+fn lookup(value: &Value, path: &[String]) -> Value {
+    let Some((key, rest)) = path.split_first() else {
+        return value.clone();
+    };
+    match value {
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| lookup(item, path))
+                .flat_map(|item| match item {
+                    Value::Array(inner) => inner,
+                    other => vec![other],
+                })
+                .collect(),
+        ),
+        Value::Object(map) => match map.get(key) {
+            Some(inner) => lookup(inner, rest),
+            None => match map.get("nodes") {
+                Some(nodes) => lookup(nodes, path),
+                None => Value::Null,
+            },
+        },
+        _ => Value::Null,
+    }
+}
+
+fn apply(filter: &Filter, value: Value, record: &Value) -> Value {
+    let text = || value.as_text();
+    Value::String(match filter {
+        Filter::Default(default) => match value.as_text() {
+            text if text.is_empty() => default.clone(),
+            text => text,
+        },
+        Filter::Join(separator) => match &value {
+            Value::Array(items) => items.iter().map(|item| item.as_text()).collect::<Vec<_>>().join(separator),
+            _ => text(),
+        },
+        Filter::Truncate(width) => {
+            let text = text();
+            if text.chars().count() <= *width {
+                text
+            } else {
+                let kept: String = text.chars().take(width.saturating_sub(1)).collect();
+                format!("{}…", kept)
+            }
+        }
+        Filter::Pad(width) => format!("{:<width$}", text(), width = *width),
+        Filter::Upper => text().to_uppercase(),
+        Filter::Lower => text().to_lowercase(),
+        Filter::Relative => match value.as_str() {
+            Some(timestamp) if !timestamp.is_empty() => format_relative_time(timestamp),
+            _ => String::new(),
+        },
+        Filter::Color(choice) => {
+            let semantic = match choice {
+                ColorChoice::Semantic(semantic) => Some(*semantic),
+                ColorChoice::Status => record.pointer("/state/type").and_then(Value::as_str).map(status_color),
+                ColorChoice::Priority => record.get("priority").and_then(Value::as_u64).map(|p| priority_color(p as u8)),
+            };
+            match semantic {
+                Some(semantic) => text().color(theme_color(semantic)).to_string(),
+                None => text(),
+            }
+        }
+    })
+}
+
+trait AsText {
+    fn as_text(&self) -> String;
+}
+
+impl AsText for Value {
+    /// How a value is written into the line: lists joined with commas, nothing for null
+    fn as_text(&self) -> String {
+        match self {
+            Value::Null => String::new(),
+            Value::String(s) => s.clone(),
+            Value::Array(items) => items.iter().map(AsText::as_text).collect::<Vec<_>>().join(", "),
+            other => other.to_string(),
+        }
+    }
+}
@@ -2,8 +2,18 @@
 
 use colored::{Color, Colorize};
 use lazy_static::lazy_static;
+use ratatui::style::{Modifier, Style};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
 use std::sync::RwLock;
 
+use crate::config::load_config;
+use crate::constants::THEMES_DIR;
+use crate::error::{LinearError, LinearResult};
+
 /// Semantic color definitions for consistent theming
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SemanticColor {
@@ -32,6 +42,11 @@ pub enum SemanticColor {
     Border,
     Selection,
     Highlight,
+    Accent,
+    Key,
+    Background,
+    Surface,
+    SelectionBackground,
     Error,
     Warning,
     Success,
@@ -44,56 +59,263 @@ pub enum SemanticColor {
     Link,
 }
 
+impl SemanticColor {
+    /// Every color with its name, as written in output templates
+    const ALL: &'static [(&'static str, SemanticColor)] = &[
+        ("status-backlog", SemanticColor::StatusBacklog),
+        ("status-unstarted", SemanticColor::StatusUnstarted),
+        ("status-started", SemanticColor::StatusStarted),
+        ("status-completed", SemanticColor::StatusCompleted),
+        ("status-canceled", SemanticColor::StatusCanceled),
+        ("priority-none", SemanticColor::PriorityNone),
+        ("priority-urgent", SemanticColor::PriorityUrgent),
+        ("priority-high", SemanticColor::PriorityHigh),
+        ("priority-medium", SemanticColor::PriorityMedium),
+        ("priority-low", SemanticColor::PriorityLow),
+        ("project", SemanticColor::Project),
+        ("label", SemanticColor::Label),
+        ("user", SemanticColor::User),
+        ("assignee", SemanticColor::Assignee),
+        ("header", SemanticColor::Header),
+        ("border", SemanticColor::Border),
+        ("selection", SemanticColor::Selection),
+        ("highlight", SemanticColor::Highlight),
+        ("accent", SemanticColor::Accent),
+        ("key", SemanticColor::Key),
+        ("background", SemanticColor::Background),
+        ("surface", SemanticColor::Surface),
+        ("selection-background", SemanticColor::SelectionBackground),
+        ("error", SemanticColor::Error),
+        ("warning", SemanticColor::Warning),
+        ("success", SemanticColor::Success),
+        ("info", SemanticColor::Info),
+        ("primary", SemanticColor::Primary),
+        ("secondary", SemanticColor::Secondary),
+        ("muted", SemanticColor::Muted),
+        ("link", SemanticColor::Link),
+    ];
+
+    /// The names colors are written with, e.g. `status-started`
+    pub const NAMES: &'static [&'static str] = &[
+        "status-backlog",
+        "status-unstarted",
+        "status-started",
+        "status-completed",
+        "status-canceled",
+        "priority-none",
+        "priority-urgent",
+        "priority-high",
+        "priority-medium",
+        "priority-low",
+        "project",
+        "label",
+        "user",
+        "assignee",
+        "header",
+        "border",
+        "selection",
+        "highlight",
+        "accent",
+        "key",
+        "background",
+        "surface",
+        "selection-background",
+        "error",
+        "warning",
+        "success",
+        "info",
+        "primary",
+        "secondary",
+        "muted",
+        "link",
+    ];
+
+    /// The color called `name`, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, color)| *color)
+    }
+}
+
 /// Theme configuration for the CLI
 #[derive(Debug, Clone)]
 pub struct ColorTheme {
-    colors: std::collections::HashMap<SemanticColor, Color>,
+    colors: HashMap<SemanticColor, Color>,
+}
+
+/// Built-in themes that can be selected by name or extended by a theme file
+pub const PRESETS: &[&str] = &["dark", "light", "high-contrast", "monochrome"];
+
+const fn gray(level: u8) -> Color {
+    Color::TrueColor { r: level, g: level, b: level }
 }
 
 impl ColorTheme {
-    /// Create the default theme
-    pub fn default() -> Self {
-        let mut colors = std::collections::HashMap::new();
-        
-        // Status colors
-        colors.insert(SemanticColor::StatusBacklog, Color::TrueColor { r: 124, g: 124, b: 124 });
-        colors.insert(SemanticColor::StatusUnstarted, Color::Blue);
-        colors.insert(SemanticColor::StatusStarted, Color::Yellow);
-        colors.insert(SemanticColor::StatusCompleted, Color::Green);
-        colors.insert(SemanticColor::StatusCanceled, Color::Red);
-        
-        // Priority colors
-        colors.insert(SemanticColor::PriorityNone, Color::TrueColor { r: 90, g: 90, b: 90 });
-        colors.insert(SemanticColor::PriorityUrgent, Color::BrightRed);
-        colors.insert(SemanticColor::PriorityHigh, Color::Red);
-        colors.insert(SemanticColor::PriorityMedium, Color::Yellow);
-        colors.insert(SemanticColor::PriorityLow, Color::Blue);
-        
-        // Entity colors
-        colors.insert(SemanticColor::Project, Color::Magenta);
-        colors.insert(SemanticColor::Label, Color::Cyan);
-        colors.insert(SemanticColor::User, Color::Green);
-        colors.insert(SemanticColor::Assignee, Color::Blue);
-        
-        // UI colors
-        colors.insert(SemanticColor::Header, Color::TrueColor { r: 21, g: 76, b: 121 });
-        colors.insert(SemanticColor::Border, Color::TrueColor { r: 120, g: 120, b: 120 });
-        colors.insert(SemanticColor::Selection, Color::BrightYellow);
-        colors.insert(SemanticColor::Highlight, Color::BrightMagenta);
-        colors.insert(SemanticColor::Error, Color::Red);
-        colors.insert(SemanticColor::Warning, Color::Yellow);
-        colors.insert(SemanticColor::Success, Color::Green);
-        colors.insert(SemanticColor::Info, Color::Blue);
-        
-        // Text colors
-        colors.insert(SemanticColor::Primary, Color::Black);
-        colors.insert(SemanticColor::Secondary, Color::TrueColor { r: 40, g: 40, b: 40 });
-        colors.insert(SemanticColor::Muted, Color::TrueColor { r: 90, g: 90, b: 90 });
-        colors.insert(SemanticColor::Link, Color::Blue);
-        
-        Self { colors }
+    fn from_pairs(pairs: &[(SemanticColor, Color)]) -> Self {
+        Self { colors: pairs.iter().copied().collect() }
     }
-    
+
+    /// Create the built-in theme, meant for dark terminals
+    fn built_in() -> Self {
+        use SemanticColor::*;
+        Self::from_pairs(&[
+            // Status colors
+            (StatusBacklog, gray(124)),
+            (StatusUnstarted, Color::BrightBlue),
+            (StatusStarted, Color::Yellow),
+            (StatusCompleted, Color::Green),
+            (StatusCanceled, Color::Red),
+            // Priority colors
+            (PriorityNone, gray(90)),
+            (PriorityUrgent, Color::BrightRed),
+            (PriorityHigh, Color::Red),
+            (PriorityMedium, Color::Yellow),
+            (PriorityLow, Color::Blue),
+            // Entity colors
+            (Project, Color::BrightGreen),
+            (Label, Color::Magenta),
+            (User, Color::Green),
+            (Assignee, Color::Cyan),
+            // UI colors
+            (Header, Color::Blue),
+            (Border, gray(120)),
+            (Selection, Color::BrightYellow),
+            (Highlight, Color::BrightMagenta),
+            (Accent, Color::Cyan),
+            (Key, Color::Yellow),
+            (Background, Color::Black),
+            (Surface, Color::TrueColor { r: 20, g: 22, b: 30 }),
+            (SelectionBackground, Color::TrueColor { r: 30, g: 35, b: 50 }),
+            (Error, Color::Red),
+            (Warning, Color::Yellow),
+            (Success, Color::Green),
+            (Info, Color::Blue),
+            // Text colors
+            (Primary, Color::BrightWhite),
+            (Secondary, Color::White),
+            (Muted, Color::BrightBlack),
+            (Link, Color::Blue),
+        ])
+    }
+
+    /// Dark text on a light background, with the yellows darkened to stay readable
+    fn light() -> Self {
+        use SemanticColor::*;
+        let amber = Color::TrueColor { r: 165, g: 110, b: 0 };
+        Self::from_pairs(&[
+            (StatusBacklog, gray(110)),
+            (StatusUnstarted, Color::Blue),
+            (StatusStarted, amber),
+            (StatusCompleted, Color::Green),
+            (StatusCanceled, Color::Red),
+            (PriorityNone, gray(150)),
+            (PriorityUrgent, Color::Red),
+            (PriorityHigh, Color::TrueColor { r: 200, g: 90, b: 0 }),
+            (PriorityMedium, amber),
+            (PriorityLow, Color::Blue),
+            (Project, Color::Green),
+            (Label, Color::Magenta),
+            (User, Color::Green),
+            (Assignee, Color::Blue),
+            (Header, Color::Blue),
+            (Border, gray(160)),
+            (Selection, Color::Blue),
+            (Highlight, Color::Magenta),
+            (Accent, Color::Blue),
+            (Key, amber),
+            (Background, Color::BrightWhite),
+            (Surface, gray(235)),
+            (SelectionBackground, Color::TrueColor { r: 210, g: 222, b: 245 }),
+            (Error, Color::Red),
+            (Warning, amber),
+            (Success, Color::Green),
+            (Info, Color::Blue),
+            (Primary, Color::Black),
+            (Secondary, gray(60)),
+            (Muted, gray(130)),
+            (Link, Color::Blue),
+        ])
+    }
+
+    /// Bright colors only, on black
+    fn high_contrast() -> Self {
+        use SemanticColor::*;
+        Self::from_pairs(&[
+            (StatusBacklog, Color::BrightWhite),
+            (StatusUnstarted, Color::BrightBlue),
+            (StatusStarted, Color::BrightYellow),
+            (StatusCompleted, Color::BrightGreen),
+            (StatusCanceled, Color::BrightRed),
+            (PriorityNone, Color::BrightWhite),
+            (PriorityUrgent, Color::BrightRed),
+            (PriorityHigh, Color::BrightMagenta),
+            (PriorityMedium, Color::BrightYellow),
+            (PriorityLow, Color::BrightBlue),
+            (Project, Color::BrightGreen),
+            (Label, Color::BrightMagenta),
+            (User, Color::BrightGreen),
+            (Assignee, Color::BrightCyan),
+            (Header, Color::BrightCyan),
+            (Border, Color::BrightWhite),
+            (Selection, Color::BrightYellow),
+            (Highlight, Color::BrightMagenta),
+            (Accent, Color::BrightCyan),
+            (Key, Color::BrightYellow),
+            (Background, Color::Black),
+            (Surface, Color::Black),
+            (SelectionBackground, Color::Blue),
+            (Error, Color::BrightRed),
+            (Warning, Color::BrightYellow),
+            (Success, Color::BrightGreen),
+            (Info, Color::BrightCyan),
+            (Primary, Color::BrightWhite),
+            (Secondary, Color::BrightWhite),
+            (Muted, Color::White),
+            (Link, Color::BrightCyan),
+        ])
+    }
+
+    /// Shades of gray only, for terminals or people that don't tell colors apart
+    fn monochrome() -> Self {
+        let mut theme = Self::from_pairs(&[]);
+        for semantic in SemanticColor::ALL.iter().map(|(_, semantic)| *semantic) {
+            theme.set(semantic, Color::White);
+        }
+        for semantic in [SemanticColor::Primary, SemanticColor::Header, SemanticColor::Accent, SemanticColor::PriorityUrgent, SemanticColor::Error] {
+            theme.set(semantic, Color::BrightWhite);
+        }
+        for semantic in [SemanticColor::Muted, SemanticColor::Border, SemanticColor::PriorityNone, SemanticColor::StatusCanceled] {
+            theme.set(semantic, Color::BrightBlack);
+        }
+        theme.set(SemanticColor::Background, Color::Black);
+        theme.set(SemanticColor::Surface, Color::Black);
+        theme.set(SemanticColor::SelectionBackground, gray(60));
+        theme
+    }
+
+    /// The built-in theme called `name`, one of [`PRESETS`]
+    pub fn preset(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "dark" | "default" => Some(Self::built_in()),
+            "light" => Some(Self::light()),
+            "high-contrast" | "high_contrast" => Some(Self::high_contrast()),
+            "monochrome" | "mono" => Some(Self::monochrome()),
+            _ => None,
+        }
+    }
+
+    /// Parse a theme file in JSON: `{"extends": "light", "colors": {"accent": "#5e6ad2"}}`
+    pub fn from_json(text: &str) -> LinearResult<Self> {
+        let file: ThemeFile = serde_json::from_str(text)?;
+        file.build()
+    }
+
+    /// Parse a theme file in TOML: an optional top-level `extends = "light"`
+    /// followed by a `[colors]` table of `name = "color"` entries.
+    pub fn from_toml(text: &str) -> LinearResult<Self> {
+        let file: ThemeFile = toml::from_str(text).map_err(|e| LinearError::ConfigError(e.to_string()))?;
+        file.build()
+    }
+
     /// Get a color for a semantic meaning
     pub fn get(&self, semantic: SemanticColor) -> Color {
         self.colors.get(&semantic).copied().unwrap_or(Color::White)
@@ -107,10 +329,143 @@ impl ColorTheme {
 
 impl Default for ColorTheme {
     fn default() -> Self {
-        Self::default()
+        Self::built_in()
     }
 }
 
+/// A theme file as written on disk: the preset it starts from and the colors it changes
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    extends: Option<String>,
+    #[serde(default)]
+    colors: HashMap<String, String>,
+}
+
+impl ThemeFile {
+    fn build(self) -> LinearResult<ColorTheme> {
+        let base = self.extends.as_deref().unwrap_or("dark");
+        let mut theme = ColorTheme::preset(base).ok_or_else(|| {
+            LinearError::ConfigError(format!("Unknown theme '{}' to extend (use one of {})", base, PRESETS.join(", ")))
+        })?;
+        for (name, value) in &self.colors {
+            let semantic = SemanticColor::from_name(name).ok_or_else(|| {
+                LinearError::ConfigError(format!("Unknown theme color '{}' (use one of {})", name, SemanticColor::NAMES.join(", ")))
+            })?;
+            let color = parse_color(value).ok_or_else(|| {
+                LinearError::ConfigError(format!(
+                    "Invalid color '{}' for '{}' (use a name like bright-blue or #rrggbb)",
+                    value, name
+                ))
+            })?;
+            theme.set(semantic, color);
+        }
+        Ok(theme)
+    }
+}
+
+/// A color written as a name (`cyan`, `bright-blue`, `gray`) or as `#rrggbb`. Gray is
+/// the terminal's bright black, shown as `DarkGray` in the TUI, and light gray its
+/// white, shown as `Gray`.
+pub fn parse_color(value: &str) -> Option<Color> {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some(Color::TrueColor { r: channel(0)?, g: channel(2)?, b: channel(4)? });
+    }
+    let name = value.to_lowercase().replace(['-', '_'], " ");
+    match name.as_str() {
+        "gray" | "grey" | "dark gray" | "dark grey" => Some(Color::BrightBlack),
+        "light gray" | "light grey" => Some(Color::White),
+        _ => name.parse().ok(),
+    }
+}
+
+/// Directory holding theme files: `linear-cli/themes` under the platform config directory
+pub fn themes_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(THEMES_DIR))
+}
+
+/// The theme called `name`: `<name>.toml` or `<name>.json` in the themes directory,
+/// or one of the [`PRESETS`].
+pub fn load_theme(name: &str) -> LinearResult<ColorTheme> {
+    if let Some(dir) = themes_dir() {
+        for (extension, parse) in [("toml", ColorTheme::from_toml as fn(&str) -> LinearResult<ColorTheme>), ("json", ColorTheme::from_json)] {
+            let path = dir.join(format!("{}.{}", name, extension));
+            if path.is_file() {
+                let text = fs::read_to_string(&path)?;
+                return parse(&text).map_err(|e| {
+                    let message = match e {
+                        LinearError::ConfigError(message) => message,
+                        other => other.to_string(),
+                    };
+                    LinearError::ConfigError(format!("{}: {}", path.display(), message))
+                });
+            }
+        }
+    }
+    ColorTheme::preset(name).ok_or_else(|| {
+        LinearError::ConfigError(format!(
+            "Unknown theme '{}' (use one of {} or add {}.toml to {})",
+            name,
+            PRESETS.join(", "),
+            name,
+            themes_dir().map_or_else(|| THEMES_DIR.to_string(), |dir| dir.display().to_string())
+        ))
+    })
+}
+
+/// The configured theme name: `LINEAR_THEME`, then `theme` in the config file
+pub fn theme_setting() -> Option<String> {
+    env::var("LINEAR_THEME").ok().filter(|name| !name.is_empty()).or_else(|| load_config().theme)
+}
+
+/// When to color output, as given to `--color`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Color when writing to a terminal and `NO_COLOR` is unset
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    pub const NAMES: &'static [&'static str] = &["auto", "always", "never"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "auto" => Some(Self::Auto),
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+}
+
+/// Apply `--color` and load the configured theme. A theme that fails to load is
+/// reported and the built-in one is used instead.
+pub fn init(mode: ColorMode) {
+    match mode {
+        ColorMode::Auto => colored::control::unset_override(),
+        ColorMode::Always => colored::control::set_override(true),
+        ColorMode::Never => colored::control::set_override(false),
+    }
+    if let Some(name) = theme_setting() {
+        match load_theme(&name) {
+            Ok(theme) => set_theme(theme),
+            Err(e) => eprintln!("{} {}", "Warning:".yellow(), e),
+        }
+    }
+}
+
+/// Whether output is colored at all, after `--color`, `NO_COLOR` and `CLICOLOR_FORCE`
+pub fn colors_enabled() -> bool {
+    colored::control::SHOULD_COLORIZE.should_colorize()
+}
+
 lazy_static! {
     /// Global theme instance
     static ref THEME: RwLock<ColorTheme> = RwLock::new(ColorTheme::default());
@@ -131,6 +486,56 @@ pub fn theme_color(semantic: SemanticColor) -> Color {
     THEME.read().unwrap().get(semantic)
 }
 
+/// The same color for ratatui. The plain ANSI colors map onto the terminal's own
+/// palette, so `white` is ratatui's `Gray` and `bright-white` its `White`.
+pub fn to_tui_color(color: Color) -> ratatui::style::Color {
+    use ratatui::style::Color as Tui;
+    match color {
+        Color::Black => Tui::Black,
+        Color::Red => Tui::Red,
+        Color::Green => Tui::Green,
+        Color::Yellow => Tui::Yellow,
+        Color::Blue => Tui::Blue,
+        Color::Magenta => Tui::Magenta,
+        Color::Cyan => Tui::Cyan,
+        Color::White => Tui::Gray,
+        Color::BrightBlack => Tui::DarkGray,
+        Color::BrightRed => Tui::LightRed,
+        Color::BrightGreen => Tui::LightGreen,
+        Color::BrightYellow => Tui::LightYellow,
+        Color::BrightBlue => Tui::LightBlue,
+        Color::BrightMagenta => Tui::LightMagenta,
+        Color::BrightCyan => Tui::LightCyan,
+        Color::BrightWhite => Tui::White,
+        Color::TrueColor { r, g, b } => Tui::Rgb(r, g, b),
+    }
+}
+
+/// Get a color from the current theme for the interactive mode, or the terminal's
+/// default when colors are off
+pub fn tui_color(semantic: SemanticColor) -> ratatui::style::Color {
+    if colors_enabled() {
+        to_tui_color(theme_color(semantic))
+    } else {
+        ratatui::style::Color::Reset
+    }
+}
+
+/// A style with a foreground color from the current theme
+pub fn tui_fg(semantic: SemanticColor) -> Style {
+    Style::default().fg(tui_color(semantic))
+}
+
+/// A style marking the selected row or option with the `background` color from
+/// the current theme, or reversed video when colors are off
+pub fn tui_highlight(background: SemanticColor) -> Style {
+    if colors_enabled() {
+        Style::default().bg(tui_color(background))
+    } else {
+        Style::default().add_modifier(Modifier::REVERSED)
+    }
+}
+
 /// Extension trait for colorizing strings with semantic colors
 pub trait ThemedColorize {
     fn with_theme(&self, semantic: SemanticColor) -> colored::ColoredString;
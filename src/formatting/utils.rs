@@ -1,5 +1,6 @@
 use colored::*;
 use chrono::{DateTime, Utc};
+use crate::filtering::priority_label;
 
 pub fn extract_first_name(name: &str) -> &str {
     name.split_whitespace()
@@ -8,11 +9,12 @@ pub fn extract_first_name(name: &str) -> &str {
 }
 
 pub fn format_priority(priority: Option<u8>) -> ColoredString {
+    let name = priority_label(priority.unwrap_or(0));
     match priority {
-        Some(4) => "Urgent".red().bold(),
-        Some(3) => "High".red(),
-        Some(2) => "Medium".yellow(),
-        Some(1) => "Low".normal(),
+        Some(4) => name.red().bold(),
+        Some(3) => name.red(),
+        Some(2) => name.yellow(),
+        Some(1) => name.normal(),
         _ => "None".dimmed(),
     }
 }
@@ -89,4 +91,33 @@ pub fn clean_description(desc: &str) -> String {
     } else {
         format!("{}.", trimmed)
     }
-}
\ No newline at end of file
+}
+/// Parse a `#rrggbb` or `#rgb` hex color (the leading `#` is optional).
+pub fn parse_hex_color(color: &str) -> Option<(u8, u8, u8)> {
+    let hex = color.trim().trim_start_matches('#');
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+    match hex.len() {
+        6 => Some((channel(&hex[0..2])?, channel(&hex[2..4])?, channel(&hex[4..6])?)),
+        3 => {
+            let expand = |i: usize| channel(&hex[i..i + 1]).map(|v| v * 17);
+            Some((expand(0)?, expand(1)?, expand(2)?))
+        }
+        _ => None,
+    }
+}
+
+/// Normalize a user supplied color to the `#rrggbb` form Linear expects.
+pub fn normalize_hex_color(color: &str) -> Option<String> {
+    parse_hex_color(color).map(|(r, g, b)| format!("#{:02x}{:02x}{:02x}", r, g, b))
+}
+
+/// A filled dot in the given hex color, or a dimmed dot if the color is invalid.
+pub fn color_swatch(color: &str) -> ColoredString {
+    match parse_hex_color(color) {
+        Some((r, g, b)) => "●".truecolor(r, g, b),
+        None => "●".dimmed(),
+    }
+}
@@ -120,7 +120,7 @@ pub mod selections {
         FieldSelection::new()
             .fields(&[
                 "id", "identifier", "title", "description", "priority",
-                "createdAt", "updatedAt", "completedAt", "canceledAt",
+                "createdAt", "updatedAt", "startedAt", "completedAt", "canceledAt",
                 "url", "branchName", "estimate"
             ])
             .nested("assignee", user_fields())
@@ -1,12 +1,24 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Instant;
-use crate::models::{Issue, WorkflowState, Comment};
+use crate::models::{thread_comments, Cycle, Issue, WorkflowState, Comment, User};
+use crate::resolver::{match_project, match_team, sort_states, team_states};
+use crate::templates::{load_local_templates, IssueTemplate};
+use crate::timer::{Journal, Session};
 use crate::client::LinearClient;
-use crate::config::get_api_key;
+use crate::config::{get_api_key, load_config};
+use crate::filtering::{
+    bind, builtin_values, check as filter_check, complete, diagnose, matches as filter_matches, parse_filter, parse_sort, sort_issues, Calendar, Completion,
+    Diagnostic, FilterAdapter, FilterError,
+    SortKey, Vocabulary, variables,
+};
 use crate::logging::log_error;
 use std::error::Error;
 
+/// Sort orders the issue list cycles through, applied within each group. A `sort:`
+/// clause in the filter takes precedence.
+pub const SORT_PRESETS: &[&str] = &["priority", "-updated", "-created", "title", "id"];
+
 // ---------------------------------------------------------------------------
 // Enums
 // ---------------------------------------------------------------------------
@@ -16,6 +28,7 @@ use std::error::Error;
 pub enum Focus {
     TeamList,
     ProjectList,
+    ViewList,
     IssueList,
     DetailPanel,
 }
@@ -29,10 +42,12 @@ pub enum Popup {
     LabelPicker,
     ProjectPicker,
     AssigneePicker,
+    TemplatePicker,
     TextInput(TextInputContext),
     Confirmation(ConfirmAction),
     CreateIssue,
     BulkActions,
+    ReactionPicker,
     Help,
 }
 
@@ -40,6 +55,8 @@ pub enum Popup {
 #[derive(Debug, Clone, PartialEq)]
 pub enum TextInputContext {
     Comment,
+    ReplyComment(String),
+    EditComment(String),
     EditTitle,
     EditDescription,
     Search,
@@ -51,8 +68,12 @@ pub enum TextInputContext {
 #[derive(Debug, Clone, PartialEq)]
 pub enum ConfirmAction {
     ArchiveIssue(String),
+    DeleteComment(String),
 }
 
+/// Emoji offered by the reaction picker
+pub const REACTION_EMOJIS: &[&str] = &["👍", "👎", "❤️", "🎉", "😄", "😕", "👀", "🚀"];
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GroupBy {
     Status,
@@ -72,6 +93,15 @@ pub enum DetailSection {
 // Supporting structs
 // ---------------------------------------------------------------------------
 
+/// A saved search or Linear custom view offered in the sidebar, with its filter
+/// already compiled to GraphQL.
+#[derive(Debug, Clone)]
+pub struct SidebarView {
+    pub name: String,
+    pub linear: bool,
+    pub filter: serde_json::Value,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct CreateIssueForm {
     pub title: String,
@@ -81,6 +111,9 @@ pub struct CreateIssueForm {
     pub project_id: Option<String>,
     pub label_ids: Vec<String>,
     pub assignee_id: Option<String>,
+    pub description: Option<String>,
+    pub estimate: Option<f64>,
+    pub template_name: Option<String>,
     pub active_field: usize,
 }
 
@@ -119,12 +152,16 @@ pub struct InteractiveApp {
     pub active_project: Option<usize>, // index into available_projects (0 = "All", 1+ = project)
     pub team_index: usize,             // cursor position in teams box
     pub project_index: usize,          // cursor position in projects box
+    pub saved_views: Vec<SidebarView>, // saved searches, then Linear custom views
+    pub active_view: Option<usize>,    // index into saved_views (None = no view)
+    pub view_index: usize,             // cursor position in views box
 
     // Issue list state
     pub issues: Vec<Issue>,
     pub filtered_issues: Vec<Issue>,
     pub selected_index: usize,
     pub group_by: GroupBy,
+    pub sort_index: usize, // index into SORT_PRESETS
     pub hide_done_issues: bool,
     pub multi_selected: HashSet<usize>,
 
@@ -134,10 +171,15 @@ pub struct InteractiveApp {
     pub comments: Vec<Comment>,
     pub comments_loading: bool,
     pub last_comment_issue_id: Option<String>,
+    pub comment_index: Option<usize>, // selected comment in thread order (None = no selection)
 
     // Search/filter
     pub search_query: String,
+    pub text_search: bool,                    // `/` searches Linear's full-text index
+    pub search_results: Option<Vec<Issue>>,   // full-text results, most relevant first
     pub filter_query: String,
+    pub vocabulary: Vocabulary,   // names offered by filter completion
+    pub completion_index: usize,  // selected suggestion in the filter popup
 
     // Text input (reusable for any popup text field)
     pub text_input: String,
@@ -162,6 +204,10 @@ pub struct InteractiveApp {
     pub available_labels: Vec<crate::models::issue::Label>,
     pub available_projects: Vec<crate::models::Project>,
     pub team_members: Vec<crate::models::User>,
+    pub templates: Vec<IssueTemplate>,
+    pub running_timer: Option<Session>, // from the local timer journal
+    pub viewer: Option<User>,
+    pub cycles: Vec<Cycle>, // behind `this-cycle` and friends in filters
 
     // App state
     pub should_quit: bool,
@@ -189,12 +235,16 @@ impl InteractiveApp {
             active_project: None,
             team_index: 0,
             project_index: 0,
+            saved_views: Vec::new(),
+            active_view: None,
+            view_index: 0,
 
             // Issue list
             issues: Vec::new(),
             filtered_issues: Vec::new(),
             selected_index: 0,
             group_by: GroupBy::Status,
+            sort_index: 0,
             hide_done_issues: false,
             multi_selected: HashSet::new(),
 
@@ -204,10 +254,15 @@ impl InteractiveApp {
             comments: Vec::new(),
             comments_loading: false,
             last_comment_issue_id: None,
+            comment_index: None,
 
             // Search/filter
             search_query: String::new(),
+            text_search: false,
+            search_results: None,
             filter_query: String::new(),
+            vocabulary: Vocabulary::default(),
+            completion_index: 0,
 
             // Text input
             text_input: String::new(),
@@ -232,6 +287,10 @@ impl InteractiveApp {
             available_labels: Vec::new(),
             available_projects: Vec::new(),
             team_members: Vec::new(),
+            templates: Vec::new(),
+            running_timer: None,
+            viewer: None,
+            cycles: Vec::new(),
 
             // App state
             should_quit: false,
@@ -243,13 +302,17 @@ impl InteractiveApp {
         };
 
         // Make all API calls in parallel for faster startup
-        let (issues_result, states_result, labels_result, projects_result, members_result, teams_result) = tokio::join!(
+        let (issues_result, states_result, labels_result, projects_result, members_result, teams_result, viewer_result, templates_result, views_result, cycles_result) = tokio::join!(
             app.client.get_issues(None, Some(100)),
             app.client.get_workflow_states(),
             app.client.get_labels(),
             app.client.get_projects(),
             app.client.get_team_members(),
-            app.client.get_teams()
+            app.client.get_teams(),
+            app.client.get_viewer(),
+            app.client.get_templates(),
+            app.client.get_custom_views(),
+            app.client.get_cycles()
         );
 
         // Handle issues result
@@ -319,6 +382,102 @@ impl InteractiveApp {
             }
         }
 
+        // Handle viewer result (needed to tell which comments are our own)
+        match viewer_result {
+            Ok(viewer) => {
+                app.viewer = Some(viewer);
+            }
+            Err(e) => {
+                log_error(&format!("Failed to fetch viewer: {}", e));
+            }
+        }
+
+        // Cycle dates for `this-cycle` and friends in the filter
+        match cycles_result {
+            Ok(cycles) => {
+                app.cycles = cycles;
+            }
+            Err(e) => {
+                log_error(&format!("Failed to fetch cycles: {}", e));
+            }
+        }
+
+        // Handle templates: local files first, then the workspace's Linear templates
+        match load_local_templates() {
+            Ok(templates) => app.templates = templates,
+            Err(e) => log_error(&format!("Failed to load local templates: {}", e)),
+        }
+        match templates_result {
+            Ok(templates) => {
+                app.templates.extend(templates.iter().filter_map(IssueTemplate::from_linear));
+            }
+            Err(e) => {
+                log_error(&format!("Failed to fetch templates: {}", e));
+            }
+        }
+
+        // Remember the names for filter completion, here and in the shell
+        app.vocabulary = Vocabulary {
+            labels: app.available_labels.iter().map(|l| l.name.clone()).collect(),
+            states: app.workflow_states.iter().map(|s| s.name.clone()).collect(),
+            members: app.team_members.iter().map(|u| u.name.clone()).collect(),
+            projects: app.available_projects.iter().map(|p| p.name.clone()).collect(),
+            teams: app.teams.iter().map(|t| t.key.clone()).collect(),
+        };
+        app.vocabulary.normalize();
+        if !app.vocabulary.is_empty() {
+            if let Err(e) = app.vocabulary.save() {
+                log_error(&format!("Failed to save completion cache: {}", e));
+            }
+        }
+
+        app.load_running_timer();
+
+        // Handle views: local saved searches first, then Linear custom views
+        // with `$me`, `$team` and `$cycle` filled in and other variables at their defaults
+        let config = load_config();
+        let default_team = config.default_team_id.as_deref().and_then(|team| match_team(&app.teams, team).ok());
+        let mut searches: Vec<(String, String)> = config.saved_searches.into_iter().collect();
+        searches.sort();
+        for (name, query) in searches {
+            // Only a search that uses `$cycle` can find it ambiguous
+            let uses_cycle = variables(&query).is_ok_and(|variables| variables.iter().any(|v| v.name == "cycle"));
+            let cycles: &[Cycle] = if uses_cycle { &app.cycles } else { &[] };
+            let query = match builtin_values(app.viewer.as_ref(), default_team, cycles).and_then(|values| {
+                bind(&query, &values).map_err(|e| e.to_string())
+            }) {
+                Ok(query) => query,
+                Err(e) => {
+                    log_error(&format!("Skipping saved search '{}': {}", name, e));
+                    continue;
+                }
+            };
+            let calendar = match Calendar::for_query(&query, &app.cycles, config.default_team_id.as_deref()) {
+                Ok(calendar) => calendar,
+                Err(e) => {
+                    log_error(&format!("Skipping saved search '{}': {}", name, e));
+                    continue;
+                }
+            };
+            match FilterAdapter::parse_and_build(&query, &calendar) {
+                Ok(filter) => app.saved_views.push(SidebarView { name, linear: false, filter }),
+                Err(e) => log_error(&format!("Skipping saved search '{}': {}", name, e)),
+            }
+        }
+        match views_result {
+            Ok(mut views) => {
+                views.sort_by_key(|v| v.name.to_lowercase());
+                app.saved_views.extend(views.into_iter().map(|view| SidebarView {
+                    name: view.name,
+                    linear: true,
+                    filter: view.filter_data,
+                }));
+            }
+            Err(e) => {
+                log_error(&format!("Failed to fetch custom views: {}", e));
+            }
+        }
+
         app.loading = false;
         Ok(app)
     }
@@ -328,10 +487,11 @@ impl InteractiveApp {
     // -----------------------------------------------------------------------
 
     pub fn apply_filters(&mut self) {
-        self.filtered_issues = self.issues.clone();
+        // Full-text results take the place of the loaded issues
+        self.filtered_issues = self.search_results.clone().unwrap_or_else(|| self.issues.clone());
 
         // Apply search filter (title/identifier)
-        if !self.search_query.is_empty() {
+        if !self.search_query.is_empty() && self.search_results.is_none() {
             let query = self.search_query.to_lowercase();
             self.filtered_issues.retain(|issue| {
                 issue.title.to_lowercase().contains(&query)
@@ -339,21 +499,34 @@ impl InteractiveApp {
             });
         }
 
-        // Apply advanced filter (searches all visible fields)
+        // Apply advanced filter: the filter language when it parses, otherwise plain text
+        // across all visible fields
         if !self.filter_query.is_empty() {
-            let query = self.filter_query.to_lowercase();
-            self.filtered_issues.retain(|issue| {
-                issue.title.to_lowercase().contains(&query)
-                    || issue.identifier.to_lowercase().contains(&query)
-                    || issue.state.name.to_lowercase().contains(&query)
-                    || issue.project.as_ref().map_or(false, |p| p.name.to_lowercase().contains(&query))
-                    || issue.assignee.as_ref().map_or(false, |a| {
-                        a.name.to_lowercase().contains(&query)
-                            || a.email.to_lowercase().contains(&query)
-                    })
-                    || issue.labels.nodes.iter().any(|l| l.name.to_lowercase().contains(&query))
-                    || issue.description.as_ref().map_or(false, |d| d.to_lowercase().contains(&query))
-            });
+            match parse_filter(&self.filter_query).map(|b| b.build()) {
+                Ok(Ok(expr)) => {
+                    let viewer_id = self.viewer.as_ref().map(|v| v.id.as_str());
+                    let calendar = self.filter_calendar().unwrap_or_else(|_| Calendar::from_config());
+                    self.filtered_issues
+                        .retain(|issue| filter_matches(&expr, issue, viewer_id, &calendar).unwrap_or(false));
+                }
+                // Nothing but a sort clause
+                Ok(Err(FilterError::EmptyFilter)) => {}
+                _ => {
+                    let query = self.filter_query.to_lowercase();
+                    self.filtered_issues.retain(|issue| {
+                        issue.title.to_lowercase().contains(&query)
+                            || issue.identifier.to_lowercase().contains(&query)
+                            || issue.state.name.to_lowercase().contains(&query)
+                            || issue.project.as_ref().is_some_and(|p| p.name.to_lowercase().contains(&query))
+                            || issue.assignee.as_ref().is_some_and(|a| {
+                                a.name.to_lowercase().contains(&query)
+                                    || a.email.to_lowercase().contains(&query)
+                            })
+                            || issue.labels.nodes.iter().any(|l| l.name.to_lowercase().contains(&query))
+                            || issue.description.as_ref().is_some_and(|d| d.to_lowercase().contains(&query))
+                    });
+                }
+            }
         }
 
         // Filter out done issues if toggle is on
@@ -363,15 +536,15 @@ impl InteractiveApp {
             });
         }
 
-        // Apply sorting based on group_by
+        // Sort by the active sort keys, then (stably) by group so each group keeps that order.
+        // Full-text results stay in order of relevance.
+        if self.search_results.is_none() {
+            let sort_keys = self.sort_keys();
+            sort_issues(&mut self.filtered_issues, &sort_keys);
+        }
         match self.group_by {
             GroupBy::Status => {
-                self.filtered_issues.sort_by(|a, b| {
-                    a.state
-                        .name
-                        .cmp(&b.state.name)
-                        .then(a.priority.cmp(&b.priority).reverse())
-                });
+                self.filtered_issues.sort_by(|a, b| a.state.name.cmp(&b.state.name));
             }
             GroupBy::Project => {
                 self.filtered_issues.sort_by(|a, b| {
@@ -380,7 +553,6 @@ impl InteractiveApp {
                     a_project
                         .cmp(&b_project)
                         .then(a.state.name.cmp(&b.state.name))
-                        .then(a.priority.cmp(&b.priority).reverse())
                 });
             }
         }
@@ -391,7 +563,53 @@ impl InteractiveApp {
         }
     }
 
-    /// Build the GraphQL IssueFilter based on active team and project selections
+    /// Suggestions for the filter being typed in the filter popup
+    pub fn filter_completions(&self) -> Vec<Completion> {
+        complete(&self.text_input, self.text_cursor, &self.vocabulary)
+    }
+
+    /// Problems with the filter being typed. Input without any operator is plain-text
+    /// search rather than a filter, so it has none.
+    pub fn filter_diagnostics(&self) -> Vec<Diagnostic> {
+        if !self.text_input.contains([':', '=', '<', '>', '~']) {
+            return Vec::new();
+        }
+        diagnose(&self.text_input, &self.vocabulary)
+    }
+
+    /// The calendar the filter is read in. Cycle anchors refer to the selected team's
+    /// cycles, or the default team's when all teams are shown.
+    pub fn filter_calendar(&self) -> Result<Calendar, String> {
+        let team = match self.active_team.and_then(|i| self.teams.get(i)) {
+            Some(team) => Some(team.id.clone()),
+            None => load_config().default_team_id,
+        };
+        Calendar::for_query(&self.filter_query, &self.cycles, team.as_deref())
+    }
+
+    /// Why the applied filter can't be checked against the loaded issues, if it can't.
+    pub fn filter_problem(&self) -> Option<String> {
+        let calendar = match self.filter_calendar() {
+            Ok(calendar) => calendar,
+            Err(e) => return Some(e),
+        };
+        let expr = parse_filter(&self.filter_query).ok()?.build().ok()?;
+        filter_check(&expr, self.viewer.as_ref().map(|v| v.id.as_str()), &calendar).err().map(|e| e.to_string())
+    }
+
+    /// The sort applied to the issue list: the filter's `sort:` clause if it has one,
+    /// otherwise the selected preset.
+    pub fn sort_keys(&self) -> Vec<SortKey> {
+        let clause = parse_filter(&self.filter_query)
+            .map(|b| b.sort_keys().to_vec())
+            .unwrap_or_default();
+        if !clause.is_empty() {
+            return clause;
+        }
+        parse_sort(SORT_PRESETS[self.sort_index % SORT_PRESETS.len()]).unwrap_or_default()
+    }
+
+    /// Build the GraphQL IssueFilter based on active team, project and view selections
     pub fn build_issue_filter(&self) -> Option<serde_json::Value> {
         let mut filter = serde_json::json!({});
         let mut has_filter = false;
@@ -413,12 +631,45 @@ impl InteractiveApp {
             }
         }
 
-        if has_filter { Some(filter) } else { None }
+        // A view's filter may use the same keys, so combine it with `and` instead of merging
+        let view_filter = self
+            .active_view
+            .and_then(|idx| self.saved_views.get(idx))
+            .map(|view| view.filter.clone())
+            .filter(|f| f.as_object().is_some_and(|o| !o.is_empty()));
+
+        match (has_filter, view_filter) {
+            (true, Some(view)) => Some(serde_json::json!({ "and": [filter, view] })),
+            (false, Some(view)) => Some(view),
+            (true, None) => Some(filter),
+            (false, None) => None,
+        }
+    }
+
+    /// Re-read the timer journal, which `linear timer` may have changed in another terminal.
+    pub fn load_running_timer(&mut self) {
+        match Journal::load() {
+            Ok(journal) => self.running_timer = journal.running().cloned(),
+            Err(e) => log_error(&format!("Failed to load timer journal: {}", e)),
+        }
+    }
+
+    /// Run `search_query` through Linear's full-text search, including comments.
+    pub async fn run_text_search(&mut self) -> Result<(), Box<dyn Error>> {
+        let results = self.client.search_issues(&self.search_query, None, Some(100), true).await?;
+        self.search_results = Some(results.into_iter().map(|result| result.issue).collect());
+        Ok(())
     }
 
     pub async fn refresh_issues(&mut self) -> Result<(), Box<dyn Error>> {
         self.loading = true;
         self.error_message = None;
+        self.load_running_timer();
+        if self.search_results.is_some() {
+            if let Err(e) = self.run_text_search().await {
+                log_error(&format!("Failed to refresh search results: {}", e));
+            }
+        }
 
         let filter = self.build_issue_filter();
         match self.client.get_issues(filter, Some(100)).await {
@@ -445,6 +696,102 @@ impl InteractiveApp {
         self.issues.iter().find(|i| i.id == id)
     }
 
+    /// Comments of the selected issue in thread order, with nesting depth
+    pub fn threaded_comments(&self) -> Vec<(usize, &Comment)> {
+        thread_comments(&self.comments)
+    }
+
+    pub fn get_selected_comment(&self) -> Option<&Comment> {
+        let index = self.comment_index?;
+        self.threaded_comments().get(index).map(|(_, c)| *c)
+    }
+
+    /// Whether the comment was written by the current user
+    pub fn is_own_comment(&self, comment: &Comment) -> bool {
+        match (&self.viewer, &comment.user) {
+            (Some(viewer), Some(author)) => viewer.id == author.id,
+            _ => false,
+        }
+    }
+
+    /// Team of the issue(s) a picker action applies to: the first multi-selected issue
+    /// in bulk mode, otherwise the selected issue.
+    pub fn target_team_id(&self) -> Option<&str> {
+        let issue = if self.bulk_mode && !self.multi_selected.is_empty() {
+            self.multi_selected
+                .iter()
+                .filter_map(|&idx| self.filtered_issues.get(idx))
+                .next()
+        } else {
+            self.get_selected_issue()
+        };
+        issue.map(|i| i.team.id.as_str())
+    }
+
+    /// Workflow states offered by the status picker: the target team's states in
+    /// board order, or every state if the team is unknown.
+    pub fn status_options(&self) -> Vec<&WorkflowState> {
+        let states = self
+            .target_team_id()
+            .map(|team_id| team_states(&self.workflow_states, team_id))
+            .unwrap_or_default();
+        if states.is_empty() {
+            let mut all: Vec<&WorkflowState> = self.workflow_states.iter().collect();
+            sort_states(&mut all);
+            all
+        } else {
+            states
+        }
+    }
+
+    /// Fill the create form from a template. The title typed so far fills the
+    /// template's `{{title}}` placeholder; teams, labels and projects are matched
+    /// against the loaded data by id or name.
+    pub fn apply_template(&mut self, index: usize) {
+        let Some(template) = self.templates.get(index).cloned() else { return };
+
+        let mut vars = HashMap::new();
+        vars.insert("title".to_string(), self.create_form.title.clone());
+        vars.insert("date".to_string(), chrono::Local::now().format("%Y-%m-%d").to_string());
+
+        let form = &mut self.create_form;
+        if let Some(title) = template.render_title(&vars) {
+            form.title = title;
+        }
+        form.description = template.render_description(&vars);
+        if let Some(team) = &template.team {
+            if let Some(team) = self.teams.iter().find(|t| {
+                t.id == *team || t.key.eq_ignore_ascii_case(team) || t.name.eq_ignore_ascii_case(team)
+            }) {
+                form.team_id = Some(team.id.clone());
+            }
+        }
+        if template.priority.is_some() {
+            form.priority = template.priority;
+        }
+        if template.estimate.is_some() {
+            form.estimate = template.estimate;
+        }
+        if let Some(project) = &template.project {
+            if let Ok(project) = match_project(&self.available_projects, project) {
+                form.project_id = Some(project.id.clone());
+            }
+        }
+        for label in &template.labels {
+            let found = self
+                .available_labels
+                .iter()
+                .find(|l| l.id == *label || l.name.eq_ignore_ascii_case(label));
+            if let Some(found) = found {
+                if !form.label_ids.contains(&found.id) {
+                    form.label_ids.push(found.id.clone());
+                }
+            }
+        }
+        form.template_name = Some(template.name);
+        self.text_cursor = form.title.len();
+    }
+
     pub fn get_multi_selected_issue_ids(&self) -> Vec<String> {
         self.multi_selected
             .iter()
@@ -24,10 +24,11 @@ impl EventHandler {
                 match event::poll(Duration::from_millis(tick_rate)) {
                     Ok(true) => {
                         match event::read() {
-                            Ok(CrosstermEvent::Key(key)) if key.kind == KeyEventKind::Press => {
-                                if sender_clone.send(Event::Key(key)).is_err() {
-                                    break;
-                                }
+                            Ok(CrosstermEvent::Key(key))
+                                if key.kind == KeyEventKind::Press
+                                    && sender_clone.send(Event::Key(key)).is_err() =>
+                            {
+                                break;
                             }
                             Ok(CrosstermEvent::Resize(_, _)) => {
                                 // Terminal resized — send a tick to trigger redraw
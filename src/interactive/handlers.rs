@@ -9,11 +9,13 @@ use std::time::Instant;
 use tokio::sync::mpsc as tokio_mpsc;
 
 use crate::config::get_api_key;
+use crate::filtering::priority_label;
 use crate::interactive::app::{
     ConfirmAction, CreateIssueForm, Focus, GroupBy, InteractiveApp, NotificationKind, Popup,
-    TextInputContext,
+    TextInputContext, REACTION_EMOJIS, SORT_PRESETS,
 };
 use crate::interactive::keys::{self, Action};
+use crate::resolver::resolve_state;
 use super::event::{Event, EventHandler};
 
 /// Result from a background comment fetch
@@ -61,6 +63,14 @@ pub async fn run_interactive_mode() -> Result<(), Box<dyn std::error::Error>> {
                     Ok(comments) => {
                         app.comments = comments;
                         app.comments_loading = false;
+                        // Keep the comment selection within bounds after a refetch
+                        if let Some(idx) = app.comment_index {
+                            app.comment_index = if app.comments.is_empty() {
+                                None
+                            } else {
+                                Some(idx.min(app.comments.len() - 1))
+                            };
+                        }
                     }
                     Err(_) => {
                         app.comments_loading = false;
@@ -148,11 +158,18 @@ pub async fn run_interactive_mode() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
 
-        // Schedule comment fetch if selected issue changed
+        // Schedule comment fetch if selected issue changed, or if a handler
+        // cleared `last_comment_issue_id` to force a refetch of the same issue
+        let force_refetch = app.last_comment_issue_id.is_none() && last_detail_issue_id.is_some();
         if let Some(issue) = app.get_selected_issue() {
             let issue_id = issue.id.clone();
-            if last_detail_issue_id.as_ref() != Some(&issue_id) {
+            let changed = last_detail_issue_id.as_ref() != Some(&issue_id);
+            if changed || force_refetch {
+                if changed {
+                    app.comment_index = None;
+                }
                 last_detail_issue_id = Some(issue_id.clone());
+                app.last_comment_issue_id = Some(issue_id.clone());
                 last_nav_time = Instant::now();
                 pending_comment_issue = Some(issue_id);
                 // Show loading immediately but don't block
@@ -254,6 +271,11 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
                         app.project_index -= 1;
                     }
                 }
+                Focus::ViewList => {
+                    if app.view_index > 0 {
+                        app.view_index -= 1;
+                    }
+                }
                 Focus::IssueList => {
                     if app.selected_index > 0 {
                         app.selected_index -= 1;
@@ -279,6 +301,11 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
                         app.project_index += 1;
                     }
                 }
+                Focus::ViewList => {
+                    if app.view_index < app.saved_views.len().saturating_sub(1) {
+                        app.view_index += 1;
+                    }
+                }
                 Focus::IssueList => {
                     if app.selected_index < app.filtered_issues.len().saturating_sub(1) {
                         app.selected_index += 1;
@@ -304,6 +331,9 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
                 Focus::ProjectList => {
                     app.project_index = app.project_index.saturating_sub(5);
                 }
+                Focus::ViewList => {
+                    app.view_index = app.view_index.saturating_sub(5);
+                }
                 Focus::IssueList => {
                     app.selected_index = app.selected_index.saturating_sub(5);
                     app.detail_scroll = 0;
@@ -324,6 +354,9 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
                     let max = app.available_projects.len();
                     app.project_index = (app.project_index + 5).min(max);
                 }
+                Focus::ViewList => {
+                    app.view_index = (app.view_index + 5).min(app.saved_views.len().saturating_sub(1));
+                }
                 Focus::IssueList => {
                     if !app.filtered_issues.is_empty() {
                         app.selected_index = (app.selected_index + 5).min(app.filtered_issues.len() - 1);
@@ -340,7 +373,8 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
         Action::SwitchPanel => {
             app.focus = match app.focus {
                 Focus::TeamList => Focus::ProjectList,
-                Focus::ProjectList => Focus::IssueList,
+                Focus::ProjectList => Focus::ViewList,
+                Focus::ViewList => Focus::IssueList,
                 Focus::IssueList => {
                     app.show_detail_fullscreen = true;
                     Focus::DetailPanel
@@ -359,7 +393,8 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
                     Focus::DetailPanel
                 }
                 Focus::ProjectList => Focus::TeamList,
-                Focus::IssueList => Focus::ProjectList,
+                Focus::ViewList => Focus::ProjectList,
+                Focus::IssueList => Focus::ViewList,
                 Focus::DetailPanel => {
                     app.show_detail_fullscreen = false;
                     Focus::IssueList
@@ -377,6 +412,65 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
             app.text_cursor = 0;
             app.popup = Some(Popup::TextInput(TextInputContext::Comment));
         }
+        Action::NextComment => {
+            let count = app.comments.len();
+            if count > 0 {
+                app.comment_index = Some(match app.comment_index {
+                    None => 0,
+                    Some(idx) => (idx + 1).min(count - 1),
+                });
+            }
+        }
+        Action::PrevComment => {
+            app.comment_index = match app.comment_index {
+                Some(idx) if idx > 0 => Some(idx - 1),
+                _ => None,
+            };
+        }
+        Action::ReplyComment => {
+            if let Some(comment) = app.get_selected_comment() {
+                let comment_id = comment.id.clone();
+                app.text_input.clear();
+                app.text_cursor = 0;
+                app.popup = Some(Popup::TextInput(TextInputContext::ReplyComment(comment_id)));
+            } else {
+                app.notify(NotificationKind::Info, "Select a comment with ] first".into());
+            }
+        }
+        Action::EditComment => {
+            if let Some(comment) = app.get_selected_comment() {
+                if app.is_own_comment(comment) {
+                    let comment_id = comment.id.clone();
+                    app.text_input = comment.body.clone();
+                    app.text_cursor = app.text_input.len();
+                    app.popup = Some(Popup::TextInput(TextInputContext::EditComment(comment_id)));
+                } else {
+                    app.notify(NotificationKind::Info, "You can only edit your own comments".into());
+                }
+            } else {
+                app.notify(NotificationKind::Info, "Select a comment with ] first".into());
+            }
+        }
+        Action::DeleteComment => {
+            if let Some(comment) = app.get_selected_comment() {
+                if app.is_own_comment(comment) {
+                    let comment_id = comment.id.clone();
+                    app.popup = Some(Popup::Confirmation(ConfirmAction::DeleteComment(comment_id)));
+                } else {
+                    app.notify(NotificationKind::Info, "You can only delete your own comments".into());
+                }
+            } else {
+                app.notify(NotificationKind::Info, "Select a comment with ] first".into());
+            }
+        }
+        Action::ReactComment => {
+            if app.get_selected_comment().is_some() {
+                app.picker_index = 0;
+                app.popup = Some(Popup::ReactionPicker);
+            } else {
+                app.notify(NotificationKind::Info, "Select a comment with ] first".into());
+            }
+        }
         Action::ChangeLabels => {
             app.picker_index = 0;
             // Pre-select current labels
@@ -410,8 +504,31 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
         Action::Filter => {
             app.text_input = app.filter_query.clone();
             app.text_cursor = app.text_input.len();
+            app.completion_index = 0;
             app.popup = Some(Popup::TextInput(TextInputContext::Filter));
         }
+        Action::NextCompletion => {
+            let count = app.filter_completions().len();
+            if count > 0 {
+                app.completion_index = (app.completion_index + 1) % count;
+            }
+        }
+        Action::PrevCompletion => {
+            let count = app.filter_completions().len();
+            if count > 0 {
+                app.completion_index = (app.completion_index + count - 1) % count;
+            }
+        }
+        Action::AcceptCompletion => {
+            let completions = app.filter_completions();
+            if let Some(completion) = completions.get(app.completion_index.min(completions.len().saturating_sub(1))) {
+                (app.text_input, app.text_cursor) = completion.apply(&app.text_input, app.text_cursor);
+                app.completion_index = 0;
+            }
+        }
+        Action::ToggleTextSearch => {
+            app.text_search = !app.text_search;
+        }
         Action::Help => {
             app.popup = Some(Popup::Help);
         }
@@ -466,12 +583,14 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
         }
         Action::PickerDown => {
             let max = match &app.popup {
-                Some(Popup::StatusPicker) => app.workflow_states.len().saturating_sub(1),
+                Some(Popup::StatusPicker) => app.status_options().len().saturating_sub(1),
                 Some(Popup::PriorityPicker) => 4,
                 Some(Popup::LabelPicker) => app.available_labels.len().saturating_sub(1),
                 Some(Popup::ProjectPicker) => app.available_projects.len(), // includes "None" at 0
                 Some(Popup::AssigneePicker) => app.team_members.len(),      // includes "Unassign" at 0
+                Some(Popup::TemplatePicker) => app.templates.len().saturating_sub(1),
                 Some(Popup::BulkActions) => 5,
+                Some(Popup::ReactionPicker) => REACTION_EMOJIS.len() - 1,
                 _ => 0,
             };
             if app.picker_index < max {
@@ -480,8 +599,13 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
         }
         Action::PickerConfirm => handle_picker_confirm(app).await,
         Action::PickerCancel => {
-            app.popup = None;
-            app.bulk_mode = false;
+            if matches!(app.popup, Some(Popup::TemplatePicker)) {
+                // Back to the form the picker was opened from
+                app.popup = Some(Popup::CreateIssue);
+            } else {
+                app.popup = None;
+                app.bulk_mode = false;
+            }
         }
         Action::PickerToggle => handle_picker_toggle(app),
 
@@ -503,6 +627,10 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
             };
             app.apply_filters();
         }
+        Action::CycleSort => {
+            app.sort_index = (app.sort_index + 1) % SORT_PRESETS.len();
+            app.apply_filters();
+        }
         Action::Refresh => {
             let nid = app.notify(NotificationKind::Loading, "Refreshing issues...".into());
             match app.refresh_issues().await {
@@ -566,6 +694,28 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
                 }
             }
         }
+        Action::SelectView => {
+            if app.view_index < app.saved_views.len() {
+                let was_same = app.active_view == Some(app.view_index);
+                app.active_view = if was_same { None } else { Some(app.view_index) };
+                app.selected_index = 0;
+                app.detail_scroll = 0;
+                let msg = if was_same {
+                    "View cleared".to_string()
+                } else {
+                    format!("View: {}", app.saved_views[app.view_index].name)
+                };
+                let nid = app.notify(NotificationKind::Loading, msg.clone());
+                match app.refresh_issues().await {
+                    Ok(_) => app.replace_notification(nid, NotificationKind::Success, msg),
+                    Err(e) => app.replace_notification(
+                        nid,
+                        NotificationKind::Error,
+                        format!("Failed: {}", e),
+                    ),
+                }
+            }
+        }
         Action::SelectProject => {
             let max_idx = app.available_projects.len(); // 0=All, 1..=len=projects
             if app.project_index <= max_idx {
@@ -608,18 +758,37 @@ async fn handle_action(app: &mut InteractiveApp, action: Action) {
 // Text input helpers
 // ---------------------------------------------------------------------------
 
+/// The text the cursor is in. The cursor is a byte offset that is always kept
+/// on a char boundary.
+fn edited_text(app: &InteractiveApp) -> &str {
+    match &app.popup {
+        Some(Popup::CreateIssue) => &app.create_form.title,
+        Some(Popup::TextInput(_)) => &app.text_input,
+        _ => "",
+    }
+}
+
+/// Where the char before `cursor` starts
+fn prev_boundary(text: &str, cursor: usize) -> usize {
+    text.get(..cursor).and_then(|before| before.char_indices().next_back()).map_or(0, |(index, _)| index)
+}
+
+/// Where the char after `cursor` ends
+fn next_boundary(text: &str, cursor: usize) -> usize {
+    text.get(cursor..).and_then(|after| after.chars().next()).map_or(cursor.min(text.len()), |c| cursor + c.len_utf8())
+}
+
 fn handle_type_char(app: &mut InteractiveApp, c: char) {
     match &app.popup {
-        Some(Popup::CreateIssue) => {
-            // Only type into title field (active_field == 0)
-            if app.create_form.active_field == 0 {
-                app.create_form.title.insert(app.text_cursor, c);
-                app.text_cursor += 1;
-            }
+        // Only type into title field (active_field == 0)
+        Some(Popup::CreateIssue) if app.create_form.active_field == 0 => {
+            app.create_form.title.insert(app.text_cursor, c);
+            app.text_cursor += c.len_utf8();
         }
         Some(Popup::TextInput(_)) => {
             app.text_input.insert(app.text_cursor, c);
-            app.text_cursor += 1;
+            app.text_cursor += c.len_utf8();
+            app.completion_index = 0;
         }
         _ => {}
     }
@@ -627,46 +796,31 @@ fn handle_type_char(app: &mut InteractiveApp, c: char) {
 
 fn handle_backspace(app: &mut InteractiveApp) {
     match &app.popup {
-        Some(Popup::CreateIssue) => {
-            if app.create_form.active_field == 0 && app.text_cursor > 0 {
-                app.text_cursor -= 1;
-                app.create_form.title.remove(app.text_cursor);
-            }
+        Some(Popup::CreateIssue) if app.create_form.active_field == 0 && app.text_cursor > 0 => {
+            app.text_cursor = prev_boundary(&app.create_form.title, app.text_cursor);
+            app.create_form.title.remove(app.text_cursor);
         }
-        Some(Popup::TextInput(_)) => {
-            if app.text_cursor > 0 {
-                app.text_cursor -= 1;
-                app.text_input.remove(app.text_cursor);
-            }
+        Some(Popup::TextInput(_)) if app.text_cursor > 0 => {
+            app.text_cursor = prev_boundary(&app.text_input, app.text_cursor);
+            app.text_input.remove(app.text_cursor);
+            app.completion_index = 0;
         }
         _ => {}
     }
 }
 
 fn handle_delete(app: &mut InteractiveApp) {
-    match &app.popup {
-        Some(Popup::TextInput(_)) => {
-            if app.text_cursor < app.text_input.len() {
-                app.text_input.remove(app.text_cursor);
-            }
-        }
-        _ => {}
+    if matches!(app.popup, Some(Popup::TextInput(_))) && app.text_cursor < app.text_input.len() {
+        app.text_input.remove(app.text_cursor);
     }
 }
 
 fn handle_cursor_left(app: &mut InteractiveApp) {
-    app.text_cursor = app.text_cursor.saturating_sub(1);
+    app.text_cursor = prev_boundary(edited_text(app), app.text_cursor);
 }
 
 fn handle_cursor_right(app: &mut InteractiveApp) {
-    let max = match &app.popup {
-        Some(Popup::CreateIssue) => app.create_form.title.len(),
-        Some(Popup::TextInput(_)) => app.text_input.len(),
-        _ => 0,
-    };
-    if app.text_cursor < max {
-        app.text_cursor += 1;
-    }
+    app.text_cursor = next_boundary(edited_text(app), app.text_cursor);
 }
 
 fn handle_cursor_home(app: &mut InteractiveApp) {
@@ -674,26 +828,18 @@ fn handle_cursor_home(app: &mut InteractiveApp) {
 }
 
 fn handle_cursor_end(app: &mut InteractiveApp) {
-    app.text_cursor = match &app.popup {
-        Some(Popup::CreateIssue) => app.create_form.title.len(),
-        Some(Popup::TextInput(_)) => app.text_input.len(),
-        _ => 0,
-    };
+    app.text_cursor = edited_text(app).len();
 }
 
 fn handle_next_field(app: &mut InteractiveApp) {
-    if matches!(app.popup, Some(Popup::CreateIssue)) {
-        if app.create_form.active_field < 6 {
-            app.create_form.active_field += 1;
-        }
+    if matches!(app.popup, Some(Popup::CreateIssue)) && app.create_form.active_field < 7 {
+        app.create_form.active_field += 1;
     }
 }
 
 fn handle_prev_field(app: &mut InteractiveApp) {
-    if matches!(app.popup, Some(Popup::CreateIssue)) {
-        if app.create_form.active_field > 0 {
-            app.create_form.active_field -= 1;
-        }
+    if matches!(app.popup, Some(Popup::CreateIssue)) && app.create_form.active_field > 0 {
+        app.create_form.active_field -= 1;
     }
 }
 
@@ -711,7 +857,7 @@ async fn handle_confirm(app: &mut InteractiveApp) {
                 if !body.trim().is_empty() {
                     app.popup = None;
                     let nid = app.notify(NotificationKind::Loading, "Adding comment...".into());
-                    match app.client.create_comment(&issue_id, &body).await {
+                    match app.client.create_comment(&issue_id, &body, None).await {
                         Ok(_) => {
                             app.replace_notification(
                                 nid,
@@ -732,13 +878,85 @@ async fn handle_confirm(app: &mut InteractiveApp) {
                 }
             }
         }
+        Some(Popup::TextInput(TextInputContext::ReplyComment(parent_id))) => {
+            if let Some(issue) = app.get_selected_issue() {
+                let issue_id = issue.id.clone();
+                let body = app.text_input.clone();
+                if !body.trim().is_empty() {
+                    app.popup = None;
+                    let nid = app.notify(NotificationKind::Loading, "Adding reply...".into());
+                    match app.client.create_comment(&issue_id, &body, Some(&parent_id)).await {
+                        Ok(_) => {
+                            app.replace_notification(
+                                nid,
+                                NotificationKind::Success,
+                                "Reply added".into(),
+                            );
+                            app.last_comment_issue_id = None;
+                        }
+                        Err(e) => {
+                            app.replace_notification(
+                                nid,
+                                NotificationKind::Error,
+                                format!("Failed: {}", e),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        Some(Popup::TextInput(TextInputContext::EditComment(comment_id))) => {
+            let body = app.text_input.clone();
+            if !body.trim().is_empty() {
+                app.popup = None;
+                let nid = app.notify(NotificationKind::Loading, "Updating comment...".into());
+                match app.client.update_comment(&comment_id, &body).await {
+                    Ok(_) => {
+                        app.replace_notification(
+                            nid,
+                            NotificationKind::Success,
+                            "Comment updated".into(),
+                        );
+                        app.last_comment_issue_id = None;
+                    }
+                    Err(e) => {
+                        app.replace_notification(
+                            nid,
+                            NotificationKind::Error,
+                            format!("Failed: {}", e),
+                        );
+                    }
+                }
+            }
+        }
         Some(Popup::TextInput(TextInputContext::Search)) => {
             app.search_query = app.text_input.clone();
-            app.apply_filters();
+            app.search_results = None;
             app.popup = None;
+            if app.text_search && !app.search_query.trim().is_empty() {
+                let nid = app.notify(NotificationKind::Loading, "Searching...".into());
+                match app.run_text_search().await {
+                    Ok(()) => {
+                        let count = app.search_results.as_ref().map_or(0, Vec::len);
+                        app.replace_notification(nid, NotificationKind::Success, format!("{} issues found", count));
+                    }
+                    Err(e) => {
+                        app.replace_notification(nid, NotificationKind::Error, format!("Search failed: {}", e));
+                    }
+                }
+            }
+            app.apply_filters();
+            app.selected_index = 0;
         }
         Some(Popup::TextInput(TextInputContext::Filter)) => {
             app.filter_query = app.text_input.clone();
+            if let Some(diagnostic) = app.filter_diagnostics().into_iter().next() {
+                let kind = if diagnostic.is_error() { NotificationKind::Error } else { NotificationKind::Info };
+                let help = diagnostic.help.map(|help| format!(" ({})", help)).unwrap_or_default();
+                app.notify(kind, format!("Filter: {}{}", diagnostic.message, help));
+            } else if let Some(problem) = app.filter_problem() {
+                app.notify(NotificationKind::Error, format!("Filter: {}", problem));
+            }
             app.apply_filters();
             app.selected_index = 0;
             app.detail_scroll = 0;
@@ -827,6 +1045,42 @@ async fn handle_confirm(app: &mut InteractiveApp) {
                 }
             }
         }
+        Some(Popup::Confirmation(ConfirmAction::DeleteComment(comment_id))) => {
+            app.popup = None;
+            let nid = app.notify(NotificationKind::Loading, "Deleting comment...".into());
+            match app.client.delete_comment(&comment_id).await {
+                Ok(true) => {
+                    app.replace_notification(
+                        nid,
+                        NotificationKind::Success,
+                        "Comment deleted".into(),
+                    );
+                    app.last_comment_issue_id = None;
+                }
+                Ok(false) => {
+                    app.replace_notification(
+                        nid,
+                        NotificationKind::Error,
+                        "Failed to delete comment".into(),
+                    );
+                }
+                Err(e) => {
+                    app.replace_notification(
+                        nid,
+                        NotificationKind::Error,
+                        format!("Failed: {}", e),
+                    );
+                }
+            }
+        }
+        Some(Popup::CreateIssue) if app.create_form.active_field == 7 => {
+            if app.templates.is_empty() {
+                app.notify(NotificationKind::Error, "No issue templates found".into());
+            } else {
+                app.picker_index = 0;
+                app.popup = Some(Popup::TemplatePicker);
+            }
+        }
         Some(Popup::CreateIssue) => {
             // Submit issue creation
             if !app.create_form.title.trim().is_empty() {
@@ -854,11 +1108,13 @@ async fn handle_confirm(app: &mut InteractiveApp) {
                         .client
                         .create_issue(
                             &title,
-                            None,
+                            app.create_form.description.as_deref(),
                             &team_id,
                             app.create_form.priority,
                             app.create_form.assignee_id.as_deref(),
                             labels_arg,
+                            app.create_form.project_id.as_deref(),
+                            app.create_form.estimate,
                         )
                         .await
                     {
@@ -934,21 +1190,39 @@ async fn finish_update(app: &mut InteractiveApp, nid: u64, success: usize, total
 async fn handle_picker_confirm(app: &mut InteractiveApp) {
     let popup = app.popup.clone();
     match popup {
+        Some(Popup::TemplatePicker) => {
+            app.apply_template(app.picker_index);
+            app.popup = Some(Popup::CreateIssue);
+        }
         Some(Popup::StatusPicker) => {
-            if let Some(state) = app.workflow_states.get(app.picker_index) {
-                let state_id = state.id.clone();
-                let state_name = state.name.clone();
+            if let Some(state) = app.status_options().get(app.picker_index).map(|s| (*s).clone()) {
                 let ids = get_target_ids(app);
                 if !ids.is_empty() {
                     app.popup = None;
-                    let action = format!("Status -> {}", state_name);
+                    let action = format!("Status -> {}", state.name);
                     let nid = app.notify(NotificationKind::Loading, format!("{}...", action));
                     let mut ok = 0;
                     let mut err = String::new();
                     for id in &ids {
-                        match app.client.update_issue(id, None, None, Some(&state_id), None, None, None).await {
+                        // In bulk mode issues may span teams: map the chosen state onto
+                        // each issue's own team by name, falling back to its type
+                        let team_id = app.issues.iter().find(|i| i.id == *id).map(|i| i.team.id.clone());
+                        let state_id = match team_id {
+                            Some(team_id) if state.team.as_ref().is_some_and(|t| t.id != team_id) => {
+                                resolve_state(&app.workflow_states, &team_id, &state.name)
+                                    .or_else(|_| resolve_state(&app.workflow_states, &team_id, &state.state_type))
+                                    .map(|s| s.id.clone())
+                            }
+                            _ => Ok(state.id.clone()),
+                        };
+                        let result = match state_id {
+                            Ok(state_id) => app.client.update_issue(id, None, None, Some(&state_id), None, None, None).await
+                                .map_err(|e| e.to_string()),
+                            Err(e) => Err(e.to_string()),
+                        };
+                        match result {
                             Ok(_) => ok += 1,
-                            Err(e) => err = e.to_string(),
+                            Err(e) => err = e,
                         }
                     }
                     finish_update(app, nid, ok, ids.len(), &action, &err).await;
@@ -957,8 +1231,7 @@ async fn handle_picker_confirm(app: &mut InteractiveApp) {
         }
         Some(Popup::PriorityPicker) => {
             let priority = app.picker_index as u8;
-            let names = ["None", "Low", "Medium", "High", "Urgent"];
-            let name = names.get(app.picker_index).unwrap_or(&"Unknown").to_string();
+            let name = priority_label(priority);
             let ids = get_target_ids(app);
             if !ids.is_empty() {
                 app.popup = None;
@@ -1064,6 +1337,45 @@ async fn handle_picker_confirm(app: &mut InteractiveApp) {
                 }
             }
         }
+        Some(Popup::ReactionPicker) => {
+            let Some(emoji) = REACTION_EMOJIS.get(app.picker_index).copied() else {
+                app.popup = None;
+                return;
+            };
+            let Some(comment) = app.get_selected_comment() else {
+                app.popup = None;
+                return;
+            };
+            let comment_id = comment.id.clone();
+            // Picking an emoji we already reacted with removes that reaction
+            let existing = comment
+                .reactions
+                .iter()
+                .find(|r| {
+                    r.emoji == emoji
+                        && matches!((&r.user, &app.viewer), (Some(u), Some(v)) if u.id == v.id)
+                })
+                .map(|r| r.id.clone());
+            app.popup = None;
+            let result = match &existing {
+                Some(reaction_id) => app.client.delete_reaction(reaction_id).await.map(|_| ()),
+                None => app.client.create_reaction(&comment_id, emoji).await.map(|_| ()),
+            };
+            match result {
+                Ok(_) => {
+                    let msg = if existing.is_some() {
+                        format!("Removed {}", emoji)
+                    } else {
+                        format!("Reacted {}", emoji)
+                    };
+                    app.notify(NotificationKind::Success, msg);
+                    app.last_comment_issue_id = None;
+                }
+                Err(e) => {
+                    app.notify(NotificationKind::Error, format!("Failed: {}", e));
+                }
+            }
+        }
         Some(Popup::BulkActions) => {
             // Bulk action selection - open the appropriate picker in bulk mode
             app.bulk_mode = true;
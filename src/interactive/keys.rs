@@ -1,6 +1,7 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use crate::interactive::app::{Focus, Popup, TextInputContext};
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone, Copy)]
 pub enum Action {
     // Navigation
@@ -27,6 +28,15 @@ pub enum Action {
     ToggleDone,
     Refresh,
     GroupBy,
+    CycleSort,
+
+    // Comment actions
+    NextComment,
+    PrevComment,
+    ReplyComment,
+    EditComment,
+    DeleteComment,
+    ReactComment,
 
     // Multi-select
     ToggleSelect,
@@ -36,6 +46,10 @@ pub enum Action {
     // Search / Filter
     Search,
     Filter,
+    NextCompletion,
+    PrevCompletion,
+    AcceptCompletion,
+    ToggleTextSearch,
 
     // Popup: text input
     Confirm,
@@ -62,6 +76,7 @@ pub enum Action {
     // Team/Project selection
     SelectTeam,
     SelectProject,
+    SelectView,
 
     // General
     Help,
@@ -80,6 +95,7 @@ pub fn map_key(key: KeyEvent, focus: &Focus, popup: &Option<Popup>) -> Action {
     match focus {
         Focus::TeamList => map_team_key(key),
         Focus::ProjectList => map_project_key(key),
+        Focus::ViewList => map_view_key(key),
         Focus::IssueList => map_list_key(key),
         Focus::DetailPanel => map_detail_key(key),
     }
@@ -128,6 +144,22 @@ fn map_project_key(key: KeyEvent) -> Action {
     }
 }
 
+fn map_view_key(key: KeyEvent) -> Action {
+    if is_shift_nav_down(&key) { return Action::MoveDownFast; }
+    if is_shift_nav_up(&key) { return Action::MoveUpFast; }
+    match key.code {
+        KeyCode::Char('q') => Action::Quit,
+        KeyCode::Char('j') | KeyCode::Down => Action::MoveDown,
+        KeyCode::Char('k') | KeyCode::Up => Action::MoveUp,
+        KeyCode::Enter => Action::SelectView,
+        KeyCode::Tab => Action::SwitchPanel,
+        KeyCode::BackTab => Action::FocusList,
+        KeyCode::Char('?') => Action::Help,
+        KeyCode::Char('r') => Action::Refresh,
+        _ => Action::None,
+    }
+}
+
 fn map_list_key(key: KeyEvent) -> Action {
     if is_shift_nav_down(&key) { return Action::MoveDownFast; }
     if is_shift_nav_up(&key) { return Action::MoveUpFast; }
@@ -147,6 +179,7 @@ fn map_list_key(key: KeyEvent) -> Action {
         KeyCode::Char('d') => Action::ToggleDone,
         KeyCode::Char('r') => Action::Refresh,
         KeyCode::Char('g') => Action::GroupBy,
+        KeyCode::Char('S') => Action::CycleSort,
         KeyCode::Char('/') => Action::Search,
         KeyCode::Char('f') => Action::Filter,
         KeyCode::Char('x') => Action::ToggleSelect,
@@ -172,6 +205,12 @@ fn map_detail_key(key: KeyEvent) -> Action {
         KeyCode::Char('a') => Action::ChangeAssignee,
         KeyCode::Char('e') => Action::EditFull,
         KeyCode::Char('o') => Action::OpenInBrowser,
+        KeyCode::Char(']') => Action::NextComment,
+        KeyCode::Char('[') => Action::PrevComment,
+        KeyCode::Char('R') => Action::ReplyComment,
+        KeyCode::Char('E') => Action::EditComment,
+        KeyCode::Char('D') => Action::DeleteComment,
+        KeyCode::Char('+') => Action::ReactComment,
         KeyCode::Char('?') => Action::Help,
         _ => Action::None,
     }
@@ -189,6 +228,10 @@ fn map_popup_key(key: KeyEvent, popup: &Popup) -> Action {
             KeyCode::Home => Action::CursorHome,
             KeyCode::End => Action::CursorEnd,
             KeyCode::Char('\x05') if *ctx == TextInputContext::EditDescription => Action::ExternalEditor,
+            KeyCode::Tab if *ctx == TextInputContext::Filter => Action::AcceptCompletion,
+            KeyCode::Tab if *ctx == TextInputContext::Search => Action::ToggleTextSearch,
+            KeyCode::Down if *ctx == TextInputContext::Filter => Action::NextCompletion,
+            KeyCode::Up if *ctx == TextInputContext::Filter => Action::PrevCompletion,
             KeyCode::Char(c) => Action::TypeChar(c),
             _ => Action::None,
         },
@@ -219,7 +262,7 @@ fn map_popup_key(key: KeyEvent, popup: &Popup) -> Action {
             KeyCode::Esc | KeyCode::Char('q') => Action::PickerCancel,
             _ => Action::None,
         },
-        // All pickers: Status, Priority, Label, Project, Assignee
+        // All pickers: Status, Priority, Label, Project, Assignee, Reaction
         _ => match key.code {
             KeyCode::Char('j') | KeyCode::Down => Action::PickerDown,
             KeyCode::Char('k') | KeyCode::Up => Action::PickerUp,
@@ -71,26 +71,29 @@ pub fn centered_popup(width: u16, height: u16, area: Rect) -> Rect {
     Rect::new(x, y, width.min(area.width), height.min(area.height))
 }
 
-/// Left column split: teams, projects, issues
+/// Left column split: teams, projects, views, issues
 pub struct LeftColumnLayout {
     pub teams: Rect,
     pub projects: Rect,
+    pub views: Rect,
     pub issues: Rect,
 }
 
-/// Split the left column into teams box, projects box, and issue list.
-/// Teams and projects get fixed height based on item count (max 5 rows + 2 for borders).
-/// Issues get the remaining space.
-pub fn left_column_layout(area: Rect, team_count: usize, project_count: usize) -> LeftColumnLayout {
+/// Split the left column into teams box, projects box, views box, and issue list.
+/// Teams, projects and views get fixed height based on item count (max 5 rows + 2
+/// for borders). Issues get the remaining space.
+pub fn left_column_layout(area: Rect, team_count: usize, project_count: usize, view_count: usize) -> LeftColumnLayout {
     // Each box needs item_count rows + 2 for borders, capped at 7 (5 visible + 2 borders)
-    let teams_height = ((team_count as u16) + 2).min(7).max(3);
-    let projects_height = ((project_count as u16) + 2).min(7).max(3);
+    let teams_height = ((team_count as u16) + 2).clamp(3, 7);
+    let projects_height = ((project_count as u16) + 2).clamp(3, 7);
+    let views_height = ((view_count as u16) + 2).clamp(3, 7);
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(teams_height),
             Constraint::Length(projects_height),
+            Constraint::Length(views_height),
             Constraint::Min(5),
         ])
         .split(area);
@@ -98,6 +101,7 @@ pub fn left_column_layout(area: Rect, team_count: usize, project_count: usize) -
     LeftColumnLayout {
         teams: chunks[0],
         projects: chunks[1],
-        issues: chunks[2],
+        views: chunks[2],
+        issues: chunks[3],
     }
 }
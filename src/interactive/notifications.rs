@@ -1,10 +1,11 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
+use crate::formatting::theme::{tui_color, tui_fg, SemanticColor};
 use crate::interactive::app::{InteractiveApp, NotificationKind};
 
 pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
@@ -14,7 +15,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(tui_fg(SemanticColor::Muted));
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -24,10 +25,10 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
         .take(3)
         .map(|n| {
             let (icon, color) = match n.kind {
-                NotificationKind::Success => ("✓", Color::Green),
-                NotificationKind::Error => ("✗", Color::Red),
-                NotificationKind::Loading => ("⟳", Color::Yellow),
-                NotificationKind::Info => ("ⓘ", Color::Blue),
+                NotificationKind::Success => ("✓", tui_color(SemanticColor::Success)),
+                NotificationKind::Error => ("✗", tui_color(SemanticColor::Error)),
+                NotificationKind::Loading => ("⟳", tui_color(SemanticColor::Warning)),
+                NotificationKind::Info => ("ⓘ", tui_color(SemanticColor::Info)),
             };
             let elapsed = n.created_at.elapsed().as_secs();
             let timer = match n.kind {
@@ -40,7 +41,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
             Line::from(vec![
                 Span::styled(format!(" {} ", icon), Style::default().fg(color).add_modifier(Modifier::BOLD)),
                 Span::styled(n.message.clone(), Style::default().fg(color)),
-                Span::styled(format!("  {}", timer), Style::default().fg(Color::DarkGray)),
+                Span::styled(format!("  {}", timer), tui_fg(SemanticColor::Muted)),
             ])
         })
         .collect();
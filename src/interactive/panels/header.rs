@@ -1,13 +1,18 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::Paragraph,
     Frame,
 };
 
+use chrono::Utc;
+
 use super::list::truncate;
+use crate::formatting::theme::{tui_color, tui_fg, SemanticColor};
 use crate::interactive::app::{GroupBy, InteractiveApp};
+use crate::filtering::format_sort;
+use crate::timer::format_duration;
 
 pub fn draw_header(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let width = area.width as usize;
@@ -19,45 +24,57 @@ pub fn draw_header(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
         vec![
             Span::styled(
                 format!(" {} ", issue.identifier),
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                tui_fg(SemanticColor::Accent).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(title, Style::default().fg(Color::White)),
+            Span::styled(title, tui_fg(SemanticColor::Primary)),
         ]
     } else {
         vec![Span::styled(
             " Linear CLI",
-            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            tui_fg(SemanticColor::Accent).add_modifier(Modifier::BOLD),
         )]
     };
 
     // Right: status indicators
     let mut right_parts = Vec::new();
 
+    if let Some(session) = &app.running_timer {
+        right_parts.push(Span::styled(
+            format!("▶ {} {}  ", session.identifier, format_duration(session.seconds(Utc::now()))),
+            tui_fg(SemanticColor::Success).add_modifier(Modifier::BOLD),
+        ));
+    }
+
     let group_label = match app.group_by {
         GroupBy::Status => "status",
         GroupBy::Project => "project",
     };
     right_parts.push(Span::styled(
         format!("group:{}", group_label),
-        Style::default().fg(Color::DarkGray),
+        tui_fg(SemanticColor::Muted),
+    ));
+
+    right_parts.push(Span::styled(
+        format!(" sort:{}", format_sort(&app.sort_keys())),
+        tui_fg(SemanticColor::Muted),
     ));
 
     if app.hide_done_issues {
-        right_parts.push(Span::styled(" hide:done", Style::default().fg(Color::DarkGray)));
+        right_parts.push(Span::styled(" hide:done", tui_fg(SemanticColor::Muted)));
     }
 
     if !app.filter_query.is_empty() {
         right_parts.push(Span::styled(
             format!(" filter:{}", truncate(&app.filter_query, 12)),
-            Style::default().fg(Color::Yellow),
+            tui_fg(SemanticColor::Warning),
         ));
     }
 
     right_parts.push(Span::raw(" "));
 
     // Calculate right side width to pad correctly
-    let right_text_len: usize = right_parts.iter().map(|s| s.content.len()).sum();
-    let left_text_len: usize = left.iter().map(|s| s.content.len()).sum();
+    let right_text_len: usize = right_parts.iter().map(|s| s.content.chars().count()).sum();
+    let left_text_len: usize = left.iter().map(|s| s.content.chars().count()).sum();
     let pad = width.saturating_sub(left_text_len + right_text_len);
 
     let mut spans = left;
@@ -65,6 +82,6 @@ pub fn draw_header(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     spans.extend(right_parts);
 
     let header = Paragraph::new(Line::from(spans))
-        .style(Style::default().bg(Color::Rgb(20, 22, 30)));
+        .style(Style::default().bg(tui_color(SemanticColor::Surface)));
     frame.render_widget(header, area);
 }
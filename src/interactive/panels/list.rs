@@ -8,6 +8,9 @@ use ratatui::{
 
 use chrono::{DateTime, Utc};
 
+use crate::formatting::table::{display_width, fit_widths, pad_to_width, truncate_to_width, WidthRule};
+use crate::formatting::theme::helpers::{priority_color, priority_symbol};
+use crate::formatting::theme::{helpers, tui_color, tui_fg, tui_highlight, SemanticColor};
 use crate::interactive::app::{Focus, InteractiveApp};
 use crate::models::Issue;
 
@@ -37,53 +40,39 @@ pub struct ColumnWidths {
 pub fn calculate_column_widths(available_width: u16) -> ColumnWidths {
     let width = available_width as usize;
 
-    // Title-first approach: fixed columns take what they need, title gets the rest.
-    // Columns are progressively added as width allows.
-    let id_w = 7;
-    let pri_w = 3; // symbol + 2 padding
-    let status_w = 10;
-    let age_w = 5;
-
-    // Always-on columns cost (with 1-char separators between each)
-    let fixed_cost = id_w + pri_w + status_w + age_w;
-
-    // Optional column sizes
-    let project_w = 10;
-    let labels_w = 12;
-    let assignee_w = 10;
-    let links_w = 3;
-
-    // Determine which optional columns fit. We need at least 15 chars for title.
-    let mut used = fixed_cost;
-    let show_project = width.saturating_sub(used + project_w) >= 15;
-    if show_project { used += project_w; }
-
-    let show_labels = width >= 120 && width.saturating_sub(used + labels_w) >= 20;
-    if show_labels { used += labels_w; }
-
-    let show_assignee = width >= 140 && width.saturating_sub(used + assignee_w) >= 25;
-    if show_assignee { used += assignee_w; }
-
-    let show_links = width >= 170 && width.saturating_sub(used + links_w) >= 30;
-    if show_links { used += links_w; }
-
-    // Title gets ALL remaining space — no cap
-    let title_w = width.saturating_sub(used).max(10);
+    // Title-first, laid out like `--format table`: fixed columns take what they
+    // need, the title gets the rest, and optional columns are hidden while the
+    // title would get fewer than 40 cells (links first, project last).
+    let rules = [
+        WidthRule::fixed(7),                        // id
+        WidthRule::fixed(3),                        // priority: symbol + 2 padding
+        WidthRule::between(40, usize::MAX).flex(),  // title
+        WidthRule::fixed(10).drop(1),               // project
+        WidthRule::fixed(12).drop(2),               // labels
+        WidthRule::fixed(10),                       // status
+        WidthRule::fixed(10).drop(3),               // assignee
+        WidthRule::fixed(3).drop(4),                // links
+        WidthRule::fixed(5),                        // age
+    ];
+    // The title wants the whole width, so it takes all that's left
+    let wanted = [0, 0, width, 0, 0, 0, 0, 0, 0];
+    let widths = fit_widths(&rules, &wanted, width, 0);
+    let w = |i: usize| widths[i].unwrap_or(0);
 
     ColumnWidths {
-        id: id_w,
-        priority: pri_w,
-        title: title_w,
-        project: if show_project { project_w } else { 0 },
-        labels: if show_labels { labels_w } else { 0 },
-        status: status_w,
-        assignee: if show_assignee { assignee_w } else { 0 },
-        links: if show_links { links_w } else { 0 },
-        age: age_w,
-        show_project,
-        show_labels,
-        show_assignee,
-        show_links,
+        id: w(0),
+        priority: w(1),
+        title: w(2),
+        project: w(3),
+        labels: w(4),
+        status: w(5),
+        assignee: w(6),
+        links: w(7),
+        age: w(8),
+        show_project: widths[3].is_some(),
+        show_labels: widths[4].is_some(),
+        show_assignee: widths[6].is_some(),
+        show_links: widths[7].is_some(),
         show_age: true,
     }
 }
@@ -93,24 +82,17 @@ pub fn calculate_column_widths(available_width: u16) -> ColumnWidths {
 // ---------------------------------------------------------------------------
 
 pub fn truncate(s: &str, max_width: usize) -> String {
-    if max_width < 4 {
-        return s.chars().take(max_width).collect();
-    }
-    if s.len() <= max_width {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max_width.saturating_sub(3)])
-    }
+    truncate_to_width(s, max_width)
 }
 
 pub fn truncate_id(id: &str, max_width: usize) -> String {
-    if id.len() <= max_width {
+    if display_width(id) <= max_width {
         id.to_string()
     } else {
         // Try to extract just the number part for very narrow displays
         if let Some(dash_pos) = id.find('-') {
             let number_part = &id[dash_pos + 1..];
-            if number_part.len() <= max_width {
+            if display_width(number_part) <= max_width {
                 return number_part.to_string();
             }
         }
@@ -216,25 +198,12 @@ pub fn get_issue_links(issue: &Issue) -> Vec<String> {
 // ---------------------------------------------------------------------------
 
 fn priority_symbol_and_color(priority: Option<u8>) -> (&'static str, Color) {
-    match priority {
-        Some(0) => (" ", Color::Gray),
-        Some(1) => ("\u{25e6}", Color::Blue),      // ◦
-        Some(2) => ("\u{2022}", Color::Yellow),     // •
-        Some(3) => ("\u{25a0}", Color::Rgb(255, 165, 0)), // ■  Orange
-        Some(4) => ("\u{25b2}", Color::Red),        // ▲
-        _ => (" ", Color::Gray),
-    }
+    let priority = priority.unwrap_or(0);
+    (priority_symbol(priority), tui_color(priority_color(priority)))
 }
 
 pub fn status_color(state_type: &str) -> Color {
-    match state_type {
-        "backlog" => Color::Gray,
-        "unstarted" => Color::LightBlue,
-        "started" => Color::Yellow,
-        "completed" => Color::Green,
-        "canceled" => Color::DarkGray,
-        _ => Color::White,
-    }
+    tui_color(helpers::status_color(state_type))
 }
 
 // ---------------------------------------------------------------------------
@@ -244,11 +213,9 @@ pub fn status_color(state_type: &str) -> Color {
 pub fn draw_list(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let focused = app.focus == Focus::IssueList;
     let border_style = if focused {
-        Style::default()
-            .fg(Color::Cyan)
-            .add_modifier(Modifier::BOLD)
+        tui_fg(SemanticColor::Accent).add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::DarkGray)
+        tui_fg(SemanticColor::Border)
     };
 
     let block = Block::default()
@@ -259,7 +226,7 @@ pub fn draw_list(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     // Loading state
     if app.loading {
         let loading = Paragraph::new("Loading issues...")
-            .style(Style::default().fg(Color::Yellow))
+            .style(tui_fg(SemanticColor::Warning))
             .block(block)
             .alignment(Alignment::Center);
         frame.render_widget(loading, area);
@@ -269,7 +236,7 @@ pub fn draw_list(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     // Error state
     if let Some(error) = &app.error_message {
         let error_widget = Paragraph::new(error.as_str())
-            .style(Style::default().fg(Color::Red))
+            .style(tui_fg(SemanticColor::Error))
             .block(block);
         frame.render_widget(error_widget, area);
         return;
@@ -278,7 +245,7 @@ pub fn draw_list(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     // Empty state
     if app.filtered_issues.is_empty() {
         let empty = Paragraph::new("No issues found")
-            .style(Style::default().fg(Color::DarkGray))
+            .style(tui_fg(SemanticColor::Muted))
             .block(block)
             .alignment(Alignment::Center);
         frame.render_widget(empty, area);
@@ -290,9 +257,7 @@ pub fn draw_list(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let col_widths = calculate_column_widths(inner_width);
 
     // Build dynamic header row
-    let header_style = Style::default()
-        .fg(Color::Gray)
-        .add_modifier(Modifier::UNDERLINED);
+    let header_style = tui_fg(SemanticColor::Secondary).add_modifier(Modifier::UNDERLINED);
     let mut header = format!(
         "{:<id_w$}{:<pri_w$}{:<title_w$}",
         "ID", "P", "Title",
@@ -302,20 +267,20 @@ pub fn draw_list(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     );
 
     if col_widths.show_project {
-        header.push_str(&format!("{:<w$}", "Project", w = col_widths.project));
+        header.push_str(&pad_to_width("Project", col_widths.project));
     }
     if col_widths.show_labels {
-        header.push_str(&format!("{:<w$}", "Labels", w = col_widths.labels));
+        header.push_str(&pad_to_width("Labels", col_widths.labels));
     }
-    header.push_str(&format!("{:<w$}", "Status", w = col_widths.status));
+    header.push_str(&pad_to_width("Status", col_widths.status));
     if col_widths.show_assignee {
-        header.push_str(&format!("{:<w$}", "Assignee", w = col_widths.assignee));
+        header.push_str(&pad_to_width("Assignee", col_widths.assignee));
     }
     if col_widths.show_links {
-        header.push_str(&format!("{:<w$}", "\u{1f517}", w = col_widths.links));
+        header.push_str(&pad_to_width("\u{1f517}", col_widths.links));
     }
     if col_widths.show_age {
-        header.push_str(&format!("{:<w$}", "Age", w = col_widths.age));
+        header.push_str(&pad_to_width("Age", col_widths.age));
     }
 
     let header_item = ListItem::new(header).style(header_style);
@@ -356,7 +321,9 @@ pub fn draw_list(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     if !app.filter_query.is_empty() {
         title.push_str(&format!(" [filter: {}]", app.filter_query));
     }
-    if !app.search_query.is_empty() {
+    if app.search_results.is_some() {
+        title.push_str(&format!(" [full text: {}]", app.search_query));
+    } else if !app.search_query.is_empty() {
         title.push_str(&format!(" [search: {}]", app.search_query));
     }
     title.push(' ');
@@ -364,7 +331,7 @@ pub fn draw_list(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
 
     let list = List::new(items)
         .block(block)
-        .style(Style::default().fg(Color::White));
+        .style(tui_fg(SemanticColor::Primary));
 
     frame.render_widget(list, area);
 }
@@ -382,20 +349,13 @@ fn build_row<'a>(
     let selected = index == app.selected_index;
     let multi = app.multi_selected.contains(&index);
 
-    // Row background
-    let row_bg = if selected {
-        Some(Color::Rgb(30, 35, 50))
-    } else {
-        None
-    };
-
     let (priority_symbol, priority_color) = priority_symbol_and_color(issue.priority);
     let st_color = status_color(&issue.state.state_type);
 
     let assignee_name = issue
         .assignee
         .as_ref()
-        .map(|a| parse_assignee_name(a))
+        .map(parse_assignee_name)
         .unwrap_or_else(|| "Unassigned".to_string());
 
     // ID column — prepend checkmark for multi-selected rows
@@ -407,11 +367,7 @@ fn build_row<'a>(
             width = col_widths.id.saturating_sub(2)
         )
     } else {
-        format!(
-            "{:<width$}",
-            truncate_id(&issue.identifier, col_widths.id),
-            width = col_widths.id
-        )
+        pad_to_width(&truncate_id(&issue.identifier, col_widths.id), col_widths.id)
     };
 
     let id_span = Span::styled(id_text, Style::default());
@@ -422,11 +378,7 @@ fn build_row<'a>(
     );
 
     let title_span = Span::styled(
-        format!(
-            "{:<width$}",
-            truncate(&issue.title, col_widths.title),
-            width = col_widths.title
-        ),
+        pad_to_width(&issue.title, col_widths.title),
         Style::default(),
     );
 
@@ -438,11 +390,7 @@ fn build_row<'a>(
         Style::default().fg(st_color)
     };
     let status_span = Span::styled(
-        format!(
-            "{:<width$}",
-            truncate(&issue.state.name, col_widths.status),
-            width = col_widths.status
-        ),
+        pad_to_width(&issue.state.name, col_widths.status),
         status_style,
     );
 
@@ -457,12 +405,8 @@ fn build_row<'a>(
             .map(|p| p.name.as_str())
             .unwrap_or("-");
         let project_span = Span::styled(
-            format!(
-                "{:<width$}",
-                truncate(project_name, col_widths.project),
-                width = col_widths.project
-            ),
-            Style::default().fg(Color::LightGreen),
+            pad_to_width(project_name, col_widths.project),
+            tui_fg(SemanticColor::Project),
         );
         spans.push(project_span);
     }
@@ -482,12 +426,8 @@ fn build_row<'a>(
             labels.join(", ")
         };
         let labels_span = Span::styled(
-            format!(
-                "{:<width$}",
-                truncate(&labels_text, col_widths.labels),
-                width = col_widths.labels
-            ),
-            Style::default().fg(Color::Magenta),
+            pad_to_width(&labels_text, col_widths.labels),
+            tui_fg(SemanticColor::Label),
         );
         spans.push(labels_span);
     }
@@ -497,12 +437,8 @@ fn build_row<'a>(
     // Assignee column
     if col_widths.show_assignee {
         let assignee_span = Span::styled(
-            format!(
-                "{:<width$}",
-                truncate(&assignee_name, col_widths.assignee),
-                width = col_widths.assignee
-            ),
-            Style::default().fg(Color::Cyan),
+            pad_to_width(&assignee_name, col_widths.assignee),
+            tui_fg(SemanticColor::Assignee),
         );
         spans.push(assignee_span);
     }
@@ -516,7 +452,7 @@ fn build_row<'a>(
         } else {
             "  ".to_string()
         };
-        let links_span = Span::styled(links_text, Style::default().fg(Color::Blue));
+        let links_span = Span::styled(links_text, tui_fg(SemanticColor::Link));
         spans.push(links_span);
     }
 
@@ -525,15 +461,15 @@ fn build_row<'a>(
         let age_text = format_age(&issue.created_at);
         let age_span = Span::styled(
             format!("{:<width$}", age_text, width = col_widths.age),
-            Style::default().fg(Color::Gray),
+            tui_fg(SemanticColor::Secondary),
         );
         spans.push(age_span);
     }
 
     let line = Line::from(spans);
     let item = ListItem::new(line);
-    if let Some(bg) = row_bg {
-        item.style(Style::default().bg(bg))
+    if selected {
+        item.style(tui_highlight(SemanticColor::SelectionBackground))
     } else {
         item
     }
@@ -3,3 +3,4 @@ pub mod list;
 pub mod detail;
 pub mod teams;
 pub mod projects;
+pub mod views;
@@ -1,20 +1,21 @@
 use std::collections::HashMap;
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem},
     Frame,
 };
 
+use crate::formatting::theme::{tui_color, tui_fg, tui_highlight, SemanticColor};
 use crate::interactive::app::{Focus, InteractiveApp};
 
 pub fn draw_projects(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let focused = app.focus == Focus::ProjectList;
     let border_style = if focused {
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        tui_fg(SemanticColor::Accent).add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::DarkGray)
+        tui_fg(SemanticColor::Border)
     };
 
     // Count issues per project from loaded issues
@@ -65,14 +66,13 @@ pub fn draw_projects(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
             let display = format!("{} {}", marker, name);
 
             let style = if *idx == app.project_index && focused {
-                Style::default()
-                    .bg(Color::Rgb(30, 35, 50))
-                    .fg(Color::White)
+                tui_highlight(SemanticColor::SelectionBackground)
+                    .fg(tui_color(SemanticColor::Primary))
                     .add_modifier(Modifier::BOLD)
             } else if is_active {
-                Style::default().fg(Color::LightGreen)
+                tui_fg(SemanticColor::Project)
             } else {
-                Style::default().fg(Color::White)
+                tui_fg(SemanticColor::Primary)
             };
 
             ListItem::new(Line::from(Span::styled(display, style)))
@@ -1,19 +1,20 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem},
     Frame,
 };
 
+use crate::formatting::theme::{tui_color, tui_fg, tui_highlight, SemanticColor};
 use crate::interactive::app::{Focus, InteractiveApp};
 
 pub fn draw_teams(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let focused = app.focus == Focus::TeamList;
     let border_style = if focused {
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        tui_fg(SemanticColor::Accent).add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::DarkGray)
+        tui_fg(SemanticColor::Border)
     };
 
     let title = format!(" Teams ({}) ", app.teams.len());
@@ -24,7 +25,7 @@ pub fn draw_teams(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
 
     if app.teams.is_empty() {
         let empty = ratatui::widgets::Paragraph::new("No teams")
-            .style(Style::default().fg(Color::DarkGray))
+            .style(tui_fg(SemanticColor::Muted))
             .block(block);
         frame.render_widget(empty, area);
         return;
@@ -48,14 +49,13 @@ pub fn draw_teams(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
             let display = format!("{} {} ({})", marker, team.name, team.key);
 
             let style = if i == app.team_index && focused {
-                Style::default()
-                    .bg(Color::Rgb(30, 35, 50))
-                    .fg(Color::White)
+                tui_highlight(SemanticColor::SelectionBackground)
+                    .fg(tui_color(SemanticColor::Primary))
                     .add_modifier(Modifier::BOLD)
             } else if app.active_team == Some(i) {
-                Style::default().fg(Color::Cyan)
+                tui_fg(SemanticColor::Accent)
             } else {
-                Style::default().fg(Color::White)
+                tui_fg(SemanticColor::Primary)
             };
 
             ListItem::new(Line::from(Span::styled(display, style)))
@@ -0,0 +1,73 @@
+use ratatui::{
+    layout::Rect,
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+
+use crate::formatting::theme::{tui_color, tui_fg, tui_highlight, SemanticColor};
+use crate::interactive::app::{Focus, InteractiveApp};
+
+/// Saved searches and Linear custom views. Enter applies one as a server-side
+/// filter on top of the team and project selection; Enter again clears it.
+pub fn draw_views(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
+    let focused = app.focus == Focus::ViewList;
+    let border_style = if focused {
+        tui_fg(SemanticColor::Accent).add_modifier(Modifier::BOLD)
+    } else {
+        tui_fg(SemanticColor::Border)
+    };
+
+    let title = format!(" Views ({}) ", app.saved_views.len());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(border_style);
+
+    if app.saved_views.is_empty() {
+        let empty = ratatui::widgets::Paragraph::new("No saved searches or views")
+            .style(tui_fg(SemanticColor::Muted))
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let scroll_offset = if app.view_index >= inner_height {
+        app.view_index - inner_height + 1
+    } else {
+        0
+    };
+
+    let items: Vec<ListItem> = app
+        .saved_views
+        .iter()
+        .enumerate()
+        .skip(scroll_offset)
+        .take(inner_height)
+        .map(|(i, view)| {
+            let marker = if app.active_view == Some(i) { "►" } else { " " };
+            let source = if view.linear { "◆" } else { "★" };
+
+            let style = if i == app.view_index && focused {
+                tui_highlight(SemanticColor::SelectionBackground)
+                    .fg(tui_color(SemanticColor::Primary))
+                    .add_modifier(Modifier::BOLD)
+            } else if app.active_view == Some(i) {
+                tui_fg(SemanticColor::Highlight)
+            } else {
+                tui_fg(SemanticColor::Primary)
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{} ", marker), style),
+                Span::styled(format!("{} ", source), tui_fg(if view.linear { SemanticColor::Info } else { SemanticColor::Key })),
+                Span::styled(view.name.clone(), style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(block);
+    frame.render_widget(list, area);
+}
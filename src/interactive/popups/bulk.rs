@@ -1,11 +1,12 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
     Frame,
 };
 
+use crate::formatting::theme::{tui_color, tui_fg, tui_highlight, SemanticColor};
 use crate::interactive::app::InteractiveApp;
 use crate::interactive::layout::centered_popup;
 
@@ -32,7 +33,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let block = Block::default()
         .borders(Borders::ALL)
         .title(format!(" Bulk Actions ({} issues) ", count))
-        .border_style(Style::default().fg(Color::Yellow));
+        .border_style(tui_fg(SemanticColor::Warning));
 
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
@@ -42,12 +43,11 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
         .enumerate()
         .map(|(i, option)| {
             let style = if i == app.picker_index {
-                Style::default()
-                    .fg(Color::Rgb(0, 0, 0))
-                    .bg(Color::Cyan)
+                tui_highlight(SemanticColor::Accent)
+                    .fg(tui_color(SemanticColor::Background))
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                tui_fg(SemanticColor::Primary)
             };
             ListItem::new(Line::from(Span::styled(format!(" {} ", option), style)))
         })
@@ -66,7 +66,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     );
     let hints_widget = Paragraph::new(Line::from(Span::styled(
         "Enter: Select  Esc: Cancel",
-        Style::default().fg(Color::DarkGray),
+        tui_fg(SemanticColor::Muted),
     )));
     frame.render_widget(hints_widget, hints_area);
 }
@@ -1,11 +1,12 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
+use crate::formatting::theme::{tui_fg, SemanticColor};
 use crate::interactive::app::{ConfirmAction, InteractiveApp, Popup};
 use crate::interactive::layout::centered_popup;
 
@@ -17,6 +18,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
 
     let message = match action {
         ConfirmAction::ArchiveIssue(_) => "Archive this issue?",
+        ConfirmAction::DeleteComment(_) => "Delete this comment?",
     };
 
     let width: u16 = 40;
@@ -28,7 +30,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let block = Block::default()
         .borders(Borders::ALL)
         .title(" Confirm ")
-        .border_style(Style::default().fg(Color::Red));
+        .border_style(tui_fg(SemanticColor::Error));
 
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
@@ -37,9 +39,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let message_area = Rect::new(inner.x, inner.y, inner.width, 1);
     let message_widget = Paragraph::new(Line::from(Span::styled(
         message,
-        Style::default()
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD),
+        tui_fg(SemanticColor::Primary).add_modifier(Modifier::BOLD),
     )));
     frame.render_widget(message_widget, message_area);
 
@@ -51,22 +51,18 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
         1,
     );
     let options_line = Line::from(vec![
-        Span::styled("[", Style::default().fg(Color::DarkGray)),
+        Span::styled("[", tui_fg(SemanticColor::Muted)),
         Span::styled(
             "Y",
-            Style::default()
-                .fg(Color::Green)
-                .add_modifier(Modifier::BOLD),
+            tui_fg(SemanticColor::Success).add_modifier(Modifier::BOLD),
         ),
-        Span::styled("]es  ", Style::default().fg(Color::DarkGray)),
-        Span::styled("[", Style::default().fg(Color::DarkGray)),
+        Span::styled("]es  ", tui_fg(SemanticColor::Muted)),
+        Span::styled("[", tui_fg(SemanticColor::Muted)),
         Span::styled(
             "N",
-            Style::default()
-                .fg(Color::Red)
-                .add_modifier(Modifier::BOLD),
+            tui_fg(SemanticColor::Error).add_modifier(Modifier::BOLD),
         ),
-        Span::styled("]o", Style::default().fg(Color::DarkGray)),
+        Span::styled("]o", tui_fg(SemanticColor::Muted)),
     ]);
     let options_widget = Paragraph::new(options_line);
     frame.render_widget(options_widget, options_area);
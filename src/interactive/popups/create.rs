@@ -1,11 +1,13 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
+use crate::filtering::priority_label;
+use crate::formatting::theme::{tui_fg, SemanticColor};
 use crate::interactive::app::InteractiveApp;
 use crate::interactive::layout::centered_popup;
 use crate::interactive::panels::list::truncate;
@@ -13,7 +15,7 @@ use crate::interactive::panels::list::truncate;
 /// Draw the issue creation form popup.
 pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let width: u16 = 60;
-    let height: u16 = 14;
+    let height: u16 = 15;
     let popup_area = centered_popup(width, height, area);
 
     frame.render_widget(Clear, popup_area);
@@ -21,7 +23,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let block = Block::default()
         .borders(Borders::ALL)
         .title(" New Issue ")
-        .border_style(Style::default().fg(Color::Green));
+        .border_style(tui_fg(SemanticColor::Success));
 
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
@@ -64,16 +66,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
                 })
                 .unwrap_or_else(|| "Backlog".to_string())
         }),
-        ("Priority", {
-            match form.priority {
-                Some(0) | None => "None".to_string(),
-                Some(1) => "Low".to_string(),
-                Some(2) => "Medium".to_string(),
-                Some(3) => "High".to_string(),
-                Some(4) => "Urgent".to_string(),
-                Some(n) => format!("P{}", n),
-            }
-        }),
+        ("Priority", priority_label(form.priority.unwrap_or(0))),
         ("Project", {
             form.project_id
                 .as_ref()
@@ -118,6 +111,11 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
                 })
                 .unwrap_or_else(|| "None".to_string())
         }),
+        ("Template", {
+            form.template_name
+                .clone()
+                .unwrap_or_else(|| "None (Enter to pick)".to_string())
+        }),
     ];
 
     for (i, (label, value)) in fields.iter().enumerate() {
@@ -129,19 +127,15 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
         let is_active = i == form.active_field;
 
         let label_style = if is_active {
-            Style::default()
-                .fg(Color::Green)
-                .add_modifier(Modifier::BOLD)
+            tui_fg(SemanticColor::Success).add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(Color::Gray)
+            tui_fg(SemanticColor::Secondary)
         };
 
         let value_style = if is_active {
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD)
+            tui_fg(SemanticColor::Primary).add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(Color::DarkGray)
+            tui_fg(SemanticColor::Muted)
         };
 
         let indicator = if is_active { "\u{25b6} " } else { "  " };
@@ -165,7 +159,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     );
     let hints_widget = Paragraph::new(Line::from(Span::styled(
         "Tab: Next field  Enter: Edit/Create  Esc: Cancel",
-        Style::default().fg(Color::DarkGray),
+        tui_fg(SemanticColor::Muted),
     )));
     frame.render_widget(hints_widget, hints_area);
 }
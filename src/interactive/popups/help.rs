@@ -1,18 +1,19 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
+use crate::formatting::theme::{tui_fg, SemanticColor};
 use crate::interactive::app::InteractiveApp;
 use crate::interactive::layout::centered_popup;
 
 /// Draw the full keyboard shortcuts help overlay.
 pub fn draw(frame: &mut Frame, area: Rect, _app: &InteractiveApp) {
     let width: u16 = 70;
-    let height: u16 = 22;
+    let height: u16 = 24;
     let popup_area = centered_popup(width, height, area);
 
     frame.render_widget(Clear, popup_area);
@@ -20,19 +21,15 @@ pub fn draw(frame: &mut Frame, area: Rect, _app: &InteractiveApp) {
     let block = Block::default()
         .borders(Borders::ALL)
         .title(" Keyboard Shortcuts ")
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(tui_fg(SemanticColor::Accent));
 
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
 
-    let header_style = Style::default()
-        .fg(Color::Cyan)
-        .add_modifier(Modifier::BOLD);
-    let separator_style = Style::default().fg(Color::DarkGray);
-    let key_style = Style::default()
-        .fg(Color::Yellow)
-        .add_modifier(Modifier::BOLD);
-    let desc_style = Style::default().fg(Color::White);
+    let header_style = tui_fg(SemanticColor::Accent).add_modifier(Modifier::BOLD);
+    let separator_style = tui_fg(SemanticColor::Muted);
+    let key_style = tui_fg(SemanticColor::Key).add_modifier(Modifier::BOLD);
+    let desc_style = tui_fg(SemanticColor::Primary);
 
     // Build the three-column layout as lines
     // Each line contains content across all three columns
@@ -67,17 +64,19 @@ pub fn draw(frame: &mut Frame, area: Rect, _app: &InteractiveApp) {
         // Row 4
         build_help_row("f", "Filter", "p", "Change project", "q", "Quit", key_style, desc_style),
         // Row 5
-        build_help_row("d", "Toggle done", "a", "Change assignee", "", "", key_style, desc_style),
+        build_help_row("d", "Toggle done", "a", "Change assignee", "S", "Cycle sort", key_style, desc_style),
         // Row 6
         build_help_row("r", "Refresh", "e", "Full edit", "", "", key_style, desc_style),
         // Row 7
         build_help_row("n", "New issue", "o", "Open in browser", "", "", key_style, desc_style),
         // Row 8
-        build_help_row("x", "Multi-select", "", "", "", "", key_style, desc_style),
+        build_help_row("x", "Multi-select", "R", "Reply to comment", "", "", key_style, desc_style),
         // Row 9
-        build_help_row("X", "Clear selection", "", "", "", "", key_style, desc_style),
+        build_help_row("X", "Clear selection", "E", "Edit comment", "", "", key_style, desc_style),
         // Row 10
-        build_help_row("Space", "Bulk actions", "", "", "", "", key_style, desc_style),
+        build_help_row("Space", "Bulk actions", "D", "Delete comment", "", "", key_style, desc_style),
+        // Row 11
+        build_help_row("]/[", "Select comment", "+", "React to comment", "", "", key_style, desc_style),
     ];
 
     let content = Paragraph::new(lines);
@@ -93,12 +92,13 @@ pub fn draw(frame: &mut Frame, area: Rect, _app: &InteractiveApp) {
     );
     let footer = Paragraph::new(Line::from(Span::styled(
         "Press ? or Esc to close",
-        Style::default().fg(Color::DarkGray),
+        tui_fg(SemanticColor::Muted),
     )));
     frame.render_widget(footer, footer_area);
 }
 
 /// Build a single row across three columns (Navigation, Actions, Panels).
+#[allow(clippy::too_many_arguments)]
 fn build_help_row<'a>(
     nav_key: &'a str,
     nav_desc: &'a str,
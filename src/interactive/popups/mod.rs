@@ -15,7 +15,8 @@ pub fn draw_popup(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     match popup {
         Popup::StatusPicker | Popup::PriorityPicker |
         Popup::LabelPicker | Popup::ProjectPicker |
-        Popup::AssigneePicker => picker::draw(frame, area, app),
+        Popup::AssigneePicker | Popup::TemplatePicker |
+        Popup::ReactionPicker => picker::draw(frame, area, app),
         Popup::TextInput(_) => text_input::draw(frame, area, app),
         Popup::Confirmation(_) => confirm::draw(frame, area, app),
         Popup::CreateIssue => create::draw(frame, area, app),
@@ -6,30 +6,23 @@ use ratatui::{
     Frame,
 };
 
-use crate::interactive::app::{InteractiveApp, Popup};
+use crate::filtering::{priority_label, PRIORITIES};
+use crate::formatting::theme::helpers::{priority_color, status_color};
+use crate::formatting::theme::{tui_color, tui_fg, tui_highlight, SemanticColor};
+use crate::interactive::app::{InteractiveApp, Popup, REACTION_EMOJIS};
 use crate::interactive::layout::centered_popup;
 use crate::interactive::panels::list::truncate;
 
-/// Draw the picker popup for status, priority, labels, project, or assignee.
+/// Draw the picker popup for status, priority, labels, project, assignee, template, or reaction.
 pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let Some(popup) = &app.popup else { return };
 
     let (title, options, hints) = match popup {
         Popup::StatusPicker => {
             let opts: Vec<(String, Color)> = app
-                .workflow_states
-                .iter()
-                .map(|state| {
-                    let color = match state.state_type.as_str() {
-                        "backlog" => Color::Gray,
-                        "unstarted" => Color::LightBlue,
-                        "started" => Color::Yellow,
-                        "completed" => Color::Green,
-                        "canceled" => Color::DarkGray,
-                        _ => Color::White,
-                    };
-                    (state.name.clone(), color)
-                })
+                .status_options()
+                .into_iter()
+                .map(|state| (state.name.clone(), tui_color(status_color(&state.state_type))))
                 .collect();
             (
                 "Select Status",
@@ -38,13 +31,9 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
             )
         }
         Popup::PriorityPicker => {
-            let opts = vec![
-                ("None".to_string(), Color::Gray),
-                ("Low".to_string(), Color::Blue),
-                ("Medium".to_string(), Color::Yellow),
-                ("High".to_string(), Color::Rgb(255, 165, 0)),
-                ("Urgent".to_string(), Color::Red),
-            ];
+            let opts = (0..PRIORITIES.len() as u8)
+                .map(|priority| (priority_label(priority), tui_color(priority_color(priority))))
+                .collect();
             (
                 "Select Priority",
                 opts,
@@ -61,7 +50,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
                     } else {
                         "[ ]"
                     };
-                    (format!("{} {}", checkbox, label.name), Color::Magenta)
+                    (format!("{} {}", checkbox, label.name), tui_color(SemanticColor::Label))
                 })
                 .collect();
             (
@@ -71,11 +60,11 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
             )
         }
         Popup::ProjectPicker => {
-            let mut opts: Vec<(String, Color)> = vec![("None".to_string(), Color::LightGreen)];
+            let mut opts: Vec<(String, Color)> = vec![("None".to_string(), tui_color(SemanticColor::Project))];
             opts.extend(
                 app.available_projects
                     .iter()
-                    .map(|p| (p.name.clone(), Color::LightGreen)),
+                    .map(|p| (p.name.clone(), tui_color(SemanticColor::Project))),
             );
             (
                 "Select Project",
@@ -84,11 +73,11 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
             )
         }
         Popup::AssigneePicker => {
-            let mut opts: Vec<(String, Color)> = vec![("Unassign".to_string(), Color::DarkGray)];
+            let mut opts: Vec<(String, Color)> = vec![("Unassign".to_string(), tui_color(SemanticColor::Muted))];
             opts.extend(
                 app.team_members
                     .iter()
-                    .map(|member| (member.name.clone(), Color::Cyan)),
+                    .map(|member| (member.name.clone(), tui_color(SemanticColor::Assignee))),
             );
             (
                 "Select Assignee",
@@ -96,6 +85,32 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
                 "\u{2191}/\u{2193} Navigate  Enter: Select  Esc: Cancel",
             )
         }
+        Popup::TemplatePicker => {
+            let opts: Vec<(String, Color)> = app
+                .templates
+                .iter()
+                .map(|t| {
+                    let source = if t.is_local() { "local" } else { "Linear" };
+                    (format!("{} ({})", t.name, source), tui_color(SemanticColor::Highlight))
+                })
+                .collect();
+            (
+                "Apply Template",
+                opts,
+                "\u{2191}/\u{2193} Navigate  Enter: Apply  Esc: Back",
+            )
+        }
+        Popup::ReactionPicker => {
+            let opts: Vec<(String, Color)> = REACTION_EMOJIS
+                .iter()
+                .map(|emoji| (emoji.to_string(), tui_color(SemanticColor::Primary)))
+                .collect();
+            (
+                "Add Reaction",
+                opts,
+                "Enter: React/Unreact  Esc: Cancel",
+            )
+        }
         _ => return,
     };
 
@@ -109,7 +124,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let block = Block::default()
         .borders(Borders::ALL)
         .title(format!(" {} ", title))
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(tui_fg(SemanticColor::Accent));
 
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
@@ -132,9 +147,8 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
         .map(|(i, (name, color))| {
             let display = truncate(name, (width - 4) as usize);
             let style = if i == app.picker_index {
-                Style::default()
-                    .fg(Color::Rgb(0, 0, 0))
-                    .bg(Color::Cyan)
+                tui_highlight(SemanticColor::Accent)
+                    .fg(tui_color(SemanticColor::Background))
                     .add_modifier(Modifier::BOLD)
             } else {
                 Style::default().fg(*color)
@@ -156,7 +170,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     );
     let hints_widget = Paragraph::new(Line::from(Span::styled(
         hints,
-        Style::default().fg(Color::DarkGray),
+        tui_fg(SemanticColor::Muted),
     )));
     frame.render_widget(hints_widget, hints_area);
 }
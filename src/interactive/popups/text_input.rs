@@ -1,15 +1,20 @@
 use ratatui::{
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
+use crate::filtering::CompletionKind;
+use crate::formatting::theme::{tui_color, tui_fg, tui_highlight, SemanticColor};
 use crate::interactive::app::{InteractiveApp, Popup, TextInputContext};
 use crate::interactive::layout::centered_popup;
 
-/// Draw a text input popup for comments, search, title edit, description edit, or filter.
+/// Suggestions shown under the filter input at once
+const MAX_SUGGESTIONS: usize = 6;
+
+/// Draw a text input popup for comments, replies, search, title edit, description edit, or filter.
 pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let Some(Popup::TextInput(ctx)) = &app.popup else {
         return;
@@ -20,9 +25,21 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
             "Add Comment",
             "Enter: Submit  Esc: Cancel",
         ),
+        TextInputContext::ReplyComment(_) => (
+            "Reply to Comment",
+            "Enter: Submit  Esc: Cancel",
+        ),
+        TextInputContext::EditComment(_) => (
+            "Edit Comment",
+            "Enter: Submit  Esc: Cancel",
+        ),
+        TextInputContext::Search if app.text_search => (
+            "Search (full text)",
+            "Tab: Titles only  Enter: Apply  Esc: Cancel",
+        ),
         TextInputContext::Search => (
             "Search",
-            "Enter: Apply  Esc: Cancel",
+            "Tab: Full text  Enter: Apply  Esc: Cancel",
         ),
         TextInputContext::EditTitle => (
             "Edit Title",
@@ -34,12 +51,30 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
         ),
         TextInputContext::Filter => (
             "Filter",
-            "Enter: Apply  Esc: Cancel",
+            "Tab: Complete  ↑/↓: Choose  Enter: Apply  Esc: Cancel",
         ),
     };
 
+    // Filter completions, scrolled so the selected one stays visible
+    let completions = if *ctx == TextInputContext::Filter {
+        app.filter_completions()
+    } else {
+        Vec::new()
+    };
+    let selected = app.completion_index.min(completions.len().saturating_sub(1));
+    let first = (selected + 1).saturating_sub(MAX_SUGGESTIONS);
+    let shown = &completions[first.min(completions.len())..completions.len().min(first + MAX_SUGGESTIONS)];
+
+    // The first problem with the filter, shown under the input
+    let diagnostic = if *ctx == TextInputContext::Filter {
+        app.filter_diagnostics().into_iter().next()
+    } else {
+        None
+    };
+    let diagnostic_rows: u16 = if diagnostic.is_some() { 2 } else { 0 };
+
     let width: u16 = 60;
-    let height: u16 = 5;
+    let height: u16 = 5 + diagnostic_rows + shown.len() as u16;
     let popup_area = centered_popup(width, height, area);
 
     frame.render_widget(Clear, popup_area);
@@ -47,7 +82,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let block = Block::default()
         .borders(Borders::ALL)
         .title(format!(" {} ", title))
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(tui_fg(SemanticColor::Accent));
 
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
@@ -65,15 +100,14 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     };
 
     let input_line = Line::from(vec![
-        Span::styled(before, Style::default().fg(Color::White)),
+        Span::styled(before, tui_fg(SemanticColor::Primary)),
         Span::styled(
             cursor_char.to_string(),
-            Style::default()
-                .fg(Color::Rgb(0, 0, 0))
-                .bg(Color::White)
+            tui_highlight(SemanticColor::Primary)
+                .fg(tui_color(SemanticColor::Background))
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled(rest, Style::default().fg(Color::White)),
+        Span::styled(rest, tui_fg(SemanticColor::Primary)),
     ]);
 
     // Render input on first line of inner area
@@ -81,6 +115,40 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     let input_widget = Paragraph::new(input_line);
     frame.render_widget(input_widget, input_area);
 
+    // Render the diagnostic: carets under the span, then the message
+    if let Some(diagnostic) = &diagnostic {
+        let color = tui_color(if diagnostic.is_error() { SemanticColor::Error } else { SemanticColor::Warning });
+        let carets = Paragraph::new(Line::from(Span::styled(diagnostic.underline(), Style::default().fg(color))));
+        frame.render_widget(carets, Rect::new(inner.x, inner.y + 1, inner.width, 1));
+        let mut message = vec![Span::styled(diagnostic.message.clone(), Style::default().fg(color))];
+        if let Some(help) = &diagnostic.help {
+            message.push(Span::styled(format!("  {}", help), tui_fg(SemanticColor::Muted)));
+        }
+        frame.render_widget(Paragraph::new(Line::from(message)), Rect::new(inner.x, inner.y + 2, inner.width, 1));
+    }
+
+    // Render suggestions below the input
+    for (row, completion) in shown.iter().enumerate() {
+        let is_selected = first + row == selected;
+        let kind = match completion.kind {
+            CompletionKind::Field => "field",
+            CompletionKind::Operator => "operator",
+            CompletionKind::Value => "value",
+            CompletionKind::Keyword => "keyword",
+        };
+        let style = if is_selected {
+            tui_highlight(SemanticColor::Accent).fg(tui_color(SemanticColor::Background))
+        } else {
+            tui_fg(SemanticColor::Secondary)
+        };
+        let line = Line::from(vec![
+            Span::styled(format!(" {} ", completion.text.trim_end()), style),
+            Span::styled(format!(" {}", kind), tui_fg(SemanticColor::Muted)),
+        ]);
+        let row_area = Rect::new(inner.x, inner.y + 1 + diagnostic_rows + row as u16, inner.width, 1);
+        frame.render_widget(Paragraph::new(line), row_area);
+    }
+
     // Render hints on the last line
     let hints_area = Rect::new(
         inner.x,
@@ -90,7 +158,7 @@ pub fn draw(frame: &mut Frame, area: Rect, app: &InteractiveApp) {
     );
     let hints_widget = Paragraph::new(Line::from(Span::styled(
         hints,
-        Style::default().fg(Color::DarkGray),
+        tui_fg(SemanticColor::Muted),
     )));
     frame.render_widget(hints_widget, hints_area);
 }
@@ -1,8 +1,9 @@
 use ratatui::{
-    style::{Color, Style},
+    style::Style,
     widgets::Paragraph,
     Frame,
 };
+use crate::formatting::theme::{tui_color, tui_fg, SemanticColor};
 use crate::interactive::app::InteractiveApp;
 use crate::interactive::layout;
 
@@ -12,7 +13,7 @@ pub fn draw(frame: &mut Frame, app: &InteractiveApp) {
     // Guard: terminal too small to render anything meaningful
     if area.width < 20 || area.height < 5 {
         let msg = ratatui::widgets::Paragraph::new("Terminal too small")
-            .style(Style::default().fg(Color::Red));
+            .style(tui_fg(SemanticColor::Error));
         frame.render_widget(msg, area);
         return;
     }
@@ -34,9 +35,11 @@ pub fn draw(frame: &mut Frame, app: &InteractiveApp) {
             panels.left,
             app.teams.len(),
             app.available_projects.len() + 1,
+            app.saved_views.len(),
         );
         super::panels::teams::draw_teams(frame, left_col.teams, app);
         super::panels::projects::draw_projects(frame, left_col.projects, app);
+        super::panels::views::draw_views(frame, left_col.views, app);
         super::panels::list::draw_list(frame, left_col.issues, app);
         super::panels::detail::draw_detail(frame, panels.right, app);
     } else if app.show_detail_fullscreen {
@@ -48,9 +51,11 @@ pub fn draw(frame: &mut Frame, app: &InteractiveApp) {
             panels.left,
             app.teams.len(),
             app.available_projects.len() + 1,
+            app.saved_views.len(),
         );
         super::panels::teams::draw_teams(frame, left_col.teams, app);
         super::panels::projects::draw_projects(frame, left_col.projects, app);
+        super::panels::views::draw_views(frame, left_col.views, app);
         super::panels::list::draw_list(frame, left_col.issues, app);
     }
 
@@ -78,10 +83,10 @@ fn draw_footer(frame: &mut Frame, area: ratatui::layout::Rect, app: &Interactive
     };
 
     let line = Line::from(vec![
-        Span::styled(help_text, Style::default().fg(Color::DarkGray)),
+        Span::styled(help_text, tui_fg(SemanticColor::Muted)),
     ]);
 
     let footer = Paragraph::new(line)
-        .style(Style::default().bg(Color::Rgb(20, 22, 30)));
+        .style(Style::default().bg(tui_color(SemanticColor::Surface)));
     frame.render_widget(footer, area);
 }
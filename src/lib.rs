@@ -6,6 +6,11 @@ pub mod constants;
 pub mod filtering;
 pub mod formatting;
 pub mod models;
+pub mod resolver;
+pub mod report;
+pub mod templates;
+pub mod timer;
+pub mod editor;
 pub mod error;
 pub mod cli_context;
 pub mod graphql_fields;
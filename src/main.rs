@@ -5,16 +5,22 @@ mod client;
 mod commands;
 mod config;
 mod constants;
+mod editor;
 mod error;
 mod cli_context;
 mod filtering;
 mod formatting;
 mod models;
+mod resolver;
+mod report;
+mod templates;
+mod timer;
 mod interactive;
 mod graphql_fields;
 mod logging;
 
 use commands::*;
+use formatting::output::FORMATS;
 
 #[tokio::main]
 async fn main() {
@@ -38,6 +44,14 @@ async fn main() {
     let app = Command::new("linear")
         .about("Linear CLI - Interact with Linear's API from the command line")
         .version("1.0.0")
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .value_name("WHEN")
+                .help("When to use colors: auto (a terminal without NO_COLOR), always or never")
+                .value_parser(formatting::theme::ColorMode::NAMES.to_vec())
+                .default_value("auto")
+        )
         .subcommand(
             Command::new("auth")
                 .about("Authenticate with Linear")
@@ -109,14 +123,14 @@ async fn main() {
                 .arg(
                     Arg::new("assignee")
                         .long("assignee")
-                        .value_name("EMAIL")
-                        .help("Filter by assignee email")
+                        .value_name("USER")
+                        .help("Filter by assignee: me, email or name")
                 )
                 .arg(
                     Arg::new("team")
                         .long("team")
-                        .value_name("TEAM_KEY")
-                        .help("Filter by team key (e.g., ENG)")
+                        .value_name("TEAM")
+                        .help("Filter by team key or name (e.g., ENG)")
                 )
                 .arg(
                     Arg::new("search")
@@ -156,10 +170,32 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                     Arg::new("format")
                         .long("format")
                         .value_name("FORMAT")
-                        .help("Output format: simple, table, json")
-                        .value_parser(["simple", "table", "json"])
+                        .help("Output format: simple, table, json, csv, tsv, markdown, yaml, ndjson")
+                        .value_parser(FORMATS.to_vec())
+                        .default_value_if("columns", clap::builder::ArgPredicate::IsPresent, "table")
                         .default_value("simple")
                 )
+                .arg(
+                    Arg::new("template")
+                        .long("template")
+                        .value_name("TEMPLATE")
+                        .help("Print each result with a template such as '{{identifier}} {{title|truncate:50}}', or a named template from the config")
+                        .conflicts_with("format")
+                )
+                .arg(
+                    Arg::new("template-file")
+                        .long("template-file")
+                        .value_name("PATH")
+                        .help("Read the output template from a file")
+                        .conflicts_with_all(["format", "template"])
+                )
+                .arg(
+                    Arg::new("columns")
+                        .long("columns")
+                        .value_name("COLUMNS")
+                        .help("Columns for --format table, e.g. identifier,title:40,priority,estimate,due,labels (implies --format table)")
+                        .conflicts_with_all(["template", "template-file"])
+                )
                 .arg(
                     Arg::new("group-by")
                         .long("group-by")
@@ -168,6 +204,12 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                         .value_parser(["status", "project"])
                         .default_value("status")
                 )
+                .arg(
+                    Arg::new("sort")
+                        .long("sort")
+                        .value_name("KEYS")
+                        .help("Sort by comma-separated fields, '-' for descending (e.g. priority,-updated)")
+                )
         )
         .subcommand(
             Command::new("create")
@@ -179,8 +221,8 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                         .arg(
                             Arg::new("title")
                                 .value_name("TITLE")
-                                .help("Issue title")
-                                .required(true)
+                                .help("Issue title (fills {{title}} when using a template)")
+                                .required_unless_present("template")
                                 .index(1)
                         )
                         .arg(
@@ -193,8 +235,8 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                             Arg::new("team")
                                 .short('t')
                                 .long("team")
-                                .value_name("TEAM_KEY")
-                                .help("Team key (e.g., ENG)")
+                                .value_name("TEAM")
+                                .help("Team key or name (e.g., ENG)")
                         )
                         .arg(
                             Arg::new("priority")
@@ -207,15 +249,41 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                             Arg::new("assignee")
                                 .short('a')
                                 .long("assignee")
-                                .value_name("USER_ID")
-                                .help("Assignee user ID")
+                                .value_name("USER")
+                                .help("Assignee: me, email or name")
                         )
                         .arg(
                             Arg::new("labels")
                                 .short('l')
                                 .long("labels")
-                                .value_name("LABEL_IDS")
-                                .help("Label IDs (can be specified multiple times)")
+                                .value_name("LABELS")
+                                .help("Label names (can be specified multiple times)")
+                                .action(clap::ArgAction::Append)
+                        )
+                        .arg(
+                            Arg::new("project")
+                                .long("project")
+                                .value_name("PROJECT")
+                                .help("Project name, slug or ID")
+                        )
+                        .arg(
+                            Arg::new("estimate")
+                                .short('e')
+                                .long("estimate")
+                                .value_name("POINTS")
+                                .help("Estimate in points")
+                        )
+                        .arg(
+                            Arg::new("template")
+                                .long("template")
+                                .value_name("NAME")
+                                .help("Start from a local or Linear issue template (see 'linear templates')")
+                        )
+                        .arg(
+                            Arg::new("var")
+                                .long("var")
+                                .value_name("KEY=VALUE")
+                                .help("Fill a template placeholder (can be specified multiple times)")
                                 .action(clap::ArgAction::Append)
                         )
                 )
@@ -239,8 +307,8 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                             Arg::new("teams")
                                 .short('t')
                                 .long("teams")
-                                .value_name("TEAM_IDS")
-                                .help("Team IDs (can be specified multiple times)")
+                                .value_name("TEAMS")
+                                .help("Team keys or names (can be specified multiple times)")
                                 .action(clap::ArgAction::Append)
                         )
                 )
@@ -277,8 +345,8 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                             Arg::new("state")
                                 .short('s')
                                 .long("state")
-                                .value_name("STATE_ID")
-                                .help("New state ID")
+                                .value_name("STATE")
+                                .help("New state: name (e.g., \"In Review\"), type (e.g., done, started) or ID")
                         )
                         .arg(
                             Arg::new("priority")
@@ -291,15 +359,15 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                             Arg::new("assignee")
                                 .short('a')
                                 .long("assignee")
-                                .value_name("USER_ID")
-                                .help("New assignee user ID")
+                                .value_name("USER")
+                                .help("New assignee: me, email, name or none")
                         )
                         .arg(
                             Arg::new("labels")
                                 .short('l')
                                 .long("labels")
-                                .value_name("LABEL_IDS")
-                                .help("New label IDs (can be specified multiple times)")
+                                .value_name("LABELS")
+                                .help("New label names (can be specified multiple times)")
                                 .action(clap::ArgAction::Append)
                         )
                 )
@@ -308,8 +376,8 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                         .about("Update an existing project")
                         .arg(
                             Arg::new("id")
-                                .value_name("PROJECT_ID")
-                                .help("Project ID")
+                                .value_name("PROJECT")
+                                .help("Project name, slug or ID")
                                 .required(true)
                                 .index(1)
                         )
@@ -356,8 +424,8 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                         .about("Archive a project")
                         .arg(
                             Arg::new("id")
-                                .value_name("PROJECT_ID")
-                                .help("Project ID to archive")
+                                .value_name("PROJECT")
+                                .help("Project name, slug or ID to archive")
                                 .required(true)
                                 .index(1)
                         )
@@ -366,10 +434,274 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
         .subcommand(
             Command::new("teams")
                 .about("List teams")
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_name("FORMAT")
+                        .help("Output format: simple, table, json, csv, tsv, markdown, yaml, ndjson")
+                        .value_parser(FORMATS.to_vec())
+                        .default_value("simple")
+                )
+                .arg(
+                    Arg::new("template")
+                        .long("template")
+                        .value_name("TEMPLATE")
+                        .help("Print each result with a template such as '{{identifier}} {{title|truncate:50}}', or a named template from the config")
+                        .conflicts_with("format")
+                )
+                .arg(
+                    Arg::new("template-file")
+                        .long("template-file")
+                        .value_name("PATH")
+                        .help("Read the output template from a file")
+                        .conflicts_with_all(["format", "template"])
+                )
         )
         .subcommand(
             Command::new("projects")
                 .about("List projects")
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_name("FORMAT")
+                        .help("Output format: simple, table, json, csv, tsv, markdown, yaml, ndjson")
+                        .value_parser(FORMATS.to_vec())
+                        .default_value("simple")
+                )
+                .arg(
+                    Arg::new("template")
+                        .long("template")
+                        .value_name("TEMPLATE")
+                        .help("Print each result with a template such as '{{identifier}} {{title|truncate:50}}', or a named template from the config")
+                        .conflicts_with("format")
+                )
+                .arg(
+                    Arg::new("template-file")
+                        .long("template-file")
+                        .value_name("PATH")
+                        .help("Read the output template from a file")
+                        .conflicts_with_all(["format", "template"])
+                )
+        )
+        .subcommand(
+            Command::new("states")
+                .about("List workflow states")
+                .arg(
+                    Arg::new("team")
+                        .long("team")
+                        .short('t')
+                        .value_name("TEAM")
+                        .help("Only show this team's states (key, name or ID)")
+                )
+        )
+        .subcommand(
+            Command::new("state")
+                .about("Manage a team's workflow states")
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .subcommand(
+                    Command::new("create")
+                        .about("Create a workflow state")
+                        .arg(
+                            Arg::new("name")
+                                .value_name("NAME")
+                                .help("State name")
+                                .required(true)
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("team")
+                                .long("team")
+                                .short('t')
+                                .value_name("TEAM")
+                                .help("Team to add the state to")
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("type")
+                                .long("type")
+                                .value_name("TYPE")
+                                .help("State type: triage, backlog, unstarted, started, completed, canceled")
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("color")
+                                .long("color")
+                                .short('c')
+                                .value_name("HEX")
+                                .help("State color (defaults to the type's color)")
+                        )
+                        .arg(
+                            Arg::new("description")
+                                .long("description")
+                                .short('d')
+                                .value_name("TEXT")
+                                .help("State description")
+                        )
+                )
+                .subcommand(
+                    Command::new("move")
+                        .about("Reorder a workflow state within its type")
+                        .arg(
+                            Arg::new("state")
+                                .value_name("STATE")
+                                .help("State name or ID")
+                                .required(true)
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("team")
+                                .long("team")
+                                .short('t')
+                                .value_name("TEAM")
+                                .help("Team the state belongs to")
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("before")
+                                .long("before")
+                                .value_name("STATE")
+                                .help("Place the state before this one")
+                                .conflicts_with("after")
+                        )
+                        .arg(
+                            Arg::new("after")
+                                .long("after")
+                                .value_name("STATE")
+                                .help("Place the state after this one")
+                        )
+                )
+        )
+        .subcommand(
+            Command::new("labels")
+                .about("List issue labels")
+                .arg(
+                    Arg::new("team")
+                        .long("team")
+                        .short('t')
+                        .value_name("TEAM")
+                        .help("Only show labels available to this team (key, name or ID)")
+                )
+        )
+        .subcommand(
+            Command::new("templates")
+                .about("List local and Linear issue templates")
+        )
+        .subcommand(
+            Command::new("label")
+                .about("Manage issue labels and label groups")
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .subcommand(
+                    Command::new("create")
+                        .about("Create a label or label group")
+                        .arg(
+                            Arg::new("name")
+                                .value_name("NAME")
+                                .help("Label name")
+                                .required(true)
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("color")
+                                .long("color")
+                                .short('c')
+                                .value_name("HEX")
+                                .help("Label color (e.g., #5e6ad2)")
+                        )
+                        .arg(
+                            Arg::new("team")
+                                .long("team")
+                                .short('t')
+                                .value_name("TEAM")
+                                .help("Create a team label instead of a workspace label")
+                        )
+                        .arg(
+                            Arg::new("parent")
+                                .long("parent")
+                                .short('p')
+                                .value_name("GROUP")
+                                .help("Label group to place the label in")
+                        )
+                        .arg(
+                            Arg::new("group")
+                                .long("group")
+                                .help("Create a label group that other labels can be nested under")
+                                .action(clap::ArgAction::SetTrue)
+                        )
+                        .arg(
+                            Arg::new("description")
+                                .long("description")
+                                .short('d')
+                                .value_name("TEXT")
+                                .help("Label description")
+                        )
+                )
+                .subcommand(
+                    Command::new("rename")
+                        .about("Rename a label")
+                        .arg(
+                            Arg::new("label")
+                                .value_name("LABEL")
+                                .help("Label name, Group/Name or ID")
+                                .required(true)
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("name")
+                                .value_name("NEW_NAME")
+                                .help("New label name")
+                                .required(true)
+                                .index(2)
+                        )
+                )
+                .subcommand(
+                    Command::new("color")
+                        .about("Change a label's color")
+                        .arg(
+                            Arg::new("label")
+                                .value_name("LABEL")
+                                .help("Label name, Group/Name or ID")
+                                .required(true)
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("color")
+                                .value_name("HEX")
+                                .help("New color (e.g., #5e6ad2)")
+                                .required(true)
+                                .index(2)
+                        )
+                )
+                .subcommand(
+                    Command::new("archive")
+                        .about("Archive a label")
+                        .arg(
+                            Arg::new("label")
+                                .value_name("LABEL")
+                                .help("Label name, Group/Name or ID")
+                                .required(true)
+                                .index(1)
+                        )
+                )
+                .subcommand(
+                    Command::new("merge")
+                        .about("Move every issue from one label to another, then archive the old label")
+                        .arg(
+                            Arg::new("old")
+                                .value_name("OLD")
+                                .help("Label to merge away")
+                                .required(true)
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("new")
+                                .value_name("NEW")
+                                .help("Label to apply instead")
+                                .required(true)
+                                .index(2)
+                        )
+                )
         )
         .subcommand(
             Command::new("whoami")
@@ -405,14 +737,14 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                         .arg(
                             Arg::new("state")
                                 .long("state")
-                                .value_name("STATE_ID")
-                                .help("New state for all issues")
+                                .value_name("STATE")
+                                .help("New state for all issues, resolved per team (name, type or ID)")
                         )
                         .arg(
                             Arg::new("assignee")
                                 .long("assignee")
-                                .value_name("USER_ID")
-                                .help("New assignee for all issues")
+                                .value_name("USER")
+                                .help("New assignee for all issues: me, email, name or none")
                         )
                         .arg(
                             Arg::new("priority")
@@ -423,14 +755,14 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                         .arg(
                             Arg::new("labels")
                                 .long("labels")
-                                .value_name("LABEL_IDS")
-                                .help("Add labels to all issues (comma-separated)")
+                                .value_name("LABELS")
+                                .help("Add labels to all issues (comma-separated names)")
                         )
                         .arg(
                             Arg::new("remove-labels")
                                 .long("remove-labels")
-                                .value_name("LABEL_IDS")
-                                .help("Remove labels from all issues (comma-separated)")
+                                .value_name("LABELS")
+                                .help("Remove labels from all issues (comma-separated names)")
                         )
                 )
                 .subcommand(
@@ -447,14 +779,14 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                         .arg(
                             Arg::new("team")
                                 .long("team")
-                                .value_name("TEAM_ID")
-                                .help("Move to this team")
+                                .value_name("TEAM")
+                                .help("Move to this team (key, name or ID)")
                         )
                         .arg(
                             Arg::new("project")
                                 .long("project")
-                                .value_name("PROJECT_ID")
-                                .help("Move to this project")
+                                .value_name("PROJECT")
+                                .help("Move to this project (name, slug or ID)")
                         )
                 )
                 .subcommand(
@@ -488,7 +820,7 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                         .arg(
                             Arg::new("query")
                                 .value_name("QUERY")
-                                .help("Filter query to save")
+                                .help("Filter query to save; may use $me, $team, $cycle and ${name} or ${name:-default}")
                                 .required(true)
                                 .index(2)
                         )
@@ -522,10 +854,421 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                             Arg::new("format")
                                 .long("format")
                                 .value_name("FORMAT")
-                                .help("Output format: simple, table, json")
-                                .value_parser(["simple", "table", "json"])
+                                .help("Output format: simple, table, json, csv, tsv, markdown, yaml, ndjson")
+                                .value_parser(FORMATS.to_vec())
+                                .default_value_if("columns", clap::builder::ArgPredicate::IsPresent, "table")
                                 .default_value("simple")
                         )
+                        .arg(
+                            Arg::new("template")
+                                .long("template")
+                                .value_name("TEMPLATE")
+                                .help("Print each result with a template such as '{{identifier}} {{title|truncate:50}}', or a named template from the config")
+                                .conflicts_with("format")
+                        )
+                        .arg(
+                            Arg::new("template-file")
+                                .long("template-file")
+                                .value_name("PATH")
+                                .help("Read the output template from a file")
+                                .conflicts_with_all(["format", "template"])
+                        )
+                        .arg(
+                            Arg::new("columns")
+                                .long("columns")
+                                .value_name("COLUMNS")
+                                .help("Columns for --format table, e.g. identifier,title:40,priority,estimate,due,labels (implies --format table)")
+                                .conflicts_with_all(["template", "template-file"])
+                        )
+                        .arg(
+                            Arg::new("limit")
+                                .long("limit")
+                                .value_name("NUMBER")
+                                .help("Limit the number of results (default: 50)")
+                                .default_value("50")
+                        )
+                        .arg(
+                            Arg::new("sort")
+                                .long("sort")
+                                .value_name("KEYS")
+                                .help("Sort by comma-separated fields, overriding the search's sort clause")
+                        )
+                        .arg(
+                            Arg::new("set")
+                                .long("set")
+                                .value_name("NAME=VALUE")
+                                .help("Set a variable of the search, e.g. label=backend (can be specified multiple times)")
+                                .action(clap::ArgAction::Append)
+                        )
+                )
+                .subcommand(
+                    Command::new("push")
+                        .about("Publish a saved search as a Linear custom view")
+                        .arg(
+                            Arg::new("name")
+                                .value_name("NAME")
+                                .help("Name of the saved search to push")
+                                .required(true)
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("view")
+                                .long("view")
+                                .value_name("VIEW")
+                                .help("Name of the custom view (defaults to the search name)")
+                        )
+                        .arg(
+                            Arg::new("team")
+                                .long("team")
+                                .short('t')
+                                .value_name("TEAM")
+                                .help("Team for a new view (defaults to a workspace view)")
+                        )
+                        .arg(
+                            Arg::new("personal")
+                                .long("personal")
+                                .help("Create the view unshared")
+                                .action(clap::ArgAction::SetTrue)
+                        )
+                        .arg(
+                            Arg::new("set")
+                                .long("set")
+                                .value_name("NAME=VALUE")
+                                .help("Set a variable of the search before pushing it (can be specified multiple times)")
+                                .action(clap::ArgAction::Append)
+                        )
+                )
+                .subcommand(
+                    Command::new("pull")
+                        .about("Import Linear custom views as saved searches")
+                        .arg(
+                            Arg::new("view")
+                                .value_name("VIEW")
+                                .help("Only import this view (name or ID)")
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("force")
+                                .long("force")
+                                .short('f')
+                                .help("Replace saved searches that have the same name")
+                                .action(clap::ArgAction::SetTrue)
+                        )
+                )
+                .subcommand(
+                    Command::new("text")
+                        .about("Search issue titles, descriptions and comments, most relevant first")
+                        .arg(
+                            Arg::new("terms")
+                                .value_name("TERMS")
+                                .help("Words to search for; quote a phrase to keep it together")
+                                .required(true)
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("filter")
+                                .long("filter")
+                                .short('f')
+                                .value_name("QUERY")
+                                .help("Only show results matching this filter query")
+                        )
+                        .arg(
+                            Arg::new("no-comments")
+                                .long("no-comments")
+                                .help("Don't search comments")
+                                .action(clap::ArgAction::SetTrue)
+                        )
+                        .arg(
+                            Arg::new("format")
+                                .long("format")
+                                .value_name("FORMAT")
+                                .help("Output format: simple, json")
+                                .value_parser(["simple", "json"])
+                                .default_value("simple")
+                        )
+                        .arg(
+                            Arg::new("limit")
+                                .long("limit")
+                                .value_name("NUMBER")
+                                .help("Limit the number of results (default: 25)")
+                                .default_value("25")
+                        )
+                )
+        )
+        .subcommand(
+            Command::new("docs")
+                .about("List Linear documents")
+                .arg(
+                    Arg::new("project")
+                        .long("project")
+                        .value_name("PROJECT")
+                        .help("Only show documents in this project (name, slug or ID)")
+                )
+                .arg(
+                    Arg::new("limit")
+                        .long("limit")
+                        .value_name("NUMBER")
+                        .help("Limit the number of results (default: 50)")
+                        .default_value("50")
+                )
+        )
+        .subcommand(
+            Command::new("doc")
+                .about("Read, create and edit Linear documents")
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .subcommand(
+                    Command::new("show")
+                        .about("Show a document")
+                        .arg(
+                            Arg::new("id")
+                                .value_name("ID")
+                                .help("Document ID, slug ID or URL")
+                                .required(true)
+                                .index(1)
+                        )
+                )
+                .subcommand(
+                    Command::new("new")
+                        .about("Write a new document in $EDITOR")
+                        .arg(
+                            Arg::new("project")
+                                .long("project")
+                                .value_name("PROJECT")
+                                .help("Project the document belongs to")
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("title")
+                                .long("title")
+                                .value_name("TITLE")
+                                .help("Document title (or start the text with '# Title')")
+                        )
+                )
+                .subcommand(
+                    Command::new("edit")
+                        .about("Edit a document in $EDITOR")
+                        .arg(
+                            Arg::new("id")
+                                .value_name("ID")
+                                .help("Document ID, slug ID or URL")
+                                .required(true)
+                                .index(1)
+                        )
+                )
+        )
+        .subcommand(
+            Command::new("timer")
+                .about("Track time against issues locally")
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .subcommand(
+                    Command::new("start")
+                        .about("Start timing an issue, stopping any running timer")
+                        .arg(
+                            Arg::new("issue")
+                                .value_name("ISSUE")
+                                .help("Issue identifier (e.g., ENG-123)")
+                                .required(true)
+                                .index(1)
+                        )
+                )
+                .subcommand(
+                    Command::new("stop")
+                        .about("Stop the running timer")
+                        .arg(
+                            Arg::new("comment")
+                                .long("comment")
+                                .help("Post the time logged as a comment on the issue")
+                                .action(clap::ArgAction::SetTrue)
+                        )
+                        .arg(
+                            Arg::new("message")
+                                .short('m')
+                                .long("message")
+                                .value_name("TEXT")
+                                .help("Note to include in the comment")
+                                .requires("comment")
+                        )
+                )
+                .subcommand(
+                    Command::new("status")
+                        .about("Show the running timer")
+                )
+        )
+        .subcommand(
+            Command::new("timesheet")
+                .about("Summarize tracked time per issue and project")
+                .arg(
+                    Arg::new("week")
+                        .long("week")
+                        .help("Summarize a Monday-to-Sunday week (default: this week)")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with_all(["since", "until"])
+                )
+                .arg(
+                    Arg::new("weeks-ago")
+                        .long("weeks-ago")
+                        .value_name("N")
+                        .help("Summarize the week N weeks before this one")
+                        .conflicts_with_all(["since", "until"])
+                )
+                .arg(
+                    Arg::new("since")
+                        .long("since")
+                        .value_name("YYYY-MM-DD")
+                        .help("Start of the range")
+                )
+                .arg(
+                    Arg::new("until")
+                        .long("until")
+                        .value_name("YYYY-MM-DD")
+                        .help("End of the range, inclusive (default: today)")
+                )
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_name("FORMAT")
+                        .help("Output format")
+                        .value_parser(["table", "csv"])
+                        .default_value("table")
+                )
+        )
+        .subcommand(
+            Command::new("report")
+                .about("Report throughput, lead time and cycle time for a period")
+                .arg(
+                    Arg::new("since")
+                        .long("since")
+                        .value_name("WHEN")
+                        .help("Period to report on: 2w, 2026-10-01, last-month, last-cycle or a range a..b")
+                        .default_value("2w")
+                )
+                .arg(
+                    Arg::new("team")
+                        .long("team")
+                        .value_name("TEAM")
+                        .help("Only issues of this team (key or name, e.g. ENG)")
+                )
+                .arg(
+                    Arg::new("by")
+                        .long("by")
+                        .value_name("GROUP")
+                        .help("Break the figures down by assignee, label or project")
+                        .value_parser(report::GroupBy::NAMES.to_vec())
+                )
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_name("FORMAT")
+                        .help("Output format")
+                        .value_parser(["table", "markdown", "json"])
+                        .default_value("table")
+                )
+        )
+        .subcommand(
+            Command::new("filter")
+                .about("Work with filter queries")
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .subcommand(
+                    Command::new("complete")
+                        .about("Complete a partial filter query (used by shell completion)")
+                        .arg(
+                            Arg::new("query")
+                                .value_name("QUERY")
+                                .help("Partial filter query")
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("cursor")
+                                .long("cursor")
+                                .value_name("OFFSET")
+                                .help("Byte offset of the cursor in the query (default: the end)")
+                        )
+                        .arg(
+                            Arg::new("refresh")
+                                .long("refresh")
+                                .help("Fetch label, state, member, project and team names again")
+                                .action(clap::ArgAction::SetTrue)
+                        )
+                )
+                .subcommand(
+                    Command::new("explain")
+                        .about("Show how a filter query is parsed and what it compiles to")
+                        .arg(
+                            Arg::new("query")
+                                .value_name("QUERY")
+                                .help("Filter query to explain")
+                                .required_unless_present("search")
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("search")
+                                .long("search")
+                                .value_name("NAME")
+                                .help("Explain a saved search instead")
+                                .conflicts_with("query")
+                        )
+                        .arg(
+                            Arg::new("set")
+                                .long("set")
+                                .value_name("NAME=VALUE")
+                                .requires("search")
+                                .help("Set a variable of the saved search (can be specified multiple times)")
+                                .action(clap::ArgAction::Append)
+                        )
+                )
+        )
+        .subcommand(
+            Command::new("views")
+                .about("List Linear custom views")
+        )
+        .subcommand(
+            Command::new("view")
+                .about("Work with Linear custom views")
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .subcommand(
+                    Command::new("run")
+                        .about("Show the issues in a custom view")
+                        .arg(
+                            Arg::new("name")
+                                .value_name("NAME")
+                                .help("View name or ID")
+                                .required(true)
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("format")
+                                .long("format")
+                                .value_name("FORMAT")
+                                .help("Output format: simple, table, json, csv, tsv, markdown, yaml, ndjson")
+                                .value_parser(FORMATS.to_vec())
+                                .default_value_if("columns", clap::builder::ArgPredicate::IsPresent, "table")
+                                .default_value("simple")
+                        )
+                        .arg(
+                            Arg::new("template")
+                                .long("template")
+                                .value_name("TEMPLATE")
+                                .help("Print each result with a template such as '{{identifier}} {{title|truncate:50}}', or a named template from the config")
+                                .conflicts_with("format")
+                        )
+                        .arg(
+                            Arg::new("template-file")
+                                .long("template-file")
+                                .value_name("PATH")
+                                .help("Read the output template from a file")
+                                .conflicts_with_all(["format", "template"])
+                        )
+                        .arg(
+                            Arg::new("columns")
+                                .long("columns")
+                                .value_name("COLUMNS")
+                                .help("Columns for --format table, e.g. identifier,title:40,priority,estimate,due,labels (implies --format table)")
+                                .conflicts_with_all(["template", "template-file"])
+                        )
                         .arg(
                             Arg::new("limit")
                                 .long("limit")
@@ -550,6 +1293,28 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                                 .required(true)
                                 .index(1)
                         )
+                        .arg(
+                            Arg::new("format")
+                                .long("format")
+                                .value_name("FORMAT")
+                                .help("Output format: simple, table, json, csv, tsv, markdown, yaml, ndjson")
+                                .value_parser(FORMATS.to_vec())
+                                .default_value("simple")
+                        )
+                        .arg(
+                            Arg::new("template")
+                                .long("template")
+                                .value_name("TEMPLATE")
+                                .help("Print each result with a template such as '{{identifier}} {{title|truncate:50}}', or a named template from the config")
+                                .conflicts_with("format")
+                        )
+                        .arg(
+                            Arg::new("template-file")
+                                .long("template-file")
+                                .value_name("PATH")
+                                .help("Read the output template from a file")
+                                .conflicts_with_all(["format", "template"])
+                        )
                 )
                 .subcommand(
                     Command::new("add")
@@ -569,6 +1334,24 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                                 .index(2)
                         )
                 )
+                .subcommand(
+                    Command::new("reply")
+                        .about("Reply to an existing comment")
+                        .arg(
+                            Arg::new("id")
+                                .value_name("COMMENT_ID")
+                                .help("Comment ID to reply to")
+                                .required(true)
+                                .index(1)
+                        )
+                        .arg(
+                            Arg::new("body")
+                                .value_name("COMMENT")
+                                .help("Reply text (supports markdown)")
+                                .required(true)
+                                .index(2)
+                        )
+                )
                 .subcommand(
                     Command::new("update")
                         .about("Update an existing comment")
@@ -640,7 +1423,7 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                                 .short('s')
                                 .long("status")
                                 .value_name("STATE")
-                                .help("New status for the issue")
+                                .help("New status for the issue (name or type, e.g., done)")
                                 .requires("update-status")
                         )
                 )
@@ -711,6 +1494,11 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
         );
 
     let matches = app.get_matches();
+    let color_mode = matches
+        .get_one::<String>("color")
+        .and_then(|mode| formatting::theme::ColorMode::from_name(mode))
+        .unwrap_or(formatting::theme::ColorMode::Auto);
+    formatting::theme::init(color_mode);
 
     let result = match matches.subcommand() {
         None => interactive::handlers::run_interactive_mode().await,
@@ -739,6 +1527,26 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
         }
         Some(("teams", sub_matches)) => handle_teams(sub_matches).await,
         Some(("projects", sub_matches)) => handle_projects(sub_matches).await,
+        Some(("states", sub_matches)) => handle_states(sub_matches).await,
+        Some(("state", sub_matches)) => {
+            match sub_matches.subcommand() {
+                Some(("create", state_matches)) => handle_state_create(state_matches).await,
+                Some(("move", state_matches)) => handle_state_move(state_matches).await,
+                _ => unreachable!("Subcommand required"),
+            }
+        }
+        Some(("labels", sub_matches)) => handle_labels(sub_matches).await,
+        Some(("templates", sub_matches)) => handle_templates(sub_matches).await,
+        Some(("label", sub_matches)) => {
+            match sub_matches.subcommand() {
+                Some(("create", label_matches)) => handle_label_create(label_matches).await,
+                Some(("rename", label_matches)) => handle_label_rename(label_matches).await,
+                Some(("color", label_matches)) => handle_label_color(label_matches).await,
+                Some(("archive", label_matches)) => handle_label_archive(label_matches).await,
+                Some(("merge", label_matches)) => handle_label_merge(label_matches).await,
+                _ => unreachable!("Subcommand required"),
+            }
+        }
         Some(("whoami", sub_matches)) => handle_whoami(sub_matches).await,
         Some(("issue", sub_matches)) => handle_issue(sub_matches).await,
         Some(("search", sub_matches)) => {
@@ -747,6 +1555,42 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
                 Some(("list", _)) => handle_list_searches().await,
                 Some(("delete", search_matches)) => handle_delete_search(search_matches).await,
                 Some(("run", search_matches)) => handle_run_search(search_matches).await,
+                Some(("push", search_matches)) => handle_push_search(search_matches).await,
+                Some(("pull", search_matches)) => handle_pull_searches(search_matches).await,
+                Some(("text", search_matches)) => handle_text_search(search_matches).await,
+                _ => unreachable!("Subcommand required"),
+            }
+        }
+        Some(("docs", sub_matches)) => handle_docs(sub_matches).await,
+        Some(("doc", sub_matches)) => {
+            match sub_matches.subcommand() {
+                Some(("show", doc_matches)) => handle_doc_show(doc_matches).await,
+                Some(("new", doc_matches)) => handle_doc_new(doc_matches).await,
+                Some(("edit", doc_matches)) => handle_doc_edit(doc_matches).await,
+                _ => unreachable!("Subcommand required"),
+            }
+        }
+        Some(("timer", sub_matches)) => {
+            match sub_matches.subcommand() {
+                Some(("start", timer_matches)) => handle_timer_start(timer_matches).await,
+                Some(("stop", timer_matches)) => handle_timer_stop(timer_matches).await,
+                Some(("status", _)) => handle_timer_status().await,
+                _ => unreachable!("Subcommand required"),
+            }
+        }
+        Some(("timesheet", sub_matches)) => handle_timesheet(sub_matches).await,
+        Some(("report", sub_matches)) => handle_report(sub_matches).await,
+        Some(("filter", sub_matches)) => {
+            match sub_matches.subcommand() {
+                Some(("complete", filter_matches)) => handle_filter_complete(filter_matches).await,
+                Some(("explain", filter_matches)) => handle_filter_explain(filter_matches).await,
+                _ => unreachable!("Subcommand required"),
+            }
+        }
+        Some(("views", sub_matches)) => handle_views(sub_matches).await,
+        Some(("view", sub_matches)) => {
+            match sub_matches.subcommand() {
+                Some(("run", view_matches)) => handle_view_run(view_matches).await,
                 _ => unreachable!("Subcommand required"),
             }
         }
@@ -762,6 +1606,7 @@ Date values support relative dates: 1hour, 2days, 1week, 1month"#)
             match sub_matches.subcommand() {
                 Some(("list", comment_matches)) => handle_list_comments(comment_matches).await,
                 Some(("add", comment_matches)) => handle_add_comment(comment_matches).await,
+                Some(("reply", comment_matches)) => handle_reply_comment(comment_matches).await,
                 Some(("update", comment_matches)) => handle_update_comment(comment_matches).await,
                 Some(("delete", comment_matches)) => handle_delete_comment(comment_matches).await,
                 _ => unreachable!("Subcommand required"),
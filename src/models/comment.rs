@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Comment {
     pub id: String,
     pub body: String,
@@ -9,4 +9,76 @@ pub struct Comment {
     #[serde(rename = "updatedAt")]
     pub updated_at: String,
     pub user: Option<super::User>,
-}
\ No newline at end of file
+    #[serde(default)]
+    pub parent: Option<CommentParent>,
+    #[serde(default)]
+    pub reactions: Vec<Reaction>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CommentParent {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Reaction {
+    pub id: String,
+    pub emoji: String,
+    pub user: Option<super::User>,
+}
+
+impl Comment {
+    pub fn parent_id(&self) -> Option<&str> {
+        self.parent.as_ref().map(|p| p.id.as_str())
+    }
+
+    /// Reactions grouped by emoji, in order of first appearance, with their counts.
+    pub fn reaction_summary(&self) -> Vec<(&str, usize)> {
+        let mut summary: Vec<(&str, usize)> = Vec::new();
+        for reaction in &self.reactions {
+            match summary.iter_mut().find(|(emoji, _)| *emoji == reaction.emoji) {
+                Some((_, count)) => *count += 1,
+                None => summary.push((reaction.emoji.as_str(), 1)),
+            }
+        }
+        summary
+    }
+}
+
+/// Arrange comments into threads: every reply directly follows its parent (or an
+/// earlier sibling's subtree), ordered oldest first. Each entry carries its nesting
+/// depth. Replies whose parent is not in the list are treated as top-level.
+pub fn thread_comments(comments: &[Comment]) -> Vec<(usize, &Comment)> {
+    let known = |id: &str| comments.iter().any(|c| c.id == id);
+
+    let mut roots: Vec<&Comment> = comments
+        .iter()
+        .filter(|c| c.parent_id().is_none_or(|pid| !known(pid)))
+        .collect();
+    roots.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+    let mut threaded = Vec::with_capacity(comments.len());
+    for root in roots {
+        push_thread(comments, root, 0, &mut threaded);
+    }
+    threaded
+}
+
+fn push_thread<'a>(
+    comments: &'a [Comment],
+    comment: &'a Comment,
+    depth: usize,
+    out: &mut Vec<(usize, &'a Comment)>,
+) {
+    out.push((depth, comment));
+
+    let mut children: Vec<&Comment> = comments
+        .iter()
+        .filter(|c| c.parent_id() == Some(comment.id.as_str()))
+        .collect();
+    children.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+    for child in children {
+        push_thread(comments, child, depth + 1, out);
+    }
+}
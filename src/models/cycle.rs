@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// A team's cycle, as used to resolve `this-cycle`, `last-cycle` and `next-cycle`
+/// in filters.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Cycle {
+    pub id: String,
+    pub number: f64,
+    pub name: Option<String>,
+    pub starts_at: String,
+    pub ends_at: String,
+    #[serde(default)]
+    pub is_active: bool,
+    #[serde(default)]
+    pub is_previous: bool,
+    #[serde(default)]
+    pub is_next: bool,
+    pub team: super::Team,
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+/// A Linear document (RFCs, runbooks, specs). `content` is markdown and is only
+/// fetched when a single document is requested.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Document {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub content: Option<String>,
+    pub url: String,
+    pub slug_id: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub creator: Option<super::User>,
+    pub project: Option<DocumentProject>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DocumentProject {
+    pub id: String,
+    pub name: String,
+}
+
+impl Document {
+    /// The text handed to `$EDITOR`: the title as a level-one heading, then the body.
+    pub fn to_editor_text(&self) -> String {
+        format!("# {}\n\n{}", self.title, self.content.as_deref().unwrap_or("").trim_end())
+    }
+}
+
+/// Split editor text back into a title and body. A leading `# ` heading is the
+/// title; without one the whole text is the body and the title is `None`.
+pub fn parse_editor_text(text: &str) -> (Option<String>, String) {
+    let text = text.trim_start();
+    match text.split_once('\n').unwrap_or((text, "")) {
+        (first, rest) if first.starts_with("# ") => {
+            let title = first.trim_start_matches("# ").trim().to_string();
+            (Some(title).filter(|t| !t.is_empty()), rest.trim().to_string())
+        }
+        _ => (None, text.trim_end().to_string()),
+    }
+}
+
+/// Pull a document id out of a Linear document URL, whose last path segment ends
+/// with the slug id (`.../document/release-runbook-8f3a2b1c9d0e`). Anything that
+/// isn't a URL is returned unchanged.
+pub fn document_id_from_url(input: &str) -> &str {
+    if !input.contains("://") {
+        return input;
+    }
+    input
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .and_then(|segment| segment.rsplit('-').next())
+        .unwrap_or(input)
+}
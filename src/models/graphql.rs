@@ -35,6 +35,18 @@ pub struct CommentsData {
     pub comments: super::Connection<super::Comment>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SearchIssuesData {
+    #[serde(rename = "searchIssues")]
+    pub search_issues: super::Connection<super::IssueSearchResult>,
+}
+
+// Cycle data structures
+#[derive(Debug, Deserialize)]
+pub struct CyclesData {
+    pub cycles: super::Connection<super::Cycle>,
+}
+
 // Team data structures
 #[derive(Debug, Deserialize)]
 pub struct TeamsData {
@@ -53,6 +65,30 @@ pub struct ProjectsData {
     pub projects: super::Connection<super::Project>,
 }
 
+// Template data structures
+#[derive(Debug, Deserialize)]
+pub struct TemplatesData {
+    pub templates: Vec<super::Template>,
+}
+
+// Custom view data structures
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewsData {
+    pub custom_views: super::Connection<super::CustomView>,
+}
+
+// Document data structures
+#[derive(Debug, Deserialize)]
+pub struct DocumentsData {
+    pub documents: super::Connection<super::Document>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DocumentData {
+    pub document: super::Document,
+}
+
 // Mutation response structures
 #[derive(Debug, Deserialize)]
 pub struct IssueMutationPayload {
@@ -77,6 +113,39 @@ pub struct CommentMutationPayload {
     pub comment: Option<super::Comment>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ReactionMutationPayload {
+    pub success: bool,
+    pub reaction: Option<super::Reaction>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LabelMutationPayload {
+    pub success: bool,
+    #[serde(rename = "issueLabel")]
+    pub issue_label: Option<super::IssueLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WorkflowStateMutationPayload {
+    pub success: bool,
+    #[serde(rename = "workflowState")]
+    pub workflow_state: Option<super::WorkflowState>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CustomViewMutationPayload {
+    pub success: bool,
+    #[serde(rename = "customView")]
+    pub custom_view: Option<super::CustomView>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DocumentMutationPayload {
+    pub success: bool,
+    pub document: Option<super::Document>,
+}
+
 // Create mutation data structures
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -133,4 +202,75 @@ pub struct CommentUpdateData {
 #[serde(rename_all = "camelCase")]
 pub struct CommentDeleteData {
     pub comment_delete: ArchivePayload,
-}
\ No newline at end of file
+}
+
+// Reaction mutation data structures
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionCreateData {
+    pub reaction_create: ReactionMutationPayload,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionDeleteData {
+    pub reaction_delete: ArchivePayload,
+}
+
+// Label mutation data structures
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabelCreateData {
+    pub issue_label_create: LabelMutationPayload,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabelUpdateData {
+    pub issue_label_update: LabelMutationPayload,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabelArchiveData {
+    pub issue_label_archive: ArchivePayload,
+}
+
+// Workflow state mutation data structures
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateCreateData {
+    pub workflow_state_create: WorkflowStateMutationPayload,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateUpdateData {
+    pub workflow_state_update: WorkflowStateMutationPayload,
+}
+
+// Custom view mutation data structures
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewCreateData {
+    pub custom_view_create: CustomViewMutationPayload,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewUpdateData {
+    pub custom_view_update: CustomViewMutationPayload,
+}
+
+// Document mutation data structures
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentCreateData {
+    pub document_create: DocumentMutationPayload,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentUpdateData {
+    pub document_update: DocumentMutationPayload,
+}
@@ -12,6 +12,14 @@ pub struct Issue {
     pub created_at: String,
     #[serde(rename = "updatedAt")]
     pub updated_at: String,
+    #[serde(rename = "startedAt", default)]
+    pub started_at: Option<String>,
+    #[serde(rename = "completedAt", default)]
+    pub completed_at: Option<String>,
+    #[serde(rename = "dueDate", default)]
+    pub due_date: Option<String>,
+    #[serde(default)]
+    pub estimate: Option<f64>,
     pub state: WorkflowState,
     pub assignee: Option<super::User>,
     pub team: super::Team,
@@ -19,12 +27,33 @@ pub struct Issue {
     pub project: Option<Project>,
 }
 
+/// An issue found by full-text search, with its comments so the matches in them
+/// can be shown
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IssueSearchResult {
+    #[serde(flatten)]
+    pub issue: Issue,
+    #[serde(default)]
+    pub comments: CommentConnection,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct CommentConnection {
+    pub nodes: Vec<super::Comment>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct WorkflowState {
     pub id: String,
     pub name: String,
     #[serde(rename = "type")]
     pub state_type: String,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub position: Option<f64>,
+    #[serde(default)]
+    pub team: Option<super::Team>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -43,4 +72,6 @@ pub struct Label {
 pub struct Project {
     pub id: String,
     pub name: String,
+    #[serde(default)]
+    pub url: Option<String>,
 }
\ No newline at end of file
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// A workspace or team issue label as returned by the label management queries.
+/// Labels with `is_group` set act as parents for other labels and cannot be
+/// applied to issues directly.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabel {
+    pub id: String,
+    pub name: String,
+    pub color: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub is_group: bool,
+    pub parent: Option<LabelParent>,
+    pub team: Option<super::Team>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LabelParent {
+    pub id: String,
+    pub name: String,
+}
+
+impl IssueLabel {
+    /// Name including the parent group, e.g. `Type/Bug`.
+    pub fn full_name(&self) -> String {
+        match &self.parent {
+            Some(parent) => format!("{}/{}", parent.name, self.name),
+            None => self.name.clone(),
+        }
+    }
+}
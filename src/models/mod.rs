@@ -1,15 +1,25 @@
 pub mod comment;
+pub mod cycle;
+pub mod document;
 pub mod graphql;
 pub mod issue;
+pub mod label;
 pub mod project;
+pub mod template;
 pub mod user;
+pub mod view;
 
 // Re-export commonly used types
-pub use comment::Comment;
+pub use comment::{thread_comments, Comment, Reaction};
+pub use cycle::Cycle;
+pub use document::{document_id_from_url, parse_editor_text, Document};
 pub use graphql::GraphQLResponse;
-pub use issue::{Issue, WorkflowState};
+pub use issue::{Issue, IssueSearchResult, WorkflowState};
+pub use label::IssueLabel;
 pub use project::Project;
+pub use template::Template;
 pub use user::{Team, User};
+pub use view::CustomView;
 
 // Connection type used by GraphQL pagination
 use serde::{Deserialize, Serialize};
@@ -17,4 +27,15 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Connection<T> {
     pub nodes: Vec<T>,
+    #[serde(rename = "pageInfo", default)]
+    pub page_info: Option<PageInfo>,
+}
+
+/// Where a page of a connection ends, for fetching the next one
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PageInfo {
+    #[serde(rename = "hasNextPage")]
+    pub has_next_page: bool,
+    #[serde(rename = "endCursor")]
+    pub end_cursor: Option<String>,
 }
\ No newline at end of file
@@ -1,11 +1,13 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Project {
     pub id: String,
     pub name: String,
     pub description: Option<String>,
     pub url: String,
+    #[serde(default, rename = "slugId")]
+    pub slug_id: Option<String>,
     pub state: String,
     #[serde(rename = "createdAt")]
     pub created_at: String,
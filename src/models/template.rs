@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A template defined in Linear. Only templates with `template_type == "issue"`
+/// can be used to create issues; `template_data` holds the issue defaults.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Template {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub template_type: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub template_data: Value,
+    pub team: Option<super::Team>,
+}
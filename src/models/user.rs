@@ -5,6 +5,8 @@ pub struct User {
     pub id: String,
     pub name: String,
     pub email: String,
+    #[serde(default, rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A Linear custom view. `filter_data` is an `IssueFilter` and can be passed
+/// straight to the `issues` query.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomView {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub filter_data: Value,
+    #[serde(default)]
+    pub shared: bool,
+    pub team: Option<super::Team>,
+    pub updated_at: String,
+}
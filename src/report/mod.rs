@@ -0,0 +1,274 @@
+pub mod render;
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+use crate::filtering::{Calendar, DateSpec, Zone};
+use crate::models::Issue;
+
+#[allow(unused_imports)]
+pub use render::{render_json, render_markdown, render_table};
+
+/// What a report's rows are split by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    Assignee,
+    Label,
+    Project,
+}
+
+impl GroupBy {
+    pub const NAMES: &'static [&'static str] = &["assignee", "label", "project"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "assignee" | "assignees" => Some(Self::Assignee),
+            "label" | "labels" => Some(Self::Label),
+            "project" | "projects" => Some(Self::Project),
+            _ => None,
+        }
+    }
+
+    pub fn header(self) -> &'static str {
+        match self {
+            Self::Assignee => "Assignee",
+            Self::Label => "Label",
+            Self::Project => "Project",
+        }
+    }
+
+    /// The groups `issue` belongs to. An issue with several labels counts towards
+    /// each of them.
+    pub fn keys(self, issue: &Issue) -> Vec<String> {
+        match self {
+            Self::Assignee => vec![issue.assignee.as_ref().map_or("Unassigned".to_string(), |user| user.name.clone())],
+            Self::Project => vec![issue.project.as_ref().map_or("No project".to_string(), |project| project.name.clone())],
+            Self::Label if issue.labels.nodes.is_empty() => vec!["No label".to_string()],
+            Self::Label => issue.labels.nodes.iter().map(|label| label.name.clone()).collect(),
+        }
+    }
+}
+
+/// The span of time a report covers, from `start` up to `end`, and the zone its
+/// dates are shown in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Window {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub zone: Zone,
+}
+
+impl Window {
+    /// The window a `--since` value stands for. An offset, timestamp or day runs up
+    /// to now; a named period such as `last-month` or a range `a..b` covers just
+    /// that period, cut off at now.
+    pub fn from_spec(spec: &DateSpec, calendar: &Calendar, now: DateTime<Utc>) -> Result<Self, String> {
+        let period = spec.resolve(calendar, now)?;
+        let start = period.start.ok_or_else(|| "the report needs a start date".to_string())?;
+        let end = match spec {
+            DateSpec::Anchor(..) | DateSpec::Range(..) => period.end.unwrap_or(now).min(now),
+            _ => now,
+        };
+        if start >= end {
+            return Err("the report would start after it ends".to_string());
+        }
+        Ok(Self { start, end, zone: calendar.zone })
+    }
+
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        self.start <= at && at < self.end
+    }
+
+    /// Daily buckets for a month or less, weekly ones beyond that
+    pub fn bucket(&self) -> Bucket {
+        if self.end - self.start <= Duration::days(31) {
+            Bucket::Day
+        } else {
+            Bucket::Week
+        }
+    }
+
+    /// How many buckets the trend of this window has
+    pub fn buckets(&self) -> usize {
+        let length = self.bucket().length().num_seconds();
+        let span = (self.end - self.start).num_seconds();
+        ((span + length - 1) / length).max(1) as usize
+    }
+
+    fn bucket_of(&self, at: DateTime<Utc>) -> Option<usize> {
+        self.contains(at)
+            .then(|| ((at - self.start).num_seconds() / self.bucket().length().num_seconds()) as usize)
+    }
+
+    /// The first and last calendar days of the window
+    pub fn days(&self) -> (NaiveDate, NaiveDate) {
+        (self.zone.date(self.start), self.zone.date(self.end - Duration::seconds(1)))
+    }
+}
+
+/// How much time one point of a trend covers, counted from the start of the window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bucket {
+    Day,
+    Week,
+}
+
+impl Bucket {
+    pub fn length(self) -> Duration {
+        match self {
+            Self::Day => Duration::days(1),
+            Self::Week => Duration::weeks(1),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+        }
+    }
+}
+
+/// Throughput figures for a set of issues within a window.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Metrics {
+    /// Issues created in the window
+    pub created: usize,
+    /// Issues completed in the window
+    pub completed: usize,
+    /// Estimate points of the completed issues
+    pub points: f64,
+    /// Seconds from creation to completion of each completed issue
+    pub lead_times: Vec<i64>,
+    /// Seconds from starting to completion of each completed issue that was started
+    pub cycle_times: Vec<i64>,
+    /// Issues completed in each bucket of the window
+    pub completed_trend: Vec<usize>,
+    /// Issues created in each bucket of the window
+    pub created_trend: Vec<usize>,
+}
+
+impl Metrics {
+    fn new(window: &Window) -> Self {
+        let buckets = window.buckets();
+        Self { completed_trend: vec![0; buckets], created_trend: vec![0; buckets], ..Self::default() }
+    }
+
+    fn add(&mut self, issue: &Issue, window: &Window) {
+        let created = parse_time(&issue.created_at);
+        if let Some(bucket) = created.and_then(|at| window.bucket_of(at)) {
+            self.created += 1;
+            self.created_trend[bucket] += 1;
+        }
+
+        let completed = issue.completed_at.as_deref().and_then(parse_time);
+        let Some((completed, bucket)) = completed.and_then(|at| Some((at, window.bucket_of(at)?))) else {
+            return;
+        };
+        self.completed += 1;
+        self.completed_trend[bucket] += 1;
+        self.points += issue.estimate.unwrap_or(0.0);
+        if let Some(created) = created {
+            self.lead_times.push((completed - created).num_seconds().max(0));
+        }
+        if let Some(started) = issue.started_at.as_deref().and_then(parse_time) {
+            self.cycle_times.push((completed - started).num_seconds().max(0));
+        }
+    }
+
+    /// Created minus completed: how much the backlog grew
+    pub fn net(&self) -> i64 {
+        self.created as i64 - self.completed as i64
+    }
+
+    pub fn median_lead_time(&self) -> Option<i64> {
+        median(&self.lead_times)
+    }
+
+    pub fn median_cycle_time(&self) -> Option<i64> {
+        median(&self.cycle_times)
+    }
+}
+
+/// One row of a grouped report
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportGroup {
+    pub name: String,
+    pub metrics: Metrics,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub window: Window,
+    /// The team the issues were limited to, for the title
+    pub team: Option<String>,
+    pub group_by: Option<GroupBy>,
+    pub total: Metrics,
+    /// Most issues completed first
+    pub groups: Vec<ReportGroup>,
+}
+
+impl Report {
+    /// Tally `issues` within `window`. Issues neither created nor completed in the
+    /// window don't count.
+    pub fn build(issues: &[Issue], window: Window, group_by: Option<GroupBy>) -> Self {
+        let mut total = Metrics::new(&window);
+        let mut groups: Vec<ReportGroup> = Vec::new();
+        for issue in issues {
+            total.add(issue, &window);
+            for key in group_by.map(|group_by| group_by.keys(issue)).unwrap_or_default() {
+                let index = match groups.iter().position(|group| group.name == key) {
+                    Some(index) => index,
+                    None => {
+                        groups.push(ReportGroup { name: key, metrics: Metrics::new(&window) });
+                        groups.len() - 1
+                    }
+                };
+                groups[index].metrics.add(issue, &window);
+            }
+        }
+        groups.retain(|group| group.metrics.created + group.metrics.completed > 0);
+        groups.sort_by(|a, b| {
+            b.metrics.completed.cmp(&a.metrics.completed)
+                .then(b.metrics.points.total_cmp(&a.metrics.points))
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        });
+        Self { window, team: None, group_by, total, groups }
+    }
+}
+
+fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value).ok().map(|at| at.with_timezone(&Utc))
+}
+
+/// The middle value, or the mean of the two middle values; `None` when empty.
+pub fn median(values: &[i64]) -> Option<i64> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let middle = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(sorted[middle]),
+        _ => Some((sorted[middle - 1] + sorted[middle]) / 2),
+    }
+}
+
+/// `values` as a row of block characters scaled to the largest; zero is the lowest
+/// block so every bucket shows.
+pub fn sparkline(values: &[usize]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = values.iter().copied().max().unwrap_or(0);
+    values
+        .iter()
+        .map(|&value| BLOCKS[(value * 7 + max / 2).checked_div(max).unwrap_or(0)])
+        .collect()
+}
+
+/// A lead or cycle time: hours under two days, days beyond, e.g. `5h` or `3.5d`.
+pub fn format_span(seconds: i64) -> String {
+    let hours = seconds as f64 / 3600.0;
+    if hours < 48.0 {
+        format!("{:.0}h", hours)
+    } else {
+        format!("{:.1}d", hours / 24.0)
+    }
+}
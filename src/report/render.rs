@@ -0,0 +1,201 @@
+use colored::*;
+use serde_json::{json, Value};
+
+use crate::formatting::output::markdown_cell;
+use crate::formatting::table::{display_width, fit_widths, pad_to_width, truncate_to_width, WidthRule};
+use super::{format_span, sparkline, Metrics, Report};
+
+/// Space between table columns
+const GAP: usize = 2;
+
+const GROUP_HEADERS: [&str; 7] = ["Completed", "Created", "Net", "Points", "Lead", "Cycle", "Trend"];
+
+/// The trend's column in a group table, after the group name
+const TREND: usize = 7;
+
+fn format_points(points: f64) -> String {
+    if points.fract() == 0.0 {
+        format!("{:.0}", points)
+    } else {
+        format!("{:.1}", points)
+    }
+}
+
+fn format_net(net: i64) -> String {
+    if net > 0 {
+        format!("+{}", net)
+    } else {
+        net.to_string()
+    }
+}
+
+fn span_or_dash(seconds: Option<i64>) -> String {
+    seconds.map_or("-".to_string(), format_span)
+}
+
+fn title(report: &Report) -> String {
+    let (first, last) = report.window.days();
+    match &report.team {
+        Some(team) => format!("Report for {}: {} to {}", team, first, last),
+        None => format!("Report: {} to {}", first, last),
+    }
+}
+
+/// The figures of one group row, in the order of [`GROUP_HEADERS`]
+fn group_cells(metrics: &Metrics) -> Vec<String> {
+    vec![
+        metrics.completed.to_string(),
+        metrics.created.to_string(),
+        format_net(metrics.net()),
+        format_points(metrics.points),
+        span_or_dash(metrics.median_lead_time()),
+        span_or_dash(metrics.median_cycle_time()),
+        sparkline(&metrics.completed_trend),
+    ]
+}
+
+/// Lines of the terminal report laid out in `width` cells: the totals with their
+/// trends, then, when grouped, a table with one row per group.
+pub fn render_table(report: &Report, width: usize) -> Vec<String> {
+    let total = &report.total;
+    let mut lines = vec![
+        format!("{}, by {}", title(report), report.window.bucket().name()).bold().to_string(),
+        String::new(),
+    ];
+    let summary = [
+        ("Completed", total.completed.to_string(), sparkline(&total.completed_trend).green().to_string()),
+        ("Created", total.created.to_string(), sparkline(&total.created_trend).bright_blue().to_string()),
+        ("Net", format_net(total.net()), String::new()),
+        ("Points", format_points(total.points), String::new()),
+        ("Lead time", span_or_dash(total.median_lead_time()), "median, created to completed".dimmed().to_string()),
+        ("Cycle time", span_or_dash(total.median_cycle_time()), "median, started to completed".dimmed().to_string()),
+    ];
+    let value_width = summary.iter().map(|(_, value, _)| display_width(value)).max().unwrap_or(0);
+    for (label, value, note) in summary {
+        let line = format!("  {}  {:>width$}  {}", pad_to_width(label, 10).bold(), value, note, width = value_width);
+        lines.push(line.trim_end().to_string());
+    }
+
+    let Some(group_by) = report.group_by else {
+        return lines;
+    };
+    lines.push(String::new());
+    if report.groups.is_empty() {
+        lines.push("No issues created or completed in this period.".to_string());
+        return lines;
+    }
+
+    let headers: Vec<&str> = std::iter::once(group_by.header()).chain(GROUP_HEADERS).collect();
+    let rows: Vec<Vec<String>> = report
+        .groups
+        .iter()
+        .map(|group| std::iter::once(group.name.clone()).chain(group_cells(&group.metrics)).collect())
+        .collect();
+    let wanted: Vec<usize> = (0..headers.len())
+        .map(|i| rows.iter().map(|row| display_width(&row[i])).chain([display_width(headers[i])]).max().unwrap_or(0))
+        .collect();
+    // Only names shrink; a cut-off figure or trend would mislead, so the trend is
+    // shown whole or not at all
+    let rules: Vec<WidthRule> = (0..headers.len())
+        .map(|i| match i {
+            0 => WidthRule::between(8, 40).flex(),
+            TREND => WidthRule::fixed(wanted[i]).drop(1),
+            _ => WidthRule::fixed(wanted[i]),
+        })
+        .collect();
+    let widths = fit_widths(&rules, &wanted, width, GAP);
+    let shown: Vec<(usize, usize)> = widths.iter().enumerate().filter_map(|(i, w)| w.map(|w| (i, w))).collect();
+    let table_width = shown.iter().map(|(_, w)| w).sum::<usize>() + GAP * shown.len().saturating_sub(1);
+
+    // Names and trends on the left, figures on the right, and no trailing spaces
+    let line = |texts: &dyn Fn(usize) -> String, paint: &dyn Fn(usize, String) -> String| {
+        shown
+            .iter()
+            .enumerate()
+            .map(|(n, &(i, w))| {
+                let text = truncate_to_width(&texts(i), w);
+                let padding = " ".repeat(w.saturating_sub(display_width(&text)));
+                match (i == 0 || i == TREND, n + 1 == shown.len()) {
+                    (true, true) => paint(i, text),
+                    (true, false) => format!("{}{}", paint(i, text), padding),
+                    (false, _) => format!("{}{}", padding, paint(i, text)),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(&" ".repeat(GAP))
+    };
+    let rule = "─".repeat(table_width).dimmed().to_string();
+    lines.push(rule.clone());
+    lines.push(line(&|i| headers[i].to_string(), &|_, text| text.bold().to_string()));
+    lines.push(rule.clone());
+    for row in &rows {
+        lines.push(line(&|i| row[i].clone(), &|i, text| if i == TREND { text.green().to_string() } else { text }));
+    }
+    lines.push(rule);
+    lines
+}
+
+/// The report as Markdown: a table of the totals and, when grouped, one of the groups.
+pub fn render_markdown(report: &Report) -> String {
+    let total = &report.total;
+    let mut out = format!("## {}\n\n", title(report));
+    out.push_str(&format!("| Metric | Value | Trend (by {}) |\n|---|---:|---|\n", report.window.bucket().name()));
+    let rows = [
+        ("Completed", total.completed.to_string(), sparkline(&total.completed_trend)),
+        ("Created", total.created.to_string(), sparkline(&total.created_trend)),
+        ("Net", format_net(total.net()), String::new()),
+        ("Points delivered", format_points(total.points), String::new()),
+        ("Median lead time", span_or_dash(total.median_lead_time()), String::new()),
+        ("Median cycle time", span_or_dash(total.median_cycle_time()), String::new()),
+    ];
+    for (metric, value, trend) in rows {
+        out.push_str(&format!("| {} | {} | {} |\n", metric, value, trend));
+    }
+
+    if let Some(group_by) = report.group_by {
+        out.push_str(&format!("\n### By {}\n\n", group_by.header().to_lowercase()));
+        out.push_str(&format!("| {} | {} |\n", group_by.header(), GROUP_HEADERS.join(" | ")));
+        out.push_str(&format!("|---|{}---|\n", "---:|".repeat(GROUP_HEADERS.len() - 1)));
+        for group in &report.groups {
+            out.push_str(&format!("| {} | {} |\n", markdown_cell(&group.name), group_cells(&group.metrics).join(" | ")));
+        }
+    }
+    out
+}
+
+fn metrics_json(metrics: &Metrics) -> Value {
+    json!({
+        "completed": metrics.completed,
+        "created": metrics.created,
+        "net": metrics.net(),
+        "points": metrics.points,
+        "medianLeadTimeHours": metrics.median_lead_time().map(|s| s as f64 / 3600.0),
+        "medianCycleTimeHours": metrics.median_cycle_time().map(|s| s as f64 / 3600.0),
+        "completedTrend": metrics.completed_trend,
+        "createdTrend": metrics.created_trend,
+    })
+}
+
+/// The report as JSON, with times in hours and trends as counts per bucket.
+pub fn render_json(report: &Report) -> Value {
+    let mut value = json!({
+        "team": report.team,
+        "since": report.window.start.to_rfc3339(),
+        "until": report.window.end.to_rfc3339(),
+        "bucket": report.window.bucket().name(),
+        "total": metrics_json(&report.total),
+    });
+    if let Some(group_by) = report.group_by {
+        value["groupBy"] = json!(group_by.header().to_lowercase());
+        value["groups"] = report
+            .groups
+            .iter()
+            .map(|group| {
+                let mut row = metrics_json(&group.metrics);
+                row["name"] = json!(group.name);
+                row
+            })
+            .collect();
+    }
+    value
+}
@@ -0,0 +1,148 @@
+use crate::error::{LinearError, LinearResult};
+use crate::models::{CustomView, IssueLabel, Project, Team, User};
+
+type Pass<'p, T> = &'p dyn Fn(&T) -> bool;
+
+/// Try each matching pass in turn. The first pass that matches anything decides:
+/// one match wins, several is an ambiguity error listing them.
+fn pick<'a, T>(
+    kind: &str,
+    query: &str,
+    items: &'a [T],
+    passes: &[Pass<'_, T>],
+    describe: impl Fn(&T) -> String,
+) -> LinearResult<&'a T> {
+    for pass in passes {
+        let matches: Vec<&T> = items.iter().filter(|item| pass(item)).collect();
+        match matches.as_slice() {
+            [] => continue,
+            [item] => return Ok(item),
+            _ => {
+                return Err(LinearError::Ambiguous {
+                    kind: kind.to_string(),
+                    query: query.to_string(),
+                    candidates: matches.iter().map(|item| describe(item)).collect(),
+                })
+            }
+        }
+    }
+    Err(LinearError::InvalidInput(format!("No {} matches '{}'", kind, query)))
+}
+
+fn eq(a: &str, b: &str) -> bool {
+    a.eq_ignore_ascii_case(b)
+}
+
+/// Lowercase, hyphen-separated form of a name, as used in Linear URLs.
+pub fn slugify(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Match a user by id, email, full name or display name, then by first name or
+/// email local part. `me` must be handled by the caller.
+pub fn match_user<'a>(users: &'a [User], query: &str) -> LinearResult<&'a User> {
+    let query = query.trim();
+    pick(
+        "user",
+        query,
+        users,
+        &[
+            &|u: &User| u.id == query,
+            &|u: &User| eq(&u.email, query),
+            &|u: &User| eq(&u.name, query) || u.display_name.as_deref().is_some_and(|d| eq(d, query)),
+            &|u: &User| {
+                u.name.split_whitespace().next().is_some_and(|first| eq(first, query))
+                    || u.email.split('@').next().is_some_and(|local| eq(local, query))
+            },
+        ],
+        |u| format!("{} <{}>", u.name, u.email),
+    )
+}
+
+/// Match a team by id, key or name.
+pub fn match_team<'a>(teams: &'a [Team], query: &str) -> LinearResult<&'a Team> {
+    let query = query.trim();
+    pick(
+        "team",
+        query,
+        teams,
+        &[
+            &|t: &Team| t.id == query,
+            &|t: &Team| eq(&t.key, query),
+            &|t: &Team| eq(&t.name, query),
+        ],
+        |t| format!("{} ({})", t.name, t.key),
+    )
+}
+
+/// Match a label by id, `Group/Name` or name. When `team_id` is given, only that
+/// team's labels and workspace labels are considered.
+pub fn match_label<'a>(labels: &'a [IssueLabel], query: &str, team_id: Option<&str>) -> LinearResult<&'a IssueLabel> {
+    let query = query.trim();
+    let in_scope = |l: &IssueLabel| match (team_id, &l.team) {
+        (Some(team_id), Some(team)) => team.id == team_id,
+        _ => true,
+    };
+    pick(
+        "label",
+        query,
+        labels,
+        &[
+            &|l: &IssueLabel| l.id == query,
+            &|l: &IssueLabel| in_scope(l) && (eq(&l.full_name(), query) || eq(&l.name, query)),
+        ],
+        |l| match &l.team {
+            Some(team) => format!("{} ({}, {})", l.full_name(), team.key, l.id),
+            None => format!("{} (workspace, {})", l.full_name(), l.id),
+        },
+    )
+}
+
+/// Match a project by id, slug id, URL slug or name.
+pub fn match_project<'a>(projects: &'a [Project], query: &str) -> LinearResult<&'a Project> {
+    let query = query.trim();
+    let url_slug = |p: &Project| {
+        p.url
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .map(str::to_string)
+            .unwrap_or_default()
+    };
+    pick(
+        "project",
+        query,
+        projects,
+        &[
+            &|p: &Project| p.id == query || p.slug_id.as_deref() == Some(query),
+            &|p: &Project| eq(&url_slug(p), query),
+            &|p: &Project| eq(&p.name, query),
+            &|p: &Project| slugify(&p.name) == slugify(query),
+        ],
+        |p| format!("{} ({})", p.name, p.id),
+    )
+}
+
+/// Match a custom view by id, name, or slugified name.
+pub fn match_view<'a>(views: &'a [CustomView], query: &str) -> LinearResult<&'a CustomView> {
+    let query = query.trim();
+    let slug = slugify(query);
+    pick(
+        "view",
+        query,
+        views,
+        &[
+            &|v: &CustomView| v.id == query,
+            &|v: &CustomView| eq(&v.name, query),
+            &|v: &CustomView| slugify(&v.name) == slug,
+        ],
+        |v| match &v.team {
+            Some(team) => format!("{} ({})", v.name, team.key),
+            None => v.name.clone(),
+        },
+    )
+}
@@ -0,0 +1,172 @@
+pub mod entities;
+pub mod states;
+
+use std::collections::HashMap;
+
+use crate::client::LinearClient;
+use crate::error::{ErrorContext, LinearError, LinearResult};
+use crate::models::{Issue, IssueLabel, Project, Team, User, WorkflowState};
+
+#[allow(unused_imports)]
+pub use entities::{match_label, match_project, match_team, match_user, match_view, slugify};
+#[allow(unused_imports)]
+pub use states::{resolve_state, sort_states, state_type_alias, team_states};
+
+/// Turns the names people type on the command line (`me`, emails, team keys, label
+/// and project names, state names) into Linear entities. Each kind of entity is
+/// fetched once, on first use, and reused for the rest of the run.
+pub struct Resolver<'a> {
+    client: &'a LinearClient,
+    viewer: Option<User>,
+    users: Option<Vec<User>>,
+    teams: Option<Vec<Team>>,
+    labels: Option<Vec<IssueLabel>>,
+    projects: Option<Vec<Project>>,
+    states: Option<Vec<WorkflowState>>,
+    /// Issues fetched so far, by identifier and by id
+    issues: HashMap<String, Issue>,
+}
+
+impl<'a> Resolver<'a> {
+    pub fn new(client: &'a LinearClient) -> Self {
+        Self {
+            client,
+            viewer: None,
+            users: None,
+            teams: None,
+            labels: None,
+            projects: None,
+            states: None,
+            issues: HashMap::new(),
+        }
+    }
+
+    pub async fn viewer(&mut self) -> LinearResult<User> {
+        if self.viewer.is_none() {
+            let viewer = self.client.get_viewer().await
+                .map_err(|e| LinearError::ApiError(format!("Failed to get current user: {}", e)))
+                .context("Getting viewer information")?;
+            self.viewer = Some(viewer);
+        }
+        self.viewer.clone().context("Viewer not loaded")
+    }
+
+    pub async fn users(&mut self) -> LinearResult<&[User]> {
+        if self.users.is_none() {
+            let users = self.client.get_team_members().await
+                .map_err(|e| LinearError::ApiError(format!("Failed to get users: {}", e)))
+                .context("Getting users")?;
+            self.users = Some(users);
+        }
+        Ok(self.users.as_deref().unwrap_or_default())
+    }
+
+    pub async fn teams(&mut self) -> LinearResult<&[Team]> {
+        if self.teams.is_none() {
+            let teams = self.client.get_teams().await
+                .map_err(|e| LinearError::ApiError(format!("Failed to get teams: {}", e)))
+                .context("Getting teams")?;
+            self.teams = Some(teams);
+        }
+        Ok(self.teams.as_deref().unwrap_or_default())
+    }
+
+    pub async fn labels(&mut self) -> LinearResult<&[IssueLabel]> {
+        if self.labels.is_none() {
+            let labels = self.client.get_issue_labels(None).await
+                .map_err(|e| LinearError::ApiError(format!("Failed to get labels: {}", e)))
+                .context("Getting labels")?;
+            self.labels = Some(labels);
+        }
+        Ok(self.labels.as_deref().unwrap_or_default())
+    }
+
+    pub async fn projects(&mut self) -> LinearResult<&[Project]> {
+        if self.projects.is_none() {
+            let projects = self.client.get_projects().await
+                .map_err(|e| LinearError::ApiError(format!("Failed to get projects: {}", e)))
+                .context("Getting projects")?;
+            self.projects = Some(projects);
+        }
+        Ok(self.projects.as_deref().unwrap_or_default())
+    }
+
+    pub async fn states(&mut self) -> LinearResult<&[WorkflowState]> {
+        if self.states.is_none() {
+            let states = self.client.get_workflow_states().await
+                .map_err(|e| LinearError::ApiError(format!("Failed to get workflow states: {}", e)))
+                .context("Getting workflow states")?;
+            self.states = Some(states);
+        }
+        Ok(self.states.as_deref().unwrap_or_default())
+    }
+
+    /// Resolve `me`, an email, a name or a user id.
+    pub async fn user(&mut self, query: &str) -> LinearResult<User> {
+        if query.trim().eq_ignore_ascii_case("me") {
+            return self.viewer().await;
+        }
+        let users = self.users().await?;
+        match_user(users, query).cloned()
+    }
+
+    /// Resolve an assignee, where `none` (or an empty value) means unassigned.
+    pub async fn assignee_id(&mut self, query: &str) -> LinearResult<Option<String>> {
+        let query = query.trim();
+        if query.is_empty() || query.eq_ignore_ascii_case("none") {
+            return Ok(None);
+        }
+        Ok(Some(self.user(query).await?.id))
+    }
+
+    pub async fn team(&mut self, query: &str) -> LinearResult<Team> {
+        let teams = self.teams().await?;
+        match_team(teams, query).cloned()
+    }
+
+    /// Resolve a label name, `Group/Name` or id, preferring labels usable by `team_id`.
+    pub async fn label(&mut self, query: &str, team_id: Option<&str>) -> LinearResult<IssueLabel> {
+        let labels = self.labels().await?;
+        match_label(labels, query, team_id).cloned()
+    }
+
+    pub async fn label_ids<S: AsRef<str>>(&mut self, queries: &[S], team_id: Option<&str>) -> LinearResult<Vec<String>> {
+        let mut ids = Vec::with_capacity(queries.len());
+        for query in queries {
+            ids.push(self.label(query.as_ref(), team_id).await?.id);
+        }
+        Ok(ids)
+    }
+
+    pub async fn project(&mut self, query: &str) -> LinearResult<Project> {
+        let projects = self.projects().await?;
+        match_project(projects, query).cloned()
+    }
+
+    pub async fn state(&mut self, team_id: &str, query: &str) -> LinearResult<WorkflowState> {
+        let states = self.states().await?;
+        resolve_state(states, team_id, query).cloned()
+    }
+
+    pub async fn issue(&mut self, identifier: &str) -> LinearResult<Issue> {
+        let key = identifier.to_uppercase();
+        if let Some(issue) = self.issues.get(&key) {
+            return Ok(issue.clone());
+        }
+        let issue = self.client.get_issue_by_identifier(identifier).await
+            .map_err(|e| LinearError::ApiError(format!("Failed to get issue: {}", e)))
+            .with_context(|| format!("Getting issue {}", identifier))?;
+        self.issues.insert(issue.id.to_uppercase(), issue.clone());
+        self.issues.insert(issue.identifier.to_uppercase(), issue.clone());
+        self.issues.insert(key, issue.clone());
+        Ok(issue)
+    }
+
+    /// Resolve `query` for the team of the given issue. Returns the issue as well, since
+    /// it had to be fetched anyway.
+    pub async fn state_for_issue(&mut self, issue_id: &str, query: &str) -> LinearResult<(Issue, WorkflowState)> {
+        let issue = self.issue(issue_id).await?;
+        let state = self.state(&issue.team.id, query).await?;
+        Ok((issue, state))
+    }
+}
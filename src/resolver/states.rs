@@ -0,0 +1,87 @@
+use crate::error::{LinearError, LinearResult};
+use crate::models::WorkflowState;
+
+/// Board order of Linear's state types.
+const STATE_TYPE_ORDER: [&str; 6] = ["triage", "backlog", "unstarted", "started", "completed", "canceled"];
+
+fn normalize(s: &str) -> String {
+    s.trim()
+        .to_lowercase()
+        .replace(['-', '_'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Map common spellings of a state type (`done`, `todo`, `in progress`, ...) to
+/// Linear's state type.
+pub fn state_type_alias(query: &str) -> Option<&'static str> {
+    match normalize(query).as_str() {
+        "triage" => Some("triage"),
+        "backlog" => Some("backlog"),
+        "todo" | "to do" | "unstarted" => Some("unstarted"),
+        "started" | "in progress" | "progress" | "doing" | "wip" => Some("started"),
+        "done" | "completed" | "complete" | "closed" | "finished" => Some("completed"),
+        "canceled" | "cancelled" | "wontfix" => Some("canceled"),
+        _ => None,
+    }
+}
+
+pub fn state_type_rank(state_type: &str) -> usize {
+    STATE_TYPE_ORDER
+        .iter()
+        .position(|t| *t == state_type)
+        .unwrap_or(STATE_TYPE_ORDER.len())
+}
+
+/// Sort states the way Linear lays them out on a board: by type, then position.
+pub fn sort_states(states: &mut [&WorkflowState]) {
+    states.sort_by(|a, b| {
+        state_type_rank(&a.state_type)
+            .cmp(&state_type_rank(&b.state_type))
+            .then(a.position.unwrap_or(0.0).total_cmp(&b.position.unwrap_or(0.0)))
+    });
+}
+
+/// The states belonging to one team, in board order.
+pub fn team_states<'a>(states: &'a [WorkflowState], team_id: &str) -> Vec<&'a WorkflowState> {
+    let mut team: Vec<&WorkflowState> = states
+        .iter()
+        .filter(|s| s.team.as_ref().is_some_and(|t| t.id == team_id))
+        .collect();
+    sort_states(&mut team);
+    team
+}
+
+/// Resolve a state id, name (`In Review`) or type (`done`, `started`) to one of the
+/// given team's states. A type matches the first state of that type on the board.
+pub fn resolve_state<'a>(states: &'a [WorkflowState], team_id: &str, query: &str) -> LinearResult<&'a WorkflowState> {
+    let candidates = team_states(states, team_id);
+    if candidates.is_empty() {
+        return Err(LinearError::InvalidInput(format!("No workflow states found for team {}", team_id)));
+    }
+
+    if let Some(state) = candidates.iter().find(|s| s.id == query) {
+        return Ok(state);
+    }
+
+    let wanted = normalize(query);
+    if let Some(state) = candidates.iter().find(|s| normalize(&s.name) == wanted) {
+        return Ok(state);
+    }
+
+    if let Some(state_type) = state_type_alias(query) {
+        if let Some(state) = candidates.iter().find(|s| s.state_type == state_type) {
+            return Ok(state);
+        }
+    }
+
+    let team_key = candidates[0].team.as_ref().map(|t| t.key.as_str()).unwrap_or(team_id);
+    let names: Vec<&str> = candidates.iter().map(|s| s.name.as_str()).collect();
+    Err(LinearError::InvalidInput(format!(
+        "Unknown state '{}' for team {}. Available states: {}",
+        query,
+        team_key,
+        names.join(", ")
+    )))
+}
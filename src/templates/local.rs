@@ -0,0 +1,124 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{IssueTemplate, TemplateSource};
+use crate::constants::{TEMPLATES_DIR, TEMPLATES_DIR_ENV};
+use crate::error::{LinearError, LinearResult};
+use crate::filtering::parse_priority;
+
+/// Directory holding local `*.md` templates: `$LINEAR_TEMPLATES_DIR`, or
+/// `linear-cli/templates` under the platform config directory.
+pub fn templates_dir() -> Option<PathBuf> {
+    if let Ok(dir) = env::var(TEMPLATES_DIR_ENV) {
+        return Some(PathBuf::from(dir));
+    }
+    dirs::config_dir().map(|dir| dir.join(TEMPLATES_DIR))
+}
+
+/// Load every `*.md` template in the templates directory, sorted by name. A missing
+/// directory simply means there are no local templates.
+pub fn load_local_templates() -> LinearResult<Vec<IssueTemplate>> {
+    let Some(dir) = templates_dir() else { return Ok(Vec::new()) };
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut templates = Vec::new();
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("md") {
+            continue;
+        }
+        let contents = fs::read_to_string(&path)?;
+        templates.push(parse_template(&path, &contents)?);
+    }
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(templates)
+}
+
+fn unquote(value: &str) -> &str {
+    let value = value.trim();
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+        .unwrap_or(value)
+}
+
+/// Parse a template file: an optional `---` delimited header of `key: value` lines
+/// followed by the description markdown.
+///
+/// ```text
+/// ---
+/// title: "Bug: {{title}}"
+/// labels: Bug, Needs triage
+/// priority: high
+/// ---
+/// ## Steps to reproduce
+/// ```
+pub fn parse_template(path: &Path, contents: &str) -> LinearResult<IssueTemplate> {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("template");
+    let mut template = IssueTemplate {
+        name: stem.to_string(),
+        source: TemplateSource::Local(path.to_path_buf()),
+        title: None,
+        description: None,
+        team: None,
+        labels: Vec::new(),
+        priority: None,
+        estimate: None,
+        project: None,
+    };
+
+    let invalid = |msg: String| LinearError::ParseError(format!("{}: {}", path.display(), msg));
+
+    let body = match contents.strip_prefix("---") {
+        Some(rest) => {
+            let (header, body) = rest
+                .split_once("\n---")
+                .ok_or_else(|| invalid("header is missing its closing '---'".to_string()))?;
+
+            for line in header.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with('#')) {
+                let (key, value) = line
+                    .split_once(':')
+                    .ok_or_else(|| invalid(format!("expected 'key: value', got '{}'", line)))?;
+                let value = unquote(value);
+                match key.trim() {
+                    "name" => template.name = value.to_string(),
+                    "title" => template.title = Some(value.to_string()),
+                    "team" => template.team = Some(value.to_string()),
+                    "project" => template.project = Some(value.to_string()),
+                    "labels" => {
+                        template.labels = value
+                            .trim_start_matches('[')
+                            .trim_end_matches(']')
+                            .split(',')
+                            .map(|l| unquote(l).to_string())
+                            .filter(|l| !l.is_empty())
+                            .collect();
+                    }
+                    "priority" => {
+                        template.priority = Some(parse_priority(value)
+                            .ok_or_else(|| invalid(format!("invalid priority '{}'", value)))?);
+                    }
+                    "estimate" => {
+                        template.estimate = Some(value.parse::<f64>()
+                            .map_err(|_| invalid(format!("invalid estimate '{}'", value)))?);
+                    }
+                    other => return Err(invalid(format!("unknown key '{}'", other))),
+                }
+            }
+            // Drop the rest of the closing delimiter line
+            body.split_once('\n').map(|(_, b)| b).unwrap_or("")
+        }
+        None => contents,
+    };
+
+    let body = body.trim();
+    if !body.is_empty() {
+        template.description = Some(body.to_string());
+    }
+
+    Ok(template)
+}
@@ -0,0 +1,199 @@
+pub mod local;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde_json::Value;
+
+use crate::client::LinearClient;
+use crate::error::{ErrorContext, LinearError, LinearResult};
+use crate::models::Template;
+use crate::resolver::slugify;
+
+#[allow(unused_imports)]
+pub use local::{load_local_templates, parse_template, templates_dir};
+
+lazy_static! {
+    static ref PLACEHOLDER_RE: Regex = Regex::new(r"\{\{\s*([A-Za-z0-9_-]+)\s*\}\}").unwrap();
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateSource {
+    Local(PathBuf),
+    Linear { id: String },
+}
+
+/// Issue defaults shared by local template files and Linear-hosted templates.
+/// Teams, labels and projects are kept as typed (names or ids) and resolved when
+/// the issue is created.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueTemplate {
+    pub name: String,
+    pub source: TemplateSource,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub team: Option<String>,
+    pub labels: Vec<String>,
+    pub priority: Option<u8>,
+    pub estimate: Option<f64>,
+    pub project: Option<String>,
+}
+
+impl IssueTemplate {
+    /// Convert a Linear template. Returns `None` for non-issue templates.
+    pub fn from_linear(template: &Template) -> Option<Self> {
+        if template.template_type != "issue" {
+            return None;
+        }
+
+        // The JSON scalar sometimes arrives as an encoded string
+        let data = match &template.template_data {
+            Value::String(s) => serde_json::from_str(s).unwrap_or(Value::Null),
+            other => other.clone(),
+        };
+        let text = |key: &str| data.get(key).and_then(Value::as_str).map(str::to_string);
+
+        let description = text("description").or_else(|| {
+            data.get("descriptionData")
+                .map(prosemirror_to_markdown)
+                .filter(|d| !d.trim().is_empty())
+        });
+
+        Some(Self {
+            name: template.name.clone(),
+            source: TemplateSource::Linear { id: template.id.clone() },
+            title: text("title"),
+            description,
+            team: text("teamId").or_else(|| template.team.as_ref().map(|t| t.id.clone())),
+            labels: data
+                .get("labelIds")
+                .and_then(Value::as_array)
+                .map(|ids| ids.iter().filter_map(Value::as_str).map(str::to_string).collect())
+                .unwrap_or_default(),
+            priority: data.get("priority").and_then(Value::as_u64).map(|p| p.min(4) as u8),
+            estimate: data.get("estimate").and_then(Value::as_f64),
+            project: text("projectId"),
+        })
+    }
+
+    pub fn is_local(&self) -> bool {
+        matches!(self.source, TemplateSource::Local(_))
+    }
+
+    /// The issue title, with `{{title}}` replaced by the title given on the command
+    /// line. Without a pattern the given title is used as is.
+    pub fn render_title(&self, vars: &HashMap<String, String>) -> Option<String> {
+        match &self.title {
+            Some(pattern) => Some(render_placeholders(pattern, vars)),
+            None => vars.get("title").cloned(),
+        }
+    }
+
+    pub fn render_description(&self, vars: &HashMap<String, String>) -> Option<String> {
+        self.description.as_ref().map(|d| render_placeholders(d, vars))
+    }
+}
+
+/// Local templates followed by the workspace's Linear issue templates.
+pub async fn load_templates(client: &LinearClient) -> LinearResult<Vec<IssueTemplate>> {
+    let mut templates = load_local_templates().context("Loading local templates")?;
+    let linear = client.get_templates().await
+        .map_err(|e| LinearError::ApiError(format!("Failed to get templates: {}", e)))
+        .context("Getting templates")?;
+    templates.extend(linear.iter().filter_map(IssueTemplate::from_linear));
+    Ok(templates)
+}
+
+/// Replace `{{name}}` placeholders with values from `vars`. Unknown placeholders
+/// are left in place so they stay visible in the created issue.
+pub fn render_placeholders(text: &str, vars: &HashMap<String, String>) -> String {
+    PLACEHOLDER_RE
+        .replace_all(text, |caps: &regex::Captures| {
+            vars.get(&caps[1]).cloned().unwrap_or_else(|| caps[0].to_string())
+        })
+        .into_owned()
+}
+
+/// Names of the placeholders in `text`, in order of first appearance.
+pub fn placeholders(text: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for caps in PLACEHOLDER_RE.captures_iter(text) {
+        if !names.iter().any(|n| n == &caps[1]) {
+            names.push(caps[1].to_string());
+        }
+    }
+    names
+}
+
+/// Find a template by name, ignoring case and punctuation, so `bug` matches both
+/// `bug.md` and a Linear template called "Bug". Local templates win over Linear ones.
+pub fn find_template<'a>(templates: &'a [IssueTemplate], query: &str) -> LinearResult<&'a IssueTemplate> {
+    let wanted = slugify(query);
+    let matches: Vec<&IssueTemplate> = templates.iter().filter(|t| slugify(&t.name) == wanted).collect();
+    let local: Vec<&IssueTemplate> = matches.iter().copied().filter(|t| t.is_local()).collect();
+
+    let candidates = if local.is_empty() { matches } else { local };
+    match candidates.as_slice() {
+        [template] => Ok(template),
+        [] => Err(LinearError::InvalidInput(format!(
+            "Template '{}' not found. Run 'linear templates' to list available templates",
+            query
+        ))),
+        _ => Err(LinearError::Ambiguous {
+            kind: "template".to_string(),
+            query: query.to_string(),
+            candidates: candidates
+                .iter()
+                .map(|t| match &t.source {
+                    TemplateSource::Local(path) => format!("{} ({})", t.name, path.display()),
+                    TemplateSource::Linear { id } => format!("{} ({})", t.name, id),
+                })
+                .collect(),
+        }),
+    }
+}
+
+/// Flatten a ProseMirror document (Linear's rich description format) to markdown.
+pub fn prosemirror_to_markdown(node: &Value) -> String {
+    let children = |node: &Value| -> Vec<String> {
+        node.get("content")
+            .and_then(Value::as_array)
+            .map(|c| c.iter().map(prosemirror_to_markdown).collect())
+            .unwrap_or_default()
+    };
+    let node_type = node.get("type").and_then(Value::as_str).unwrap_or("");
+
+    match node_type {
+        "text" => node.get("text").and_then(Value::as_str).unwrap_or("").to_string(),
+        "hardBreak" => "\n".to_string(),
+        "paragraph" => children(node).concat(),
+        "heading" => {
+            let level = node.pointer("/attrs/level").and_then(Value::as_u64).unwrap_or(1) as usize;
+            format!("{} {}", "#".repeat(level.clamp(1, 6)), children(node).concat())
+        }
+        "codeBlock" => format!("```\n{}\n```", children(node).concat()),
+        "blockquote" => children(node)
+            .join("\n\n")
+            .lines()
+            .map(|l| format!("> {}", l))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "bulletList" | "orderedList" | "taskList" => children(node)
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let marker = match node_type {
+                    "orderedList" => format!("{}.", i + 1),
+                    "taskList" => "- [ ]".to_string(),
+                    _ => "-".to_string(),
+                };
+                format!("{} {}", marker, item.replace('\n', "\n  "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "listItem" | "taskItem" => children(node).join("\n"),
+        _ => children(node).join("\n\n"),
+    }
+}
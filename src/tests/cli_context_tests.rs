@@ -1,5 +1,4 @@
 use crate::cli_context::{CliContext, CliContextBuilder};
-use crate::error::LinearError;
 
 #[test]
 fn test_cli_context_new() {
@@ -0,0 +1,73 @@
+use crate::models::comment::{CommentParent, Reaction};
+use crate::models::{thread_comments, Comment};
+
+fn comment(id: &str, parent: Option<&str>, created_at: &str) -> Comment {
+    Comment {
+        id: id.to_string(),
+        body: format!("body of {}", id),
+        created_at: created_at.to_string(),
+        updated_at: created_at.to_string(),
+        user: None,
+        parent: parent.map(|p| CommentParent { id: p.to_string() }),
+        reactions: Vec::new(),
+    }
+}
+
+#[test]
+fn test_thread_comments_nests_replies_under_parent() {
+    let comments = vec![
+        comment("reply-2", Some("root-1"), "2024-01-01T12:00:00Z"),
+        comment("root-2", None, "2024-01-01T11:00:00Z"),
+        comment("root-1", None, "2024-01-01T10:00:00Z"),
+        comment("reply-1", Some("root-1"), "2024-01-01T10:30:00Z"),
+        comment("nested", Some("reply-1"), "2024-01-01T10:45:00Z"),
+    ];
+
+    let threaded: Vec<(usize, &str)> = thread_comments(&comments)
+        .into_iter()
+        .map(|(depth, c)| (depth, c.id.as_str()))
+        .collect();
+
+    assert_eq!(
+        threaded,
+        vec![
+            (0, "root-1"),
+            (1, "reply-1"),
+            (2, "nested"),
+            (1, "reply-2"),
+            (0, "root-2"),
+        ]
+    );
+}
+
+#[test]
+fn test_thread_comments_orphaned_reply_is_top_level() {
+    let comments = vec![comment("orphan", Some("missing"), "2024-01-01T10:00:00Z")];
+
+    let threaded = thread_comments(&comments);
+    assert_eq!(threaded.len(), 1);
+    assert_eq!(threaded[0].0, 0);
+}
+
+#[test]
+fn test_reaction_summary_groups_by_emoji() {
+    let mut c = comment("c1", None, "2024-01-01T10:00:00Z");
+    for (id, emoji) in [("r1", "👍"), ("r2", "🎉"), ("r3", "👍")] {
+        c.reactions.push(Reaction {
+            id: id.to_string(),
+            emoji: emoji.to_string(),
+            user: None,
+        });
+    }
+
+    assert_eq!(c.reaction_summary(), vec![("👍", 2), ("🎉", 1)]);
+}
+
+#[test]
+fn test_comment_deserializes_without_thread_fields() {
+    let json = r#"{"id":"c1","body":"hi","createdAt":"2024-01-01T10:00:00Z","updatedAt":"2024-01-01T10:00:00Z","user":null}"#;
+    let c: Comment = serde_json::from_str(json).unwrap();
+
+    assert!(c.parent_id().is_none());
+    assert!(c.reactions.is_empty());
+}
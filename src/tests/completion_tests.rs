@@ -0,0 +1,116 @@
+use crate::filtering::{complete, Completion, CompletionKind, Vocabulary};
+
+fn vocabulary() -> Vocabulary {
+    let mut vocabulary = Vocabulary {
+        labels: vec!["bug".into(), "Feature Request".into(), "Bug".into()],
+        states: vec!["Todo".into(), "In Progress".into(), "Done".into()],
+        members: vec!["Ada Lovelace".into()],
+        projects: vec!["Web".into()],
+        teams: vec!["ENG".into(), "OPS".into()],
+    };
+    vocabulary.normalize();
+    vocabulary
+}
+
+/// Every completion of `query` with the cursor at the end, applied
+fn completed(query: &str) -> Vec<String> {
+    complete(query, query.len(), &vocabulary())
+        .iter()
+        .map(|c| c.apply(query, query.len()).0)
+        .collect()
+}
+
+#[test]
+fn test_normalize_sorts_and_dedups() {
+    let vocabulary = vocabulary();
+    assert_eq!(vocabulary.labels, vec!["bug", "Feature Request"]);
+    assert_eq!(vocabulary.states, vec!["Done", "In Progress", "Todo"]);
+}
+
+#[test]
+fn test_complete_fields() {
+    let all = complete("", 0, &vocabulary());
+    assert!(all.iter().all(|c| c.kind != CompletionKind::Value));
+    assert_eq!(all[0].text, "status:");
+
+    assert_eq!(completed("lab"), vec!["label:", "no-label"]);
+    assert!(completed("priority:high AND as").contains(&"priority:high AND assignee:".to_string()));
+    assert!(completed("(pro").contains(&"(project:".to_string()));
+    assert!(completed("n").contains(&"NOT ".to_string()));
+}
+
+#[test]
+fn test_complete_operators() {
+    let completions = complete("label", 5, &vocabulary());
+    let operators: Vec<&str> = completions.iter()
+        .filter(|c| c.kind == CompletionKind::Operator)
+        .map(|c| c.text.as_str())
+        .collect();
+    assert_eq!(operators, vec!["label:", "label!=", "label in:", "label has:"]);
+    // `label:` is offered once
+    assert_eq!(completions.iter().filter(|c| c.text == "label:").count(), 1);
+
+    assert!(completed("priority").contains(&"priority>=".to_string()));
+    assert!(completed("title").contains(&"title~".to_string()));
+}
+
+#[test]
+fn test_complete_values() {
+    assert_eq!(completed("label:f"), vec![r#"label:"Feature Request""#]);
+    assert_eq!(completed("status:pro"), vec![r#"status:"In Progress""#]);
+    assert_eq!(completed(r#"status:"in"#), vec![r#"status:"In Progress""#]);
+    assert_eq!(completed("team:e"), vec!["team:ENG"]);
+    assert_eq!(completed("assignee:"), vec!["assignee:me", "assignee:null", r#"assignee:"Ada Lovelace""#]);
+    assert_eq!(completed("priority>=h"), vec!["priority>=high"]);
+    assert_eq!(completed("cycle:c"), vec!["cycle:current"]);
+    assert_eq!(completed("project!=w"), vec!["project!=Web"]);
+}
+
+#[test]
+fn test_complete_list_values() {
+    assert_eq!(completed("status in:Todo,in"), vec![r#"status in:Todo,"In Progress""#]);
+    assert_eq!(completed("label has:bug,f"), vec![r#"label has:bug,"Feature Request""#]);
+    assert_eq!(completed("sort:priority,-up"), vec!["sort:priority,-updated"]);
+    assert_eq!(completed("sort:ti"), vec!["sort:title"]);
+}
+
+#[test]
+fn test_complete_connectives() {
+    assert_eq!(completed("state:started "), vec!["state:started AND ", "state:started OR ", "state:started sort:"]);
+    assert_eq!(completed("state:started o"), vec!["state:started OR ", "state:started sort:"]);
+    assert_eq!(completed("(label:bug) a"), vec!["(label:bug) AND "]);
+    assert_eq!(completed("no-assignee "), vec!["no-assignee AND ", "no-assignee OR ", "no-assignee sort:"]);
+    // After a connective a field comes next
+    assert!(completed("label:bug AND ").contains(&"label:bug AND status:".to_string()));
+}
+
+#[test]
+fn test_complete_mid_query() {
+    let query = "label:b AND state:started";
+    let completions = complete(query, 7, &vocabulary());
+    assert_eq!(completions[0].text, "bug");
+    assert_eq!(completions[0].apply(query, 7), ("label:bug AND state:started".to_string(), 9));
+
+    // A cursor inside a multi-byte character is moved back to a boundary
+    let completion = Completion { kind: CompletionKind::Value, text: "x".into(), start: 0 };
+    assert_eq!(completion.apply("é", 1), ("xé".to_string(), 1));
+}
+
+#[test]
+fn test_complete_non_ascii_words() {
+    assert!(completed("日本").is_empty());
+    assert!(completed("bug AND 日本").is_empty());
+    assert!(completed("label:日本").is_empty());
+    assert!(completed("label in:日本").is_empty());
+    assert!(completed("日本語 ").iter().all(|c| c.starts_with("日本語 ")));
+}
+
+#[test]
+fn test_vocabulary_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("linear-cli/completion.json");
+    assert_eq!(Vocabulary::load_from(&path).unwrap(), Vocabulary::default());
+
+    vocabulary().save_to(&path).unwrap();
+    assert_eq!(Vocabulary::load_from(&path).unwrap(), vocabulary());
+}
@@ -0,0 +1,299 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::json;
+
+use crate::filtering::builder::FilterOperator;
+use crate::filtering::dates::{comparisons, format_timestamp};
+use crate::filtering::{
+    format_query, matches, parse_filter, Calendar, CycleDates, DateSpec, ParseError, Period, Zone,
+};
+use crate::models::{Cycle, Issue, Team};
+
+fn at(timestamp: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(timestamp).unwrap().with_timezone(&Utc)
+}
+
+fn in_zone(zone: &str) -> Calendar {
+    Calendar { zone: Zone::parse(zone).unwrap(), cycles: CycleDates::default() }
+}
+
+/// `text` resolved in `calendar` at `now`, as RFC 3339 bounds
+fn resolve(text: &str, calendar: &Calendar, now: &str) -> (Option<String>, Option<String>) {
+    let period = DateSpec::parse(text)
+        .unwrap_or_else(|| panic!("'{}' is not a date", text))
+        .resolve(calendar, at(now))
+        .unwrap_or_else(|e| panic!("{}: {}", text, e));
+    (period.start.map(format_timestamp), period.end.map(format_timestamp))
+}
+
+fn span(start: &str, end: &str) -> (Option<String>, Option<String>) {
+    (Some(start.to_string()), Some(end.to_string()))
+}
+
+// Sunday 18 October 2026, 23:30 UTC
+const NOW: &str = "2026-10-18T23:30:00Z";
+
+#[test]
+fn test_zone_parse() {
+    assert_eq!(Zone::parse("local"), Some(Zone::Local));
+    assert_eq!(Zone::parse("UTC"), Some(Zone::Utc));
+    for (text, seconds) in [("+02:00", 7200), ("-0530", -19800), ("UTC+9", 32400), ("+1", 3600)] {
+        match Zone::parse(text) {
+            Some(Zone::Fixed(offset)) => assert_eq!(offset.local_minus_utc(), seconds, "{}", text),
+            other => panic!("{} parsed as {:?}", text, other),
+        }
+    }
+    assert_eq!(Zone::parse("Mars/Olympus"), None);
+    assert_eq!(Zone::parse("+25:00"), None);
+}
+
+#[test]
+fn test_absolute_dates_cover_the_whole_day_in_the_zone() {
+    let utc = in_zone("UTC");
+    assert_eq!(resolve("2026-10-01", &utc, NOW), span("2026-10-01T00:00:00Z", "2026-10-02T00:00:00Z"));
+
+    let berlin = in_zone("+02:00");
+    assert_eq!(resolve("2026-10-01", &berlin, NOW), span("2026-09-30T22:00:00Z", "2026-10-01T22:00:00Z"));
+
+    let timestamp = resolve("2026-10-01T12:00:00+02:00", &utc, NOW);
+    assert_eq!(timestamp, span("2026-10-01T10:00:00Z", "2026-10-01T10:00:00Z"));
+}
+
+#[test]
+fn test_today_follows_the_zone() {
+    // Still the 18th in UTC, already the 19th two hours east
+    assert_eq!(resolve("today", &in_zone("UTC"), NOW), span("2026-10-18T00:00:00Z", "2026-10-19T00:00:00Z"));
+    assert_eq!(resolve("today", &in_zone("+02:00"), NOW), span("2026-10-18T22:00:00Z", "2026-10-19T22:00:00Z"));
+    assert_eq!(resolve("yesterday", &in_zone("UTC"), NOW), span("2026-10-17T00:00:00Z", "2026-10-18T00:00:00Z"));
+    assert_eq!(resolve("tomorrow", &in_zone("UTC"), NOW), span("2026-10-19T00:00:00Z", "2026-10-20T00:00:00Z"));
+}
+
+#[test]
+fn test_weeks_start_on_monday() {
+    let utc = in_zone("UTC");
+    assert_eq!(resolve("this-week", &utc, NOW), span("2026-10-12T00:00:00Z", "2026-10-19T00:00:00Z"));
+    assert_eq!(resolve("last-week", &utc, NOW), span("2026-10-05T00:00:00Z", "2026-10-12T00:00:00Z"));
+    // Monday already, two hours east
+    assert_eq!(resolve("this-week", &in_zone("+02:00"), NOW), span("2026-10-18T22:00:00Z", "2026-10-25T22:00:00Z"));
+}
+
+#[test]
+fn test_months_quarters_and_years() {
+    let utc = in_zone("UTC");
+    assert_eq!(resolve("this-month", &utc, NOW), span("2026-10-01T00:00:00Z", "2026-11-01T00:00:00Z"));
+    assert_eq!(resolve("last-month", &utc, NOW), span("2026-09-01T00:00:00Z", "2026-10-01T00:00:00Z"));
+    assert_eq!(resolve("this-quarter", &utc, NOW), span("2026-10-01T00:00:00Z", "2027-01-01T00:00:00Z"));
+    assert_eq!(resolve("last-quarter", &utc, NOW), span("2026-07-01T00:00:00Z", "2026-10-01T00:00:00Z"));
+    assert_eq!(resolve("this-year", &utc, NOW), span("2026-01-01T00:00:00Z", "2027-01-01T00:00:00Z"));
+
+    let december = "2026-12-31T12:00:00Z";
+    assert_eq!(resolve("next-month", &utc, december), span("2027-01-01T00:00:00Z", "2027-02-01T00:00:00Z"));
+    assert_eq!(resolve("next-quarter", &utc, december), span("2027-01-01T00:00:00Z", "2027-04-01T00:00:00Z"));
+}
+
+#[test]
+fn test_ranges_run_from_the_start_of_one_to_the_end_of_the_other() {
+    let utc = in_zone("UTC");
+    assert_eq!(
+        resolve("2026-10-01..2026-10-31", &utc, NOW),
+        span("2026-10-01T00:00:00Z", "2026-11-01T00:00:00Z")
+    );
+    assert_eq!(resolve("last-month..today", &utc, NOW), span("2026-09-01T00:00:00Z", "2026-10-19T00:00:00Z"));
+    assert_eq!(resolve("2026-10-01..", &utc, NOW), (Some("2026-10-01T00:00:00Z".to_string()), None));
+    assert_eq!(resolve("..2026-10-01", &utc, NOW), (None, Some("2026-10-02T00:00:00Z".to_string())));
+
+    assert_eq!(DateSpec::parse(".."), None);
+    assert_eq!(DateSpec::parse("2026-10-01..someday"), None);
+    let backwards = DateSpec::parse("2026-10-31..2026-10-01").unwrap().resolve(&utc, at(NOW));
+    assert!(backwards.is_err());
+}
+
+#[test]
+fn test_offsets() {
+    let utc = in_zone("UTC");
+    let instant = |at: &str| span(at, at);
+    assert_eq!(resolve("7d", &utc, NOW), instant("2026-10-11T23:30:00Z"));
+    assert_eq!(resolve("36h", &utc, NOW), instant("2026-10-17T11:30:00Z"));
+    assert_eq!(resolve("2weeks", &utc, NOW), instant("2026-10-04T23:30:00Z"));
+    assert_eq!(resolve("-P2W", &utc, NOW), instant("2026-10-04T23:30:00Z"));
+    assert_eq!(resolve("P1D", &utc, NOW), instant("2026-10-19T23:30:00Z"));
+    // Calendar months, clamped to the end of shorter ones
+    assert_eq!(resolve("1m", &utc, "2026-03-31T08:00:00Z"), instant("2026-02-28T08:00:00Z"));
+
+    // Offsets too far off are an error, not a panic
+    for text in ["9999999999w", "-PT99999999999H", "P9999999999999D", "99999999999y", "9223372036854775807y"] {
+        let spec = DateSpec::parse(text).unwrap();
+        assert!(spec.resolve(&utc, at(NOW)).is_err(), "{}", text);
+    }
+}
+
+#[test]
+fn test_comparison_boundaries() {
+    let day = Period::between(at("2026-10-01T00:00:00Z"), at("2026-10-02T00:00:00Z"));
+    let start = at("2026-10-01T00:00:00Z");
+    let end = at("2026-10-02T00:00:00Z");
+    for (op, expected) in [
+        (FilterOperator::Equals, vec![("gte", start), ("lt", end)]),
+        (FilterOperator::NotEquals, vec![("lt", start), ("gte", end)]),
+        (FilterOperator::GreaterThan, vec![("gte", end)]),
+        (FilterOperator::GreaterThanOrEquals, vec![("gte", start)]),
+        (FilterOperator::LessThan, vec![("lt", start)]),
+        (FilterOperator::LessThanOrEquals, vec![("lt", end)]),
+    ] {
+        assert_eq!(comparisons(&op, &day), Some(expected), "{:?}", op);
+    }
+
+    // An instant on its own means "since"
+    let instant = Period::instant(start);
+    assert_eq!(comparisons(&FilterOperator::Equals, &instant), Some(vec![("gte", start)]));
+    assert_eq!(comparisons(&FilterOperator::GreaterThan, &instant), Some(vec![("gt", start)]));
+    assert_eq!(comparisons(&FilterOperator::LessThanOrEquals, &instant), Some(vec![("lte", start)]));
+
+    let open = Period { start: Some(start), end: None };
+    assert_eq!(comparisons(&FilterOperator::GreaterThan, &open), None);
+    assert_eq!(comparisons(&FilterOperator::Contains, &day), None);
+}
+
+fn cycle(team: &str, starts: &str, ends: &str, active: bool, previous: bool) -> Cycle {
+    Cycle {
+        id: format!("{}-{}", team, starts),
+        number: 1.0,
+        name: None,
+        starts_at: starts.to_string(),
+        ends_at: ends.to_string(),
+        is_active: active,
+        is_previous: previous,
+        is_next: !active && !previous,
+        team: Team { id: format!("team-{}", team), name: team.to_string(), key: team.to_string() },
+    }
+}
+
+#[test]
+fn test_cycle_anchors() {
+    let cycles = vec![
+        cycle("OPS", "2026-10-05T00:00:00Z", "2026-10-19T00:00:00Z", true, false),
+        cycle("ENG", "2026-10-12T00:00:00Z", "2026-10-26T00:00:00Z", true, false),
+        cycle("ENG", "2026-09-28T00:00:00Z", "2026-10-12T00:00:00Z", false, true),
+        cycle("ENG", "2026-10-26T00:00:00Z", "2026-11-09T00:00:00Z", false, false),
+    ];
+    let calendar = Calendar { zone: Zone::Utc, cycles: CycleDates::from_cycles(&cycles, Some("eng")).unwrap() };
+    assert_eq!(resolve("this-cycle", &calendar, NOW), span("2026-10-12T00:00:00Z", "2026-10-26T00:00:00Z"));
+    assert_eq!(resolve("last-cycle", &calendar, NOW), span("2026-09-28T00:00:00Z", "2026-10-12T00:00:00Z"));
+    assert_eq!(resolve("next-cycle", &calendar, NOW), span("2026-10-26T00:00:00Z", "2026-11-09T00:00:00Z"));
+
+    let ops = Calendar { zone: Zone::Utc, cycles: CycleDates::from_cycles(&cycles, Some("team-OPS")).unwrap() };
+    assert_eq!(resolve("this-cycle", &ops, NOW), span("2026-10-05T00:00:00Z", "2026-10-19T00:00:00Z"));
+
+    // Two teams with cycles and none named is ambiguous; one alone isn't
+    assert!(CycleDates::from_cycles(&cycles, None).unwrap_err().contains("ENG, OPS"));
+    assert_eq!(CycleDates::from_cycles(&cycles[1..], None).unwrap(), calendar.cycles);
+    assert_eq!(CycleDates::from_cycles(&cycles, Some("QA")).unwrap(), CycleDates::default());
+
+    let none = DateSpec::parse("last-cycle").unwrap().resolve(&in_zone("UTC"), at(NOW));
+    assert!(none.unwrap_err().contains("no cycle dates"));
+}
+
+#[test]
+fn test_a_team_condition_picks_the_cycles() {
+    let cycles = vec![
+        cycle("OPS", "2026-10-05T00:00:00Z", "2026-10-19T00:00:00Z", true, false),
+        cycle("ENG", "2026-10-12T00:00:00Z", "2026-10-26T00:00:00Z", true, false),
+    ];
+    let pinned = Calendar::for_query("created:this-cycle AND team:ops", &cycles, Some("ENG")).unwrap();
+    assert_eq!(pinned.cycles.current.unwrap().start, Some(at("2026-10-05T00:00:00Z")));
+    let fallback = Calendar::for_query("created:this-cycle", &cycles, Some("ENG")).unwrap();
+    assert_eq!(fallback.cycles.current.unwrap().start, Some(at("2026-10-12T00:00:00Z")));
+
+    // Only a team every match shares pins it
+    assert!(Calendar::for_query("created:this-cycle OR team:ops", &cycles, None).is_err());
+    assert!(Calendar::for_query("created:7d", &cycles, None).is_ok());
+}
+
+#[test]
+fn test_parser_keeps_date_values_as_written() {
+    for query in ["created:this-week", "due:2026-10-01..2026-10-31", "updated>-P2W", "completed<=last-quarter"] {
+        let builder = parse_filter(query).unwrap();
+        let expr = builder.build().unwrap();
+        assert_eq!(format_query(Some(&expr), &[]), query);
+    }
+    assert!(matches!(parse_filter("created:someday"), Err(ParseError::InvalidDateValue(_))));
+    assert!(matches!(parse_filter("due:2026-13-01"), Err(ParseError::InvalidDateValue(_))));
+}
+
+fn compile(query: &str) -> serde_json::Value {
+    parse_filter(query).unwrap().to_graphql(&Calendar::default()).unwrap_or_else(|e| panic!("{}: {}", query, e))
+}
+
+fn day_start(date: &str) -> String {
+    format_timestamp(Calendar::default().zone.start_of(NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap()))
+}
+
+#[test]
+fn test_compiled_dates() {
+    // Offsets stay relative, for Linear to resolve
+    assert_eq!(compile("created:7d"), json!({ "createdAt": { "gte": "-P7D" } }));
+    assert_eq!(compile("updated<36h"), json!({ "updatedAt": { "lt": "-PT36H" } }));
+    assert_eq!(
+        compile("created:2026-10-01..2026-10-31"),
+        json!({ "createdAt": { "gte": day_start("2026-10-01"), "lt": day_start("2026-11-01") } })
+    );
+    assert_eq!(
+        compile("completed!=2026-10-01"),
+        json!({ "or": [
+            { "completedAt": { "lt": day_start("2026-10-01") } },
+            { "completedAt": { "gte": day_start("2026-10-02") } }
+        ] })
+    );
+    // Due dates are plain dates
+    assert_eq!(compile("due<=2026-10-01"), json!({ "dueDate": { "lte": "2026-10-01" } }));
+    assert_eq!(
+        compile("due:2026-10-01..2026-10-31"),
+        json!({ "dueDate": { "gte": "2026-10-01", "lt": "2026-11-01" } })
+    );
+}
+
+fn issue(due: Option<&str>, completed: Option<&str>) -> Issue {
+    serde_json::from_value(json!({
+        "id": "issue",
+        "identifier": "ENG-1",
+        "title": "Untitled",
+        "description": null,
+        "url": "https://linear.app/acme/issue/ENG-1",
+        "priority": 0,
+        "createdAt": "2026-10-01T10:00:00.000Z",
+        "updatedAt": "2026-10-01T10:00:00.000Z",
+        "completedAt": completed,
+        "dueDate": due,
+        "state": { "id": "state-todo", "name": "Todo", "type": "unstarted" },
+        "assignee": null,
+        "team": { "id": "team-eng", "name": "Engineering", "key": "ENG" },
+        "labels": { "nodes": [] },
+        "project": null
+    }))
+    .unwrap()
+}
+
+fn matches_query(query: &str, issue: &Issue) -> bool {
+    matches(&parse_filter(query).unwrap().build().unwrap(), issue, None, &Calendar::default()).unwrap()
+}
+
+#[test]
+fn test_evaluated_date_boundaries() {
+    let last_day = issue(Some("2026-10-31"), None);
+    let next_month = issue(Some("2026-11-01"), None);
+    assert!(matches_query("due:2026-10-01..2026-10-31", &last_day));
+    assert!(!matches_query("due:2026-10-01..2026-10-31", &next_month));
+    assert!(matches_query("due>2026-10-31", &next_month));
+    assert!(!matches_query("due>2026-10-31", &last_day));
+    assert!(matches_query("due<=2026-10-31", &last_day));
+    assert!(matches_query("due!=2026-10-31", &next_month));
+
+    assert!(matches_query("created:2026-10-01", &last_day));
+    assert!(!matches_query("created>2026-10-01", &last_day));
+    assert!(matches_query("created<2026-10-02", &last_day));
+
+    // A missing date never matches, not even `!=`
+    let open = issue(None, None);
+    assert!(!matches_query("completed<2026-10-01", &open));
+    assert!(!matches_query("completed!=2026-10-01", &open));
+    assert!(matches_query("completed:2026-10-01..", &issue(None, Some("2026-10-05T09:00:00Z"))));
+}
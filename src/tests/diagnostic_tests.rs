@@ -0,0 +1,137 @@
+use crate::filtering::diagnostic::closest;
+use crate::filtering::{diagnose, Diagnostic, Severity, Vocabulary};
+
+fn vocabulary() -> Vocabulary {
+    Vocabulary {
+        labels: vec!["bug".to_string(), "feature".to_string()],
+        states: vec!["Todo".to_string(), "In Progress".to_string(), "Done".to_string()],
+        projects: vec!["Mobile App".to_string()],
+        ..Default::default()
+    }
+}
+
+/// The only diagnostic for `query`
+fn diagnostic(query: &str) -> Diagnostic {
+    let mut diagnostics = diagnose(query, &vocabulary());
+    assert_eq!(diagnostics.len(), 1, "{}: {:?}", query, diagnostics);
+    diagnostics.remove(0)
+}
+
+/// The part of `query` a diagnostic points at
+fn marked(query: &str, diagnostic: &Diagnostic) -> String {
+    query.chars().skip(diagnostic.span.start).take(diagnostic.span.len()).collect()
+}
+
+#[test]
+fn test_valid_queries_have_no_diagnostics() {
+    for query in [
+        "assignee:me AND status:\"In Progress\"",
+        "label in:bug,feature OR priority>=high",
+        "status~progress AND title:anything",
+        "no-assignee AND project:\"Mobile App\" sort:-updated",
+    ] {
+        assert_eq!(diagnose(query, &vocabulary()), Vec::new(), "{}", query);
+    }
+}
+
+#[test]
+fn test_unknown_field_suggests_closest() {
+    let query = "asignee:me AND status:Todo";
+    let d = diagnostic(query);
+    assert_eq!(d.severity, Severity::Error);
+    assert_eq!(marked(query, &d), "asignee");
+    assert_eq!(d.help.as_deref(), Some("did you mean `assignee`?"));
+
+    assert_eq!(diagnostic("xyzzy:1").help, None);
+}
+
+#[test]
+fn test_render_places_caret_under_span() {
+    let query = "status:Todo AND asignee:me";
+    assert_eq!(
+        diagnostic(query).render(query),
+        "error: Unknown field 'asignee'\n  status:Todo AND asignee:me\n                  ^^^^^^^\n  help: did you mean `assignee`?"
+    );
+}
+
+#[test]
+fn test_priority_typo() {
+    let query = "priority:hgh";
+    let d = diagnostic(query);
+    assert_eq!(marked(query, &d), "hgh");
+    assert_eq!(d.help.as_deref(), Some("did you mean `high`?"));
+
+    let d = diagnostic("priority:whenever");
+    assert!(d.help.unwrap().starts_with("use one of none, low"));
+}
+
+#[test]
+fn test_sort_typo() {
+    let query = "status:Todo sort:priority,-updatd";
+    let d = diagnostic(query);
+    assert_eq!(marked(query, &d), "-updatd");
+    assert_eq!(d.help.as_deref(), Some("did you mean `-updated`?"));
+}
+
+#[test]
+fn test_juxtaposed_conditions() {
+    let query = "status:Todo label:bug";
+    let d = diagnostic(query);
+    assert_eq!(d.severity, Severity::Error);
+    assert_eq!(marked(query, &d), "label");
+    assert_eq!(d.message, "Unexpected 'label'");
+    assert_eq!(d.help.as_deref(), Some("join conditions with AND or OR"));
+}
+
+#[test]
+fn test_unterminated_string_and_missing_paren() {
+    let query = "title:\"login page";
+    let d = diagnostic(query);
+    assert_eq!(marked(query, &d), "\"login page");
+
+    let query = "(status:Todo OR status:Done";
+    let d = diagnostic(query);
+    assert_eq!(d.span, query.len()..query.len() + 1);
+    assert_eq!(d.help.as_deref(), Some("add the missing )"));
+}
+
+#[test]
+fn test_unknown_names_warn() {
+    let query = "label:bgu";
+    let d = diagnostic(query);
+    assert_eq!(d.severity, Severity::Warning);
+    assert_eq!(marked(query, &d), "bgu");
+    assert_eq!(d.help.as_deref(), Some("did you mean `bug`?"));
+
+    let d = diagnostic("status:\"in progress\"");
+    assert!(d.message.contains("case-sensitive"));
+    assert_eq!(d.help.as_deref(), Some("did you mean `In Progress`?"));
+
+    let d = diagnostic("status:started");
+    assert_eq!(d.help.as_deref(), Some("did you mean `state-type:started`?"));
+
+    let query = "label in:bug,featrue";
+    assert_eq!(marked(query, &diagnostic(query)), "featrue");
+}
+
+#[test]
+fn test_names_are_not_checked_without_vocabulary_or_for_partial_matches() {
+    assert!(diagnose("label:whatever", &Vocabulary::default()).is_empty());
+    assert!(diagnose("label~bu", &vocabulary()).is_empty());
+    assert!(diagnose("status:null", &vocabulary()).is_empty());
+}
+
+#[test]
+fn test_spans_count_characters() {
+    let query = "title:\"café\" AND asignee:me";
+    assert_eq!(marked(query, &diagnostic(query)), "asignee");
+}
+
+#[test]
+fn test_closest() {
+    let fields = ["assignee", "label", "priority"];
+    assert_eq!(closest("ASIGNEE", fields), Some("assignee"));
+    assert_eq!(closest("lable", fields), Some("label"));
+    assert_eq!(closest("prioirty", fields), Some("priority"));
+    assert_eq!(closest("zzz", fields), None);
+}
@@ -0,0 +1,59 @@
+use serde_json::json;
+
+use crate::models::{document_id_from_url, parse_editor_text, Document};
+
+fn document(content: Option<&str>) -> Document {
+    serde_json::from_value(json!({
+        "id": "doc-1",
+        "title": "Release runbook",
+        "content": content,
+        "url": "https://linear.app/acme/document/release-runbook-8f3a2b1c9d0e",
+        "slugId": "8f3a2b1c9d0e",
+        "createdAt": "2024-05-01T10:00:00.000Z",
+        "updatedAt": "2024-05-02T10:00:00.000Z",
+        "creator": { "id": "user-1", "name": "Ada", "email": "ada@example.com" },
+        "project": { "id": "project-1", "name": "Platform" }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_document_deserialize_without_content() {
+    let mut value = serde_json::to_value(document(None)).unwrap();
+    value.as_object_mut().unwrap().remove("content");
+    let doc: Document = serde_json::from_value(value).unwrap();
+    assert!(doc.content.is_none());
+    assert_eq!(doc.project.unwrap().name, "Platform");
+}
+
+#[test]
+fn test_editor_text_round_trip() {
+    let doc = document(Some("## Steps\n\n1. Tag the release\n"));
+    let text = doc.to_editor_text();
+    assert_eq!(text, "# Release runbook\n\n## Steps\n\n1. Tag the release");
+
+    let (title, body) = parse_editor_text(&text);
+    assert_eq!(title.as_deref(), Some("Release runbook"));
+    assert_eq!(body, "## Steps\n\n1. Tag the release");
+}
+
+#[test]
+fn test_parse_editor_text_without_title() {
+    let (title, body) = parse_editor_text("\nJust some notes\n\n## Not a title\n");
+    assert_eq!(title, None);
+    assert_eq!(body, "Just some notes\n\n## Not a title");
+
+    let (title, body) = parse_editor_text("# \n\nbody");
+    assert_eq!(title, None);
+    assert_eq!(body, "body");
+}
+
+#[test]
+fn test_document_id_from_url() {
+    assert_eq!(
+        document_id_from_url("https://linear.app/acme/document/release-runbook-8f3a2b1c9d0e"),
+        "8f3a2b1c9d0e"
+    );
+    assert_eq!(document_id_from_url("https://linear.app/acme/document/8f3a2b1c9d0e/"), "8f3a2b1c9d0e");
+    assert_eq!(document_id_from_url("doc-uuid-1234"), "doc-uuid-1234");
+}
@@ -1,4 +1,8 @@
+use serde_json::json;
+
 use crate::filtering::builder::FilterBuilder;
+use crate::filtering::{parse_priority, priority_label, Calendar};
+use crate::filtering::dates::format_timestamp;
 use crate::filtering::parser::parse_filter;
 
 #[test]
@@ -6,7 +10,7 @@ fn test_filter_builder_simple() {
     let mut builder = FilterBuilder::new();
     builder.status().equals("In Progress");
     
-    let graphql = builder.to_graphql();
+    let graphql = builder.to_graphql(&Calendar::default());
     assert!(graphql.is_ok());
     let graphql_str = format!("{:?}", graphql.unwrap());
     assert!(graphql_str.contains("state"));
@@ -22,7 +26,7 @@ fn test_filter_builder_compound() {
         .and()
         .priority().greater_than(2);
     
-    let graphql = builder.to_graphql();
+    let graphql = builder.to_graphql(&Calendar::default());
     assert!(graphql.is_ok());
     let graphql_str = format!("{:?}", graphql.unwrap());
     assert!(graphql_str.contains("and"));
@@ -35,7 +39,7 @@ fn test_filter_builder_with_labels() {
         .or()
         .label().contains("critical");
     
-    let graphql = builder.to_graphql();
+    let graphql = builder.to_graphql(&Calendar::default());
     assert!(graphql.is_ok());
     let graphql_str = format!("{:?}", graphql.unwrap());
     assert!(graphql_str.contains("or"));
@@ -88,4 +92,237 @@ fn test_filter_parser_negation() {
     
     let result = parse_filter("status:!done");
     assert!(result.is_ok());
-}
\ No newline at end of file
+}
+// Conformance: the exact `IssueFilter` each query compiles to.
+
+fn compile(query: &str) -> serde_json::Value {
+    parse_filter(query)
+        .unwrap_or_else(|e| panic!("could not parse '{}': {}", query, e))
+        .to_graphql(&Calendar::default())
+        .unwrap_or_else(|e| panic!("could not compile '{}': {}", query, e))
+}
+
+/// When `date` starts in the configured timezone, as sent to Linear
+fn day_start(date: &str) -> String {
+    let date = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap();
+    format_timestamp(Calendar::default().zone.start_of(date))
+}
+
+fn compile_error(query: &str) -> String {
+    match parse_filter(query).unwrap().to_graphql(&Calendar::default()) {
+        Ok(filter) => panic!("'{}' should not compile, got {}", query, filter),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[test]
+fn test_conformance_single_conditions() {
+    for (query, expected) in [
+        ("status:Done", json!({ "state": { "name": { "eq": "Done" } } })),
+        ("status!=Done", json!({ "state": { "name": { "neq": "Done" } } })),
+        ("status in:Todo,Backlog", json!({ "state": { "name": { "in": ["Todo", "Backlog"] } } })),
+        ("priority>=3", json!({ "priority": { "gte": 3.0 } })),
+        ("priority:urgent", json!({ "priority": { "eq": 4.0 } })),
+        ("title~crash", json!({ "title": { "containsIgnoreCase": "crash" } })),
+        ("title!~crash", json!({ "title": { "notContainsIgnoreCase": "crash" } })),
+        ("title$=TODO", json!({ "title": { "endsWith": "TODO" } })),
+        ("description~flaky", json!({ "description": { "containsIgnoreCase": "flaky" } })),
+        ("assignee:ada@example.com", json!({ "assignee": { "email": { "eq": "ada@example.com" } } })),
+        ("assignee:null", json!({ "assignee": { "null": true } })),
+        ("project!=null", json!({ "project": { "null": false } })),
+        ("label:bug", json!({ "labels": { "some": { "name": { "eq": "bug" } } } })),
+        ("label!=bug", json!({ "labels": { "every": { "name": { "neq": "bug" } } } })),
+        ("has-label:bug,urgent", json!({ "labels": { "some": { "name": { "in": ["bug", "urgent"] } } } })),
+        ("created>2024-01-01", json!({ "createdAt": { "gte": day_start("2024-01-02") } })),
+        ("due:2024-03-01", json!({ "dueDate": { "eq": "2024-03-01" } })),
+    ] {
+        assert_eq!(compile(query), expected, "{}", query);
+    }
+}
+
+#[test]
+fn test_conformance_and_keeps_colliding_keys() {
+    assert_eq!(
+        compile("label:bug AND label:urgent"),
+        json!({ "and": [
+            { "labels": { "some": { "name": { "eq": "bug" } } } },
+            { "labels": { "some": { "name": { "eq": "urgent" } } } }
+        ] })
+    );
+    assert_eq!(
+        compile("created>2024-01-01 AND created<2024-02-01"),
+        json!({ "and": [
+            { "createdAt": { "gte": day_start("2024-01-02") } },
+            { "createdAt": { "lt": day_start("2024-02-01") } }
+        ] })
+    );
+}
+
+#[test]
+fn test_conformance_nested_groups() {
+    assert_eq!(
+        compile("(priority>2 OR label:urgent) AND status!=Done AND title~api"),
+        json!({ "and": [
+            { "or": [
+                { "priority": { "gt": 2.0 } },
+                { "labels": { "some": { "name": { "eq": "urgent" } } } }
+            ] },
+            { "state": { "name": { "neq": "Done" } } },
+            { "title": { "containsIgnoreCase": "api" } }
+        ] })
+    );
+    // OR binds looser than AND
+    assert_eq!(
+        compile("status:Done OR priority>2 AND assignee:null"),
+        json!({ "or": [
+            { "state": { "name": { "eq": "Done" } } },
+            { "and": [
+                { "priority": { "gt": 2.0 } },
+                { "assignee": { "null": true } }
+            ] }
+        ] })
+    );
+}
+
+#[test]
+fn test_conformance_not_single_expression() {
+    for (query, expected) in [
+        ("NOT status:Done", json!({ "state": { "name": { "neq": "Done" } } })),
+        ("NOT priority>2", json!({ "priority": { "lte": 2.0 } })),
+        ("NOT priority<=1", json!({ "priority": { "gt": 1.0 } })),
+        ("NOT title~wip", json!({ "title": { "notContainsIgnoreCase": "wip" } })),
+        ("NOT status in:Done,Canceled", json!({ "state": { "name": { "nin": ["Done", "Canceled"] } } })),
+        ("NOT assignee:null", json!({ "assignee": { "null": false } })),
+        ("NOT has-label:bug,wontfix", json!({ "labels": { "every": { "name": { "nin": ["bug", "wontfix"] } } } })),
+        ("NOT created<2024-01-01", json!({ "createdAt": { "gte": day_start("2024-01-01") } })),
+        ("NOT NOT status:Done", json!({ "state": { "name": { "eq": "Done" } } })),
+    ] {
+        assert_eq!(compile(query), expected, "{}", query);
+    }
+}
+
+#[test]
+fn test_conformance_not_group_uses_de_morgan() {
+    assert_eq!(
+        compile("NOT (priority<2 OR assignee:null)"),
+        json!({ "and": [
+            { "priority": { "gte": 2.0 } },
+            { "assignee": { "null": false } }
+        ] })
+    );
+    assert_eq!(
+        compile("NOT (status:Done AND label:bug)"),
+        json!({ "or": [
+            { "state": { "name": { "neq": "Done" } } },
+            { "labels": { "every": { "name": { "neq": "bug" } } } }
+        ] })
+    );
+}
+
+#[test]
+fn test_conformance_builder_not_group() {
+    let mut builder = FilterBuilder::new();
+    builder.not_group().status().equals("Done");
+    assert_eq!(builder.to_graphql(&Calendar::default()).unwrap(), json!({ "state": { "name": { "neq": "Done" } } }));
+}
+
+#[test]
+fn test_conformance_unsupported_is_an_error() {
+    assert!(compile_error("team~eng").contains("team"));
+    assert!(compile_error("NOT title^=Draft").contains("NOT title^=Draft"));
+    assert!(compile_error("priority~high").contains("priority"));
+    assert!(compile_error("sprint:12").contains("sprint"));
+    assert!(compile_error("status:Done AND id~ENG").contains("identifier"));
+}
+
+#[test]
+fn test_conformance_extended_fields() {
+    for (query, expected) in [
+        ("cycle:current", json!({ "cycle": { "isActive": { "eq": true } } })),
+        ("cycle:next", json!({ "cycle": { "isNext": { "eq": true } } })),
+        ("cycle:42", json!({ "cycle": { "number": { "eq": 42 } } })),
+        ("cycle:null", json!({ "cycle": { "null": true } })),
+        ("estimate>=3", json!({ "estimate": { "gte": 3.0 } })),
+        ("estimate:null", json!({ "estimate": { "null": true } })),
+        ("due!=null", json!({ "dueDate": { "null": false } })),
+        ("creator:me", json!({ "creator": { "isMe": { "eq": true } } })),
+        ("assignee!=me", json!({ "assignee": { "isMe": { "eq": false } } })),
+        ("creator:ada@example.com", json!({ "creator": { "email": { "eq": "ada@example.com" } } })),
+        (
+            "creator:ada",
+            json!({ "creator": { "or": [
+                { "name": { "eqIgnoreCase": "ada" } },
+                { "displayName": { "eqIgnoreCase": "ada" } }
+            ] } }),
+        ),
+        ("subscriber:me", json!({ "subscribers": { "some": { "isMe": { "eq": true } } } })),
+        (
+            "parent:ENG-12",
+            json!({ "parent": { "and": [
+                { "team": { "key": { "eqIgnoreCase": "ENG" } } },
+                { "number": { "eq": 12 } }
+            ] } }),
+        ),
+        ("parent:null", json!({ "parent": { "null": true } })),
+        ("has-children", json!({ "children": { "some": { "id": { "null": false } } } })),
+        ("no-children", json!({ "children": { "every": { "id": { "null": true } } } })),
+        ("completed>2024-01-01", json!({ "completedAt": { "gte": day_start("2024-01-02") } })),
+        ("started:null", json!({ "startedAt": { "null": true } })),
+        ("state-type:done", json!({ "state": { "type": { "eq": "completed" } } })),
+        ("state-type in:started,unstarted", json!({ "state": { "type": { "in": ["started", "unstarted"] } } })),
+        ("sla:high-risk", json!({ "slaStatus": { "eq": "HighRisk" } })),
+        ("relation:blocked", json!({ "hasBlockedByRelations": { "eq": true } })),
+        (
+            "team:ENG",
+            json!({ "team": { "or": [
+                { "key": { "eqIgnoreCase": "ENG" } },
+                { "name": { "eqIgnoreCase": "ENG" } }
+            ] } }),
+        ),
+        ("description:null", json!({ "description": { "null": true } })),
+        (
+            "id:ENG-7",
+            json!({ "and": [
+                { "team": { "key": { "eqIgnoreCase": "ENG" } } },
+                { "number": { "eq": 7 } }
+            ] }),
+        ),
+    ] {
+        assert_eq!(compile(query), expected, "{}", query);
+    }
+}
+
+#[test]
+fn test_conformance_extended_fields_negate() {
+    assert_eq!(compile("NOT relation:blocked"), json!({ "hasBlockedByRelations": { "eq": false } }));
+    assert_eq!(compile("NOT has-children"), json!({ "children": { "every": { "id": { "null": true } } } }));
+    assert_eq!(compile("NOT state-type:canceled"), json!({ "state": { "type": { "neq": "canceled" } } }));
+    assert_eq!(
+        compile("NOT id:ENG-7"),
+        json!({ "or": [
+            { "team": { "key": { "neqIgnoreCase": "ENG" } } },
+            { "number": { "neq": 7 } }
+        ] })
+    );
+}
+
+#[test]
+fn test_conformance_extended_fields_reject_bad_values() {
+    assert!(parse_filter("estimate>lots").is_err());
+    assert!(compile_error("sla:sometimes").contains("sla"));
+    assert!(compile_error("relation:cousin").contains("relation"));
+    assert!(compile_error("state-type:doing").contains("state-type"));
+    assert!(compile_error("id:ENG").contains("identifier"));
+}
+
+#[test]
+fn test_priority_names() {
+    assert_eq!(parse_priority("Urgent"), Some(4));
+    assert_eq!(parse_priority("med"), Some(2));
+    assert_eq!(parse_priority("0"), Some(0));
+    assert_eq!(parse_priority("5"), None);
+    assert_eq!(parse_priority("asap"), None);
+    assert_eq!(priority_label(3), "High");
+    assert_eq!(priority_label(0), "None");
+    assert_eq!(priority_label(7), "P7");
+}
@@ -0,0 +1,351 @@
+use serde_json::{json, Map, Value};
+
+use crate::filtering::{check, matches, parse_filter, split_for_server, Calendar};
+use crate::filtering::builder::FilterExpression;
+use crate::models::Issue;
+
+const VIEWER: &str = "user-ada";
+
+fn issue(value: Value) -> Issue {
+    let mut base = json!({
+        "id": "issue",
+        "identifier": "ENG-1",
+        "title": "Untitled",
+        "description": null,
+        "url": "https://linear.app/acme/issue/ENG-1",
+        "priority": 0,
+        "createdAt": "2024-05-01T10:00:00.000Z",
+        "updatedAt": "2024-05-01T10:00:00.000Z",
+        "state": { "id": "state-todo", "name": "Todo", "type": "unstarted" },
+        "assignee": null,
+        "team": { "id": "team-eng", "name": "Engineering", "key": "ENG" },
+        "labels": { "nodes": [] },
+        "project": null
+    });
+    for (key, field) in value.as_object().unwrap() {
+        base[key] = field.clone();
+    }
+    serde_json::from_value(base).unwrap()
+}
+
+fn label(name: &str) -> Value {
+    json!({ "id": format!("label-{}", name), "name": name, "color": "#000000" })
+}
+
+fn sample_issues() -> Vec<Issue> {
+    vec![
+        issue(json!({
+            "identifier": "ENG-1",
+            "title": "Crash on login",
+            "description": "Stack trace attached",
+            "priority": 1,
+            "estimate": 3,
+            "state": { "id": "s1", "name": "In Progress", "type": "started" },
+            "assignee": { "id": VIEWER, "name": "Ada Lovelace", "email": "ada@example.com", "displayName": "ada" },
+            "labels": { "nodes": [label("bug"), label("urgent")] },
+            "project": { "id": "p1", "name": "Mobile App" },
+            "createdAt": "2024-03-10T09:00:00.000Z"
+        })),
+        issue(json!({
+            "identifier": "ENG-2",
+            "title": "Draft: new onboarding",
+            "priority": 3,
+            "estimate": 1,
+            "state": { "id": "s2", "name": "Done", "type": "completed" },
+            "labels": { "nodes": [label("feature")] },
+            "createdAt": "2024-04-20T09:00:00.000Z"
+        })),
+        issue(json!({
+            "identifier": "OPS-7",
+            "title": "Rotate certificates TODO",
+            "description": "",
+            "priority": 2,
+            "estimate": 5,
+            "team": { "id": "team-ops", "name": "Operations", "key": "OPS" },
+            "assignee": { "id": "user-bob", "name": "Bob", "email": "bob@example.com", "displayName": "bobby" },
+            "labels": { "nodes": [label("bug")] },
+            "project": { "id": "p2", "name": "Infra" },
+            "createdAt": "2024-05-02T09:00:00.000Z"
+        })),
+        issue(json!({
+            "identifier": "ENG-40",
+            "title": "Crash reporter",
+            "priority": 4,
+            "state": { "id": "s3", "name": "Canceled", "type": "canceled" }
+        })),
+    ]
+}
+
+// A small interpreter for the `IssueFilter` subset the compiler emits, so the
+// evaluator can be checked against the compiled GraphQL.
+
+fn filter_matches(filter: &Value, issue: &Issue) -> bool {
+    filter.as_object().unwrap().iter().all(|(key, value)| match key.as_str() {
+        "and" => value.as_array().unwrap().iter().all(|f| filter_matches(f, issue)),
+        "or" => value.as_array().unwrap().iter().any(|f| filter_matches(f, issue)),
+        "title" => string_matches(Some(&issue.title), value),
+        "description" => string_matches(issue.description.as_deref(), value),
+        "priority" => number_matches(issue.priority.map(f64::from), value),
+        "estimate" => number_matches(issue.estimate, value),
+        "number" => number_matches(issue.identifier.rsplit_once('-').and_then(|(_, n)| n.parse().ok()), value),
+        "createdAt" => date_matches(&issue.created_at, value),
+        "updatedAt" => date_matches(&issue.updated_at, value),
+        "state" => object(value).iter().all(|(k, v)| match k.as_str() {
+            "name" => string_matches(Some(&issue.state.name), v),
+            "type" => string_matches(Some(&issue.state.state_type), v),
+            other => panic!("state.{}", other),
+        }),
+        "team" => team_matches(issue, value),
+        "project" => match (&issue.project, object(value).get("null")) {
+            (project, Some(null)) => project.is_none() == null.as_bool().unwrap(),
+            (None, None) => false,
+            (Some(project), None) => string_matches(Some(&project.name), &value["name"]),
+        },
+        "assignee" => match (&issue.assignee, object(value).get("null")) {
+            (assignee, Some(null)) => assignee.is_none() == null.as_bool().unwrap(),
+            (None, None) => false,
+            (Some(user), None) => user_matches(user, value),
+        },
+        "labels" => object(value).iter().all(|(k, v)| {
+            let label_matches = |l: &crate::models::issue::Label| match v.as_object().unwrap().iter().next().unwrap() {
+                (field, comparator) if field == "name" => string_matches(Some(&l.name), comparator),
+                (field, comparator) if field == "id" => string_matches(Some(&l.id), comparator),
+                (field, _) => panic!("labels.{}", field),
+            };
+            match k.as_str() {
+                "some" => issue.labels.nodes.iter().any(label_matches),
+                "every" => issue.labels.nodes.iter().all(label_matches),
+                other => panic!("labels.{}", other),
+            }
+        }),
+        other => panic!("the test interpreter doesn't know '{}'", other),
+    })
+}
+
+fn object(value: &Value) -> &Map<String, Value> {
+    value.as_object().unwrap()
+}
+
+fn string_matches(value: Option<&str>, comparator: &Value) -> bool {
+    object(comparator).iter().all(|(op, wanted)| {
+        if op == "null" {
+            return value.is_none() == wanted.as_bool().unwrap();
+        }
+        let Some(value) = value else { return false };
+        let text = || wanted.as_str().unwrap();
+        let list = || wanted.as_array().unwrap().iter().map(|v| v.as_str().unwrap()).collect::<Vec<_>>();
+        match op.as_str() {
+            "eq" => value == text(),
+            "neq" => value != text(),
+            "eqIgnoreCase" => value.eq_ignore_ascii_case(text()),
+            "neqIgnoreCase" => !value.eq_ignore_ascii_case(text()),
+            "in" => list().contains(&value),
+            "nin" => !list().contains(&value),
+            "containsIgnoreCase" => value.to_lowercase().contains(&text().to_lowercase()),
+            "notContainsIgnoreCase" => !value.to_lowercase().contains(&text().to_lowercase()),
+            "startsWithIgnoreCase" => value.to_lowercase().starts_with(&text().to_lowercase()),
+            "endsWith" => value.ends_with(text()),
+            other => panic!("string comparator {}", other),
+        }
+    })
+}
+
+fn number_matches(value: Option<f64>, comparator: &Value) -> bool {
+    object(comparator).iter().all(|(op, wanted)| {
+        let Some(value) = value else { return op == "null" && wanted == &json!(true) };
+        let wanted = wanted.as_f64().unwrap();
+        match op.as_str() {
+            "eq" => value == wanted,
+            "neq" => value != wanted,
+            "gt" => value > wanted,
+            "gte" => value >= wanted,
+            "lt" => value < wanted,
+            "lte" => value <= wanted,
+            other => panic!("number comparator {}", other),
+        }
+    })
+}
+
+fn date_matches(value: &str, comparator: &Value) -> bool {
+    let parse = |s: &str| chrono::DateTime::parse_from_rfc3339(s).unwrap_or_else(|_| {
+        chrono::DateTime::parse_from_rfc3339(&format!("{}T00:00:00Z", s)).unwrap()
+    });
+    let value = parse(value);
+    object(comparator).iter().all(|(op, wanted)| {
+        let wanted = parse(wanted.as_str().unwrap());
+        match op.as_str() {
+            "eq" => value == wanted,
+            "neq" => value != wanted,
+            "gt" => value > wanted,
+            "gte" => value >= wanted,
+            "lt" => value < wanted,
+            "lte" => value <= wanted,
+            other => panic!("date comparator {}", other),
+        }
+    })
+}
+
+fn team_matches(issue: &Issue, filter: &Value) -> bool {
+    object(filter).iter().all(|(k, v)| match k.as_str() {
+        "and" => v.as_array().unwrap().iter().all(|f| team_matches(issue, f)),
+        "or" => v.as_array().unwrap().iter().any(|f| team_matches(issue, f)),
+        "key" => string_matches(Some(&issue.team.key), v),
+        "name" => string_matches(Some(&issue.team.name), v),
+        other => panic!("team.{}", other),
+    })
+}
+
+fn user_matches(user: &crate::models::User, filter: &Value) -> bool {
+    object(filter).iter().all(|(k, v)| match k.as_str() {
+        "and" => v.as_array().unwrap().iter().all(|f| user_matches(user, f)),
+        "or" => v.as_array().unwrap().iter().any(|f| user_matches(user, f)),
+        "isMe" => (user.id == VIEWER) == v["eq"].as_bool().unwrap(),
+        "email" => string_matches(Some(&user.email), v),
+        "name" => string_matches(Some(&user.name), v),
+        "displayName" => string_matches(user.display_name.as_deref(), v),
+        other => panic!("user.{}", other),
+    })
+}
+
+fn expression(query: &str) -> FilterExpression {
+    parse_filter(query)
+        .unwrap_or_else(|e| panic!("could not parse '{}': {}", query, e))
+        .build()
+        .unwrap()
+}
+
+fn matching(query: &str) -> Vec<String> {
+    let expr = expression(query);
+    sample_issues()
+        .into_iter()
+        .filter(|issue| matches(&expr, issue, Some(VIEWER), &Calendar::default()).unwrap())
+        .map(|issue| issue.identifier)
+        .collect()
+}
+
+#[test]
+fn test_evaluator_agrees_with_compiled_graphql() {
+    let issues = sample_issues();
+    for query in [
+        "status:Done",
+        "status!=Done",
+        "status in:Done,Canceled",
+        "NOT status in:Done,Canceled",
+        "state-type:started",
+        "state-type in:completed,canceled",
+        "priority>2",
+        "priority<=1",
+        "NOT priority>=3",
+        "title~crash",
+        "title!~crash",
+        "title^=draft",
+        "title$=TODO",
+        "description:null",
+        "description~stack",
+        "assignee:null",
+        "assignee!=null",
+        "assignee:me",
+        "assignee!=me",
+        "assignee:bob@example.com",
+        "assignee:ADA",
+        "assignee!=bobby",
+        "NOT assignee:ada",
+        "project:\"Mobile App\"",
+        "project!=Infra",
+        "project:null",
+        "label:bug",
+        "label!=bug",
+        "label~URG",
+        "has-label:feature,urgent",
+        "NOT has-label:bug",
+        "no-label",
+        "label!=null",
+        "label:bug AND label:urgent",
+        "team:eng",
+        "team:Operations",
+        "team!=ENG",
+        "id:eng-40",
+        "NOT id:ENG-1",
+        "id in:ENG-1,OPS-7",
+        "created>2024-04-01",
+        "created>2024-03-01 AND created<2024-05-01",
+        "(priority>2 OR label:urgent) AND status!=Done",
+        "estimate>=3",
+        "estimate<3 OR priority:urgent",
+        "estimate!=5",
+        "NOT (priority<2 OR assignee:null)",
+        "NOT (status:Done AND label:feature)",
+        "status:Done OR priority>3 AND title~crash",
+    ] {
+        let expr = expression(query);
+        let filter = parse_filter(query).unwrap().to_graphql(&Calendar::default()).unwrap();
+        for issue in &issues {
+            assert_eq!(
+                matches(&expr, issue, Some(VIEWER), &Calendar::default()).unwrap(),
+                filter_matches(&filter, issue),
+                "'{}' on {} (compiled to {})",
+                query,
+                issue.identifier,
+                filter
+            );
+        }
+    }
+}
+
+#[test]
+fn test_evaluator_results() {
+    assert_eq!(matching("label:bug AND label:urgent"), vec!["ENG-1"]);
+    assert_eq!(matching("assignee:me"), vec!["ENG-1"]);
+    assert_eq!(matching("NOT assignee:ada"), vec!["OPS-7"]);
+    assert_eq!(matching("team:ops OR id:ENG-40"), vec!["OPS-7", "ENG-40"]);
+    assert_eq!(matching("no-label"), vec!["ENG-40"]);
+    assert_eq!(matching("estimate>=3"), vec!["ENG-1", "OPS-7"]);
+    assert_eq!(matching("estimate:null"), vec!["ENG-40"]);
+}
+
+#[test]
+fn test_evaluator_handles_conditions_linear_cannot() {
+    // NOT on a prefix can't be compiled, but can be checked locally
+    assert_eq!(matching("NOT title^=draft"), vec!["ENG-1", "OPS-7", "ENG-40"]);
+    assert_eq!(matching("team~oper"), vec!["OPS-7"]);
+    assert_eq!(matching("id~eng-4"), vec!["ENG-40"]);
+}
+
+#[test]
+fn test_evaluator_reports_fields_not_loaded() {
+    for query in ["cycle:current", "priority>1 OR relation:blocked"] {
+        let expr = expression(query);
+        assert!(check(&expr, Some(VIEWER), &Calendar::default()).is_err(), "{}", query);
+        assert!(matches(&expr, &sample_issues()[0], Some(VIEWER), &Calendar::default()).is_err(), "{}", query);
+    }
+    assert!(check(&expression("assignee:me"), None, &Calendar::default()).is_err());
+    assert!(check(&expression("label:bug AND NOT title^=draft"), None, &Calendar::default()).is_ok());
+}
+
+#[test]
+fn test_split_for_server() {
+    let (server, local) = split_for_server(&expression("status!=Done AND NOT title^=draft AND label:bug"), &Calendar::default()).unwrap();
+    assert_eq!(
+        server.unwrap(),
+        json!({ "and": [
+            { "state": { "name": { "neq": "Done" } } },
+            { "labels": { "some": { "name": { "eq": "bug" } } } }
+        ] })
+    );
+    let local = local.unwrap();
+    let kept: Vec<String> = sample_issues()
+        .into_iter()
+        .filter(|issue| matches(&local, issue, None, &Calendar::default()).unwrap())
+        .map(|issue| issue.identifier)
+        .collect();
+    assert_eq!(kept, vec!["ENG-1", "OPS-7", "ENG-40"]);
+
+    let (server, local) = split_for_server(&expression("priority>2"), &Calendar::default()).unwrap();
+    assert_eq!(server.unwrap(), json!({ "priority": { "gt": 2.0 } }));
+    assert!(local.is_none());
+
+    // An OR can't be split
+    let (server, local) = split_for_server(&expression("priority>2 OR team~ops"), &Calendar::default()).unwrap();
+    assert!(server.is_none());
+    assert!(local.is_some());
+}
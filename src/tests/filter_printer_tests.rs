@@ -0,0 +1,141 @@
+use chrono::Duration;
+
+use crate::filtering::builder::{FilterCondition, FilterExpression, FilterGroup, LogicalOperator};
+use crate::filtering::{
+    format_expression, format_query, format_tree, parse_filter, Calendar, FilterField, FilterOperator, FilterValue,
+};
+
+/// Parse `query` and print it back, with its compiled filter
+fn canonical(query: &str) -> (String, Result<serde_json::Value, String>) {
+    let builder = parse_filter(query).unwrap_or_else(|e| panic!("{}: {}", query, e));
+    let sort = builder.sort_keys().to_vec();
+    let expr = builder.build().unwrap();
+    let filter = crate::filtering::builder::expression_to_graphql(&expr, &Calendar::default()).map_err(|e| e.to_string());
+    (format_query(Some(&expr), &sort), filter)
+}
+
+/// The canonical form parses to the same filter and prints the same again.
+fn assert_round_trip(query: &str) {
+    let (printed, filter) = canonical(query);
+    let (reprinted, refilter) = canonical(&printed);
+    assert_eq!(printed, reprinted, "canonical form of {} is not stable", query);
+    assert_eq!(filter, refilter, "{} printed as {} compiles differently", query, printed);
+}
+
+fn condition(field: FilterField, operator: FilterOperator, value: FilterValue) -> FilterExpression {
+    FilterExpression::Condition(FilterCondition { field, operator, value })
+}
+
+fn group(operator: LogicalOperator, conditions: Vec<FilterExpression>) -> FilterExpression {
+    FilterExpression::Group(Box::new(FilterGroup { operator, conditions }))
+}
+
+#[test]
+fn test_round_trip_queries() {
+    for query in [
+        "status:started",
+        r#"status:"In Progress""#,
+        "state:todo AND priority:>2",
+        "priority>=high OR priority:0",
+        "assignee:me AND label:bug",
+        "assignee:ada@example.com",
+        "no-assignee AND has-children",
+        "no-label OR no-children",
+        "has-assignee",
+        "label in:bug,ui",
+        r#"status in:"In Progress",Todo"#,
+        "label has:bug,frontend",
+        "title~crash AND description!~flaky",
+        "title^=Draft OR title$=WIP",
+        "description:null AND estimate!=null",
+        "estimate>=3 AND estimate<8",
+        "cycle:current AND team:ENG",
+        "id:ENG-12",
+        "project:Web AND creator:me AND subscriber:me",
+        "state-type:started AND sla:breached AND relation:blocked",
+        "parent:ENG-1",
+        "created>7d",
+        "updated<2024-01-31",
+        "due:null",
+        "NOT label:bug",
+        "NOT NOT label:bug",
+        "NOT (label:bug OR priority:urgent)",
+        "NOT title^=Draft AND state:started",
+        "(label:bug OR label:ui) AND (priority:high OR priority:urgent)",
+        "label:bug AND priority:high OR label:ui",
+        "((state:todo))",
+        r#"title:"and" OR title:"say \"hi\"""#,
+        "state:started sort:priority,-updated",
+        "sort:-created AND label:bug",
+    ] {
+        assert_round_trip(query);
+    }
+}
+
+#[test]
+fn test_canonical_form() {
+    let cases = [
+        ("state:todo and priority:>2", "status:todo AND priority>medium"),
+        ("tags:bug or p:4", "label:bug OR priority:urgent"),
+        ("label:bug AND priority:high OR label:ui", "(label:bug AND priority:high) OR label:ui"),
+        ("(label:bug OR label:ui) AND state:started", "(label:bug OR label:ui) AND status:started"),
+        ("not (label:bug or label:ui)", "NOT (label:bug OR label:ui)"),
+        ("not not label:bug", "NOT NOT label:bug"),
+        ("assignee:null", "no-assignee"),
+        (r#"title:"and""#, r#"title:"and""#),
+        ("sort:-updated AND state:started", "status:started sort:-updated"),
+        ("estimate:2.5", "estimate:2.5"),
+    ];
+    for (query, expected) in cases {
+        assert_eq!(canonical(query).0, expected, "{}", query);
+    }
+}
+
+#[test]
+fn test_operators_without_syntax() {
+    let labels = || FilterValue::StringList(vec!["bug".into(), "In Review".into()]);
+    let cases = [
+        (condition(FilterField::Label, FilterOperator::NotIn, labels()), r#"NOT label in:bug,"In Review""#),
+        (condition(FilterField::Label, FilterOperator::HasNone, labels()), r#"NOT label has:bug,"In Review""#),
+        (condition(FilterField::Label, FilterOperator::HasAll, labels()), r#"(label:bug AND label:"In Review")"#),
+        (
+            condition(FilterField::CreatedAt, FilterOperator::GreaterThan, FilterValue::RelativeDate(Duration::days(7))),
+            "created>7d",
+        ),
+        (
+            condition(FilterField::UpdatedAt, FilterOperator::LessThan, FilterValue::RelativeDate(Duration::hours(36))),
+            "updated<36h",
+        ),
+    ];
+    for (expr, expected) in cases {
+        let printed = format_expression(&expr);
+        assert_eq!(printed, expected);
+        assert!(parse_filter(&printed).is_ok(), "{} does not parse", printed);
+    }
+}
+
+#[test]
+fn test_single_member_groups() {
+    let bug = || condition(FilterField::Label, FilterOperator::Equals, FilterValue::String("bug".into()));
+    let ui = || condition(FilterField::Label, FilterOperator::Equals, FilterValue::String("ui".into()));
+    let either = || group(LogicalOperator::Or, vec![bug(), ui()]);
+
+    // NOT over a one-member AND around an OR keeps the parentheses
+    let expr = group(LogicalOperator::Not, vec![group(LogicalOperator::And, vec![either()])]);
+    assert_eq!(format_expression(&expr), "NOT (label:bug OR label:ui)");
+
+    let expr = group(LogicalOperator::And, vec![group(LogicalOperator::And, vec![either()]), bug()]);
+    assert_eq!(format_expression(&expr), "(label:bug OR label:ui) AND label:bug");
+}
+
+#[test]
+fn test_parse_tree() {
+    let expr = parse_filter("state:started AND NOT title^=Draft AND (label:bug OR priority>=high)")
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(
+        format_tree(&expr),
+        "AND\n├─ status:started\n├─ NOT\n│  └─ title^=Draft\n└─ OR\n   ├─ label:bug\n   └─ priority>=high"
+    );
+}
@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use crate::formatting::hyperlink::{osc8, terminal_supports_hyperlinks, HyperlinkMode};
+use crate::formatting::markdown::layout::render;
+
+fn supports(vars: &[(&str, &str)]) -> bool {
+    let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+    terminal_supports_hyperlinks(|name| vars.get(name).map(|value| value.to_string()))
+}
+
+#[test]
+fn test_osc8() {
+    assert_eq!(
+        osc8("ENG-1", "https://linear.app/x/issue/ENG-1"),
+        "\u{1b}]8;;https://linear.app/x/issue/ENG-1\u{1b}\\ENG-1\u{1b}]8;;\u{1b}\\"
+    );
+}
+
+#[test]
+fn test_terminal_detection() {
+    assert!(supports(&[("TERM_PROGRAM", "iTerm.app")]));
+    assert!(supports(&[("TERM", "xterm-256color"), ("VTE_VERSION", "6800")]));
+    assert!(supports(&[("TERM", "xterm-kitty")]));
+    assert!(supports(&[("WT_SESSION", "abc")]));
+    assert!(!supports(&[("TERM_PROGRAM", "Apple_Terminal"), ("TERM", "xterm-256color")]));
+    assert!(!supports(&[("TERM", "xterm-256color"), ("VTE_VERSION", "4205")]));
+    assert!(!supports(&[("TERM", "dumb"), ("WT_SESSION", "abc")]));
+    assert!(!supports(&[]));
+}
+
+#[test]
+fn test_hyperlink_mode_names() {
+    assert_eq!(HyperlinkMode::from_name("Always"), Some(HyperlinkMode::Always));
+    assert_eq!(HyperlinkMode::from_name("off"), Some(HyperlinkMode::Never));
+    assert_eq!(HyperlinkMode::from_name(" auto "), Some(HyperlinkMode::Auto));
+    assert_eq!(HyperlinkMode::from_name("maybe"), None);
+}
+
+#[test]
+fn test_markdown_links_carry_their_url() {
+    let markdown = "See [the long spec](https://example.com/spec) and ![](https://example.com/a.png).";
+    let lines = render(markdown, 80, true);
+    let text: String = lines[0].iter().map(|piece| piece.text.as_str()).collect();
+    assert_eq!(text, "See the long spec and image.");
+    let linked: Vec<(&str, &str)> = lines[0]
+        .iter()
+        .filter_map(|piece| piece.link.as_deref().map(|url| (piece.text.as_str(), url)))
+        .collect();
+    assert_eq!(linked, vec![("the long spec", "https://example.com/spec"), ("image", "https://example.com/a.png")]);
+
+    // Without hyperlinks the URL is written out after the text
+    let lines = render(markdown, 120, false);
+    let text: String = lines[0].iter().map(|piece| piece.text.as_str()).collect();
+    assert_eq!(text, "See the long spec (https://example.com/spec) and image (https://example.com/a.png).");
+    assert!(lines[0].iter().all(|piece| piece.link.is_none()));
+}
@@ -0,0 +1,55 @@
+use crate::formatting::utils::{normalize_hex_color, parse_hex_color};
+use crate::models::IssueLabel;
+
+#[test]
+fn test_parse_hex_color() {
+    assert_eq!(parse_hex_color("#5e6ad2"), Some((0x5e, 0x6a, 0xd2)));
+    assert_eq!(parse_hex_color("5E6AD2"), Some((0x5e, 0x6a, 0xd2)));
+    assert_eq!(parse_hex_color("#fa0"), Some((0xff, 0xaa, 0x00)));
+    assert_eq!(parse_hex_color("#12345"), None);
+    assert_eq!(parse_hex_color("#zzzzzz"), None);
+    assert_eq!(parse_hex_color("#ééé"), None);
+    assert_eq!(parse_hex_color(""), None);
+}
+
+#[test]
+fn test_normalize_hex_color() {
+    assert_eq!(normalize_hex_color("FA0").as_deref(), Some("#ffaa00"));
+    assert_eq!(normalize_hex_color(" #5E6AD2 ").as_deref(), Some("#5e6ad2"));
+    assert_eq!(normalize_hex_color("red"), None);
+}
+
+#[test]
+fn test_issue_label_deserialize_with_group() {
+    let json = r##"{
+        "id": "label-1",
+        "name": "Bug",
+        "color": "#eb5757",
+        "description": null,
+        "isGroup": false,
+        "parent": { "id": "group-1", "name": "Type" },
+        "team": { "id": "team-1", "name": "Engineering", "key": "ENG" }
+    }"##;
+
+    let label: IssueLabel = serde_json::from_str(json).unwrap();
+    assert!(!label.is_group);
+    assert_eq!(label.full_name(), "Type/Bug");
+    assert_eq!(label.team.unwrap().key, "ENG");
+}
+
+#[test]
+fn test_issue_label_deserialize_workspace_group() {
+    let json = r##"{
+        "id": "group-1",
+        "name": "Type",
+        "color": "#bec2c8",
+        "isGroup": true,
+        "parent": null,
+        "team": null
+    }"##;
+
+    let label: IssueLabel = serde_json::from_str(json).unwrap();
+    assert!(label.is_group);
+    assert!(label.description.is_none());
+    assert_eq!(label.full_name(), "Type");
+}
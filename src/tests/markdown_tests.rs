@@ -0,0 +1,175 @@
+use crate::formatting::markdown::format_markdown_width;
+use crate::formatting::markdown::highlight::highlight;
+use crate::formatting::markdown::layout::{render, StyledLine, TextStyle, Tone};
+
+/// Rendered lines as plain text
+fn plain(markdown: &str, width: usize) -> Vec<String> {
+    render(markdown, width, false)
+        .iter()
+        .map(|line: &StyledLine| line.iter().map(|piece| piece.text.as_str()).collect())
+        .collect()
+}
+
+/// The first rendered line as its pieces of text and their styles
+fn styled(markdown: &str) -> Vec<(String, TextStyle)> {
+    render(markdown, 80, false)[0].iter().map(|piece| (piece.text.clone(), piece.style)).collect()
+}
+
+#[test]
+fn test_inline_emphasis() {
+    let plain_style = TextStyle::default();
+    let italic = TextStyle { italic: true, ..plain_style };
+    let bold = TextStyle { bold: true, ..plain_style };
+    assert_eq!(
+        styled("a *b* **c** ***d*** ~~e~~ _f_"),
+        vec![
+            ("a ".to_string(), plain_style),
+            ("b".to_string(), italic),
+            (" ".to_string(), plain_style),
+            ("c".to_string(), bold),
+            (" ".to_string(), plain_style),
+            ("d".to_string(), TextStyle { bold: true, ..italic }),
+            (" ".to_string(), plain_style),
+            ("e".to_string(), TextStyle { strike: true, ..plain_style }),
+            (" ".to_string(), plain_style),
+            ("f".to_string(), italic),
+        ]
+    );
+    // Intraword underscores and lone stars stay as they are
+    assert_eq!(styled("snake_case_name * 2"), vec![("snake_case_name * 2".to_string(), plain_style)]);
+    assert_eq!(styled("**unclosed"), vec![("**unclosed".to_string(), plain_style)]);
+    assert_eq!(
+        styled("*a **b** c*"),
+        vec![("a ".to_string(), italic), ("b".to_string(), TextStyle { bold: true, ..italic }), (" c".to_string(), italic)]
+    );
+}
+
+#[test]
+fn test_inline_code_links_and_escapes() {
+    assert_eq!(
+        styled("run `cargo *test*` now"),
+        vec![
+            ("run ".to_string(), TextStyle::default()),
+            ("cargo *test*".to_string(), TextStyle { tone: Tone::Code, ..TextStyle::default() }),
+            (" now".to_string(), TextStyle::default()),
+        ]
+    );
+    assert_eq!(plain("``a ` b``", 80), vec!["a ` b"]);
+    assert_eq!(plain("see [the *docs*](https://x.dev/a_(b) \"Docs\").", 80), vec!["see the docs (https://x.dev/a_(b))."]);
+    assert_eq!(plain("![logo](img.png) <ada@example.com>", 80), vec!["logo (img.png) ada@example.com"]);
+    let lines = render("<ada@example.com>", 80, true);
+    assert_eq!(lines[0][0].link.as_deref(), Some("mailto:ada@example.com"));
+    assert_eq!(plain("\\*not\\* &amp; &#169;", 80), vec!["*not* & ©"]);
+    assert_eq!(plain("one  \ntwo\nthree", 80), vec!["one", "two three"]);
+}
+
+#[test]
+fn test_blocks() {
+    assert_eq!(
+        plain("# Title #\n\nSetext\n---\n\n> quoted\nlazy\n> > nested\n\n***\n\n```rust title\nfn main() {}\n\n```\n\n    indented\n", 40),
+        vec![
+            "Title",
+            "═════",
+            "",
+            "Setext",
+            "──────",
+            "",
+            "│ quoted lazy",
+            "│ ",
+            "│ │ nested",
+            "",
+            "────────────────────────────────────────",
+            "",
+            "┌─ rust ────────────",
+            "│ fn main() {}",
+            "└───────────────────",
+            "",
+            "┌───────────────────",
+            "│ indented",
+            "└───────────────────",
+        ]
+    );
+}
+
+#[test]
+fn test_lists() {
+    assert_eq!(
+        plain("- [ ] todo\n- [x] done\n  - nested\n    more\n\n3. three\n4. four\n\n   second paragraph\n", 80),
+        vec!["☐ todo", "☑ done", "  ◦ nested more", "", "3. three", "", "4. four", "   ", "   second paragraph"]
+    );
+    // Checked tasks are muted
+    let lines = render("- [x] done", 80, false);
+    assert_eq!(lines[0].last().map(|piece| piece.style.tone), Some(Tone::Muted));
+    // A different bullet starts a new list; a rule isn't an item
+    assert_eq!(plain("- a\n+ b\n* * *", 80), vec!["• a", "", "• b", "", "────────────────────────────────────────"]);
+}
+
+#[test]
+fn test_tables() {
+    assert_eq!(plain("| Name | Count |\n|:--|--:|\n| a \\| b | 1 |\n| c |\n", 80), vec!["Name  │ Count", "──────┼──────", "a | b │     1", "c     │"]);
+    let header = render("| Name |\n|--|\n| a |", 80, false);
+    assert!(header[0][0].style.bold);
+
+    assert_eq!(
+        plain("| Name | Count |\n|:--|--:|\n| alpha | 1 |\n| b | 200 |", 80),
+        vec!["Name  │ Count", "──────┼──────", "alpha │     1", "b     │   200"]
+    );
+}
+
+#[test]
+fn test_layout_wraps_and_indents() {
+    assert_eq!(
+        plain("Some words that wrap at twenty cells.\n\n- a list item that wraps\n  - nested\n1. [ ] task", 20),
+        vec![
+            "Some words that wrap",
+            "at twenty cells.",
+            "",
+            "• a list item that",
+            "  wraps",
+            "  ◦ nested",
+            "",
+            "1. ☐ task",
+        ]
+    );
+    assert_eq!(plain("> quoted text here", 12), vec!["│ quoted", "│ text here"]);
+    // Wide characters count as two cells; a word longer than the line is split
+    assert_eq!(plain("日本語のテキスト abcdefghijklmnop", 10), vec!["日本語のテ", "キスト", "abcdefghij", "klmnop"]);
+    assert_eq!(plain("[docs](https://x.dev) and <https://y.dev>", 80), vec!["docs (https://x.dev) and https://y.dev"]);
+    assert_eq!(plain("# Title", 80), vec!["Title", "═════"]);
+}
+
+#[test]
+fn test_code_blocks() {
+    let lines = plain("```sh\necho hi # greet\n```", 80);
+    assert_eq!(lines[0], "┌─ sh ──────────────");
+    assert_eq!(lines[1], "│ echo hi # greet");
+    assert_eq!(lines[2], "└───────────────────");
+
+    let tones = |code: &str, language: &str| -> Vec<(String, Tone)> {
+        highlight(code, language)[0].iter().map(|piece| (piece.text.clone(), piece.style.tone)).collect()
+    };
+    assert_eq!(
+        tones("let n = 42; // answer", "rust"),
+        vec![
+            ("let".to_string(), Tone::Keyword),
+            (" n = ".to_string(), Tone::CodeBlock),
+            ("42".to_string(), Tone::Number),
+            ("; ".to_string(), Tone::CodeBlock),
+            ("// answer".to_string(), Tone::Comment),
+        ]
+    );
+    assert_eq!(
+        tones("fn f<'a>(s: &'a str) -> char { 'x' }", "rs").iter().filter(|(_, tone)| *tone == Tone::Str).count(),
+        1
+    );
+    assert_eq!(tones("SELECT 'a' from t", "sql")[0], ("SELECT".to_string(), Tone::Keyword));
+    assert_eq!(tones("anything at all", "unknown"), vec![("anything at all".to_string(), Tone::CodeBlock)]);
+}
+
+#[test]
+fn test_ansi_output() {
+    colored::control::set_override(false);
+    let rendered = format_markdown_width("**Bold** and `code`\n\n- [x] done", 40);
+    colored::control::unset_override();
+    assert_eq!(rendered, "Bold and code\n\n☑ done");
+}
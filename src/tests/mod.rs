@@ -8,4 +8,73 @@ mod cli_context_tests;
 mod filter_builder_tests;
 
 #[cfg(test)]
-mod theme_tests;
\ No newline at end of file
+mod theme_tests;
+
+#[cfg(test)]
+mod comment_tests;
+
+#[cfg(test)]
+mod label_tests;
+
+#[cfg(test)]
+mod state_tests;
+
+#[cfg(test)]
+mod resolver_tests;
+
+#[cfg(test)]
+mod template_tests;
+
+#[cfg(test)]
+mod view_tests;
+
+#[cfg(test)]
+mod document_tests;
+
+#[cfg(test)]
+mod timer_tests;
+
+#[cfg(test)]
+mod filter_eval_tests;
+
+#[cfg(test)]
+mod sort_tests;
+
+#[cfg(test)]
+mod completion_tests;
+
+#[cfg(test)]
+mod filter_printer_tests;
+
+#[cfg(test)]
+mod diagnostic_tests;
+
+#[cfg(test)]
+mod date_tests;
+
+#[cfg(test)]
+mod variable_tests;
+
+#[cfg(test)]
+mod snippet_tests;
+
+#[cfg(test)]
+mod output_tests;
+
+#[cfg(test)]
+mod output_template_tests;
+
+#[cfg(test)]
+mod table_tests;
+
+#[cfg(test)]
+mod markdown_tests;
+
+#[cfg(test)]
+mod theme_file_tests;
+
+#[cfg(test)]
+mod hyperlink_tests;
+
+#[cfg(test)]
+mod report_tests;
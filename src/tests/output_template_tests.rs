@@ -0,0 +1,70 @@
+use serde_json::json;
+
+use crate::formatting::template::OutputTemplate;
+use crate::formatting::theme::SemanticColor;
+use crate::models::Issue;
+
+fn issue() -> Issue {
+    serde_json::from_value(json!({
+        "id": "1", "identifier": "ENG-1", "title": "Login form times out on slow networks", "description": null,
+        "url": "https://linear.app/x/issue/ENG-1", "priority": 4,
+        "createdAt": "2026-10-01T00:00:00Z", "updatedAt": "2026-10-02T00:00:00Z",
+        "state": { "id": "s", "name": "In Progress", "type": "started" },
+        "assignee": null,
+        "team": { "id": "t", "name": "Eng", "key": "ENG" },
+        "labels": { "nodes": [{ "id": "l1", "name": "bug", "color": "#f00" }, { "id": "l2", "name": "ui", "color": "#0f0" }] },
+        "project": null
+    }))
+    .unwrap()
+}
+
+fn render(template: &str) -> String {
+    OutputTemplate::parse(template).unwrap().render(&issue())
+}
+
+#[test]
+fn test_fields_and_paths() {
+    assert_eq!(render("{{identifier}} [{{state.name}}] {{team.key}}"), "ENG-1 [In Progress] ENG");
+    assert_eq!(render("{{labels.name}}"), "bug, ui");
+    assert_eq!(render("{{labels.name|join:/}}"), "bug/ui");
+    assert_eq!(render("<{{assignee.name}}><{{no.such.field}}>"), "<><>");
+    assert_eq!(render("no fields"), "no fields");
+}
+
+#[test]
+fn test_filters() {
+    assert_eq!(render("{{title|truncate:10}}"), "Login for…");
+    assert_eq!(render("{{title|truncate:100}}"), "Login form times out on slow networks");
+    assert_eq!(render("{{state.name|upper}} {{team.key|lower}}"), "IN PROGRESS eng");
+    assert_eq!(render("{{assignee.name|default:Unassigned}}"), "Unassigned");
+    assert_eq!(render("{{team.key|pad:5}}|"), "ENG  |");
+    assert_eq!(render("{{state.name|truncate:4|upper}}"), "IN …");
+    // Nothing to show when there's no time
+    assert_eq!(render("[{{dueDate|relative}}]"), "[]");
+    assert!(render("{{createdAt|relative}}").ends_with("ago"));
+}
+
+#[test]
+fn test_color_filter() {
+    colored::control::set_override(true);
+    let colored = render("{{identifier|color:link}} {{priority|color:priority}} {{state.name|color:status}}");
+    colored::control::unset_override();
+    assert!(colored.contains("\u{1b}["), "{:?}", colored);
+    assert!(colored.contains("ENG-1") && colored.contains("In Progress"));
+
+    assert_eq!(SemanticColor::from_name("status-started"), Some(SemanticColor::StatusStarted));
+    assert_eq!(SemanticColor::from_name("nope"), None);
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = |template: &str| OutputTemplate::parse(template).unwrap_err();
+    assert!(error("{{title").contains("Unclosed"));
+    assert!(error("{{ }}").contains("Empty field"));
+    assert!(error("{{title|shout}}").contains("Unknown template filter 'shout'"));
+    assert!(error("{{title|truncate:many}}").contains("needs a number"));
+    assert!(error("{{title|pad:99999999999}}").contains("at most 1000"));
+    assert!(error("{{title|truncate:1001}}").contains("at most 1000"));
+    assert!(OutputTemplate::parse("{{title|pad:1000}}").is_ok());
+    assert!(error("{{title|color:plaid}}").contains("Unknown color 'plaid'"));
+}
@@ -0,0 +1,79 @@
+use serde_json::json;
+
+use crate::formatting::output::{markdown_cell, render_records, to_csv, tsv_field};
+use crate::models::{Issue, Team};
+
+fn teams() -> Vec<Team> {
+    vec![
+        Team { id: "t1".to_string(), name: "Engineering, Core".to_string(), key: "ENG".to_string() },
+        Team { id: "t2".to_string(), name: "Ops \"on call\"".to_string(), key: "OPS".to_string() },
+    ]
+}
+
+fn issue() -> Issue {
+    serde_json::from_value(json!({
+        "id": "1", "identifier": "ENG-1", "title": "Fix | pipe\nand tab\there", "description": null,
+        "url": "https://linear.app/x/issue/ENG-1", "priority": 3,
+        "createdAt": "2026-10-01T00:00:00Z", "updatedAt": "2026-10-02T00:00:00Z", "dueDate": "2026-10-31",
+        "state": { "id": "s", "name": "In Progress", "type": "started" },
+        "assignee": { "id": "u", "name": "Ada Lovelace", "email": "ada@example.com" },
+        "team": { "id": "t", "name": "Eng", "key": "ENG" },
+        "labels": { "nodes": [{ "id": "l1", "name": "bug", "color": "#f00" }, { "id": "l2", "name": "ui", "color": "#0f0" }] },
+        "project": null
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_field_escaping() {
+    let row = ["plain", "a,b", "say \"hi\"", "two\nlines"].map(String::from).to_vec();
+    assert_eq!(to_csv([row]), "plain,\"a,b\",\"say \"\"hi\"\"\",\"two\nlines\"\n");
+
+    assert_eq!(tsv_field("a\tb\nc\\d"), "a\\tb\\nc\\\\d");
+    assert_eq!(markdown_cell("a | b\nc"), "a \\| b<br>c");
+}
+
+#[test]
+fn test_csv_tsv_and_markdown() {
+    assert_eq!(
+        render_records(&teams(), "csv").unwrap(),
+        "key,name,id\nENG,\"Engineering, Core\",t1\nOPS,\"Ops \"\"on call\"\"\",t2\n"
+    );
+    assert_eq!(render_records(&teams(), "tsv").unwrap(), "key\tname\tid\nENG\tEngineering, Core\tt1\nOPS\tOps \"on call\"\tt2\n");
+    assert_eq!(
+        render_records(&[issue()], "markdown").unwrap().lines().take(3).collect::<Vec<_>>(),
+        vec![
+            "| id | title | state | priority | assignee | team | project | labels | due | created | updated | url |",
+            "|---|---|---|---|---|---|---|---|---|---|---|---|",
+            "| ENG-1 | Fix \\| pipe<br>and tab\there | In Progress | 3 | Ada Lovelace | ENG |  | bug, ui | 2026-10-31 | \
+             2026-10-01T00:00:00Z | 2026-10-02T00:00:00Z | https://linear.app/x/issue/ENG-1 |",
+        ]
+    );
+    // Only the header when there's nothing to list
+    assert_eq!(render_records::<Team>(&[], "csv").unwrap(), "key,name,id\n");
+}
+
+#[test]
+fn test_json_and_ndjson() {
+    let ndjson = render_records(&teams(), "ndjson").unwrap();
+    let lines: Vec<serde_json::Value> = ndjson.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1]["key"], "OPS");
+
+    let json: serde_json::Value = serde_json::from_str(&render_records(&teams(), "json").unwrap()).unwrap();
+    assert_eq!(json[0]["name"], "Engineering, Core");
+
+    assert!(render_records(&teams(), "simple").is_none());
+    assert!(render_records(&teams(), "table").is_none());
+}
+
+#[test]
+fn test_yaml() {
+    // Reads back as the records' serialized form
+    let yaml = render_records(&[issue()], "yaml").unwrap();
+    let parsed: serde_json::Value = serde_norway::from_str(&yaml).unwrap();
+    assert_eq!(parsed, serde_json::to_value([issue()]).unwrap());
+    assert!(yaml.starts_with("- id: '1'\n  identifier: ENG-1\n"), "{}", yaml);
+
+    assert_eq!(render_records::<Team>(&[], "yaml").unwrap(), "[]\n");
+}
@@ -0,0 +1,145 @@
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+
+use crate::filtering::{Calendar, CycleDates, DateSpec, Zone};
+use crate::models::Issue;
+use crate::report::{format_span, median, render_json, render_markdown, render_table, sparkline, Bucket, GroupBy, Report, Window};
+
+fn at(timestamp: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(timestamp).unwrap().with_timezone(&Utc)
+}
+
+const NOW: &str = "2026-10-18T12:00:00Z";
+
+fn window(since: &str) -> Result<Window, String> {
+    let calendar = Calendar { zone: Zone::Utc, cycles: CycleDates::default() };
+    Window::from_spec(&DateSpec::parse(since).unwrap(), &calendar, at(NOW))
+}
+
+fn issue(
+    identifier: &str,
+    created: &str,
+    started: Option<&str>,
+    completed: Option<&str>,
+    estimate: Option<f64>,
+    labels: &[&str],
+    assignee: Option<&str>,
+) -> Issue {
+    let labels: Vec<_> = labels.iter().map(|name| json!({ "id": name, "name": name, "color": "#000000" })).collect();
+    serde_json::from_value(json!({
+        "id": identifier, "identifier": identifier, "title": identifier, "description": null,
+        "url": format!("https://linear.app/x/issue/{}", identifier), "priority": 0, "estimate": estimate,
+        "createdAt": created, "updatedAt": created, "startedAt": started, "completedAt": completed,
+        "state": { "id": "s", "name": "Done", "type": "completed" },
+        "assignee": assignee.map(|name| json!({ "id": name, "name": name, "email": format!("{}@x.com", name) })),
+        "team": { "id": "t", "name": "Eng", "key": "ENG" },
+        "labels": { "nodes": labels },
+        "project": null
+    }))
+    .unwrap()
+}
+
+fn issues() -> Vec<Issue> {
+    vec![
+        issue("ENG-1", "2026-10-05T10:00:00Z", Some("2026-10-06T10:00:00Z"), Some("2026-10-08T10:00:00Z"), Some(3.0), &["bug"], Some("Ada")),
+        // Created before the window, so only its completion counts
+        issue("ENG-2", "2026-09-01T00:00:00Z", Some("2026-10-10T00:00:00Z"), Some("2026-10-12T00:00:00Z"), Some(5.0), &["bug", "api"], Some("Ada")),
+        issue("ENG-3", "2026-10-15T00:00:00Z", None, None, None, &[], None),
+        // Entirely before the window
+        issue("ENG-4", "2026-09-01T00:00:00Z", None, Some("2026-09-20T00:00:00Z"), Some(8.0), &["bug"], Some("Ada")),
+    ]
+}
+
+/// `text` without color codes
+fn plain(text: &str) -> String {
+    regex::Regex::new("\u{1b}\\[[0-9;]*m").unwrap().replace_all(text, "").to_string()
+}
+
+#[test]
+fn test_median_sparkline_and_spans() {
+    assert_eq!(median(&[]), None);
+    assert_eq!(median(&[5, 1, 3]), Some(3));
+    assert_eq!(median(&[4, 1, 3, 2]), Some(2));
+
+    assert_eq!(sparkline(&[0, 1, 2, 4]), "▁▃▅█");
+    assert_eq!(sparkline(&[0, 0]), "▁▁");
+    assert_eq!(sparkline(&[]), "");
+
+    assert_eq!(format_span(5 * 3600), "5h");
+    assert_eq!(format_span(84 * 3600), "3.5d");
+}
+
+#[test]
+fn test_report_windows() {
+    // An offset runs up to now, in daily buckets
+    let two_weeks = window("2w").unwrap();
+    assert_eq!((two_weeks.start, two_weeks.end), (at("2026-10-04T12:00:00Z"), at(NOW)));
+    assert_eq!((two_weeks.bucket(), two_weeks.buckets()), (Bucket::Day, 14));
+
+    // So does a day; a named period covers just that period
+    assert_eq!(window("2026-10-01").unwrap().start, at("2026-10-01T00:00:00Z"));
+    assert_eq!(window("2026-10-01").unwrap().end, at(NOW));
+    let last_month = window("last-month").unwrap();
+    assert_eq!((last_month.start, last_month.end), (at("2026-09-01T00:00:00Z"), at("2026-10-01T00:00:00Z")));
+    assert_eq!(window("this-month").unwrap().end, at(NOW));
+
+    // Past a month the buckets are weeks
+    let quarter = window("2026-07-01").unwrap();
+    assert_eq!((quarter.bucket(), quarter.buckets()), (Bucket::Week, 16));
+
+    assert!(window("..2026-10-01").is_err());
+    assert!(window("2026-10-20").is_err());
+}
+
+#[test]
+fn test_report_totals_and_groups() {
+    let report = Report::build(&issues(), window("2w").unwrap(), Some(GroupBy::Label));
+    let total = &report.total;
+    assert_eq!((total.created, total.completed, total.net()), (2, 2, 0));
+    assert_eq!(total.points, 8.0);
+    // 3 days and 41 days from creation; 2 days each from starting
+    assert_eq!(total.median_lead_time(), Some(Duration::days(22).num_seconds()));
+    assert_eq!(total.median_cycle_time(), Some(Duration::days(2).num_seconds()));
+    assert_eq!(sparkline(&total.completed_trend), "▁▁▁█▁▁▁█▁▁▁▁▁▁");
+
+    // An issue counts towards each of its labels; groups with most completed first
+    let groups: Vec<(&str, usize, usize, f64)> = report
+        .groups
+        .iter()
+        .map(|g| (g.name.as_str(), g.metrics.completed, g.metrics.created, g.metrics.points))
+        .collect();
+    assert_eq!(groups, vec![("bug", 2, 1, 8.0), ("api", 1, 0, 5.0), ("No label", 0, 1, 0.0)]);
+
+    let report = Report::build(&issues(), window("2w").unwrap(), Some(GroupBy::Assignee));
+    let names: Vec<&str> = report.groups.iter().map(|g| g.name.as_str()).collect();
+    assert_eq!(names, vec!["Ada", "Unassigned"]);
+}
+
+#[test]
+fn test_report_output() {
+    let mut report = Report::build(&issues(), window("2w").unwrap(), Some(GroupBy::Assignee));
+    report.team = Some("ENG".to_string());
+
+    let lines: Vec<String> = render_table(&report, 100).iter().map(|l| plain(l)).collect();
+    assert_eq!(lines[0], "Report for ENG: 2026-10-04 to 2026-10-18, by day");
+    assert_eq!(lines[2], "  Completed       2  ▁▁▁█▁▁▁█▁▁▁▁▁▁");
+    assert_eq!(lines[6], "  Lead time   22.0d  median, created to completed");
+    assert_eq!(lines[10], "Assignee    Completed  Created  Net  Points   Lead  Cycle  Trend");
+    assert_eq!(lines[12], "Ada                 2        1   -1       8  22.0d   2.0d  ▁▁▁█▁▁▁█▁▁▁▁▁▁");
+    assert_eq!(lines[13], "Unassigned          0        1   +1       0      -      -  ▁▁▁▁▁▁▁▁▁▁▁▁▁▁");
+    // The trend goes first when the table doesn't fit
+    assert!(render_table(&report, 60).iter().all(|l| !plain(l).contains("Trend")));
+
+    let markdown = render_markdown(&report);
+    assert!(markdown.starts_with("## Report for ENG: 2026-10-04 to 2026-10-18\n"), "{}", markdown);
+    assert!(markdown.contains("| Points delivered | 8 |  |\n"), "{}", markdown);
+    assert!(markdown.contains("| Ada | 2 | 1 | -1 | 8 | 22.0d | 2.0d | ▁▁▁█▁▁▁█▁▁▁▁▁▁ |\n"), "{}", markdown);
+
+    let value = render_json(&report);
+    assert_eq!(value["team"], "ENG");
+    assert_eq!(value["bucket"], "day");
+    assert_eq!(value["total"]["points"], 8.0);
+    assert_eq!(value["total"]["medianCycleTimeHours"], 48.0);
+    assert_eq!(value["groups"][0]["name"], "Ada");
+    assert_eq!(value["groups"][1]["medianLeadTimeHours"], serde_json::Value::Null);
+}
@@ -0,0 +1,121 @@
+use crate::error::LinearError;
+use crate::models::label::LabelParent;
+use crate::models::{IssueLabel, Project, Team, User};
+use crate::resolver::{match_label, match_project, match_team, match_user, slugify};
+
+fn user(id: &str, name: &str, email: &str, display_name: Option<&str>) -> User {
+    User {
+        id: id.to_string(),
+        name: name.to_string(),
+        email: email.to_string(),
+        display_name: display_name.map(str::to_string),
+    }
+}
+
+fn team(id: &str, name: &str, key: &str) -> Team {
+    Team { id: id.to_string(), name: name.to_string(), key: key.to_string() }
+}
+
+fn label(id: &str, name: &str, parent: Option<&str>, team_id: Option<&str>) -> IssueLabel {
+    IssueLabel {
+        id: id.to_string(),
+        name: name.to_string(),
+        color: "#000000".to_string(),
+        description: None,
+        is_group: false,
+        parent: parent.map(|p| LabelParent { id: format!("{}-id", p), name: p.to_string() }),
+        team: team_id.map(|t| team(t, t, &t.to_uppercase())),
+    }
+}
+
+fn project(id: &str, name: &str, url: &str, slug_id: &str) -> Project {
+    Project {
+        id: id.to_string(),
+        name: name.to_string(),
+        description: None,
+        url: url.to_string(),
+        slug_id: Some(slug_id.to_string()),
+        state: "started".to_string(),
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+    }
+}
+
+fn users() -> Vec<User> {
+    vec![
+        user("u1", "Ada Lovelace", "ada@example.com", Some("ada")),
+        user("u2", "Alan Turing", "alan@example.com", Some("alan")),
+        user("u3", "Alan Kay", "kay@example.com", None),
+    ]
+}
+
+#[test]
+fn test_match_user_by_email_name_and_display_name() {
+    let users = users();
+    assert_eq!(match_user(&users, "ADA@example.com").unwrap().id, "u1");
+    assert_eq!(match_user(&users, "alan turing").unwrap().id, "u2");
+    // Display name beats the first-name pass that would be ambiguous
+    assert_eq!(match_user(&users, "alan").unwrap().id, "u2");
+    assert_eq!(match_user(&users, "kay").unwrap().id, "u3");
+    assert_eq!(match_user(&users, "u3").unwrap().name, "Alan Kay");
+}
+
+#[test]
+fn test_match_user_ambiguous_lists_candidates() {
+    let users = vec![
+        user("u2", "Alan Turing", "turing@example.com", None),
+        user("u3", "Alan Kay", "kay@example.com", None),
+    ];
+    match match_user(&users, "Alan") {
+        Err(LinearError::Ambiguous { kind, candidates, .. }) => {
+            assert_eq!(kind, "user");
+            assert_eq!(candidates, vec![
+                "Alan Turing <turing@example.com>".to_string(),
+                "Alan Kay <kay@example.com>".to_string(),
+            ]);
+        }
+        other => panic!("Expected ambiguity error, got {:?}", other.map(|u| &u.name)),
+    }
+    assert!(match_user(&users, "grace").is_err());
+}
+
+#[test]
+fn test_match_team_by_key_and_name() {
+    let teams = vec![team("t1", "Engineering", "ENG"), team("t2", "Design", "DES")];
+    assert_eq!(match_team(&teams, "eng").unwrap().id, "t1");
+    assert_eq!(match_team(&teams, "design").unwrap().id, "t2");
+    assert!(match_team(&teams, "ops").is_err());
+}
+
+#[test]
+fn test_match_label_scoped_by_team() {
+    let labels = vec![
+        label("l1", "Bug", Some("Type"), Some("eng")),
+        label("l2", "Bug", None, Some("ops")),
+        label("l3", "Urgent", None, None),
+    ];
+
+    assert!(matches!(match_label(&labels, "bug", None), Err(LinearError::Ambiguous { .. })));
+    assert_eq!(match_label(&labels, "bug", Some("ops")).unwrap().id, "l2");
+    assert_eq!(match_label(&labels, "Type/Bug", None).unwrap().id, "l1");
+    // Workspace labels are available to every team
+    assert_eq!(match_label(&labels, "urgent", Some("eng")).unwrap().id, "l3");
+}
+
+#[test]
+fn test_match_project_by_name_and_slug() {
+    let projects = vec![
+        project("p1", "Launch Website", "https://linear.app/acme/project/launch-website-6b2d3f1a9e4c", "6b2d3f1a9e4c"),
+        project("p2", "Mobile App", "https://linear.app/acme/project/mobile-app-0a1b2c3d4e5f/", "0a1b2c3d4e5f"),
+    ];
+
+    assert_eq!(match_project(&projects, "launch website").unwrap().id, "p1");
+    assert_eq!(match_project(&projects, "launch-website").unwrap().id, "p1");
+    assert_eq!(match_project(&projects, "mobile-app-0a1b2c3d4e5f").unwrap().id, "p2");
+    assert_eq!(match_project(&projects, "0a1b2c3d4e5f").unwrap().id, "p2");
+    assert!(match_project(&projects, "roadmap").is_err());
+}
+
+#[test]
+fn test_slugify() {
+    assert_eq!(slugify("Q3: Launch  Website!"), "q3-launch-website");
+}
@@ -0,0 +1,72 @@
+use crate::formatting::snippets::{find_matches, issue_snippets, search_terms, snippet, SnippetSource};
+use crate::models::IssueSearchResult;
+
+fn terms(query: &str) -> Vec<String> {
+    search_terms(query)
+}
+
+/// The matched parts of `text`
+fn marked<'a>(text: &'a str, matches: &[std::ops::Range<usize>]) -> Vec<&'a str> {
+    matches.iter().map(|range| &text[range.clone()]).collect()
+}
+
+#[test]
+fn test_search_terms() {
+    assert_eq!(terms("Login  TIMEOUT"), vec!["login", "timeout"]);
+    assert_eq!(terms("retry \"times out\" retry"), vec!["retry", "times out"]);
+    assert!(terms("  \"\" ").is_empty());
+}
+
+#[test]
+fn test_find_matches_ignores_case_and_merges() {
+    let text = "Ünïcode LOGIN fails; login-retry";
+    let matches = find_matches(text, &terms("login ünï"));
+    assert_eq!(marked(text, &matches), vec!["Ünï", "LOGIN", "login"]);
+
+    // Overlapping terms make one match
+    let matches = find_matches("timeout", &terms("time meout"));
+    assert_eq!(matches, vec![0..7]);
+}
+
+#[test]
+fn test_snippet_window() {
+    // Short text is shown whole, on one line
+    let (text, matches) = snippet("The login\n\nform times out", &terms("times"), 100).unwrap();
+    assert_eq!(text, "The login form times out");
+    assert_eq!(marked(&text, &matches), vec!["times"]);
+
+    // Long text is cut at word boundaries around the first match
+    let long = format!("{} the retry loop times out {}", "word ".repeat(40), "tail ".repeat(40));
+    let (text, matches) = snippet(&long, &terms("retry"), 40).unwrap();
+    assert!(text.starts_with("…word") && text.ends_with("tail…"), "{}", text);
+    assert!(text.chars().count() <= 42);
+    assert_eq!(marked(&text, &matches), vec!["retry"]);
+
+    // A match near the end still gets a full window
+    let (text, _) = snippet(&format!("{}end", "word ".repeat(40)), &terms("end"), 40).unwrap();
+    assert!(text.ends_with("word end") && text.chars().count() > 30, "{}", text);
+
+    assert!(snippet("nothing here", &terms("missing"), 40).is_none());
+}
+
+#[test]
+fn test_issue_snippets() {
+    let result: IssueSearchResult = serde_json::from_value(serde_json::json!({
+        "id": "1", "identifier": "ENG-1", "title": "Login", "description": "Nothing relevant",
+        "url": "https://linear.app/x/issue/ENG-1", "priority": 0,
+        "createdAt": "2026-10-01T00:00:00Z", "updatedAt": "2026-10-01T00:00:00Z",
+        "state": { "id": "s", "name": "Todo", "type": "unstarted" },
+        "assignee": null, "team": { "id": "t", "name": "Eng", "key": "ENG" },
+        "labels": { "nodes": [] }, "project": null,
+        "comments": { "nodes": [
+            { "id": "c1", "body": "Still times out for me", "createdAt": "2026-10-02T00:00:00Z",
+              "updatedAt": "2026-10-02T00:00:00Z", "user": { "id": "u", "name": "Ada Lovelace", "email": "ada@example.com" } }
+        ] }
+    }))
+    .unwrap();
+
+    let snippets = issue_snippets(&result, &terms("timeout \"times out\""));
+    assert_eq!(snippets.len(), 1);
+    assert_eq!(snippets[0].source, SnippetSource::Comment("Ada".to_string()));
+    assert_eq!(marked(&snippets[0].text, &snippets[0].matches), vec!["times out"]);
+}
@@ -0,0 +1,131 @@
+use serde_json::{json, Value};
+
+use crate::filtering::{
+    format_sort, order_by, parse_filter, parse_sort, sort_issues, Calendar, FilterAdapter, SortField, SortKey,
+};
+use crate::models::Issue;
+
+fn issue(value: Value) -> Issue {
+    let mut base = json!({
+        "id": "issue",
+        "identifier": "ENG-1",
+        "title": "Untitled",
+        "description": null,
+        "url": "https://linear.app/acme/issue/ENG-1",
+        "priority": 0,
+        "createdAt": "2024-05-01T10:00:00.000Z",
+        "updatedAt": "2024-05-01T10:00:00.000Z",
+        "state": { "id": "state-todo", "name": "Todo", "type": "unstarted" },
+        "assignee": null,
+        "team": { "id": "team-eng", "name": "Engineering", "key": "ENG" },
+        "labels": { "nodes": [] },
+        "project": null
+    });
+    for (key, field) in value.as_object().unwrap() {
+        base[key] = field.clone();
+    }
+    serde_json::from_value(base).unwrap()
+}
+
+fn identifiers(issues: &[Issue]) -> Vec<&str> {
+    issues.iter().map(|i| i.identifier.as_str()).collect()
+}
+
+#[test]
+fn test_parse_sort() {
+    let keys = parse_sort("priority, -updated").unwrap();
+    assert_eq!(keys, vec![
+        SortKey { field: SortField::Priority, descending: false },
+        SortKey { field: SortField::Updated, descending: true },
+    ]);
+    assert_eq!(format_sort(&keys), "priority,-updated");
+
+    assert_eq!(parse_sort("+identifier").unwrap()[0].field, SortField::Identifier);
+    assert_eq!(parse_sort("state").unwrap()[0].field, SortField::Status);
+    assert!(parse_sort("").unwrap().is_empty());
+    assert!(parse_sort("priority,bogus").is_err());
+}
+
+#[test]
+fn test_sort_clause_in_filter() {
+    let builder = parse_filter("state:started sort:priority,-updated").unwrap();
+    assert_eq!(format_sort(builder.sort_keys()), "priority,-updated");
+    let filter = builder.to_graphql(&Calendar::default()).unwrap();
+    assert_eq!(filter, json!({ "state": { "name": { "eq": "started" } } }));
+
+    // With an explicit AND, and with the clause first
+    let builder = parse_filter("sort:-created AND priority:high").unwrap();
+    assert_eq!(format_sort(builder.sort_keys()), "-created");
+    assert!(builder.to_graphql(&Calendar::default()).is_ok());
+
+    // A query can be nothing but a sort
+    let (filter, keys) = FilterAdapter::parse_and_build_sorted("sort:title", &Calendar::default()).unwrap();
+    assert_eq!(filter, json!({}));
+    assert_eq!(format_sort(&keys), "title");
+
+    assert!(parse_filter("state:started sort:nope").is_err());
+}
+
+#[test]
+fn test_order_by() {
+    assert_eq!(order_by(&parse_sort("-updated,priority").unwrap()), Some("updatedAt"));
+    assert_eq!(order_by(&parse_sort("-created").unwrap()), Some("createdAt"));
+    // Linear only sorts newest first, and only on dates
+    assert_eq!(order_by(&parse_sort("created").unwrap()), None);
+    assert_eq!(order_by(&parse_sort("priority,-updated").unwrap()), None);
+    assert_eq!(order_by(&[]), None);
+}
+
+#[test]
+fn test_sort_by_priority_is_stable() {
+    let mut issues = vec![
+        issue(json!({ "identifier": "ENG-1", "priority": 0 })),
+        issue(json!({ "identifier": "ENG-2", "priority": 2 })),
+        issue(json!({ "identifier": "ENG-3", "priority": 4 })),
+        issue(json!({ "identifier": "ENG-4", "priority": 2 })),
+        issue(json!({ "identifier": "ENG-5", "priority": 1 })),
+    ];
+    sort_issues(&mut issues, &parse_sort("priority").unwrap());
+    assert_eq!(identifiers(&issues), vec!["ENG-3", "ENG-2", "ENG-4", "ENG-5", "ENG-1"]);
+
+    sort_issues(&mut issues, &parse_sort("-priority").unwrap());
+    assert_eq!(identifiers(&issues), vec!["ENG-1", "ENG-5", "ENG-2", "ENG-4", "ENG-3"]);
+}
+
+#[test]
+fn test_sort_by_multiple_keys() {
+    let mut issues = vec![
+        issue(json!({ "identifier": "ENG-1", "priority": 3, "updatedAt": "2024-05-01T10:00:00.000Z" })),
+        issue(json!({ "identifier": "ENG-2", "priority": 3, "updatedAt": "2024-05-03T10:00:00.000Z" })),
+        issue(json!({ "identifier": "ENG-3", "priority": 4, "updatedAt": "2024-05-02T10:00:00.000Z" })),
+    ];
+    sort_issues(&mut issues, &parse_sort("priority,-updated").unwrap());
+    assert_eq!(identifiers(&issues), vec!["ENG-3", "ENG-2", "ENG-1"]);
+}
+
+#[test]
+fn test_sort_by_identifier_and_status() {
+    let mut issues = vec![
+        issue(json!({ "identifier": "ENG-10", "state": { "id": "s1", "name": "Done", "type": "completed" } })),
+        issue(json!({ "identifier": "ENG-9", "state": { "id": "s2", "name": "In Progress", "type": "started" } })),
+        issue(json!({ "identifier": "ENG-2", "state": { "id": "s3", "name": "Backlog", "type": "backlog" } })),
+    ];
+    sort_issues(&mut issues, &parse_sort("id").unwrap());
+    assert_eq!(identifiers(&issues), vec!["ENG-2", "ENG-9", "ENG-10"]);
+
+    sort_issues(&mut issues, &parse_sort("status").unwrap());
+    assert_eq!(identifiers(&issues), vec!["ENG-2", "ENG-9", "ENG-10"]);
+    sort_issues(&mut issues, &parse_sort("-status").unwrap());
+    assert_eq!(identifiers(&issues), vec!["ENG-10", "ENG-9", "ENG-2"]);
+}
+
+#[test]
+fn test_sort_assignee_missing_last() {
+    let mut issues = vec![
+        issue(json!({ "identifier": "ENG-1" })),
+        issue(json!({ "identifier": "ENG-2", "assignee": { "id": "u1", "name": "Zoe", "email": "zoe@example.com" } })),
+        issue(json!({ "identifier": "ENG-3", "assignee": { "id": "u2", "name": "ada", "email": "ada@example.com" } })),
+    ];
+    sort_issues(&mut issues, &parse_sort("assignee").unwrap());
+    assert_eq!(identifiers(&issues), vec!["ENG-3", "ENG-2", "ENG-1"]);
+}
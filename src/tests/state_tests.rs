@@ -0,0 +1,83 @@
+use crate::commands::states::reorder_position;
+use crate::models::{Team, WorkflowState};
+use crate::resolver::{resolve_state, team_states};
+
+fn state(id: &str, name: &str, state_type: &str, position: f64, team: &str) -> WorkflowState {
+    WorkflowState {
+        id: id.to_string(),
+        name: name.to_string(),
+        state_type: state_type.to_string(),
+        color: None,
+        position: Some(position),
+        team: Some(Team {
+            id: team.to_string(),
+            name: team.to_string(),
+            key: team.to_uppercase(),
+        }),
+    }
+}
+
+fn states() -> Vec<WorkflowState> {
+    vec![
+        state("eng-done", "Done", "completed", 5.0, "eng"),
+        state("eng-review", "In Review", "started", 4.0, "eng"),
+        state("eng-progress", "In Progress", "started", 3.0, "eng"),
+        state("eng-todo", "Todo", "unstarted", 2.0, "eng"),
+        state("eng-backlog", "Backlog", "backlog", 1.0, "eng"),
+        state("ops-shipped", "Shipped", "completed", 3.0, "ops"),
+        state("ops-doing", "Doing", "started", 2.0, "ops"),
+    ]
+}
+
+#[test]
+fn test_resolve_state_by_name_within_team() {
+    let states = states();
+    assert_eq!(resolve_state(&states, "eng", "In Review").unwrap().id, "eng-review");
+    assert_eq!(resolve_state(&states, "eng", "in-review").unwrap().id, "eng-review");
+    assert_eq!(resolve_state(&states, "eng", "DONE").unwrap().id, "eng-done");
+}
+
+#[test]
+fn test_resolve_state_by_type_uses_team_workflow() {
+    let states = states();
+    assert_eq!(resolve_state(&states, "ops", "done").unwrap().id, "ops-shipped");
+    assert_eq!(resolve_state(&states, "ops", "started").unwrap().id, "ops-doing");
+    // The first started state on the board wins
+    assert_eq!(resolve_state(&states, "eng", "started").unwrap().id, "eng-progress");
+}
+
+#[test]
+fn test_resolve_state_by_id_and_unknown() {
+    let states = states();
+    assert_eq!(resolve_state(&states, "eng", "eng-todo").unwrap().name, "Todo");
+    // Another team's state id does not resolve
+    assert!(resolve_state(&states, "eng", "ops-doing").is_err());
+
+    let err = resolve_state(&states, "ops", "backlog").unwrap_err().to_string();
+    assert!(err.contains("Unknown state 'backlog' for team OPS"));
+    assert!(err.contains("Doing, Shipped"));
+}
+
+#[test]
+fn test_team_states_board_order() {
+    let states = states();
+    let names: Vec<&str> = team_states(&states, "eng").iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["Backlog", "Todo", "In Progress", "In Review", "Done"]);
+}
+
+#[test]
+fn test_reorder_position_within_type() {
+    let states = states();
+    let ordered = team_states(&states, "eng");
+
+    // Review before Progress: below Progress' position
+    assert_eq!(reorder_position(&ordered, "eng-review", "eng-progress", true), Some(2.0));
+    // Progress after Review: past the end of the column
+    assert_eq!(reorder_position(&ordered, "eng-progress", "eng-review", false), Some(5.0));
+
+    let mut extra = states.clone();
+    extra.push(state("eng-qa", "QA", "started", 10.0, "eng"));
+    let ordered = team_states(&extra, "eng");
+    // QA between Progress (3.0) and Review (4.0)
+    assert_eq!(reorder_position(&ordered, "eng-qa", "eng-progress", false), Some(3.5));
+}
@@ -0,0 +1,86 @@
+use serde_json::json;
+
+use crate::formatting::table::{
+    display_width, fit_widths, pad_to_width, parse_columns, render_issue_table, truncate_to_width, IssueColumn, WidthRule,
+};
+use crate::models::Issue;
+
+fn issue(identifier: &str, title: &str) -> Issue {
+    serde_json::from_value(json!({
+        "id": identifier, "identifier": identifier, "title": title, "description": null,
+        "url": format!("https://linear.app/x/issue/{}", identifier), "priority": 4, "estimate": 3.0,
+        "createdAt": "2026-10-01T00:00:00Z", "updatedAt": "2026-10-02T00:00:00Z", "dueDate": "2026-10-31",
+        "state": { "id": "s", "name": "In Progress", "type": "started" },
+        "assignee": null,
+        "team": { "id": "t", "name": "Eng", "key": "ENG" },
+        "labels": { "nodes": [{ "id": "l1", "name": "bug", "color": "#f00" }] },
+        "project": null
+    }))
+    .unwrap()
+}
+
+/// `text` without color codes
+fn plain(text: &str) -> String {
+    regex::Regex::new("\u{1b}\\[[0-9;]*m").unwrap().replace_all(text, "").to_string()
+}
+
+#[test]
+fn test_unicode_width() {
+    assert_eq!(display_width("abc"), 3);
+    assert_eq!(display_width("日本語"), 6);
+    assert_eq!(truncate_to_width("日本語のタイトル", 7), "日本語…");
+    assert_eq!(truncate_to_width("short", 10), "short");
+    assert_eq!(truncate_to_width("anything", 0), "");
+    assert_eq!(pad_to_width("日本", 6), "日本  ");
+    assert_eq!(display_width(&pad_to_width("日本語のタイトル", 8)), 8);
+}
+
+#[test]
+fn test_fit_widths() {
+    let rules = [WidthRule::fixed(5), WidthRule::between(10, 50).flex(), WidthRule::between(8, 12).drop(1)];
+
+    // Room for everything at the wanted widths
+    assert_eq!(fit_widths(&rules, &[5, 30, 9], 100, 1), vec![Some(5), Some(30), Some(9)]);
+    // The flexible column gives up width first
+    assert_eq!(fit_widths(&rules, &[5, 30, 9], 30, 1), vec![Some(5), Some(14), Some(9)]);
+    // Then optional columns are hidden
+    assert_eq!(fit_widths(&rules, &[5, 30, 9], 20, 1), vec![Some(5), Some(14), None]);
+    // And, when nothing else can give, columns go below their minimums
+    assert_eq!(fit_widths(&rules, &[5, 30, 9], 10, 1), vec![Some(5), Some(4), None]);
+}
+
+#[test]
+fn test_parse_columns() {
+    let columns = parse_columns("id, title:40,priority,estimate,due,labels").unwrap();
+    assert_eq!(columns.len(), 6);
+    assert_eq!(columns[0].column, IssueColumn::Identifier);
+    assert_eq!(columns[1].max, Some(40));
+
+    assert!(parse_columns("title,colour").unwrap_err().contains("Unknown column 'colour'"));
+    assert!(parse_columns("title:wide").unwrap_err().contains("Invalid width 'wide'"));
+    assert!(parse_columns(" , ").is_err());
+}
+
+#[test]
+fn test_issue_table() {
+    let issues = [issue("ENG-1", "日本語のタイトルがとても長い場合のテスト"), issue("ENG-22", "Short")];
+    let columns = parse_columns("identifier,title,priority,estimate,due,labels").unwrap();
+
+    let lines: Vec<String> = render_issue_table(&issues, &columns, 120).iter().map(|l| plain(l)).collect();
+    assert_eq!(lines[1], "ID      Title                                     Priority  Est  Due         Labels");
+    assert_eq!(lines[3], "ENG-1   日本語のタイトルがとても長い場合のテスト  Urgent    3    2026-10-31  bug");
+    assert_eq!(lines[4], "ENG-22  Short                                     Urgent    3    2026-10-31  bug");
+
+    // Narrow: the least essential columns go, the title shrinks, and every line
+    // stays within the width
+    let lines: Vec<String> = render_issue_table(&issues, &columns, 40).iter().map(|l| plain(l)).collect();
+    assert_eq!(lines[1], "ID      Title                   Priority");
+    assert!(lines[3].starts_with("ENG-1   日本語のタイトルがと…"), "{}", lines[3]);
+    assert!(lines.iter().all(|line| display_width(line) <= 40));
+
+    // A width from --columns caps the column
+    let columns = parse_columns("id,title:8").unwrap();
+    let lines: Vec<String> = render_issue_table(&issues, &columns, 120).iter().map(|l| plain(l)).collect();
+    assert_eq!(lines[3], "ENG-1   日本語…");
+}
+
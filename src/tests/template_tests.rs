@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde_json::json;
+
+use crate::error::LinearError;
+use crate::models::Template;
+use crate::templates::{
+    find_template, parse_template, placeholders, prosemirror_to_markdown, render_placeholders,
+    IssueTemplate, TemplateSource,
+};
+
+fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+#[test]
+fn test_parse_template_with_header() {
+    let contents = "---\n\
+        title: \"Bug: {{title}}\"\n\
+        team: ENG\n\
+        labels: [Bug, \"Needs triage\"]\n\
+        priority: high\n\
+        estimate: 2\n\
+        project: Mobile App\n\
+        ---\n\
+        ## Steps to reproduce\n\n{{steps}}\n";
+
+    let template = parse_template(Path::new("/tmp/bug.md"), contents).unwrap();
+    assert_eq!(template.name, "bug");
+    assert!(template.is_local());
+    assert_eq!(template.title.as_deref(), Some("Bug: {{title}}"));
+    assert_eq!(template.team.as_deref(), Some("ENG"));
+    assert_eq!(template.labels, vec!["Bug", "Needs triage"]);
+    assert_eq!(template.priority, Some(3));
+    assert_eq!(template.estimate, Some(2.0));
+    assert_eq!(template.project.as_deref(), Some("Mobile App"));
+    assert_eq!(template.description.as_deref(), Some("## Steps to reproduce\n\n{{steps}}"));
+}
+
+#[test]
+fn test_parse_template_without_header() {
+    let template = parse_template(Path::new("chore.md"), "Just a body\n").unwrap();
+    assert_eq!(template.name, "chore");
+    assert_eq!(template.title, None);
+    assert_eq!(template.description.as_deref(), Some("Just a body"));
+}
+
+#[test]
+fn test_parse_template_errors() {
+    let unclosed = parse_template(Path::new("a.md"), "---\ntitle: x\n");
+    assert!(matches!(unclosed, Err(LinearError::ParseError(_))));
+
+    let unknown = parse_template(Path::new("a.md"), "---\ncolour: red\n---\n");
+    assert!(matches!(unknown, Err(LinearError::ParseError(msg)) if msg.contains("colour")));
+
+    let priority = parse_template(Path::new("a.md"), "---\npriority: asap\n---\n");
+    assert!(matches!(priority, Err(LinearError::ParseError(msg)) if msg.contains("asap")));
+}
+
+#[test]
+fn test_render_placeholders_keeps_unknown() {
+    let rendered = render_placeholders("{{ title }} on {{date}} by {{who}}", &vars(&[("title", "Crash"), ("date", "2024-01-02")]));
+    assert_eq!(rendered, "Crash on 2024-01-02 by {{who}}");
+    assert_eq!(placeholders("{{a}} {{ b }} {{a}}"), vec!["a", "b"]);
+}
+
+#[test]
+fn test_render_title_falls_back_to_given_title() {
+    let template = parse_template(Path::new("plain.md"), "body").unwrap();
+    assert_eq!(template.render_title(&vars(&[("title", "Hello")])), Some("Hello".to_string()));
+    assert_eq!(template.render_title(&HashMap::new()), None);
+}
+
+fn linear_template(name: &str, data: serde_json::Value) -> Template {
+    serde_json::from_value(json!({
+        "id": format!("tmpl-{}", name.to_lowercase()),
+        "name": name,
+        "type": "issue",
+        "description": null,
+        "templateData": data,
+        "team": { "id": "team-1", "name": "Engineering", "key": "ENG" }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_from_linear_template() {
+    let data = json!({
+        "title": "Bug report",
+        "priority": 2,
+        "labelIds": ["label-1"],
+        "descriptionData": {
+            "type": "doc",
+            "content": [
+                { "type": "heading", "attrs": { "level": 2 }, "content": [{ "type": "text", "text": "Steps" }] },
+                { "type": "bulletList", "content": [
+                    { "type": "listItem", "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "Open app" }] }] },
+                    { "type": "listItem", "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "Crash" }] }] }
+                ] }
+            ]
+        }
+    });
+    // templateData can also arrive as an encoded JSON string
+    for data in [data.clone(), json!(data.to_string())] {
+        let template = IssueTemplate::from_linear(&linear_template("Bug", data)).unwrap();
+        assert_eq!(template.source, TemplateSource::Linear { id: "tmpl-bug".to_string() });
+        assert_eq!(template.title.as_deref(), Some("Bug report"));
+        assert_eq!(template.team.as_deref(), Some("team-1"));
+        assert_eq!(template.labels, vec!["label-1"]);
+        assert_eq!(template.priority, Some(2));
+        assert_eq!(template.description.as_deref(), Some("## Steps\n\n- Open app\n- Crash"));
+    }
+
+    let mut project = linear_template("Roadmap", json!({}));
+    project.template_type = "project".to_string();
+    assert!(IssueTemplate::from_linear(&project).is_none());
+}
+
+#[test]
+fn test_prosemirror_to_markdown_code_and_quote() {
+    let doc = json!({ "type": "doc", "content": [
+        { "type": "codeBlock", "content": [{ "type": "text", "text": "cargo test" }] },
+        { "type": "blockquote", "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "note" }] }] }
+    ] });
+    assert_eq!(prosemirror_to_markdown(&doc), "```\ncargo test\n```\n\n> note");
+}
+
+#[test]
+fn test_find_template_prefers_local() {
+    let local = parse_template(Path::new("bug-report.md"), "local body").unwrap();
+    let linear = IssueTemplate::from_linear(&linear_template("Bug Report", json!({}))).unwrap();
+    let feature = IssueTemplate::from_linear(&linear_template("Feature", json!({}))).unwrap();
+    let templates = vec![linear, local, feature];
+
+    assert!(find_template(&templates, "Bug report").unwrap().is_local());
+    assert_eq!(find_template(&templates, "feature").unwrap().name, "Feature");
+    assert!(matches!(find_template(&templates, "chore"), Err(LinearError::InvalidInput(_))));
+
+    let twins = vec![
+        IssueTemplate::from_linear(&linear_template("Bug", json!({}))).unwrap(),
+        IssueTemplate::from_linear(&linear_template("bug", json!({}))).unwrap(),
+    ];
+    assert!(matches!(find_template(&twins, "bug"), Err(LinearError::Ambiguous { .. })));
+}
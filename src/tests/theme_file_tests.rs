@@ -0,0 +1,73 @@
+use colored::Color;
+
+use crate::formatting::theme::{parse_color, to_tui_color, ColorMode, ColorTheme, SemanticColor, PRESETS};
+
+#[test]
+fn test_presets_are_complete() {
+    for name in PRESETS {
+        assert!(ColorTheme::preset(name).is_some(), "missing preset {}", name);
+    }
+    assert_eq!(ColorTheme::preset("light").unwrap().get(SemanticColor::Primary), Color::Black);
+    assert_eq!(ColorTheme::preset("dark").unwrap().get(SemanticColor::Primary), Color::BrightWhite);
+    assert_eq!(ColorTheme::preset("monochrome").unwrap().get(SemanticColor::StatusCompleted), Color::White);
+    assert!(ColorTheme::preset("solarized").is_none());
+}
+
+#[test]
+fn test_theme_from_toml() {
+    let theme = ColorTheme::from_toml(
+        r##"
+        # Light, with a purple accent
+        extends = "light"
+
+        [colors]
+        accent = "#5e6ad2"   # Linear purple
+        status-started = "bright-yellow"
+        "priority-urgent" = 'dark gray'
+        "##,
+    )
+    .unwrap();
+    assert_eq!(theme.get(SemanticColor::Accent), Color::TrueColor { r: 0x5e, g: 0x6a, b: 0xd2 });
+    assert_eq!(theme.get(SemanticColor::StatusStarted), Color::BrightYellow);
+    assert_eq!(theme.get(SemanticColor::PriorityUrgent), Color::BrightBlack);
+    // Untouched colors come from the preset it extends
+    assert_eq!(theme.get(SemanticColor::Primary), Color::Black);
+
+    assert!(ColorTheme::from_toml("[colours]\naccent = \"red\"").is_err());
+    assert!(ColorTheme::from_toml("[colors]\naccent = red").is_err());
+    assert!(ColorTheme::from_toml("accent = \"red\"").is_err());
+    assert!(ColorTheme::from_toml("[colors]\nshiny = \"red\"").is_err());
+    assert!(ColorTheme::from_toml("[colors]\naccent = \"#12345\"").is_err());
+    assert!(ColorTheme::from_toml("extends = \"solarized\"").is_err());
+}
+
+#[test]
+fn test_theme_from_json() {
+    let theme = ColorTheme::from_json(r##"{"colors": {"link": "#00ff00", "muted": "white"}}"##).unwrap();
+    assert_eq!(theme.get(SemanticColor::Link), Color::TrueColor { r: 0, g: 255, b: 0 });
+    assert_eq!(theme.get(SemanticColor::Muted), Color::White);
+    // Without `extends` a theme starts from dark
+    assert_eq!(theme.get(SemanticColor::StatusCompleted), Color::Green);
+
+    assert!(ColorTheme::from_json(r#"{"colors": {"link": "plaid"}}"#).is_err());
+    assert!(ColorTheme::from_json(r#"{"colours": {}}"#).is_err());
+}
+
+#[test]
+fn test_colors_and_modes() {
+    assert_eq!(parse_color("Bright_Blue"), Some(Color::BrightBlue));
+    assert_eq!(parse_color("grey"), Some(Color::BrightBlack));
+    assert_eq!(parse_color("light-gray"), Some(Color::White));
+    assert_eq!(to_tui_color(parse_color("gray").unwrap()), ratatui::style::Color::DarkGray);
+    assert_eq!(to_tui_color(parse_color("light gray").unwrap()), ratatui::style::Color::Gray);
+    assert_eq!(parse_color("#FFA500"), Some(Color::TrueColor { r: 255, g: 165, b: 0 }));
+    assert_eq!(parse_color("#ggg000"), None);
+
+    assert_eq!(to_tui_color(Color::White), ratatui::style::Color::Gray);
+    assert_eq!(to_tui_color(Color::BrightWhite), ratatui::style::Color::White);
+    assert_eq!(to_tui_color(Color::BrightBlack), ratatui::style::Color::DarkGray);
+    assert_eq!(to_tui_color(Color::TrueColor { r: 1, g: 2, b: 3 }), ratatui::style::Color::Rgb(1, 2, 3));
+
+    assert_eq!(ColorMode::from_name("NEVER"), Some(ColorMode::Never));
+    assert_eq!(ColorMode::from_name("sometimes"), None);
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Datelike, Local, TimeZone, Timelike, Utc, Weekday};
+
+use crate::timer::{format_duration, summarize, week_bounds, Journal, Session};
+
+fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 5, 6, hour, minute, 0).unwrap()
+}
+
+fn session(identifier: &str, project: Option<&str>, start: DateTime<Utc>, end: Option<DateTime<Utc>>) -> Session {
+    Session {
+        issue_id: format!("id-{}", identifier),
+        identifier: identifier.to_string(),
+        title: format!("Work on {}", identifier),
+        project: project.map(str::to_string),
+        started_at: start,
+        ended_at: end,
+    }
+}
+
+#[test]
+fn test_journal_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("linear-cli").join("timer.jsonl");
+
+    let journal = Journal {
+        sessions: vec![
+            session("ENG-1", Some("Platform"), at(9, 0), Some(at(10, 30))),
+            session("ENG-2", None, at(11, 0), None),
+        ],
+    };
+    journal.save_to(&path).unwrap();
+
+    let loaded = Journal::load_from(&path).unwrap();
+    assert_eq!(loaded.sessions, journal.sessions);
+    assert_eq!(loaded.running().unwrap().identifier, "ENG-2");
+    assert!(Journal::load_from(&dir.path().join("missing.jsonl")).unwrap().sessions.is_empty());
+}
+
+#[test]
+fn test_journal_reports_bad_lines() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("timer.jsonl");
+    std::fs::write(&path, "{\"issue_id\":\"x\"}\n").unwrap();
+    let err = Journal::load_from(&path).unwrap_err().to_string();
+    assert!(err.contains("line 1"), "{}", err);
+}
+
+#[test]
+fn test_stop_closes_only_running_session() {
+    let mut journal = Journal {
+        sessions: vec![session("ENG-1", None, at(9, 0), None)],
+    };
+    let stopped = journal.stop(at(9, 45)).unwrap();
+    assert_eq!(stopped.seconds(at(12, 0)), 45 * 60);
+    assert!(journal.running().is_none());
+    assert!(journal.stop(at(10, 0)).is_none());
+}
+
+#[test]
+fn test_summarize_clips_to_range_and_groups_projects() {
+    let sessions = vec![
+        // Starts before the range: only 30 minutes count
+        session("ENG-1", Some("Platform"), at(8, 30), Some(at(9, 30))),
+        session("ENG-1", Some("Platform"), at(10, 0), Some(at(11, 0))),
+        session("ENG-2", None, at(11, 0), Some(at(11, 20))),
+        // Still running: counts up to now
+        session("ENG-3", Some("Platform"), at(12, 0), None),
+        // Entirely outside the range
+        session("ENG-4", Some("Billing"), at(6, 0), Some(at(7, 0))),
+    ];
+
+    let (issues, projects) = summarize(&sessions, at(9, 0), at(18, 0), at(12, 15));
+
+    let issue_totals: Vec<(&str, i64)> = issues.iter().map(|r| (r.key.as_str(), r.seconds / 60)).collect();
+    assert_eq!(issue_totals, vec![("ENG-1", 90), ("ENG-2", 20), ("ENG-3", 15)]);
+
+    let project_totals: Vec<(&str, i64)> = projects.iter().map(|r| (r.key.as_str(), r.seconds / 60)).collect();
+    assert_eq!(project_totals, vec![("Platform", 105), ("No project", 20)]);
+}
+
+#[test]
+fn test_week_bounds_start_on_monday() {
+    let thursday = Local.with_ymd_and_hms(2024, 5, 9, 15, 0, 0).unwrap();
+    let (start, end) = week_bounds(thursday, 0);
+    let start = start.with_timezone(&Local);
+    assert_eq!(start.weekday(), Weekday::Mon);
+    assert_eq!(start.day(), 6);
+    assert_eq!(start.hour(), 0);
+    assert_eq!(end.with_timezone(&Local).day(), 13);
+
+    let (previous, _) = week_bounds(thursday, 1);
+    assert_eq!(previous.with_timezone(&Local).day(), 29);
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(0), "0m");
+    assert_eq!(format_duration(59 * 60 + 59), "59m");
+    assert_eq!(format_duration(65 * 60), "1h 05m");
+    assert_eq!(format_duration(26 * 3600), "26h 00m");
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use crate::filtering::{bind, builtin_values, check_search, check_values, variables, CycleDates, Severity, VariableError, Vocabulary};
+use crate::models::{Cycle, Team, User};
+
+fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+}
+
+fn team(key: &str) -> Team {
+    Team { id: format!("team-{}", key), name: key.to_string(), key: key.to_string() }
+}
+
+fn cycle(key: &str, number: f64, active: bool) -> Cycle {
+    Cycle {
+        id: format!("{}-{}", key, number),
+        number,
+        name: None,
+        starts_at: "2026-10-05T00:00:00Z".to_string(),
+        ends_at: "2026-10-19T00:00:00Z".to_string(),
+        is_active: active,
+        is_previous: !active,
+        is_next: false,
+        team: team(key),
+    }
+}
+
+#[test]
+fn test_finds_placeholders() {
+    let found = variables("assignee:$me AND team:${team} AND label in ${labels:-bug,ui}").unwrap();
+    let names: Vec<_> = found.iter().map(|v| (v.name.as_str(), v.default.as_deref())).collect();
+    assert_eq!(names, vec![("me", None), ("team", None), ("labels", Some("bug,ui"))]);
+    assert!(found[0].is_builtin() && !found[2].is_builtin());
+    assert_eq!(found[1].span, 22..29);
+    assert_eq!(found[2].display(), "${labels:-bug,ui}");
+
+    // The ends-with operator isn't a variable
+    assert!(variables("title$fix OR title:\"costs $5\"").unwrap().is_empty());
+
+    assert_eq!(variables("label:${label"), Err(VariableError::Unterminated(6)));
+    assert_eq!(variables("label:${1abel}"), Err(VariableError::InvalidName("1abel".to_string())));
+}
+
+#[test]
+fn test_bind_fills_values_and_defaults() {
+    let query = "label:${label:-bug} AND team:$team AND priority>=${level:-high}";
+    assert_eq!(
+        bind(query, &values(&[("team", "ENG"), ("label", "backend")])).unwrap(),
+        "label:backend AND team:ENG AND priority>=high"
+    );
+
+    // Values are quoted where needed, lists stay lists, quoted placeholders are escaped
+    assert_eq!(bind("status:${state}", &values(&[("state", "In Review")])).unwrap(), "status:\"In Review\"");
+    assert_eq!(bind("label in ${labels}", &values(&[("labels", "bug, Needs triage")])).unwrap(), "label in bug,\"Needs triage\"");
+    assert_eq!(bind("title:\"${word} fix\"", &values(&[("word", "say \"hi\"")])).unwrap(), "title:\"say \\\"hi\\\" fix\"");
+}
+
+#[test]
+fn test_bind_reports_missing_values() {
+    let error = bind("label:${label} AND project:${project} AND label:${label}", &HashMap::new()).unwrap_err();
+    assert_eq!(error, VariableError::Missing(vec!["label".to_string(), "project".to_string()]));
+    assert!(error.to_string().contains("$label, $project"));
+
+    assert_eq!(check_values("label:${label}", &values(&[("lable", "bug")])), Err(VariableError::Unknown("lable".to_string())));
+    assert!(check_values("label:${label}", &values(&[("label", "bug")])).is_ok());
+}
+
+#[test]
+fn test_builtin_values() {
+    let viewer = User { id: "u1".to_string(), name: "Ada".to_string(), email: "ada@example.com".to_string(), display_name: None };
+    let cycles = vec![cycle("OPS", 7.0, true), cycle("ENG", 41.0, false), cycle("ENG", 42.0, true)];
+
+    let values = builtin_values(Some(&viewer), Some(&team("ENG")), &cycles).unwrap();
+    assert_eq!(values.get("me").map(String::as_str), Some("ada@example.com"));
+    assert_eq!(values.get("team").map(String::as_str), Some("ENG"));
+    assert_eq!(values.get("cycle").map(String::as_str), Some("42"));
+
+    // Without a team, the only team with a current cycle, as for `cycle:current`
+    let values = builtin_values(None, None, &cycles[1..]).unwrap();
+    assert_eq!(values.get("cycle").map(String::as_str), Some("42"));
+    assert!(!values.contains_key("me") && !values.contains_key("team"));
+    let error = builtin_values(None, None, &cycles).unwrap_err();
+    assert_eq!(error, CycleDates::from_cycles(&cycles, None).unwrap_err());
+    assert!(error.contains("ENG, OPS"));
+}
+
+#[test]
+fn test_check_search() {
+    let vocabulary = Vocabulary { labels: vec!["bug".to_string()], ..Default::default() };
+
+    // Stand-ins fit the field and aren't warned about
+    for query in ["label:${label} AND priority>=${level} AND created:${since}", "assignee:$me AND cycle:$cycle"] {
+        assert!(check_search(query, &vocabulary).is_empty(), "{}: {:?}", query, check_search(query, &vocabulary));
+    }
+
+    // Problems point into the query as written
+    let query = "label:${label} AND asignee:me";
+    let diagnostics = check_search(query, &vocabulary);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].span, 19..26);
+
+    // A default is checked like any other value
+    let query = "label:${label:-bugg}";
+    let diagnostics = check_search(query, &vocabulary);
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_eq!(diagnostics[0].span, 6..20);
+
+    let diagnostics = check_search("label:${label", &vocabulary);
+    assert!(diagnostics[0].is_error());
+    assert_eq!(diagnostics[0].span, 6..13);
+}
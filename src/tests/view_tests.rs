@@ -0,0 +1,91 @@
+use serde_json::json;
+
+use crate::filtering::{graphql_to_query, Calendar, FilterAdapter};
+use crate::models::CustomView;
+
+/// Compiling a query, decompiling it and compiling again must give the same filter.
+fn assert_round_trip(query: &str) {
+    let filter = FilterAdapter::parse_and_build(query, &Calendar::default()).unwrap();
+    let decompiled = graphql_to_query(&filter)
+        .unwrap_or_else(|e| panic!("could not decompile {}: {}", query, e));
+    let recompiled = FilterAdapter::parse_and_build(&decompiled, &Calendar::default())
+        .unwrap_or_else(|e| panic!("could not parse '{}' (from '{}'): {}", decompiled, query, e));
+    assert_eq!(filter, recompiled, "'{}' came back as '{}'", query, decompiled);
+}
+
+#[test]
+fn test_filter_round_trips_through_query_syntax() {
+    for query in [
+        "status:completed",
+        "status!=\"In Progress\"",
+        "status in:backlog,unstarted",
+        "priority>2",
+        "priority<=1",
+        "assignee=ada@example.com",
+        "assignee:null",
+        "label:\"Needs triage\"",
+        "has-label:bug,urgent",
+        "no-label",
+        "project:\"Mobile App\"",
+        "project!=null",
+        "title~\"bug fix\"",
+        "title^=Feature",
+        "created>7d",
+        "updated>-P2W",
+        "status:started OR status:unstarted",
+        "(priority>2 OR label:urgent) AND status!=completed",
+        "NOT (priority<2 OR assignee:null)",
+        "label:bug AND label:urgent",
+        "label!=bug",
+        "NOT has-label:bug,wontfix",
+        "NOT status in:Done,Canceled",
+        "title!~wip",
+    ] {
+        assert_round_trip(query);
+    }
+}
+
+#[test]
+fn test_graphql_to_query_linear_view_filters() {
+    let filter = json!({
+        "and": [
+            { "state": { "name": { "in": ["Todo", "In Progress"] } } },
+            { "or": [
+                { "priority": { "lte": 2 } },
+                { "labels": { "some": { "name": { "eq": "Bug" } } } }
+            ] }
+        ],
+        "updatedAt": { "gt": "-P2W" }
+    });
+    assert_eq!(
+        graphql_to_query(&filter).unwrap(),
+        "status in:Todo,\"In Progress\" AND (priority<=2 OR label:Bug) AND updated>-P2W"
+    );
+}
+
+#[test]
+fn test_graphql_to_query_rejects_unsupported_fields() {
+    let by_cycle = json!({ "cycle": { "id": { "eq": "cycle-1" } } });
+    assert!(graphql_to_query(&by_cycle).unwrap_err().contains("cycle"));
+
+    let by_state_type = json!({ "state": { "type": { "eq": "started" } } });
+    assert!(graphql_to_query(&by_state_type).is_err());
+
+    assert!(graphql_to_query(&json!({})).is_err());
+}
+
+#[test]
+fn test_custom_view_deserialize() {
+    let view: CustomView = serde_json::from_value(json!({
+        "id": "view-1",
+        "name": "My bugs",
+        "description": null,
+        "filterData": { "labels": { "some": { "name": { "eq": "Bug" } } } },
+        "shared": true,
+        "updatedAt": "2024-05-01T10:00:00.000Z",
+        "team": null
+    }))
+    .unwrap();
+    assert!(view.shared);
+    assert_eq!(graphql_to_query(&view.filter_data).unwrap(), "label:Bug");
+}
@@ -0,0 +1,162 @@
+pub mod timesheet;
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::TIMER_JOURNAL;
+use crate::error::{LinearError, LinearResult};
+use crate::models::Issue;
+
+#[allow(unused_imports)]
+pub use timesheet::{format_duration, summarize, week_bounds, TimesheetRow};
+
+/// One stretch of work on an issue. The issue's identifier, title and project are
+/// copied in so timesheets don't need the API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub issue_id: String,
+    pub identifier: String,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(with = "rfc3339")]
+    pub started_at: DateTime<Utc>,
+    #[serde(default, with = "rfc3339_opt", skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+mod rfc3339 {
+    use chrono::{DateTime, Utc};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(at: &DateTime<Utc>, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&at.to_rfc3339())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<DateTime<Utc>, D::Error> {
+        let raw = String::deserialize(d)?;
+        DateTime::parse_from_rfc3339(&raw)
+            .map(|at| at.with_timezone(&Utc))
+            .map_err(D::Error::custom)
+    }
+}
+
+mod rfc3339_opt {
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(at: &Option<DateTime<Utc>>, s: S) -> Result<S::Ok, S::Error> {
+        match at {
+            Some(at) => super::rfc3339::serialize(at, s),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<DateTime<Utc>>, D::Error> {
+        #[derive(Deserialize)]
+        struct Wrapped(#[serde(with = "super::rfc3339")] DateTime<Utc>);
+        Ok(Option::<Wrapped>::deserialize(d)?.map(|Wrapped(at)| at))
+    }
+}
+
+impl Session {
+    pub fn start(issue: &Issue, at: DateTime<Utc>) -> Self {
+        Self {
+            issue_id: issue.id.clone(),
+            identifier: issue.identifier.clone(),
+            title: issue.title.clone(),
+            project: issue.project.as_ref().map(|p| p.name.clone()),
+            started_at: at,
+            ended_at: None,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// Seconds worked, counting a running session up to `now`.
+    pub fn seconds(&self, now: DateTime<Utc>) -> i64 {
+        (self.ended_at.unwrap_or(now) - self.started_at).num_seconds().max(0)
+    }
+}
+
+/// The local record of timer sessions, stored as one JSON object per line in
+/// `linear-cli/timer.jsonl` under the config directory. At most one session, the
+/// last, is running.
+#[derive(Debug, Default)]
+pub struct Journal {
+    pub sessions: Vec<Session>,
+}
+
+impl Journal {
+    pub fn path() -> LinearResult<PathBuf> {
+        dirs::config_dir()
+            .map(|dir| dir.join(TIMER_JOURNAL))
+            .ok_or_else(|| LinearError::ConfigError("Could not find config directory".to_string()))
+    }
+
+    pub fn load() -> LinearResult<Self> {
+        Self::load_from(&Self::path()?)
+    }
+
+    pub fn save(&self) -> LinearResult<()> {
+        self.save_to(&Self::path()?)
+    }
+
+    pub fn load_from(path: &Path) -> LinearResult<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)?;
+        let sessions = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line).map_err(|e| {
+                    LinearError::ParseError(format!("{} line {}: {}", path.display(), i + 1, e))
+                })
+            })
+            .collect::<LinearResult<Vec<Session>>>()?;
+        Ok(Self { sessions })
+    }
+
+    /// Write the whole journal to a temporary file next to `path`, then move it
+    /// into place so an interrupted write can't truncate the history.
+    pub fn save_to(&self, path: &Path) -> LinearResult<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp = path.with_extension("jsonl.tmp");
+        let mut file = fs::File::create(&tmp)?;
+        for session in &self.sessions {
+            writeln!(file, "{}", serde_json::to_string(session)?)?;
+        }
+        file.sync_all()?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    pub fn running(&self) -> Option<&Session> {
+        self.sessions.last().filter(|s| s.is_running())
+    }
+
+    /// Stop the running session, if any, and return it.
+    pub fn stop(&mut self, at: DateTime<Utc>) -> Option<Session> {
+        let session = self.sessions.last_mut().filter(|s| s.is_running())?;
+        session.ended_at = Some(at.max(session.started_at));
+        Some(session.clone())
+    }
+
+    /// Start timing `issue`, stopping whatever was running. Returns the stopped session.
+    pub fn start(&mut self, issue: &Issue, at: DateTime<Utc>) -> Option<Session> {
+        let stopped = self.stop(at);
+        self.sessions.push(Session::start(issue, at));
+        stopped
+    }
+}
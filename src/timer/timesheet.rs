@@ -0,0 +1,76 @@
+use chrono::{DateTime, Datelike, Duration, Local, NaiveTime, TimeZone, Utc};
+
+use super::Session;
+
+/// Total time for one issue or project within a timesheet range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimesheetRow {
+    pub key: String,
+    pub label: String,
+    pub seconds: i64,
+}
+
+/// Monday 00:00 to the following Monday 00:00, local time, of the week containing
+/// `now`. `weeks_back` moves to earlier weeks.
+pub fn week_bounds(now: DateTime<Local>, weeks_back: i64) -> (DateTime<Utc>, DateTime<Utc>) {
+    let monday = now.date_naive()
+        - Duration::days(now.weekday().num_days_from_monday() as i64)
+        - Duration::weeks(weeks_back);
+    let start = Local
+        .from_local_datetime(&monday.and_time(NaiveTime::MIN))
+        .earliest()
+        .unwrap_or(now);
+    let start = start.with_timezone(&Utc);
+    (start, start + Duration::weeks(1))
+}
+
+/// Seconds of `session` that fall inside `[from, to)`, counting a running session up to `now`.
+fn overlap(session: &Session, from: DateTime<Utc>, to: DateTime<Utc>, now: DateTime<Utc>) -> i64 {
+    let start = session.started_at.max(from);
+    let end = session.ended_at.unwrap_or(now).min(to);
+    (end - start).num_seconds().max(0)
+}
+
+/// Per-issue and per-project totals for sessions overlapping `[from, to)`, largest
+/// first. Sessions that cross a boundary only count the part inside the range.
+pub fn summarize(
+    sessions: &[Session],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> (Vec<TimesheetRow>, Vec<TimesheetRow>) {
+    let mut issues: Vec<TimesheetRow> = Vec::new();
+    let mut projects: Vec<TimesheetRow> = Vec::new();
+
+    let add = |rows: &mut Vec<TimesheetRow>, key: &str, label: &str, seconds: i64| {
+        match rows.iter_mut().find(|r| r.key == key) {
+            Some(row) => row.seconds += seconds,
+            None => rows.push(TimesheetRow { key: key.to_string(), label: label.to_string(), seconds }),
+        }
+    };
+
+    for session in sessions {
+        let seconds = overlap(session, from, to, now);
+        if seconds == 0 {
+            continue;
+        }
+        add(&mut issues, &session.identifier, &session.title, seconds);
+        let project = session.project.as_deref().unwrap_or("No project");
+        add(&mut projects, project, project, seconds);
+    }
+
+    for rows in [&mut issues, &mut projects] {
+        rows.sort_by(|a, b| b.seconds.cmp(&a.seconds).then_with(|| a.key.cmp(&b.key)));
+    }
+    (issues, projects)
+}
+
+/// `1h 05m`, or `12m` under an hour.
+pub fn format_duration(seconds: i64) -> String {
+    let minutes = seconds.max(0) / 60;
+    if minutes >= 60 {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}